@@ -30,34 +30,46 @@
 
 #[cfg(feature = "contradiction")]
 use kronroe::{ConflictPolicy, Contradiction};
-use kronroe::{Fact, FactId, KronroeSpan, KronroeTimestamp, TemporalGraph, Value};
+use kronroe::{
+    Fact, FactId, KronroeSpan, KronroeTimestamp, SearchExplanation, TemporalGraph, Value,
+};
 #[cfg(feature = "hybrid")]
-use kronroe::{HybridScoreBreakdown, HybridSearchParams, TemporalIntent, TemporalOperator};
-use std::collections::HashSet;
+use kronroe::{
+    FusionStrategy, HybridScoreBreakdown, HybridSearchParams, Reranker, Stage1Decision,
+    TemporalIntent, TemporalOperator, TemporalQuery,
+};
+use std::collections::{BTreeMap, HashSet};
 
 pub use kronroe::KronroeError as Error;
 pub type Result<T> = std::result::Result<T, Error>;
 
+#[cfg(feature = "hybrid")]
+mod embedder;
+#[cfg(feature = "hybrid")]
+use embedder::CachedEmbedder;
+#[cfg(feature = "hybrid")]
+pub use embedder::{Embedder, HashingEmbedder, DEFAULT_EMBEDDING_CACHE_CAPACITY};
+
 // ---------------------------------------------------------------------------
 // Explainable recall
 // ---------------------------------------------------------------------------
 
 /// Per-channel signal breakdown for a recalled fact.
 ///
-/// These are the *input signals* that the retrieval engine used to rank
-/// results — they explain what each channel contributed, not the final
-/// composite ranking score. The result ordering in `recall_scored()` is
-/// the authoritative ranking; inspect these fields to understand *why*
-/// a given channel dominated or was weak for a particular fact.
+/// These are the signals that the retrieval engine used to rank results —
+/// what each channel contributed and, for hybrid results, the temporal
+/// adjustment and composite rerank score that set the final order. The
+/// result ordering in `recall_scored()` is the authoritative ranking;
+/// inspect these fields to understand *why* a fact ranked where it did.
 ///
 /// Every variant includes `confidence` — the fact-level confidence score
 /// from the underlying [`Fact`] (default 1.0). This lets callers weight
 /// or filter results by trustworthiness alongside retrieval signals.
 ///
 /// The variant indicates which retrieval path produced the result:
-/// - [`Hybrid`] — RRF fusion input signals (text + vector channels).
-///   The engine's two-stage reranker uses these as inputs alongside
-///   temporal feasibility to determine final ordering.
+/// - [`Hybrid`] — RRF fusion input signals (text + vector channels),
+///   the temporal feasibility signal, and the `rerank_score` that
+///   determined final ordering.
 /// - [`TextOnly`] — fulltext search with BM25 relevance score.
 ///
 /// [`Hybrid`]: RecallScore::Hybrid
@@ -69,9 +81,9 @@ pub enum RecallScore {
     /// Input signals from hybrid retrieval (text + vector channels).
     ///
     /// The `rrf_score` is the pre-rerank RRF fusion score (sum of
-    /// text + vector contributions). Final result ordering may differ
-    /// because the two-stage reranker applies adaptive weighting and
-    /// temporal feasibility filtering on top of these signals.
+    /// text + vector contributions). Results are ordered by `rerank_score`,
+    /// which the two-stage reranker builds from adaptive channel weighting
+    /// and `temporal_adjustment`.
     #[non_exhaustive]
     Hybrid {
         /// Pre-rerank RRF fusion score (text + vector sum).
@@ -80,6 +92,17 @@ pub enum RecallScore {
         text_contrib: f64,
         /// Vector-channel contribution from weighted RRF.
         vector_contrib: f64,
+        /// Intent-weighted temporal signal added to `rerank_score`
+        /// (`0.0` for timeless queries).
+        temporal_adjustment: f64,
+        /// Temporal feasibility of the fact for the query: positive when it
+        /// fits the temporal constraint (`0.0` for timeless queries).
+        temporal_signal: f64,
+        /// Composite score that determined the result order.
+        rerank_score: f64,
+        /// Stage-1 pruning decision of the two-stage reranker.
+        #[cfg(feature = "hybrid")]
+        stage1: Stage1Decision,
         /// Fact-level confidence \[0.0, 1.0\] from the stored fact.
         confidence: f32,
         /// Effective confidence after uncertainty model (age decay × source weight).
@@ -154,6 +177,10 @@ impl RecallScore {
             rrf_score: b.final_score,
             text_contrib: b.text_rrf_contrib,
             vector_contrib: b.vector_rrf_contrib,
+            temporal_adjustment: b.temporal_adjustment,
+            temporal_signal: b.temporal_signal,
+            rerank_score: b.rerank_score,
+            stage1: b.stage1,
             confidence,
             effective_confidence,
         }
@@ -219,6 +246,20 @@ pub struct RecallOptions<'a> {
     /// Temporal operator used when intent is [`TemporalIntent::HistoricalPoint`].
     #[cfg(feature = "hybrid")]
     pub temporal_operator: TemporalOperator,
+    /// How hybrid retrieval fuses the text and vector channels.
+    #[cfg(feature = "hybrid")]
+    pub fusion: FusionStrategy,
+    /// Anchor time for historical intents (default: now).
+    #[cfg(feature = "hybrid")]
+    pub query_time: Option<KronroeTimestamp>,
+    /// Valid-time interval `[start, end)` for historical intents
+    /// (see [`HybridSearchParams::valid_interval`]).
+    #[cfg(feature = "hybrid")]
+    pub valid_interval: Option<(KronroeTimestamp, KronroeTimestamp)>,
+    /// Only recall facts the store held at this time
+    /// (see [`HybridSearchParams::known_at`]).
+    #[cfg(feature = "hybrid")]
+    pub known_at: Option<KronroeTimestamp>,
 }
 
 const DEFAULT_MAX_SCORED_ROWS: usize = 4_096;
@@ -239,6 +280,14 @@ impl<'a> RecallOptions<'a> {
             temporal_intent: TemporalIntent::Timeless,
             #[cfg(feature = "hybrid")]
             temporal_operator: TemporalOperator::Current,
+            #[cfg(feature = "hybrid")]
+            fusion: FusionStrategy::TwoStageRerank,
+            #[cfg(feature = "hybrid")]
+            query_time: None,
+            #[cfg(feature = "hybrid")]
+            valid_interval: None,
+            #[cfg(feature = "hybrid")]
+            known_at: None,
         }
     }
 
@@ -311,6 +360,65 @@ impl<'a> RecallOptions<'a> {
         self.temporal_operator = operator;
         self
     }
+
+    /// Choose how hybrid retrieval fuses the text and vector channels
+    /// (default: [`FusionStrategy::TwoStageRerank`]).
+    ///
+    /// No effect without the `hybrid` feature.
+    #[cfg(feature = "hybrid")]
+    pub fn with_fusion(mut self, fusion: FusionStrategy) -> Self {
+        self.fusion = fusion;
+        self
+    }
+
+    /// Anchor historical intents at `at` instead of now.
+    ///
+    /// No effect without the `hybrid` feature.
+    #[cfg(feature = "hybrid")]
+    pub fn with_query_time(mut self, at: KronroeTimestamp) -> Self {
+        self.query_time = Some(at);
+        self
+    }
+
+    /// Restrict historical intents to the valid-time interval
+    /// `[start, end)` instead of a single query time.
+    ///
+    /// No effect without the `hybrid` feature.
+    #[cfg(feature = "hybrid")]
+    pub fn with_valid_interval(mut self, start: KronroeTimestamp, end: KronroeTimestamp) -> Self {
+        self.valid_interval = Some((start, end));
+        self
+    }
+
+    /// Only recall facts the store held at `at` (transaction time).
+    ///
+    /// No effect without the `hybrid` feature.
+    #[cfg(feature = "hybrid")]
+    pub fn with_known_at(mut self, at: KronroeTimestamp) -> Self {
+        self.known_at = Some(at);
+        self
+    }
+
+    /// Apply a temporal constraint detected by
+    /// [`AgentMemory::detect_temporal`]: its intent, operator and anchor
+    /// time, plus its period as the valid-time interval for
+    /// [`TemporalIntent::HistoricalInterval`]. A timeless detection leaves
+    /// the options unchanged.
+    ///
+    /// No effect without the `hybrid` feature.
+    #[cfg(feature = "hybrid")]
+    pub fn with_detected_temporal(mut self, detected: &TemporalQuery) -> Self {
+        if detected.is_temporal() {
+            self.temporal_intent = detected.intent;
+            self.temporal_operator = detected.operator;
+            self.query_time = detected.at;
+            self.valid_interval = match detected.intent {
+                TemporalIntent::HistoricalInterval => detected.interval,
+                _ => None,
+            };
+        }
+        self
+    }
 }
 
 fn normalize_min_confidence(min_confidence: f32) -> Result<f32> {
@@ -338,11 +446,51 @@ fn normalize_fact_confidence(confidence: f32) -> Result<f32> {
 /// It wraps [`TemporalGraph`] with an API designed for agent use cases.
 pub struct AgentMemory {
     graph: TemporalGraph,
+    #[cfg(feature = "hybrid")]
+    embedder: Option<CachedEmbedder>,
+    #[cfg(feature = "hybrid")]
+    reranker: Option<std::sync::Arc<dyn Reranker>>,
 }
 
+/// Per-write options for the `*_with_params` assert methods.
 #[derive(Debug, Clone)]
 pub struct AssertParams {
     pub valid_from: KronroeTimestamp,
+    /// Qualifiers stored on the fact (see [`Fact::metadata`]).
+    pub metadata: BTreeMap<String, Value>,
+}
+
+impl AssertParams {
+    /// Params with the given valid-time start and no metadata.
+    pub fn new(valid_from: KronroeTimestamp) -> Self {
+        Self {
+            valid_from,
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    /// Attach one metadata qualifier (e.g. `"model"`, `"turn"`, `"locale"`).
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    fn into_fact(
+        self,
+        subject: &str,
+        predicate: &str,
+        object: Value,
+        confidence: f32,
+        source: Option<&str>,
+    ) -> Fact {
+        let mut fact =
+            Fact::new(subject, predicate, object, self.valid_from).with_confidence(confidence);
+        if let Some(source) = source {
+            fact = fact.with_source(source);
+        }
+        fact.metadata = self.metadata;
+        fact
+    }
 }
 
 /// A paired correction event linking the invalidated fact and its replacement.
@@ -412,6 +560,16 @@ pub fn is_high_impact_predicate(predicate: &str) -> bool {
 
 const CORRECTION_LINK_TOLERANCE_SECONDS: i64 = 2;
 
+/// Text a fact is embedded from: a remembered episode's text, otherwise
+/// `"subject predicate object"` with the predicate's underscores as spaces.
+#[cfg(feature = "hybrid")]
+fn embedding_text(subject: &str, predicate: &str, object: &Value) -> String {
+    if predicate == "memory" {
+        return object.to_string();
+    }
+    format!("{subject} {} {object}", predicate.replace('_', " "))
+}
+
 impl AgentMemory {
     /// Open or create an agent memory store at the given path.
     ///
@@ -420,24 +578,160 @@ impl AgentMemory {
     /// let memory = AgentMemory::open("./my-agent.kronroe").unwrap();
     /// ```
     pub fn open(path: &str) -> Result<Self> {
-        let graph = TemporalGraph::open(path)?;
-        #[cfg(feature = "contradiction")]
-        Self::register_default_singletons(&graph)?;
-        #[cfg(feature = "uncertainty")]
-        Self::register_default_volatilities(&graph)?;
-        Ok(Self { graph })
+        Self::from_graph(TemporalGraph::open(path)?)
+    }
+
+    /// Open or create a store with custom full-text settings (tokenizer and
+    /// index checkpoint).
+    ///
+    /// ```rust,no_run
+    /// use kronroe::{SearchConfig, StandardTokenizer};
+    /// use kronroe_agent_memory::AgentMemory;
+    /// let config = SearchConfig::new().with_tokenizer(StandardTokenizer::english());
+    /// let memory = AgentMemory::open_with_search_config("./my-agent.kronroe", config).unwrap();
+    /// ```
+    #[cfg(feature = "fulltext")]
+    pub fn open_with_search_config(path: &str, config: kronroe::SearchConfig) -> Result<Self> {
+        Self::from_graph(TemporalGraph::open_with_search_config(path, config)?)
     }
 
     /// Create an in-memory agent memory store.
     ///
     /// Useful for tests, WASM/browser bindings, and ephemeral workloads.
     pub fn open_in_memory() -> Result<Self> {
-        let graph = TemporalGraph::open_in_memory()?;
+        Self::from_graph(TemporalGraph::open_in_memory()?)
+    }
+
+    fn from_graph(graph: TemporalGraph) -> Result<Self> {
         #[cfg(feature = "contradiction")]
         Self::register_default_singletons(&graph)?;
         #[cfg(feature = "uncertainty")]
         Self::register_default_volatilities(&graph)?;
-        Ok(Self { graph })
+        Ok(Self {
+            graph,
+            #[cfg(feature = "hybrid")]
+            embedder: None,
+            #[cfg(feature = "hybrid")]
+            reranker: None,
+        })
+    }
+
+    /// Embed text automatically with `embedder`, caching up to
+    /// [`DEFAULT_EMBEDDING_CACHE_CAPACITY`] embeddings by text hash.
+    ///
+    /// Once configured, `remember*` and `assert*` attach an embedding to
+    /// every fact they write unless one is passed explicitly, and recall
+    /// methods run hybrid retrieval with an embedded query when no query
+    /// embedding is passed. The embedder's dimension must match the
+    /// embeddings already stored.
+    ///
+    /// ```rust
+    /// use kronroe_agent_memory::{AgentMemory, HashingEmbedder};
+    /// let memory = AgentMemory::open_in_memory()
+    ///     .unwrap()
+    ///     .with_embedder(HashingEmbedder::new(256));
+    /// ```
+    #[cfg(feature = "hybrid")]
+    pub fn with_embedder(self, embedder: impl Embedder + 'static) -> Self {
+        self.with_embedder_and_cache_capacity(embedder, DEFAULT_EMBEDDING_CACHE_CAPACITY)
+    }
+
+    /// [`with_embedder`](Self::with_embedder) with a cache of at most
+    /// `capacity` embeddings (`0` disables caching).
+    #[cfg(feature = "hybrid")]
+    pub fn with_embedder_and_cache_capacity(
+        mut self,
+        embedder: impl Embedder + 'static,
+        capacity: usize,
+    ) -> Self {
+        self.embedder = Some(CachedEmbedder::new(std::sync::Arc::new(embedder), capacity));
+        self
+    }
+
+    /// Rerank hybrid recall candidates with `reranker` instead of the
+    /// built-in [`IntentGatedReranker`](kronroe::IntentGatedReranker).
+    ///
+    /// Applies to every hybrid recall regardless of
+    /// [`RecallOptions::fusion`]; text-only recall is unaffected.
+    #[cfg(feature = "hybrid")]
+    pub fn with_reranker(mut self, reranker: impl Reranker + 'static) -> Self {
+        self.reranker = Some(std::sync::Arc::new(reranker));
+        self
+    }
+
+    /// Detect the temporal constraint in a recall query ("in 2023",
+    /// "last March", "before she moved", "currently").
+    ///
+    /// Pass the result to [`RecallOptions::with_detected_temporal`] and keep
+    /// it to audit what was inferred. See [`TemporalGraph::detect_temporal`].
+    ///
+    /// ```rust
+    /// use kronroe_agent_memory::{AgentMemory, RecallOptions};
+    /// let memory = AgentMemory::open_in_memory().unwrap();
+    /// let query = "where did alice work in 2023";
+    /// let detected = memory.detect_temporal(query).unwrap();
+    /// assert_eq!(detected.expression.as_deref(), Some("in 2023"));
+    /// let opts = RecallOptions::new(query).with_detected_temporal(&detected);
+    /// ```
+    #[cfg(feature = "hybrid")]
+    pub fn detect_temporal(&self, query: &str) -> Result<TemporalQuery> {
+        self.graph.detect_temporal(query, None)
+    }
+
+    /// Embedding for a fact about to be written, when an embedder is
+    /// configured. Computed before the write so an embedder failure leaves
+    /// nothing stored.
+    fn pending_embedding(&self, fact: &Fact) -> Result<Option<Vec<f32>>> {
+        #[cfg(feature = "hybrid")]
+        if let Some(embedder) = &self.embedder {
+            let text = embedding_text(&fact.subject, &fact.predicate, &fact.object);
+            return embedder.embed(&text).map(Some);
+        }
+        let _ = fact;
+        Ok(None)
+    }
+
+    /// Write `fact`, committing `embedding` (from
+    /// [`pending_embedding`](Self::pending_embedding)) together with it.
+    fn write_fact(&self, fact: Fact, embedding: Option<Vec<f32>>) -> Result<FactId> {
+        #[cfg(feature = "hybrid")]
+        if let Some(embedding) = embedding {
+            return self
+                .graph
+                .assert_prepared_fact_with_embedding(fact, embedding);
+        }
+        #[cfg(not(feature = "hybrid"))]
+        let _ = embedding;
+        self.graph.assert_prepared_fact(fact)
+    }
+
+    /// Idempotent [`write_fact`](Self::write_fact). A replayed key returns
+    /// the original fact, which keeps the embedding it was written with.
+    fn write_fact_idempotent(
+        &self,
+        idempotency_key: &str,
+        fact: Fact,
+        embedding: Option<Vec<f32>>,
+    ) -> Result<FactId> {
+        let new_id = fact.id.clone();
+        let fact_id = self
+            .graph
+            .assert_prepared_fact_idempotent(idempotency_key, fact)?;
+        if fact_id == new_id {
+            self.attach_embedding(&fact_id, embedding)?;
+        }
+        Ok(fact_id)
+    }
+
+    /// Attach an already computed embedding to a just-written fact.
+    fn attach_embedding(&self, fact_id: &FactId, embedding: Option<Vec<f32>>) -> Result<()> {
+        #[cfg(feature = "hybrid")]
+        if let Some(embedding) = embedding {
+            self.graph.set_embedding(fact_id, embedding)?;
+        }
+        #[cfg(not(feature = "hybrid"))]
+        let _ = (fact_id, embedding);
+        Ok(())
     }
 
     /// Store a structured fact with the current time as `valid_from`.
@@ -450,8 +744,12 @@ impl AgentMemory {
         predicate: &str,
         object: impl Into<Value>,
     ) -> Result<FactId> {
-        self.graph
-            .assert_fact(subject, predicate, object, KronroeTimestamp::now_utc())
+        self.assert_with_params(
+            subject,
+            predicate,
+            object,
+            AssertParams::new(KronroeTimestamp::now_utc()),
+        )
     }
 
     /// Store a structured fact with idempotent retry semantics.
@@ -465,12 +763,12 @@ impl AgentMemory {
         predicate: &str,
         object: impl Into<Value>,
     ) -> Result<FactId> {
-        self.graph.assert_fact_idempotent(
+        self.assert_idempotent_with_params(
             idempotency_key,
             subject,
             predicate,
             object,
-            KronroeTimestamp::now_utc(),
+            AssertParams::new(KronroeTimestamp::now_utc()),
         )
     }
 
@@ -483,13 +781,9 @@ impl AgentMemory {
         object: impl Into<Value>,
         params: AssertParams,
     ) -> Result<FactId> {
-        self.graph.assert_fact_idempotent(
-            idempotency_key,
-            subject,
-            predicate,
-            object,
-            params.valid_from,
-        )
+        let fact = params.into_fact(subject, predicate, object.into(), 1.0, None);
+        let embedding = self.pending_embedding(&fact)?;
+        self.write_fact_idempotent(idempotency_key, fact, embedding)
     }
 
     /// Store a structured fact with explicit parameters.
@@ -500,8 +794,9 @@ impl AgentMemory {
         object: impl Into<Value>,
         params: AssertParams,
     ) -> Result<FactId> {
-        self.graph
-            .assert_fact(subject, predicate, object, params.valid_from)
+        let fact = params.into_fact(subject, predicate, object.into(), 1.0, None);
+        let embedding = self.pending_embedding(&fact)?;
+        self.write_fact(fact, embedding)
     }
 
     /// Get all currently known facts about an entity (across all predicates).
@@ -509,6 +804,12 @@ impl AgentMemory {
         self.graph.all_facts_about(entity)
     }
 
+    /// Get every fact whose metadata has `key` set to exactly `value`
+    /// (`where metadata.<key> = <value>`), across all entities and history.
+    pub fn facts_with_metadata(&self, key: &str, value: &Value) -> Result<Vec<Fact>> {
+        self.graph.facts_with_metadata(key, value)
+    }
+
     /// Get what was known about an entity for a given predicate at a point in time.
     pub fn facts_about_at(
         &self,
@@ -720,6 +1021,11 @@ impl AgentMemory {
 
     /// Build task-focused recall output that is immediately useful for planning
     /// and execution-oriented workflows.
+    ///
+    /// With a `query_embedding` or a configured embedder, key facts come from
+    /// one [`search_hybrid_multi`](kronroe::TemporalGraph::search_hybrid_multi)
+    /// call over the task and the subject, with query expansion from
+    /// registered aliases and predicate synonyms.
     pub fn recall_for_task(
         &self,
         task: &str,
@@ -751,19 +1057,26 @@ impl AgentMemory {
 
         let opts = RecallOptions::new(&query_used).with_limit(limit);
         #[cfg(feature = "hybrid")]
-        let opts = if let Some(embedding) = query_embedding {
-            opts.with_embedding(embedding).with_hybrid(true)
-        } else {
-            opts
+        let hybrid_facts = match (query_embedding, &self.embedder) {
+            (Some(embedding), _) => Some(self.task_facts_hybrid(task, embedding, subject, limit)?),
+            (None, Some(embedder)) => {
+                let embedding = embedder.embed(task)?;
+                Some(self.task_facts_hybrid(task, &embedding, subject, limit)?)
+            }
+            (None, None) => None,
         };
         #[cfg(not(feature = "hybrid"))]
+        let hybrid_facts: Option<Vec<Fact>> = None;
+        #[cfg(not(feature = "hybrid"))]
         if _query_embedding.is_some() {
             return Err(Error::search(
                 "query_embedding requires the hybrid feature".to_string(),
             ));
         }
 
-        let key_facts: Vec<Fact> = if let Some(subject) = subject {
+        let key_facts: Vec<Fact> = if let Some(facts) = hybrid_facts {
+            facts
+        } else if let Some(subject) = subject {
             let mut subject_facts = Vec::new();
             let mut seen_fact_ids: HashSet<FactId> = HashSet::new();
             let mut fetch_limit = limit.clamp(1, DEFAULT_MAX_SCORED_ROWS);
@@ -886,6 +1199,14 @@ impl AgentMemory {
         self.graph.search(query, limit)
     }
 
+    /// Explain why a recalled fact matches `query`: matched terms, the field
+    /// each matched in, fuzzy substitutions, and highlighted object snippets.
+    ///
+    /// Works for facts from any recall path, including hybrid recall.
+    pub fn explain_recall(&self, query: &str, fact: &Fact) -> Result<SearchExplanation> {
+        self.graph.explain_search_match(query, fact)
+    }
+
     /// Correct an existing fact by id, preserving temporal history.
     pub fn correct_fact(
         &self,
@@ -936,8 +1257,11 @@ impl AgentMemory {
         predicate: &str,
         object: impl Into<Value>,
     ) -> Result<(FactId, Vec<Contradiction>)> {
-        self.graph
-            .assert_fact_checked(subject, predicate, object, KronroeTimestamp::now_utc())
+        let fact = Fact::new(subject, predicate, object, KronroeTimestamp::now_utc());
+        let embedding = self.pending_embedding(&fact)?;
+        let (fact_id, contradictions) = self.graph.assert_prepared_fact_checked(fact)?;
+        self.attach_embedding(&fact_id, embedding)?;
+        Ok((fact_id, contradictions))
     }
 
     /// Audit a subject for contradictions across all registered singletons.
@@ -957,6 +1281,8 @@ impl AgentMemory {
     /// Store an unstructured memory episode as one fact.
     ///
     /// Subject is the `episode_id`, predicate is `"memory"`, object is `text`.
+    /// Without an `embedding`, a configured
+    /// [embedder](Self::with_embedder) embeds `text`.
     pub fn remember(
         &self,
         text: &str,
@@ -964,6 +1290,11 @@ impl AgentMemory {
         #[cfg(feature = "hybrid")] embedding: Option<Vec<f32>>,
         #[cfg(not(feature = "hybrid"))] _embedding: Option<Vec<f32>>,
     ) -> Result<FactId> {
+        #[cfg(feature = "hybrid")]
+        let embedding = match (embedding, &self.embedder) {
+            (None, Some(embedder)) => Some(embedder.embed(text)?),
+            (embedding, _) => embedding,
+        };
         #[cfg(feature = "hybrid")]
         if let Some(emb) = embedding {
             return self.graph.assert_fact_with_embedding(
@@ -992,13 +1323,59 @@ impl AgentMemory {
         text: &str,
         episode_id: &str,
     ) -> Result<FactId> {
-        self.graph.assert_fact_idempotent(
-            idempotency_key,
-            episode_id,
-            "memory",
-            text.to_string(),
-            KronroeTimestamp::now_utc(),
-        )
+        let fact = Fact::new(episode_id, "memory", text, KronroeTimestamp::now_utc());
+        let embedding = self.pending_embedding(&fact)?;
+        self.write_fact_idempotent(idempotency_key, fact, embedding)
+    }
+
+    /// Store many memory episodes, given as `(text, episode_id)` pairs,
+    /// embedding all of their texts in batches first when an
+    /// [embedder](Self::with_embedder) is configured.
+    ///
+    /// Returns the fact IDs in input order.
+    pub fn remember_many(&self, episodes: &[(&str, &str)]) -> Result<Vec<FactId>> {
+        #[cfg(feature = "hybrid")]
+        if let Some(embedder) = &self.embedder {
+            let texts: Vec<&str> = episodes.iter().map(|(text, _)| *text).collect();
+            let embeddings = embedder.embed_all(&texts)?;
+            return episodes
+                .iter()
+                .zip(embeddings)
+                .map(|((text, episode_id), embedding)| {
+                    self.remember(text, episode_id, Some(embedding))
+                })
+                .collect();
+        }
+        episodes
+            .iter()
+            .map(|(text, episode_id)| self.remember(text, episode_id, None))
+            .collect()
+    }
+
+    /// Embed every currently valid fact that has no embedding yet, in
+    /// batches, with the configured [embedder](Self::with_embedder).
+    ///
+    /// Use this after configuring an embedder on a store written without
+    /// one. Returns the number of facts embedded.
+    #[cfg(feature = "hybrid")]
+    pub fn backfill_embeddings(&self) -> Result<usize> {
+        let embedder = self.embedder.as_ref().ok_or_else(|| {
+            Error::invalid_input("backfill_embeddings requires an embedder (see with_embedder)")
+        })?;
+        let facts: Vec<Fact> = self
+            .graph
+            .facts_missing_embeddings()?
+            .into_iter()
+            .filter(Fact::is_currently_valid)
+            .collect();
+        let texts: Vec<String> = facts
+            .iter()
+            .map(|fact| embedding_text(&fact.subject, &fact.predicate, &fact.object))
+            .collect();
+        let texts: Vec<&str> = texts.iter().map(String::as_str).collect();
+        let embeddings = embedder.embed_all(&texts)?;
+        self.graph
+            .backfill_embeddings(facts.into_iter().map(|fact| fact.id).zip(embeddings))
     }
 
     /// Retrieve memory facts by query.
@@ -1047,11 +1424,7 @@ impl AgentMemory {
             .with_min_confidence(min_confidence);
 
         #[cfg(feature = "hybrid")]
-        let opts = if let Some(embedding) = query_embedding {
-            opts.with_embedding(embedding).with_hybrid(true)
-        } else {
-            opts
-        };
+        let opts = self.with_query_embedding(opts, query_embedding);
 
         self.recall_with_options(&opts)
     }
@@ -1080,33 +1453,108 @@ impl AgentMemory {
         #[cfg(not(feature = "hybrid"))]
         let opts = RecallOptions::new(query).with_limit(limit);
         #[cfg(feature = "hybrid")]
-        if let Some(embedding) = query_embedding {
-            opts = opts
-                .with_embedding(embedding)
-                .with_hybrid(true)
+        if query_embedding.is_some() || self.embedder.is_some() {
+            opts = self
+                .with_query_embedding(opts, query_embedding)
                 .with_temporal_intent(TemporalIntent::Timeless)
                 .with_temporal_operator(TemporalOperator::Current);
         }
         self.recall_scored_with_options(&opts)
     }
 
+    /// Key facts for [`recall_for_task`](Self::recall_for_task) from one
+    /// multi-query hybrid search: the task through both channels, expanded
+    /// with registered aliases and predicate synonyms, plus the subject as a
+    /// lexical sub-query.
+    #[cfg(feature = "hybrid")]
+    fn task_facts_hybrid(
+        &self,
+        task: &str,
+        embedding: &[f32],
+        subject: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<Fact>> {
+        let mut queries: Vec<(&str, Option<&[f32]>)> = vec![(task, Some(embedding))];
+        if let Some(subject) = subject {
+            queries.push((subject, None));
+        }
+        // Subject scoping happens after fusion, so fetch the whole budget.
+        let k = if subject.is_some() {
+            DEFAULT_MAX_SCORED_ROWS
+        } else {
+            limit.min(DEFAULT_MAX_SCORED_ROWS)
+        };
+        let defaults = HybridSearchParams::default();
+        let params = HybridSearchParams {
+            k,
+            candidate_window: k.max(defaults.candidate_window),
+            expand_query: true,
+            reranker: self.reranker.clone(),
+            ..defaults
+        };
+        Ok(self
+            .graph
+            .search_hybrid_multi(&queries, params, None)?
+            .into_iter()
+            .map(|(fact, _)| fact)
+            .filter(|fact| {
+                fact.is_currently_valid() && subject.is_none_or(|subject| fact.subject == subject)
+            })
+            .take(limit)
+            .collect())
+    }
+
+    /// Enable hybrid retrieval for `query_embedding`, or for an embedded
+    /// query when an embedder is configured.
+    #[cfg(feature = "hybrid")]
+    fn with_query_embedding<'a>(
+        &self,
+        opts: RecallOptions<'a>,
+        query_embedding: Option<&'a [f32]>,
+    ) -> RecallOptions<'a> {
+        match query_embedding {
+            Some(embedding) => opts.with_embedding(embedding).with_hybrid(true),
+            None if self.embedder.is_some() => opts.with_hybrid(true),
+            None => opts,
+        }
+    }
+
+    /// BM25 hits over currently valid facts, filtered inside the search so
+    /// invalidated facts do not use up `limit`.
+    fn current_text_hits(&self, query: &str, limit: usize) -> Result<Vec<(Fact, f32)>> {
+        #[cfg(feature = "fulltext")]
+        {
+            let filter = kronroe::SearchFilter::new().with_current_only();
+            self.graph.search_scored_filtered(query, limit, &filter)
+        }
+        #[cfg(not(feature = "fulltext"))]
+        {
+            self.graph.search_scored(query, limit)
+        }
+    }
+
     #[cfg(feature = "hybrid")]
     fn recall_scored_internal(
         &self,
         query: &str,
         query_embedding: Option<&[f32]>,
         limit: usize,
-        intent: TemporalIntent,
-        operator: TemporalOperator,
+        opts: &RecallOptions<'_>,
     ) -> Result<Vec<(Fact, RecallScore)>> {
         if let Some(emb) = query_embedding {
             let params = HybridSearchParams {
                 k: limit,
-                intent,
-                operator,
+                intent: opts.temporal_intent,
+                operator: opts.temporal_operator,
+                fusion: opts.fusion,
+                valid_interval: opts.valid_interval,
+                known_at: opts.known_at,
+                reranker: self.reranker.clone(),
                 ..HybridSearchParams::default()
             };
-            let hits = self.graph.search_hybrid(query, emb, params, None)?;
+            let hits = self
+                .graph
+                .search_hybrid(query, emb, params, opts.query_time)?;
             let mut scored = Vec::with_capacity(hits.len());
             for (fact, breakdown) in hits {
                 if !fact.is_currently_valid() {
@@ -1122,7 +1570,7 @@ impl AgentMemory {
             return Ok(scored);
         }
 
-        let scored_facts = self.graph.search_scored(query, limit)?;
+        let scored_facts = self.current_text_hits(query, limit)?;
         let mut scored = Vec::with_capacity(scored_facts.len());
         for (i, (fact, bm25)) in scored_facts.into_iter().enumerate() {
             if !fact.is_currently_valid() {
@@ -1149,10 +1597,9 @@ impl AgentMemory {
         query: &str,
         _query_embedding: Option<&[f32]>,
         limit: usize,
-        _intent: (),
-        _operator: (),
+        _opts: &RecallOptions<'_>,
     ) -> Result<Vec<(Fact, RecallScore)>> {
-        let scored_facts = self.graph.search_scored(query, limit)?;
+        let scored_facts = self.current_text_hits(query, limit)?;
         let mut scored = Vec::with_capacity(scored_facts.len());
         for (i, (fact, bm25)) in scored_facts.into_iter().enumerate() {
             if !fact.is_currently_valid() {
@@ -1205,11 +1652,7 @@ impl AgentMemory {
             .with_min_confidence(min_confidence);
 
         #[cfg(feature = "hybrid")]
-        let opts = if let Some(embedding) = query_embedding {
-            opts.with_embedding(embedding).with_hybrid(true)
-        } else {
-            opts
-        };
+        let opts = self.with_query_embedding(opts, query_embedding);
 
         self.recall_scored_with_options(&opts)
     }
@@ -1234,11 +1677,7 @@ impl AgentMemory {
             .with_min_effective_confidence(min_effective_confidence);
 
         #[cfg(feature = "hybrid")]
-        let opts = if let Some(embedding) = query_embedding {
-            opts.with_embedding(embedding).with_hybrid(true)
-        } else {
-            opts
-        };
+        let opts = self.with_query_embedding(opts, query_embedding);
 
         self.recall_scored_with_options(&opts)
     }
@@ -1246,11 +1685,14 @@ impl AgentMemory {
     /// Build a token-bounded prompt context from recalled facts.
     ///
     /// Internally uses scored recall so results are ordered by relevance.
-    /// The output format includes a retrieval score tag for transparency:
+    /// The output format includes a retrieval score tag for transparency,
+    /// and — with the `fulltext` feature — the query terms each fact matched
+    /// (`~` marks a fuzzy match), with matched words in text objects
+    /// highlighted:
     ///
     /// ```text
-    /// [2024-06-01] (0.032) alice · works_at · Acme            ← hybrid, full confidence
-    /// [2024-06-01] (#1 bm25:4.21 conf:0.7) bob · lives_in · NYC  ← text-only, low confidence
+    /// [2024-06-01] (0.032) alice · works_at · **Acme** [matched: object:acme]         ← hybrid
+    /// [2024-06-01] (#1 bm25:4.21 conf:0.7) bob · lives_in · NYC [matched: subject:bob~]  ← text-only
     /// ```
     ///
     /// `query_embedding` is used only when the `hybrid` feature is enabled.
@@ -1270,6 +1712,11 @@ impl AgentMemory {
                 Value::Text(s) | Value::Entity(s) => s.clone(),
                 Value::Number(n) => n.to_string(),
                 Value::Boolean(b) => b.to_string(),
+                Value::Timestamp(t) => t.to_rfc3339_z(),
+                Value::Integer(n) => n.to_string(),
+                // Raw payloads are opaque to the model; just note their size.
+                Value::Bytes(bytes) => format!("[{} bytes]", bytes.len()),
+                Value::Json(json) => json.to_json_string(),
             };
             // Only show confidence when it deviates from the default (1.0),
             // keeping the common case clean and highlighting uncertainty.
@@ -1278,14 +1725,28 @@ impl AgentMemory {
             } else {
                 String::new()
             };
+            #[cfg(feature = "fulltext")]
+            let (object, evidence) = {
+                let explanation = self.graph.explain_search_match(query, fact)?;
+                let object = match &fact.object {
+                    Value::Text(_) | Value::Entity(_) if !explanation.snippets.is_empty() => {
+                        explanation.snippets.join(" … ")
+                    }
+                    _ => object,
+                };
+                (object, context_evidence(&explanation))
+            };
+            #[cfg(not(feature = "fulltext"))]
+            let evidence = "";
             let line = format!(
-                "[{}] ({}{}) {} · {} · {}\n",
+                "[{}] ({}{}) {} · {} · {}{}\n",
                 fact.valid_from.date_ymd(),
                 score.display_tag(),
                 conf_tag,
                 fact.subject,
                 fact.predicate,
-                object
+                object,
+                evidence
             );
             if context.len() + line.len() > char_budget {
                 break;
@@ -1318,8 +1779,13 @@ impl AgentMemory {
                 .unwrap_or_else(|| score.confidence()),
         };
         #[cfg(feature = "hybrid")]
+        let auto_embedding = match (&self.embedder, opts.use_hybrid, opts.query_embedding) {
+            (Some(embedder), true, None) => Some(embedder.embed(opts.query)?),
+            _ => None,
+        };
+        #[cfg(feature = "hybrid")]
         let query_embedding_for_path = if opts.use_hybrid {
-            opts.query_embedding
+            opts.query_embedding.or(auto_embedding.as_deref())
         } else {
             None
         };
@@ -1350,14 +1816,7 @@ impl AgentMemory {
                         opts.query,
                         query_embedding_for_path,
                         max_scored_rows,
-                        #[cfg(feature = "hybrid")]
-                        opts.temporal_intent,
-                        #[cfg(feature = "hybrid")]
-                        opts.temporal_operator,
-                        #[cfg(not(feature = "hybrid"))]
-                        (),
-                        #[cfg(not(feature = "hybrid"))]
-                        (),
+                        opts,
                     )?;
                     let mut filtered = Vec::new();
 
@@ -1383,14 +1842,7 @@ impl AgentMemory {
                         opts.query,
                         query_embedding_for_path,
                         fetch_limit,
-                        #[cfg(feature = "hybrid")]
-                        opts.temporal_intent,
-                        #[cfg(feature = "hybrid")]
-                        opts.temporal_operator,
-                        #[cfg(not(feature = "hybrid"))]
-                        (),
-                        #[cfg(not(feature = "hybrid"))]
-                        (),
+                        opts,
                     )?;
                     let mut newly_seen = 0usize;
                     let mut newly_confident = 0usize;
@@ -1442,19 +1894,9 @@ impl AgentMemory {
 
                 Ok(filtered)
             }
-            None => self.recall_scored_internal(
-                opts.query,
-                query_embedding_for_path,
-                opts.limit,
-                #[cfg(feature = "hybrid")]
-                opts.temporal_intent,
-                #[cfg(feature = "hybrid")]
-                opts.temporal_operator,
-                #[cfg(not(feature = "hybrid"))]
-                (),
-                #[cfg(not(feature = "hybrid"))]
-                (),
-            ),
+            None => {
+                self.recall_scored_internal(opts.query, query_embedding_for_path, opts.limit, opts)
+            }
         }
     }
 
@@ -1482,9 +1924,7 @@ impl AgentMemory {
             subject,
             predicate,
             object,
-            AssertParams {
-                valid_from: KronroeTimestamp::now_utc(),
-            },
+            AssertParams::new(KronroeTimestamp::now_utc()),
             confidence,
         )
     }
@@ -1499,13 +1939,9 @@ impl AgentMemory {
         confidence: f32,
     ) -> Result<FactId> {
         let confidence = normalize_fact_confidence(confidence)?;
-        self.graph.assert_fact_with_confidence(
-            subject,
-            predicate,
-            object,
-            params.valid_from,
-            confidence,
-        )
+        let fact = params.into_fact(subject, predicate, object.into(), confidence, None);
+        let embedding = self.pending_embedding(&fact)?;
+        self.write_fact(fact, embedding)
     }
 
     /// Store a structured fact with explicit source provenance.
@@ -1525,9 +1961,7 @@ impl AgentMemory {
             subject,
             predicate,
             object,
-            AssertParams {
-                valid_from: KronroeTimestamp::now_utc(),
-            },
+            AssertParams::new(KronroeTimestamp::now_utc()),
             confidence,
             source,
         )
@@ -1544,14 +1978,9 @@ impl AgentMemory {
         source: &str,
     ) -> Result<FactId> {
         let confidence = normalize_fact_confidence(confidence)?;
-        self.graph.assert_fact_with_source(
-            subject,
-            predicate,
-            object,
-            params.valid_from,
-            confidence,
-            source,
-        )
+        let fact = params.into_fact(subject, predicate, object.into(), confidence, Some(source));
+        let embedding = self.pending_embedding(&fact)?;
+        self.write_fact(fact, embedding)
     }
 
     // -----------------------------------------------------------------------
@@ -1594,6 +2023,18 @@ impl AgentMemory {
             .register_predicate_volatility(predicate, PredicateVolatility::new(half_life_days))
     }
 
+    /// Register synonyms for a predicate, used to expand hybrid recall
+    /// queries: a task mentioning "employer" also searches `works_at`.
+    /// Replaces any earlier list; an empty list removes it.
+    #[cfg(feature = "fulltext")]
+    pub fn register_predicate_synonyms<I, S>(&self, predicate: &str, synonyms: I) -> Result<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.graph.register_predicate_synonyms(predicate, synonyms)
+    }
+
     /// Register a source authority weight.
     ///
     /// Weight is clamped to \[0.0, 2.0\]. `1.0` = neutral, `>1.0` = boosted,
@@ -1641,6 +2082,24 @@ impl AgentMemory {
     }
 }
 
+/// Context-line suffix listing matched `field:term` pairs, with `~` marking
+/// fuzzy matches; empty when nothing matched lexically.
+#[cfg(feature = "fulltext")]
+fn context_evidence(explanation: &SearchExplanation) -> String {
+    if explanation.matches.is_empty() {
+        return String::new();
+    }
+    let terms: Vec<String> = explanation
+        .matches
+        .iter()
+        .map(|m| {
+            let fuzzy = if m.is_fuzzy() { "~" } else { "" };
+            format!("{}:{}{}", m.field.as_str(), m.term, fuzzy)
+        })
+        .collect();
+    format!(" [matched: {}]", terms.join(", "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1716,7 +2175,7 @@ mod tests {
                 "alice",
                 "works_at",
                 "Acme",
-                AssertParams { valid_from },
+                AssertParams::new(valid_from),
             )
             .unwrap();
         let second = mem
@@ -1725,9 +2184,7 @@ mod tests {
                 "alice",
                 "works_at",
                 "Acme",
-                AssertParams {
-                    valid_from: KronroeTimestamp::now_utc(),
-                },
+                AssertParams::new(KronroeTimestamp::now_utc()),
             )
             .unwrap();
         assert_eq!(first, second);
@@ -1776,7 +2233,7 @@ mod tests {
             "alice",
             "works_at",
             "Acme",
-            AssertParams { valid_from: old },
+            AssertParams::new(old),
             0.65,
         )
         .unwrap();
@@ -1890,6 +2347,209 @@ mod tests {
         assert_eq!(hits[0].subject, "ep-rust");
     }
 
+    #[cfg(feature = "hybrid")]
+    #[test]
+    fn embedder_embeds_writes_and_queries_for_hybrid_recall() {
+        let (mem, _tmp) = open_temp_memory();
+        let mem = mem.with_embedder(HashingEmbedder::new(128));
+        mem.remember("Rust systems programming", "ep-rust", None)
+            .unwrap();
+        mem.remember("Python data notebooks", "ep-py", None)
+            .unwrap();
+        mem.assert("alice", "works_at", "Acme").unwrap();
+        assert!(mem.graph.facts_missing_embeddings().unwrap().is_empty());
+
+        // "notebooks" shares no term with the Rust episode, so the vector
+        // channel agrees with the text channel.
+        let scored = mem.recall_scored("data notebooks", None, 1).unwrap();
+        assert_eq!(scored.len(), 1);
+        assert_eq!(scored[0].0.subject, "ep-py");
+        assert!(matches!(scored[0].1, RecallScore::Hybrid { .. }));
+
+        // An explicitly passed embedding still wins over the embedder.
+        let id = mem
+            .remember("explicit", "ep-explicit", Some(vec![1.0; 128]))
+            .unwrap();
+        let hits = mem.recall("", Some(&[1.0; 128]), 1).unwrap();
+        assert_eq!(hits[0].id, id);
+    }
+
+    #[cfg(feature = "hybrid")]
+    #[test]
+    fn with_reranker_reorders_hybrid_recall() {
+        struct Reverse;
+        impl Reranker for Reverse {
+            fn rerank(
+                &self,
+                _ctx: &kronroe::RerankContext<'_>,
+                mut candidates: Vec<(Fact, HybridScoreBreakdown)>,
+            ) -> Result<Vec<(Fact, HybridScoreBreakdown)>> {
+                candidates.reverse();
+                Ok(candidates)
+            }
+        }
+
+        let (mem, _tmp) = open_temp_memory();
+        let mem = mem.with_embedder(HashingEmbedder::new(128));
+        mem.remember("Rust systems programming", "ep-rust", None)
+            .unwrap();
+        mem.remember("Python data notebooks", "ep-py", None)
+            .unwrap();
+        let default = mem.recall("data notebooks", None, 2).unwrap();
+        assert_eq!(default.len(), 2);
+        assert_eq!(default[0].subject, "ep-py");
+
+        let mem = mem.with_reranker(Reverse);
+        let reranked = mem.recall("data notebooks", None, 2).unwrap();
+        let subjects: Vec<&str> = reranked.iter().map(|f| f.subject.as_str()).collect();
+        let expected: Vec<&str> = default.iter().rev().map(|f| f.subject.as_str()).collect();
+        assert_eq!(subjects, expected);
+    }
+
+    #[cfg(feature = "hybrid")]
+    #[test]
+    fn detected_temporal_anchors_hybrid_recall() {
+        let (mem, _tmp) = open_temp_memory();
+        let jan2023 = KronroeTimestamp::from_utc_components(2023, 1, 1, 0, 0, 0, 0).unwrap();
+        let jan2025 = KronroeTimestamp::from_utc_components(2025, 1, 1, 0, 0, 0, 0).unwrap();
+        let beta = mem
+            .graph
+            .assert_fact_with_embedding("alice", "works_at", "BetaCorp", jan2023, vec![1.0, 0.0])
+            .unwrap();
+        let acme = mem
+            .graph
+            .assert_fact_with_embedding("alice", "advises", "Acme", jan2025, vec![1.0, 0.0])
+            .unwrap();
+
+        let query = "alice on 2023-06-01";
+        let detected = mem.detect_temporal(query).unwrap();
+        assert_eq!(detected.intent, TemporalIntent::HistoricalPoint);
+        assert_eq!(detected.operator, TemporalOperator::AsOf);
+        let opts = RecallOptions::new(query)
+            .with_embedding(&[1.0, 0.0])
+            .with_hybrid(true)
+            .with_detected_temporal(&detected);
+        assert_eq!(
+            opts.query_time,
+            KronroeTimestamp::from_utc_components(2023, 6, 1, 0, 0, 0, 0).ok()
+        );
+        // Only the fact valid on the anchor date is temporally feasible.
+        let scored = mem.recall_scored_with_options(&opts).unwrap();
+        let ids: Vec<&FactId> = scored.iter().map(|(fact, _)| &fact.id).collect();
+        assert_eq!(ids, [&beta]);
+        match scored[0].1 {
+            RecallScore::Hybrid {
+                temporal_signal,
+                temporal_adjustment,
+                rerank_score,
+                stage1,
+                ..
+            } => {
+                assert!(temporal_signal > 0.0);
+                assert!(temporal_adjustment > 0.0);
+                assert!(rerank_score > temporal_adjustment);
+                assert!(matches!(stage1, Stage1Decision::Kept { .. }));
+            }
+            _ => panic!("expected Hybrid variant"),
+        }
+        let unanchored = mem
+            .recall_scored_with_options(
+                &opts.clone().with_temporal_intent(TemporalIntent::Timeless),
+            )
+            .unwrap();
+        assert!(unanchored.iter().any(|(fact, _)| fact.id == acme));
+
+        // A detected period becomes the valid-time interval.
+        let detected = mem.detect_temporal("alice in 2023").unwrap();
+        let opts = RecallOptions::new("alice in 2023")
+            .with_embedding(&[1.0, 0.0])
+            .with_hybrid(true)
+            .with_detected_temporal(&detected);
+        assert_eq!(opts.valid_interval, detected.interval);
+        let scored = mem.recall_scored_with_options(&opts).unwrap();
+        assert_eq!(scored.len(), 1);
+        assert_eq!(scored[0].0.id, beta);
+        let before_writes = KronroeTimestamp::from_utc_components(2000, 1, 1, 0, 0, 0, 0).unwrap();
+        let unknown = mem
+            .recall_scored_with_options(&opts.clone().with_known_at(before_writes))
+            .unwrap();
+        assert!(unknown.is_empty());
+
+        let timeless = mem.detect_temporal("alice employer").unwrap();
+        let opts = RecallOptions::new("alice employer").with_detected_temporal(&timeless);
+        assert_eq!(opts.temporal_intent, TemporalIntent::Timeless);
+        assert_eq!(opts.query_time, None);
+    }
+
+    #[cfg(feature = "hybrid")]
+    #[test]
+    fn remember_many_and_backfill_embed_in_batches() {
+        let (mem, _tmp) = open_temp_memory();
+        let old = mem
+            .remember("written before the embedder", "ep-0", None)
+            .unwrap();
+        let stale = mem.assert("bob", "lives_in", "Paris").unwrap();
+        mem.invalidate_fact(&stale).unwrap();
+        assert!(mem.backfill_embeddings().unwrap_err().is_invalid_input());
+
+        let mem = mem.with_embedder(HashingEmbedder::new(64));
+        let ids = mem
+            .remember_many(&[("first episode", "ep-1"), ("second episode", "ep-2")])
+            .unwrap();
+        assert_eq!(ids.len(), 2);
+
+        assert_eq!(mem.backfill_embeddings().unwrap(), 1);
+        assert_eq!(mem.backfill_embeddings().unwrap(), 0);
+        let missing: Vec<FactId> = mem
+            .graph
+            .facts_missing_embeddings()
+            .unwrap()
+            .into_iter()
+            .map(|fact| fact.id)
+            .collect();
+        assert_eq!(missing, vec![stale]);
+        assert!(!missing.contains(&old));
+    }
+
+    #[cfg(feature = "hybrid")]
+    #[test]
+    fn embedder_runs_before_writes_and_skips_idempotent_replays() {
+        struct Failing;
+        impl Embedder for Failing {
+            fn dimension(&self) -> usize {
+                8
+            }
+            fn embed_batch(&self, _texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+                Err(Error::invalid_input("embedder unavailable"))
+            }
+        }
+
+        let (mem, _tmp) = open_temp_memory();
+        let mem = mem.with_embedder(Failing);
+        assert!(mem.assert("alice", "works_at", "Acme").is_err());
+        assert!(mem
+            .assert_idempotent("k1", "alice", "works_at", "Acme")
+            .is_err());
+        assert!(mem.remember_idempotent("k2", "an episode", "ep-1").is_err());
+        assert!(mem.facts_about("alice").unwrap().is_empty());
+        assert!(mem.facts_about("ep-1").unwrap().is_empty());
+
+        // The retry writes the fact once, and a replay under a different
+        // embedding dimension returns it without re-embedding.
+        let mem = mem.with_embedder(HashingEmbedder::new(64));
+        let id = mem
+            .assert_idempotent("k1", "alice", "works_at", "Acme")
+            .unwrap();
+        let mem = mem.with_embedder(HashingEmbedder::new(32));
+        assert_eq!(
+            mem.assert_idempotent("k1", "alice", "works_at", "Acme")
+                .unwrap(),
+            id
+        );
+        assert_eq!(mem.facts_about("alice").unwrap().len(), 1);
+        assert!(mem.graph.facts_missing_embeddings().unwrap().is_empty());
+    }
+
     #[cfg(feature = "hybrid")]
     #[test]
     fn recall_for_task_accepts_query_embedding_for_hybrid_path() {
@@ -1917,6 +2577,44 @@ mod tests {
         assert_eq!(report.subject.as_deref(), Some("alice"));
     }
 
+    #[cfg(all(feature = "hybrid", feature = "fulltext"))]
+    #[test]
+    fn recall_for_task_expands_predicate_synonyms() {
+        let (mem, _tmp) = open_temp_memory();
+        mem.remember("Carol plays chess", "carol", Some(vec![1.0, 0.0]))
+            .unwrap();
+        let acme = mem.assert("alice", "works_at", "Acme").unwrap();
+        let initech = mem.assert("bob", "works_at", "Initech").unwrap();
+        mem.assert("alice", "likes", "tea").unwrap();
+
+        let task = |subject| {
+            mem.recall_for_task(
+                "email the employer",
+                subject,
+                None,
+                None,
+                5,
+                Some(&[1.0, 0.0]),
+            )
+            .unwrap()
+            .key_facts
+            .into_iter()
+            .map(|fact| fact.id)
+            .collect::<Vec<_>>()
+        };
+        assert!(!task(None).contains(&acme));
+
+        mem.register_predicate_synonyms("works_at", ["employer"])
+            .unwrap();
+        let ids = task(None);
+        assert!(ids.contains(&acme) && ids.contains(&initech));
+        // The subject sub-query finds both of Alice's facts; the expansion
+        // ranks her employer first.
+        let ids = task(Some("alice"));
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[0], acme);
+    }
+
     #[cfg(feature = "hybrid")]
     #[test]
     fn recall_with_embedding_without_hybrid_toggle_is_text_scored() {
@@ -2058,6 +2756,20 @@ mod tests {
     // Explainable recall tests
     // -------------------------------------------------------------------
 
+    #[test]
+    fn recall_scored_skips_invalidated_facts_before_the_limit() {
+        let (mem, _tmp) = open_temp_memory();
+        for employer in ["ACME (Acme Labs)", "ACME (Acme Corp)"] {
+            let id = mem.assert("alice", "works_at", employer).unwrap();
+            mem.invalidate_fact(&id).unwrap();
+        }
+        let current = mem.assert("alice", "works_at", "Acme").unwrap();
+
+        let scored = mem.recall_scored("acme", None, 1).unwrap();
+        assert_eq!(scored.len(), 1, "stale hits must not use up the limit");
+        assert_eq!(scored[0].0.id, current);
+    }
+
     #[test]
     fn recall_scored_text_only_returns_ranks_and_bm25() {
         let (mem, _tmp) = open_temp_memory();
@@ -2156,6 +2868,10 @@ mod tests {
             rrf_score: 0.1,
             text_contrib: 0.05,
             vector_contrib: 0.05,
+            temporal_adjustment: 0.0,
+            temporal_signal: 0.0,
+            rerank_score: 0.1,
+            stage1: Stage1Decision::NotApplied,
             confidence: 0.9,
             effective_confidence: None,
         };
@@ -2272,6 +2988,10 @@ mod tests {
             rrf_score: 0.0325,
             text_contrib: 0.02,
             vector_contrib: 0.0125,
+            temporal_adjustment: 0.0,
+            temporal_signal: 0.0,
+            rerank_score: 0.0325,
+            stage1: Stage1Decision::NotApplied,
             confidence: 1.0,
             effective_confidence: None,
         };
@@ -2293,6 +3013,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn assemble_context_highlights_matches_and_lists_evidence() {
+        let (mem, _tmp) = open_temp_memory();
+        mem.assert("alice", "works_at", "Acme Rocket Works")
+            .unwrap();
+        mem.assert("alice", "alias", "ally").unwrap();
+
+        let ctx = mem.assemble_context("ally AND rockte", None, 500).unwrap();
+        assert!(
+            ctx.contains(
+                "alice · works_at · Acme **Rocket** Works [matched: alias:ally, object:rocket~]"
+            ),
+            "context should carry highlights and evidence, got: {ctx}"
+        );
+
+        let hits = mem.recall_scored("rockte", None, 5).unwrap();
+        let explanation = mem.explain_recall("rockte", &hits[0].0).unwrap();
+        assert!(explanation.matches[0].is_fuzzy());
+        assert_eq!(explanation.snippets, vec!["Acme **Rocket** Works"]);
+    }
+
     #[test]
     fn assemble_context_omits_confidence_at_default() {
         let (mem, _tmp) = open_temp_memory();
@@ -2696,7 +3437,7 @@ mod tests {
             "alice",
             "worked_at",
             "Acme",
-            AssertParams { valid_from },
+            AssertParams::new(valid_from),
             0.7,
         )
         .unwrap();
@@ -2715,7 +3456,7 @@ mod tests {
             "alice",
             "works_at",
             "Acme",
-            AssertParams { valid_from },
+            AssertParams::new(valid_from),
             0.85,
             "agent:planner",
         )
@@ -2729,6 +3470,35 @@ mod tests {
         assert!((facts[0].confidence - 0.85).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn assert_params_metadata_is_stored_and_filterable() {
+        let (mem, _tmp) = open_temp_memory();
+        let params = AssertParams::new(KronroeTimestamp::now_utc())
+            .with_metadata("model", "extractor-v2")
+            .with_metadata("locale", "en-GB");
+        mem.assert_with_source_with_params("alice", "works_at", "Acme", params, 0.9, "chat")
+            .unwrap();
+        mem.assert_idempotent_with_params(
+            "k1",
+            "bob",
+            "works_at",
+            "Initech",
+            AssertParams::new(KronroeTimestamp::now_utc()).with_metadata("model", "other"),
+        )
+        .unwrap();
+
+        let facts = mem
+            .facts_with_metadata("model", &Value::Text("extractor-v2".into()))
+            .unwrap();
+        assert_eq!(facts.len(), 1);
+        assert_eq!(facts[0].subject, "alice");
+        assert_eq!(facts[0].source.as_deref(), Some("chat"));
+        assert_eq!(
+            facts[0].metadata.get("locale"),
+            Some(&Value::Text("en-GB".into()))
+        );
+    }
+
     #[test]
     fn what_changed_reports_new_invalidated_and_confidence_shift() {
         let (mem, _tmp) = open_temp_memory();
@@ -2737,9 +3507,7 @@ mod tests {
                 "alice",
                 "works_at",
                 "Acme",
-                AssertParams {
-                    valid_from: KronroeTimestamp::now_utc() - KronroeSpan::days(365),
-                },
+                AssertParams::new(KronroeTimestamp::now_utc() - KronroeSpan::days(365)),
             )
             .unwrap();
 
@@ -2761,9 +3529,7 @@ mod tests {
                 "alice",
                 "works_at",
                 "Beta Corp",
-                AssertParams {
-                    valid_from: replacement_valid_from,
-                },
+                AssertParams::new(replacement_valid_from),
                 0.6,
             )
             .unwrap();
@@ -2791,9 +3557,7 @@ mod tests {
                 "alice",
                 "works_at",
                 "Acme",
-                AssertParams {
-                    valid_from: KronroeTimestamp::now_utc() - KronroeSpan::days(30),
-                },
+                AssertParams::new(KronroeTimestamp::now_utc() - KronroeSpan::days(30)),
             )
             .unwrap();
 
@@ -2815,9 +3579,7 @@ mod tests {
             "alice",
             "works_at",
             "Beta Corp",
-            AssertParams {
-                valid_from: jittered_valid_from,
-            },
+            AssertParams::new(jittered_valid_from),
             0.65,
         )
         .unwrap();
@@ -2840,9 +3602,7 @@ mod tests {
                 "alice",
                 "works_at",
                 "Acme",
-                AssertParams {
-                    valid_from: KronroeTimestamp::now_utc() - KronroeSpan::days(30),
-                },
+                AssertParams::new(KronroeTimestamp::now_utc() - KronroeSpan::days(30)),
             )
             .unwrap();
 
@@ -2864,9 +3624,7 @@ mod tests {
             "alice",
             "works_at",
             "Beta Corp",
-            AssertParams {
-                valid_from: distant_valid_from,
-            },
+            AssertParams::new(distant_valid_from),
             0.65,
         )
         .unwrap();
@@ -2890,7 +3648,7 @@ mod tests {
             "alice",
             "nickname",
             "Bex",
-            AssertParams { valid_from: old },
+            AssertParams::new(old),
             0.4,
         )
         .unwrap();
@@ -2898,7 +3656,7 @@ mod tests {
             "alice",
             "email",
             "alice@example.com",
-            AssertParams { valid_from: old },
+            AssertParams::new(old),
             0.9,
         )
         .unwrap();
@@ -0,0 +1,303 @@
+//! Text embedding for hybrid recall.
+//!
+//! Kronroe stores and searches vectors but never computes them. An
+//! [`Embedder`] closes that gap for [`AgentMemory`](crate::AgentMemory):
+//! once one is configured with
+//! [`AgentMemory::with_embedder`](crate::AgentMemory::with_embedder),
+//! remembered text and asserted facts are embedded on write and recall
+//! queries are embedded when no query embedding is passed.
+//!
+//! ```rust
+//! use kronroe_agent_memory::{AgentMemory, HashingEmbedder};
+//!
+//! let memory = AgentMemory::open_in_memory()
+//!     .unwrap()
+//!     .with_embedder(HashingEmbedder::new(64));
+//! memory.remember("Alice moved to Lisbon", "ep-1", None).unwrap();
+//! let facts = memory.recall("where does alice live", None, 5).unwrap();
+//! assert_eq!(facts.len(), 1);
+//! ```
+
+use crate::{Error, Result};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+/// Default number of cached text embeddings per [`AgentMemory`](crate::AgentMemory).
+pub const DEFAULT_EMBEDDING_CACHE_CAPACITY: usize = 1024;
+
+/// Converts text into embedding vectors.
+///
+/// Implementations wrap a model (local or remote) and must return one
+/// vector of [`dimension`](Embedder::dimension) components per input, in
+/// input order. Batches never exceed
+/// [`max_batch_size`](Embedder::max_batch_size) texts.
+pub trait Embedder: Send + Sync {
+    /// Number of components in every returned vector.
+    fn dimension(&self) -> usize;
+
+    /// Embed `texts`, returning one vector per text in the same order.
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>>;
+
+    /// Largest batch passed to [`embed_batch`](Embedder::embed_batch).
+    fn max_batch_size(&self) -> usize {
+        64
+    }
+}
+
+impl<T: Embedder + ?Sized> Embedder for Arc<T> {
+    fn dimension(&self) -> usize {
+        (**self).dimension()
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        (**self).embed_batch(texts)
+    }
+
+    fn max_batch_size(&self) -> usize {
+        (**self).max_batch_size()
+    }
+}
+
+/// Deterministic hashed bag-of-words embedder.
+///
+/// Each lowercased alphanumeric word is hashed into one of `dimension`
+/// buckets with a hash-derived sign, and the result is L2-normalized. Texts
+/// sharing words land close together, which is enough for offline tests and
+/// demos of hybrid recall; it captures no meaning beyond word overlap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HashingEmbedder {
+    dimension: usize,
+}
+
+impl HashingEmbedder {
+    /// Embedder producing `dimension`-component vectors (at least 1).
+    pub fn new(dimension: usize) -> Self {
+        Self {
+            dimension: dimension.max(1),
+        }
+    }
+
+    fn embed_one(&self, text: &str) -> Vec<f32> {
+        let mut vector = vec![0.0f32; self.dimension];
+        for word in text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+        {
+            let hash = fnv1a(word.to_lowercase().as_bytes());
+            let bucket = (hash % self.dimension as u64) as usize;
+            vector[bucket] += if hash >> 63 == 0 { 1.0 } else { -1.0 };
+        }
+        let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|x| *x /= norm);
+        }
+        vector
+    }
+}
+
+impl Embedder for HashingEmbedder {
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        Ok(texts.iter().map(|text| self.embed_one(text)).collect())
+    }
+}
+
+/// An [`Embedder`] behind a bounded cache keyed by a hash of the text.
+///
+/// The lock is released while the embedder runs, so slow (e.g. remote)
+/// models do not serialize cache hits on other threads.
+pub(crate) struct CachedEmbedder {
+    embedder: Arc<dyn Embedder>,
+    cache: Mutex<EmbeddingCache>,
+}
+
+impl CachedEmbedder {
+    pub(crate) fn new(embedder: Arc<dyn Embedder>, capacity: usize) -> Self {
+        Self {
+            embedder,
+            cache: Mutex::new(EmbeddingCache::new(capacity)),
+        }
+    }
+
+    pub(crate) fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        self.embed_all(&[text])?
+            .pop()
+            .ok_or_else(|| Error::internal("embedder returned no vector"))
+    }
+
+    /// Embed `texts` in order, calling the embedder only for cache misses,
+    /// de-duplicated and split into batches of at most `max_batch_size`.
+    pub(crate) fn embed_all(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        let keys: Vec<u64> = texts.iter().map(|text| fnv1a(text.as_bytes())).collect();
+        let mut found: HashMap<u64, Vec<f32>> = HashMap::new();
+        let mut misses: Vec<(u64, &str)> = Vec::new();
+        {
+            let cache = self.lock()?;
+            for (&key, &text) in keys.iter().zip(texts) {
+                if found.contains_key(&key) {
+                    continue;
+                }
+                match cache.get(key) {
+                    Some(vector) => {
+                        found.insert(key, vector.clone());
+                    }
+                    None if !misses.iter().any(|(miss, _)| *miss == key) => {
+                        misses.push((key, text));
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        let dimension = self.embedder.dimension();
+        for chunk in misses.chunks(self.embedder.max_batch_size().max(1)) {
+            let batch: Vec<&str> = chunk.iter().map(|(_, text)| *text).collect();
+            let vectors = self.embedder.embed_batch(&batch)?;
+            if vectors.len() != batch.len() {
+                return Err(Error::invalid_embedding(format!(
+                    "embedder returned {} vectors for {} texts",
+                    vectors.len(),
+                    batch.len()
+                )));
+            }
+            if let Some(bad) = vectors.iter().find(|v| v.len() != dimension) {
+                return Err(Error::invalid_embedding(format!(
+                    "embedder returned a {}-component vector, expected {dimension}",
+                    bad.len()
+                )));
+            }
+            let mut cache = self.lock()?;
+            for ((key, _), vector) in chunk.iter().zip(vectors) {
+                cache.insert(*key, vector.clone());
+                found.insert(*key, vector);
+            }
+        }
+
+        keys.iter()
+            .map(|key| {
+                found
+                    .get(key)
+                    .cloned()
+                    .ok_or_else(|| Error::internal("embedding missing after batch"))
+            })
+            .collect()
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, EmbeddingCache>> {
+        self.cache
+            .lock()
+            .map_err(|_| Error::internal("embedding cache lock poisoned"))
+    }
+}
+
+/// First-in, first-out bounded map from text hash to vector.
+struct EmbeddingCache {
+    capacity: usize,
+    entries: HashMap<u64, Vec<f32>>,
+    order: VecDeque<u64>,
+}
+
+impl EmbeddingCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get(&self, key: u64) -> Option<&Vec<f32>> {
+        self.entries.get(&key)
+    }
+
+    fn insert(&mut self, key: u64, vector: Vec<f32>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.insert(key, vector).is_none() {
+            self.order.push_back(key);
+        }
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// 64-bit FNV-1a.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingEmbedder {
+        inner: HashingEmbedder,
+        calls: AtomicUsize,
+        texts: AtomicUsize,
+    }
+
+    impl Embedder for CountingEmbedder {
+        fn dimension(&self) -> usize {
+            self.inner.dimension()
+        }
+
+        fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.texts.fetch_add(texts.len(), Ordering::SeqCst);
+            self.inner.embed_batch(texts)
+        }
+
+        fn max_batch_size(&self) -> usize {
+            2
+        }
+    }
+
+    #[test]
+    fn hashing_embedder_is_deterministic_and_normalized() {
+        let embedder = HashingEmbedder::new(32);
+        let a = embedder.embed_one("Alice works at Acme");
+        assert_eq!(a, embedder.embed_one("alice WORKS at acme!"));
+        let norm = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-5);
+        assert!(embedder.embed_one("").iter().all(|x| *x == 0.0));
+    }
+
+    #[test]
+    fn cache_dedupes_batches_and_evicts_oldest() {
+        let counting = Arc::new(CountingEmbedder {
+            inner: HashingEmbedder::new(16),
+            calls: AtomicUsize::new(0),
+            texts: AtomicUsize::new(0),
+        });
+        let cached = CachedEmbedder::new(counting.clone(), 3);
+
+        let out = cached.embed_all(&["a", "b", "a", "c"]).unwrap();
+        assert_eq!(out.len(), 4);
+        assert_eq!(out[0], out[2]);
+        // Three distinct texts in batches of two.
+        assert_eq!(counting.texts.load(Ordering::SeqCst), 3);
+        assert_eq!(counting.calls.load(Ordering::SeqCst), 2);
+
+        cached.embed("b").unwrap();
+        assert_eq!(counting.texts.load(Ordering::SeqCst), 3);
+
+        cached.embed("d").unwrap();
+        cached.embed("a").unwrap();
+        assert_eq!(counting.texts.load(Ordering::SeqCst), 5);
+
+        let uncached = CachedEmbedder::new(counting.clone(), 0);
+        uncached.embed("d").unwrap();
+        uncached.embed("d").unwrap();
+        assert_eq!(counting.texts.load(Ordering::SeqCst), 7);
+    }
+}
@@ -52,6 +52,14 @@ class KronroeGraph private constructor(private var handle: Long) : AutoCloseable
             obj: String,
         ): Boolean
 
+        @JvmStatic
+        private external fun nativeAssertValueJson(
+            handle: Long,
+            subject: String,
+            predicate: String,
+            valueJson: String,
+        ): Boolean
+
         @JvmStatic
         private external fun nativeFactsAboutJson(handle: Long, entity: String): String?
 
@@ -67,6 +75,18 @@ class KronroeGraph private constructor(private var handle: Long) : AutoCloseable
         }
     }
 
+    /**
+     * Assert a fact whose object is a tagged value, e.g.
+     * `{"type":"Integer","value":"42"}` or `{"type":"Json","value":{"k":1}}`.
+     */
+    fun assertValueJson(subject: String, predicate: String, valueJson: String) {
+        check(!closed) { "KronroeGraph is closed" }
+        val ok = nativeAssertValueJson(handle, subject, predicate, valueJson)
+        if (!ok) {
+            throw KronroeException(nativeLastErrorMessage() ?: "assert failed")
+        }
+    }
+
     fun factsAboutJson(entity: String): String {
         check(!closed) { "KronroeGraph is closed" }
         return nativeFactsAboutJson(handle, entity)
@@ -1,5 +1,6 @@
 use kronroe::KronroeTimestamp;
 use kronroe::TemporalGraph;
+use kronroe::Value;
 use std::cell::RefCell;
 
 // ---------------------------------------------------------------------------
@@ -53,6 +54,21 @@ impl KronroeGraphHandle {
             .map_err(|e| e.to_string())
     }
 
+    /// Assert a fact whose object is a tagged value JSON string, e.g.
+    /// `{"type":"Integer","value":"42"}`.
+    fn assert_value_json(
+        &self,
+        subject: &str,
+        predicate: &str,
+        value_json: &str,
+    ) -> Result<bool, String> {
+        let value = Value::from_json_str(value_json).map_err(|e| e.to_string())?;
+        self.graph
+            .assert_fact(subject, predicate, value, KronroeTimestamp::now_utc())
+            .map(|_| true)
+            .map_err(|e| e.to_string())
+    }
+
     fn facts_about_json(&self, entity: &str) -> Result<String, String> {
         let facts = self
             .graph
@@ -194,6 +210,53 @@ mod jni_bridge {
         }
     }
 
+    #[no_mangle]
+    pub extern "system" fn Java_com_kronroe_KronroeGraph_nativeAssertValueJson(
+        mut env: JNIEnv,
+        _class: JClass,
+        handle: jlong,
+        subject: JString,
+        predicate: JString,
+        value_json: JString,
+    ) -> jboolean {
+        clear_last_error();
+        if handle == 0 {
+            set_last_error("graph handle is null".to_string());
+            return JNI_FALSE;
+        }
+
+        let subject = match jstring_to_string(&mut env, &subject) {
+            Ok(v) => v,
+            Err(msg) => {
+                set_last_error(msg);
+                return JNI_FALSE;
+            }
+        };
+        let predicate = match jstring_to_string(&mut env, &predicate) {
+            Ok(v) => v,
+            Err(msg) => {
+                set_last_error(msg);
+                return JNI_FALSE;
+            }
+        };
+        let value_json = match jstring_to_string(&mut env, &value_json) {
+            Ok(v) => v,
+            Err(msg) => {
+                set_last_error(msg);
+                return JNI_FALSE;
+            }
+        };
+
+        let graph = unsafe { handle_ref(handle) };
+        match graph.assert_value_json(&subject, &predicate, &value_json) {
+            Ok(_) => JNI_TRUE,
+            Err(msg) => {
+                set_last_error(msg);
+                JNI_FALSE
+            }
+        }
+    }
+
     #[no_mangle]
     pub extern "system" fn Java_com_kronroe_KronroeGraph_nativeFactsAboutJson(
         mut env: JNIEnv,
@@ -296,6 +359,29 @@ mod tests {
         assert_eq!(arr[0]["subject"], "alice");
     }
 
+    #[test]
+    fn assert_value_json_stores_typed_values() {
+        let handle = KronroeGraphHandle::open_in_memory().expect("open_in_memory");
+        handle
+            .assert_value_json(
+                "alice",
+                "born",
+                r#"{"type":"Timestamp","value":"1990-05-17T00:00:00Z"}"#,
+            )
+            .expect("assert timestamp");
+        let err = handle
+            .assert_value_json("alice", "born", r#"{"type":"Integer","value":"x"}"#)
+            .expect_err("malformed integer should fail");
+        assert!(err.contains("Integer"), "{err}");
+
+        let json = handle.facts_about_json("alice").expect("facts_about");
+        let facts: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+        let arr = facts.as_array().expect("json array");
+        assert_eq!(arr.len(), 1);
+        assert_eq!(arr[0]["object"]["type"], "Timestamp");
+        assert_eq!(arr[0]["object"]["value"], "1990-05-17T00:00:00Z");
+    }
+
     #[test]
     fn error_propagation_empty_entity() {
         let handle = KronroeGraphHandle::open_in_memory().expect("open_in_memory");
@@ -0,0 +1,428 @@
+//! Numeric aggregation and calendar roll-ups over valid time.
+//!
+//! Aggregates are computed over the *value timeline* of one
+//! `(subject, predicate)` pair: at any instant `t` the value is the numeric
+//! object of the fact that [`facts_at`](crate::TemporalGraph::facts_at) would
+//! return for `t`. When several facts are valid at once, the one with the
+//! latest `valid_from` wins (ties broken by `recorded_at`), so a new reading
+//! supersedes older ones even if they were never invalidated.
+//!
+//! The timeline is piecewise constant. Within a window (or bucket):
+//!
+//! - `min` / `max` are over every value the timeline takes,
+//! - `avg` is **time-weighted** by how long each value held,
+//! - `last` is the final value the timeline takes,
+//! - `count` is the number of distinct facts that supplied a value.
+//!
+//! Only [`Value::Number`] and [`Value::Integer`] objects participate; other
+//! variants are ignored.
+
+use crate::{Fact, KronroeError, KronroeSpan, KronroeTimestamp, Result, Value};
+use std::collections::{BTreeSet, HashSet};
+
+/// Upper bound on buckets per query, to keep a typo'd range from allocating
+/// millions of rows.
+const MAX_BUCKETS: usize = 100_000;
+
+/// Calendar bucket width for roll-ups. Boundaries are UTC-aligned; weeks
+/// start on Monday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeBucket {
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl TimeBucket {
+    /// Start of the bucket containing `t`.
+    fn floor(self, t: KronroeTimestamp) -> Result<KronroeTimestamp> {
+        let (year, month, day) = t.utc_ymd();
+        match self {
+            TimeBucket::Hour => {
+                let micros = t.to_unix_micros();
+                let hour = KronroeSpan::hours(1).as_micros();
+                KronroeTimestamp::from_unix_micros(micros - micros.rem_euclid(hour))
+            }
+            TimeBucket::Day => KronroeTimestamp::from_utc_components(year, month, day, 0, 0, 0, 0),
+            TimeBucket::Week => {
+                let midnight = TimeBucket::Day.floor(t)?;
+                let days_since_epoch = midnight.to_unix_micros() / KronroeSpan::days(1).as_micros();
+                // 1970-01-01 was a Thursday; shift so Monday is 0.
+                let since_monday = (days_since_epoch + 3).rem_euclid(7);
+                Ok(midnight - KronroeSpan::days(since_monday))
+            }
+            TimeBucket::Month => KronroeTimestamp::from_utc_components(year, month, 1, 0, 0, 0, 0),
+            TimeBucket::Year => KronroeTimestamp::from_utc_components(year, 1, 1, 0, 0, 0, 0),
+        }
+    }
+
+    /// Start of the bucket after the one starting at `start`.
+    fn next(self, start: KronroeTimestamp) -> Result<KronroeTimestamp> {
+        match self {
+            TimeBucket::Hour => Ok(start + KronroeSpan::hours(1)),
+            TimeBucket::Day => Ok(start + KronroeSpan::days(1)),
+            TimeBucket::Week => Ok(start + KronroeSpan::days(7)),
+            TimeBucket::Month => {
+                let (year, month, _) = start.utc_ymd();
+                let (year, month) = if month == 12 {
+                    (year + 1, 1)
+                } else {
+                    (year, month + 1)
+                };
+                KronroeTimestamp::from_utc_components(year, month, 1, 0, 0, 0, 0)
+            }
+            TimeBucket::Year => {
+                let (year, _, _) = start.utc_ymd();
+                KronroeTimestamp::from_utc_components(year + 1, 1, 1, 0, 0, 0, 0)
+            }
+        }
+    }
+}
+
+/// What to aggregate: a half-open valid-time window `[from, to)` and an
+/// optional calendar bucket width.
+#[derive(Debug, Clone)]
+pub struct AggregateSpec {
+    pub from: KronroeTimestamp,
+    pub to: KronroeTimestamp,
+    pub bucket: Option<TimeBucket>,
+}
+
+impl AggregateSpec {
+    /// Aggregate over `[from, to)` without bucketing.
+    pub fn new(from: KronroeTimestamp, to: KronroeTimestamp) -> Self {
+        Self {
+            from,
+            to,
+            bucket: None,
+        }
+    }
+
+    /// Also roll the window up into calendar buckets.
+    pub fn with_bucket(mut self, bucket: TimeBucket) -> Self {
+        self.bucket = Some(bucket);
+        self
+    }
+}
+
+/// Summary statistics for one window or bucket. All fields are `None` (and
+/// `count` is 0) when no numeric value held during the period.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AggregateStats {
+    /// Distinct facts that supplied a value during the period.
+    pub count: usize,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    /// Time-weighted mean over the part of the period that had a value.
+    pub avg: Option<f64>,
+    /// The last value held during the period.
+    pub last: Option<f64>,
+}
+
+/// One calendar bucket of a roll-up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregateBucket {
+    /// Calendar start of the bucket (inclusive).
+    pub start: KronroeTimestamp,
+    /// Calendar end of the bucket (exclusive).
+    pub end: KronroeTimestamp,
+    /// The value valid exactly at `start` — the bucket-boundary series.
+    pub value_at_start: Option<f64>,
+    /// Statistics over the part of the bucket inside the query window.
+    pub stats: AggregateStats,
+}
+
+/// Result of [`TemporalGraph::aggregate`](crate::TemporalGraph::aggregate).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregateResult {
+    /// Statistics over the whole `[from, to)` window.
+    pub overall: AggregateStats,
+    /// Calendar buckets covering the window, in order. Empty when the spec
+    /// has no bucket. The first and last bucket may extend past the window;
+    /// only time inside the window contributes to their stats.
+    pub buckets: Vec<AggregateBucket>,
+}
+
+#[derive(Default)]
+struct StatsAccumulator {
+    facts: HashSet<usize>,
+    min: Option<f64>,
+    max: Option<f64>,
+    weighted_sum: f64,
+    covered_micros: f64,
+    last: Option<f64>,
+}
+
+impl StatsAccumulator {
+    fn add(&mut self, fact_index: usize, value: f64, duration_micros: i64) {
+        self.facts.insert(fact_index);
+        self.min = Some(self.min.map_or(value, |m| m.min(value)));
+        self.max = Some(self.max.map_or(value, |m| m.max(value)));
+        self.weighted_sum += value * duration_micros as f64;
+        self.covered_micros += duration_micros as f64;
+        self.last = Some(value);
+    }
+
+    fn finish(self) -> AggregateStats {
+        AggregateStats {
+            count: self.facts.len(),
+            min: self.min,
+            max: self.max,
+            avg: (self.covered_micros > 0.0).then(|| self.weighted_sum / self.covered_micros),
+            last: self.last,
+        }
+    }
+}
+
+struct NumericFact {
+    value: f64,
+    valid_from: KronroeTimestamp,
+    recorded_at: KronroeTimestamp,
+    /// First instant the fact is no longer visible to `facts_at`.
+    end: KronroeTimestamp,
+}
+
+/// Aggregate the facts of one `(subject, predicate)` pair.
+pub(crate) fn aggregate(facts: &[Fact], spec: &AggregateSpec) -> Result<AggregateResult> {
+    if spec.from >= spec.to {
+        return Err(KronroeError::invalid_input(format!(
+            "aggregate window is empty: from {} is not before to {}",
+            spec.from, spec.to
+        )));
+    }
+
+    let mut numeric: Vec<NumericFact> = facts
+        .iter()
+        .filter_map(|fact| {
+            let value = match fact.object {
+                Value::Number(n) if n.is_finite() => n,
+                Value::Integer(n) => n as f64,
+                _ => return None,
+            };
+            let end = match (fact.valid_to, fact.expired_at) {
+                (Some(a), Some(b)) => a.min(b),
+                (Some(t), None) | (None, Some(t)) => t,
+                (None, None) => KronroeTimestamp::MAX,
+            };
+            Some(NumericFact {
+                value,
+                valid_from: fact.valid_from,
+                recorded_at: fact.recorded_at,
+                end,
+            })
+        })
+        .collect();
+    numeric.sort_by_key(|f| (f.valid_from, f.recorded_at));
+
+    let buckets = match spec.bucket {
+        Some(bucket) => bucket_bounds(bucket, spec.from, spec.to)?,
+        None => Vec::new(),
+    };
+
+    // Every instant at which the timeline may change, plus the window and
+    // bucket edges. Bucket starts before `from` are included so
+    // `value_at_start` can be answered for the first bucket.
+    let mut points: Vec<KronroeTimestamp> = vec![spec.from, spec.to];
+    for fact in &numeric {
+        for t in [fact.valid_from, fact.end] {
+            if t > spec.from && t < spec.to {
+                points.push(t);
+            }
+        }
+    }
+    for (start, _) in &buckets {
+        points.push(*start);
+    }
+    points.sort();
+    points.dedup();
+
+    let values = value_timeline(&numeric, &points);
+
+    let mut overall = StatsAccumulator::default();
+    let mut per_bucket: Vec<StatsAccumulator> = buckets
+        .iter()
+        .map(|_| StatsAccumulator::default())
+        .collect();
+    for (i, window) in points.windows(2).enumerate() {
+        let (start, end) = (window[0], window[1]);
+        if start < spec.from || end > spec.to {
+            continue;
+        }
+        let Some((fact_index, value)) = values[i] else {
+            continue;
+        };
+        let duration = (end - start).as_micros();
+        overall.add(fact_index, value, duration);
+        if !buckets.is_empty() {
+            let bucket = buckets.partition_point(|(b, _)| *b <= start) - 1;
+            per_bucket[bucket].add(fact_index, value, duration);
+        }
+    }
+
+    let buckets = buckets
+        .iter()
+        .zip(per_bucket)
+        .map(|(&(start, end), acc)| {
+            let at = points
+                .binary_search(&start)
+                .expect("bucket start is a point");
+            AggregateBucket {
+                start,
+                end,
+                value_at_start: values[at].map(|(_, v)| v),
+                stats: acc.finish(),
+            }
+        })
+        .collect();
+
+    Ok(AggregateResult {
+        overall: overall.finish(),
+        buckets,
+    })
+}
+
+fn bucket_bounds(
+    bucket: TimeBucket,
+    from: KronroeTimestamp,
+    to: KronroeTimestamp,
+) -> Result<Vec<(KronroeTimestamp, KronroeTimestamp)>> {
+    let mut out = Vec::new();
+    let mut start = bucket.floor(from)?;
+    while start < to {
+        if out.len() == MAX_BUCKETS {
+            return Err(KronroeError::invalid_input(format!(
+                "aggregate window spans more than {MAX_BUCKETS} buckets"
+            )));
+        }
+        let end = bucket.next(start)?;
+        out.push((start, end));
+        start = end;
+    }
+    Ok(out)
+}
+
+/// The winning `(fact index, value)` at each of the ascending `points`.
+///
+/// Sweeps once over the facts (sorted by `valid_from`) keeping an ordered set
+/// of started facts; the winner is the greatest `(valid_from, recorded_at)`
+/// that has not yet ended. Ended facts are pruned lazily from the top, which
+/// is sound because points only move forward.
+fn value_timeline(facts: &[NumericFact], points: &[KronroeTimestamp]) -> Vec<Option<(usize, f64)>> {
+    let mut active: BTreeSet<(KronroeTimestamp, KronroeTimestamp, usize)> = BTreeSet::new();
+    let mut next = 0;
+    points
+        .iter()
+        .map(|&t| {
+            while next < facts.len() && facts[next].valid_from <= t {
+                let f = &facts[next];
+                active.insert((f.valid_from, f.recorded_at, next));
+                next += 1;
+            }
+            while let Some(&top) = active.last() {
+                if facts[top.2].end <= t {
+                    active.remove(&top);
+                } else {
+                    return Some((top.2, facts[top.2].value));
+                }
+            }
+            None
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> KronroeTimestamp {
+        s.parse().unwrap()
+    }
+
+    fn reading(value: impl Into<Value>, at: &str) -> Fact {
+        Fact::new("alice", "weight_kg", value, dt(at))
+    }
+
+    #[test]
+    fn later_readings_supersede_open_earlier_ones() {
+        let facts = vec![
+            reading(80.0, "2024-01-01T00:00:00Z"),
+            reading(78.0, "2024-01-03T00:00:00Z"),
+            reading(79_i64, "2024-01-04T00:00:00Z"),
+        ];
+        let spec = AggregateSpec::new(dt("2024-01-01T00:00:00Z"), dt("2024-01-05T00:00:00Z"));
+        let result = aggregate(&facts, &spec).unwrap();
+        let stats = result.overall;
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.min, Some(78.0));
+        assert_eq!(stats.max, Some(80.0));
+        assert_eq!(stats.last, Some(79.0));
+        // 2 days at 80, 1 at 78, 1 at 79.
+        assert_eq!(stats.avg, Some((80.0 * 2.0 + 78.0 + 79.0) / 4.0));
+        assert!(result.buckets.is_empty());
+    }
+
+    #[test]
+    fn invalidated_values_leave_gaps() {
+        let mut closed = reading(10.0, "2024-01-01T00:00:00Z");
+        closed.valid_to = Some(dt("2024-01-02T00:00:00Z"));
+        let facts = vec![closed, reading(20.0, "2024-01-03T00:00:00Z")];
+        let spec = AggregateSpec::new(dt("2024-01-01T00:00:00Z"), dt("2024-01-04T00:00:00Z"))
+            .with_bucket(TimeBucket::Day);
+        let result = aggregate(&facts, &spec).unwrap();
+        assert_eq!(result.overall.avg, Some(15.0));
+        let starts: Vec<Option<f64>> = result.buckets.iter().map(|b| b.value_at_start).collect();
+        assert_eq!(starts, vec![Some(10.0), None, Some(20.0)]);
+        assert_eq!(result.buckets[1].stats, AggregateStats::default());
+    }
+
+    #[test]
+    fn monthly_buckets_are_calendar_aligned_and_clipped() {
+        let facts = vec![
+            reading(100.0, "2023-12-20T00:00:00Z"),
+            reading(50.0, "2024-02-15T00:00:00Z"),
+        ];
+        let spec = AggregateSpec::new(dt("2024-01-10T00:00:00Z"), dt("2024-03-01T00:00:00Z"))
+            .with_bucket(TimeBucket::Month);
+        let result = aggregate(&facts, &spec).unwrap();
+        assert_eq!(result.buckets.len(), 2);
+        assert_eq!(result.buckets[0].start, dt("2024-01-01T00:00:00Z"));
+        assert_eq!(result.buckets[0].end, dt("2024-02-01T00:00:00Z"));
+        assert_eq!(result.buckets[0].value_at_start, Some(100.0));
+        assert_eq!(result.buckets[0].stats.count, 1);
+        assert_eq!(result.buckets[1].stats.min, Some(50.0));
+        assert_eq!(result.buckets[1].stats.last, Some(50.0));
+        assert_eq!(result.buckets[1].value_at_start, Some(100.0));
+    }
+
+    #[test]
+    fn week_buckets_start_on_monday() {
+        // 2024-01-03 is a Wednesday.
+        let start = TimeBucket::Week.floor(dt("2024-01-03T15:00:00Z")).unwrap();
+        assert_eq!(start, dt("2024-01-01T00:00:00Z"));
+        assert_eq!(
+            TimeBucket::Month.next(dt("2024-12-01T00:00:00Z")).unwrap(),
+            dt("2025-01-01T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn rejects_empty_window_and_ignores_non_numeric() {
+        let t = dt("2024-01-01T00:00:00Z");
+        assert!(aggregate(&[], &AggregateSpec::new(t, t))
+            .unwrap_err()
+            .is_invalid_input());
+
+        let facts = vec![Fact::new("alice", "weight_kg", "heavy", t)];
+        let spec = AggregateSpec::new(t, dt("2024-02-01T00:00:00Z"));
+        assert_eq!(
+            aggregate(&facts, &spec).unwrap().overall,
+            AggregateStats::default()
+        );
+    }
+}
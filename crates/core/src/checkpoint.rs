@@ -0,0 +1,47 @@
+//! Sidecar checkpoint files for the in-memory search indexes.
+
+use crate::{KronroeError, Result};
+
+/// Atomically replace `path` with `bytes` (write a temporary sibling, sync,
+/// rename). `kind` names the index in error messages.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn write_checkpoint_file(path: &str, bytes: &[u8], kind: &str) -> Result<()> {
+    use std::io::Write;
+
+    let path = std::path::Path::new(path);
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| format!("{kind}-index"));
+    let temp_path = path.with_file_name(format!("{file_name}.tmp"));
+    let write = || -> std::io::Result<()> {
+        let mut file = std::fs::File::create(&temp_path)?;
+        file.write_all(bytes)?;
+        file.sync_all()?;
+        std::fs::rename(&temp_path, path)
+    };
+    write().map_err(|error| {
+        let _ = std::fs::remove_file(&temp_path);
+        KronroeError::storage(format!(
+            "{kind} checkpoint write failed for {}: {error}",
+            path.display()
+        ))
+    })
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn write_checkpoint_file(path: &str, _bytes: &[u8], kind: &str) -> Result<()> {
+    Err(KronroeError::storage(format!(
+        "{kind} checkpoints are not supported on wasm32 (`{path}`)"
+    )))
+}
+
+/// 64-bit FNV-1a hash.
+pub(crate) fn fnv1a(input: &[u8]) -> u64 {
+    let mut hash = 0xcbf29ce484222325u64;
+    for byte in input {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
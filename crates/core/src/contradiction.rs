@@ -36,6 +36,12 @@ pub enum ConflictSeverity {
 }
 
 /// What the engine should do when a contradiction is detected at write time.
+///
+/// The automatic policies (`SupersedeOlder`, `PreferHigherConfidence`,
+/// `PreferTrustedSource`) store the fact and resolve every contradiction in
+/// the same write. Each returned [`Contradiction`] carries the
+/// [`AppliedResolution`], and the write is recorded as a
+/// [`ContradictionResolution`] for audit.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ConflictPolicy {
     /// Store the fact regardless — caller handles conflicts downstream.
@@ -45,6 +51,20 @@ pub enum ConflictPolicy {
     Warn,
     /// Reject the fact if it contradicts existing facts.
     Reject,
+    /// Close the older fact's `valid_to` at the newer fact's `valid_from`.
+    /// "Older" compares `valid_from`: an existing fact that starts first is
+    /// closed at the new fact's start; a new fact that starts first is closed
+    /// at the earliest start of the facts it conflicts with. An existing fact
+    /// with the same start is retracted.
+    SupersedeOlder,
+    /// Keep the fact with the higher confidence and retract the other. The
+    /// new fact must beat every fact it conflicts with; ties keep the
+    /// existing facts.
+    PreferHigherConfidence,
+    /// Like `PreferHigherConfidence`, comparing registered source weights
+    /// first (unregistered or missing sources weigh `1.0`) and confidence on
+    /// a tie. Without the `uncertainty` feature every source weighs `1.0`.
+    PreferTrustedSource,
 }
 
 // -- Registry codec: encodes as JSON array ["Singleton","Warn"] --
@@ -71,6 +91,9 @@ impl ConflictPolicy {
             ConflictPolicy::Allow => "Allow",
             ConflictPolicy::Warn => "Warn",
             ConflictPolicy::Reject => "Reject",
+            ConflictPolicy::SupersedeOlder => "SupersedeOlder",
+            ConflictPolicy::PreferHigherConfidence => "PreferHigherConfidence",
+            ConflictPolicy::PreferTrustedSource => "PreferTrustedSource",
         }
     }
 
@@ -79,9 +102,22 @@ impl ConflictPolicy {
             "Allow" => Some(ConflictPolicy::Allow),
             "Warn" => Some(ConflictPolicy::Warn),
             "Reject" => Some(ConflictPolicy::Reject),
+            "SupersedeOlder" => Some(ConflictPolicy::SupersedeOlder),
+            "PreferHigherConfidence" => Some(ConflictPolicy::PreferHigherConfidence),
+            "PreferTrustedSource" => Some(ConflictPolicy::PreferTrustedSource),
             _ => None,
         }
     }
+
+    /// Whether the engine resolves contradictions itself under this policy.
+    pub fn is_automatic(&self) -> bool {
+        matches!(
+            self,
+            ConflictPolicy::SupersedeOlder
+                | ConflictPolicy::PreferHigherConfidence
+                | ConflictPolicy::PreferTrustedSource
+        )
+    }
 }
 
 /// Encode a (PredicateCardinality, ConflictPolicy) pair as a JSON string.
@@ -136,6 +172,38 @@ pub enum SuggestedResolution {
     ManualReview,
 }
 
+/// Resolution the engine applied to a contradiction under an automatic
+/// [`ConflictPolicy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppliedResolution {
+    /// `valid_to` of the fact was set to `valid_to`, ending the overlap.
+    ClosedFact {
+        fact_id: String,
+        valid_to: KronroeTimestamp,
+    },
+    /// The fact lost and was retracted: its `expired_at` was set to the
+    /// write's transaction time. Its valid time is unchanged.
+    RetractedFact { fact_id: String },
+}
+
+/// Audit record of the resolutions applied by one contradiction-checked
+/// write. Persisted with the write itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContradictionResolution {
+    /// The fact the checked write stored.
+    pub fact_id: String,
+    /// Subject of the fact.
+    pub subject: String,
+    /// Predicate of the fact.
+    pub predicate: String,
+    /// Policy that produced the resolutions.
+    pub policy: ConflictPolicy,
+    /// Transaction time of the write (the fact's `recorded_at`).
+    pub resolved_at: KronroeTimestamp,
+    /// Each contradicted existing fact id with the resolution applied to it.
+    pub applied: Vec<(String, AppliedResolution)>,
+}
+
 /// A detected contradiction between two facts.
 #[derive(Debug, Clone)]
 pub struct Contradiction {
@@ -157,6 +225,8 @@ pub struct Contradiction {
     pub confidence_delta: f32,
     /// Suggested resolution.
     pub suggested_resolution: SuggestedResolution,
+    /// Resolution the engine applied, under an automatic [`ConflictPolicy`].
+    pub applied_resolution: Option<AppliedResolution>,
 }
 
 // ---------------------------------------------------------------------------
@@ -204,13 +274,24 @@ pub(crate) fn valid_time_overlap(
 /// Do two values structurally conflict?
 ///
 /// Values conflict if they are different. Comparison is structural:
-/// same variant + same inner value = no conflict.
+/// same variant + same inner value = no conflict. The one cross-variant
+/// case is numeric: `Integer(42)` and `Number(42.0)` describe the same
+/// quantity and do not conflict. Timestamps compare at microsecond
+/// precision, bytes byte-for-byte, and JSON documents structurally (object
+/// key order is irrelevant).
 pub(crate) fn values_conflict(a: &Value, b: &Value) -> bool {
     match (a, b) {
         (Value::Text(x), Value::Text(y)) => x != y,
         (Value::Number(x), Value::Number(y)) => (x - y).abs() > f64::EPSILON,
         (Value::Boolean(x), Value::Boolean(y)) => x != y,
         (Value::Entity(x), Value::Entity(y)) => x != y,
+        (Value::Timestamp(x), Value::Timestamp(y)) => x != y,
+        (Value::Integer(x), Value::Integer(y)) => x != y,
+        (Value::Integer(_), Value::Number(_)) | (Value::Number(_), Value::Integer(_)) => {
+            !a.same_value(b)
+        }
+        (Value::Bytes(x), Value::Bytes(y)) => x != y,
+        (Value::Json(x), Value::Json(y)) => x != y,
         // Different variant types always conflict.
         _ => true,
     }
@@ -321,9 +402,266 @@ pub(crate) fn detect_pairwise(a: &Fact, b: &Fact) -> Option<Contradiction> {
         severity,
         confidence_delta,
         suggested_resolution,
+        applied_resolution: None,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Automatic resolution
+// ---------------------------------------------------------------------------
+
+/// A contradiction-checked write, decided under the storage write lock.
+#[derive(Debug)]
+pub(crate) struct CheckedWrite {
+    /// The fact to store, closed or retracted when it lost a resolution.
+    pub(crate) fact: Fact,
+    pub(crate) contradictions: Vec<Contradiction>,
+    /// Existing facts closed or retracted by the resolution.
+    pub(crate) updated: Vec<Fact>,
+    pub(crate) resolution: Option<ContradictionResolution>,
+}
+
+impl CheckedWrite {
+    /// Store `fact` as is and report `contradictions`.
+    pub(crate) fn unresolved(fact: Fact, contradictions: Vec<Contradiction>) -> Self {
+        Self {
+            fact,
+            contradictions,
+            updated: Vec::new(),
+            resolution: None,
+        }
+    }
+}
+
+/// Resolve `contradictions` between `fact` and `existing` under `policy`.
+///
+/// Non-automatic policies, and writes without contradictions, store the fact
+/// unchanged. `source_weight` maps a fact source to its authority weight.
+///
+/// Every contradiction must name a fact in `existing`; a missing one is an
+/// internal error rather than a resolution applied to the wrong fact.
+pub(crate) fn resolve(
+    policy: ConflictPolicy,
+    mut fact: Fact,
+    existing: &[Fact],
+    mut contradictions: Vec<Contradiction>,
+    source_weight: impl Fn(Option<&str>) -> f32,
+) -> crate::Result<CheckedWrite> {
+    if !policy.is_automatic() || contradictions.is_empty() {
+        return Ok(CheckedWrite::unresolved(fact, contradictions));
+    }
+    let resolved_at = fact.recorded_at;
+    let mut updated = Vec::new();
+
+    if policy == ConflictPolicy::SupersedeOlder {
+        let mut close_new_at: Option<KronroeTimestamp> = None;
+        for c in &contradictions {
+            let start = existing_fact(existing, c)?.valid_from;
+            if start > fact.valid_from {
+                close_new_at = Some(close_new_at.map_or(start, |at| at.min(start)));
+            }
+        }
+        for c in contradictions.iter_mut() {
+            let old = existing_fact(existing, c)?;
+            let applied = if old.valid_from < fact.valid_from {
+                let mut closed = old.clone();
+                closed.valid_to = Some(fact.valid_from);
+                updated.push(closed);
+                AppliedResolution::ClosedFact {
+                    fact_id: old.id.to_string(),
+                    valid_to: fact.valid_from,
+                }
+            } else if let Some(valid_to) = close_new_at.filter(|_| old.valid_from > fact.valid_from)
+            {
+                AppliedResolution::ClosedFact {
+                    fact_id: fact.id.to_string(),
+                    valid_to,
+                }
+            } else {
+                let mut retracted = old.clone();
+                retracted.expired_at = Some(resolved_at);
+                updated.push(retracted);
+                AppliedResolution::RetractedFact {
+                    fact_id: old.id.to_string(),
+                }
+            };
+            c.applied_resolution = Some(applied);
+        }
+        if let Some(valid_to) = close_new_at {
+            fact.valid_to = Some(fact.valid_to.map_or(valid_to, |end| end.min(valid_to)));
+        }
+    } else {
+        let rank = |f: &Fact| match policy {
+            ConflictPolicy::PreferTrustedSource => {
+                (source_weight(f.source.as_deref()), f.confidence)
+            }
+            _ => (0.0, f.confidence),
+        };
+        let mut new_wins = true;
+        for c in &contradictions {
+            new_wins &= rank(&fact) > rank(existing_fact(existing, c)?);
+        }
+        for c in contradictions.iter_mut() {
+            let old = existing_fact(existing, c)?;
+            let loser = if new_wins {
+                let mut retracted = old.clone();
+                retracted.expired_at = Some(resolved_at);
+                updated.push(retracted);
+                old
+            } else {
+                &fact
+            };
+            c.applied_resolution = Some(AppliedResolution::RetractedFact {
+                fact_id: loser.id.to_string(),
+            });
+        }
+        if !new_wins {
+            fact.expired_at = Some(resolved_at);
+        }
+    }
+
+    let resolution = ContradictionResolution {
+        fact_id: fact.id.to_string(),
+        subject: fact.subject.clone(),
+        predicate: fact.predicate.clone(),
+        policy,
+        resolved_at,
+        applied: contradictions
+            .iter()
+            .filter_map(|c| {
+                c.applied_resolution
+                    .clone()
+                    .map(|applied| (c.existing_fact_id.clone(), applied))
+            })
+            .collect(),
+    };
+    Ok(CheckedWrite {
+        fact,
+        contradictions,
+        updated,
+        resolution: Some(resolution),
     })
 }
 
+/// The fact in `existing` that `contradiction` conflicts with.
+fn existing_fact<'a>(
+    existing: &'a [Fact],
+    contradiction: &Contradiction,
+) -> crate::Result<&'a Fact> {
+    existing
+        .iter()
+        .find(|f| f.id.as_str() == contradiction.existing_fact_id)
+        .ok_or_else(|| {
+            crate::KronroeError::internal(format!(
+                "contradiction names unknown fact {}",
+                contradiction.existing_fact_id
+            ))
+        })
+}
+
+// -- Audit codec: {"fact_id":..,"policy":..,"applied":[{"existing_fact_id":..,"ClosedFact":{..}}]} --
+impl ContradictionResolution {
+    pub(crate) fn to_json_string(&self) -> String {
+        let mut buf = Vec::new();
+        self.write_json(&mut buf)
+            .expect("Vec<u8> write is infallible");
+        String::from_utf8(buf).expect("JSON writer produces UTF-8")
+    }
+
+    fn write_json(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        use crate::json_write::*;
+        w.write_all(b"{")?;
+        write_kv_string(w, "fact_id", &self.fact_id)?;
+        w.write_all(b",")?;
+        write_kv_string(w, "subject", &self.subject)?;
+        w.write_all(b",")?;
+        write_kv_string(w, "predicate", &self.predicate)?;
+        w.write_all(b",")?;
+        write_kv_string(w, "policy", self.policy.as_str())?;
+        w.write_all(b",")?;
+        write_kv_string(w, "resolved_at", &self.resolved_at.to_rfc3339_z())?;
+        w.write_all(b",\"applied\":[")?;
+        for (i, (existing_fact_id, applied)) in self.applied.iter().enumerate() {
+            if i > 0 {
+                w.write_all(b",")?;
+            }
+            w.write_all(b"{")?;
+            write_kv_string(w, "existing_fact_id", existing_fact_id)?;
+            match applied {
+                AppliedResolution::ClosedFact { fact_id, valid_to } => {
+                    w.write_all(b",\"ClosedFact\":{")?;
+                    write_kv_string(w, "fact_id", fact_id)?;
+                    w.write_all(b",")?;
+                    write_kv_string(w, "valid_to", &valid_to.to_rfc3339_z())?;
+                }
+                AppliedResolution::RetractedFact { fact_id } => {
+                    w.write_all(b",\"RetractedFact\":{")?;
+                    write_kv_string(w, "fact_id", fact_id)?;
+                }
+            }
+            w.write_all(b"}}")?;
+        }
+        w.write_all(b"]}")
+    }
+
+    pub(crate) fn from_json_str(encoded: &str) -> crate::Result<Self> {
+        let val = crate::json_read::JsonValue::parse_str(encoded)?;
+        let get_str = |v: &crate::json_read::JsonValue, key: &str| {
+            v.get(key)
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    crate::KronroeError::serialization(format!(
+                        "contradiction resolution missing '{key}'"
+                    ))
+                })
+        };
+        let get_ts = |v: &crate::json_read::JsonValue, key: &str| {
+            KronroeTimestamp::parse_rfc3339(&get_str(v, key)?).map_err(|e| {
+                crate::KronroeError::serialization(format!("contradiction resolution {key}: {e}"))
+            })
+        };
+        let policy_str = get_str(&val, "policy")?;
+        let policy = ConflictPolicy::from_str(&policy_str).ok_or_else(|| {
+            crate::KronroeError::serialization(format!("unknown ConflictPolicy: {policy_str}"))
+        })?;
+        let applied = val
+            .get("applied")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| {
+                crate::KronroeError::serialization("contradiction resolution missing 'applied'")
+            })?
+            .iter()
+            .map(|entry| {
+                let existing_fact_id = get_str(entry, "existing_fact_id")?;
+                let applied = if let Some(closed) = entry.get("ClosedFact") {
+                    AppliedResolution::ClosedFact {
+                        fact_id: get_str(closed, "fact_id")?,
+                        valid_to: get_ts(closed, "valid_to")?,
+                    }
+                } else if let Some(retracted) = entry.get("RetractedFact") {
+                    AppliedResolution::RetractedFact {
+                        fact_id: get_str(retracted, "fact_id")?,
+                    }
+                } else {
+                    return Err(crate::KronroeError::serialization(
+                        "contradiction resolution: unknown applied resolution",
+                    ));
+                };
+                Ok((existing_fact_id, applied))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+        Ok(Self {
+            fact_id: get_str(&val, "fact_id")?,
+            subject: get_str(&val, "subject")?,
+            predicate: get_str(&val, "predicate")?,
+            policy,
+            resolved_at: get_ts(&val, "resolved_at")?,
+            applied,
+        })
+    }
+}
+
 // ---------------------------------------------------------------------------
 // ContradictionDetector
 // ---------------------------------------------------------------------------
@@ -422,6 +760,7 @@ mod tests {
             expired_at: None,
             confidence,
             source: None,
+            metadata: Default::default(),
         }
     }
 
@@ -517,6 +856,51 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn values_conflict_integer_matches_equal_number() {
+        assert!(!values_conflict(&Value::Integer(42), &Value::Number(42.0)));
+        assert!(values_conflict(&Value::Number(42.5), &Value::Integer(42)));
+        assert!(values_conflict(
+            &Value::Integer(i64::MAX),
+            &Value::Integer(i64::MAX - 1)
+        ));
+    }
+
+    #[test]
+    fn values_conflict_json_ignores_key_order() {
+        let a = crate::JsonValue::parse_str(r#"{"a":1,"b":[true]}"#).unwrap();
+        let b = crate::JsonValue::parse_str(r#"{"b":[true],"a":1}"#).unwrap();
+        let c = crate::JsonValue::parse_str(r#"{"a":2,"b":[true]}"#).unwrap();
+        assert!(!values_conflict(&Value::Json(a.clone()), &Value::Json(b)));
+        assert!(values_conflict(&Value::Json(a), &Value::Json(c)));
+    }
+
+    #[test]
+    fn values_conflict_timestamps_and_bytes() {
+        let t1: KronroeTimestamp = "2024-01-01T00:00:00Z".parse().unwrap();
+        let t2: KronroeTimestamp = "2024-01-01T00:00:01Z".parse().unwrap();
+        assert!(!values_conflict(
+            &Value::Timestamp(t1),
+            &Value::Timestamp(t1)
+        ));
+        assert!(values_conflict(
+            &Value::Timestamp(t1),
+            &Value::Timestamp(t2)
+        ));
+        assert!(values_conflict(
+            &Value::Timestamp(t1),
+            &Value::Text(t1.to_rfc3339_z())
+        ));
+        assert!(!values_conflict(
+            &Value::Bytes(vec![1, 2]),
+            &Value::Bytes(vec![1, 2])
+        ));
+        assert!(values_conflict(
+            &Value::Bytes(vec![1]),
+            &Value::Bytes(vec![2])
+        ));
+    }
+
     // -- compute_severity -----------------------------------------------------
 
     #[test]
@@ -656,4 +1040,44 @@ mod tests {
             "multi-valued predicates should not trigger contradictions"
         );
     }
+
+    // -- resolve --------------------------------------------------------------
+
+    #[test]
+    fn resolve_rejects_contradiction_with_unknown_existing_fact() {
+        let acme = make_fact("alice", "works_at", "Acme", dt(2022, 1, 1), None, 1.0);
+        let beta = make_fact("alice", "works_at", "Beta", dt(2023, 1, 1), None, 1.0);
+        let new_fact = make_fact("alice", "works_at", "Gamma", dt(2024, 1, 1), None, 1.0);
+        let contradictions: Vec<Contradiction> = [&acme, &beta]
+            .into_iter()
+            .filter_map(|old| detect_pairwise(old, &new_fact))
+            .collect();
+        assert_eq!(contradictions.len(), 2);
+
+        let checked = resolve(
+            ConflictPolicy::SupersedeOlder,
+            new_fact.clone(),
+            &[beta.clone(), acme.clone()],
+            contradictions.clone(),
+            |_| 1.0,
+        )
+        .unwrap();
+        for (c, old) in checked.contradictions.iter().zip([&acme, &beta]) {
+            assert!(matches!(
+                &c.applied_resolution,
+                Some(AppliedResolution::ClosedFact { fact_id, .. }) if *fact_id == old.id.to_string()
+            ));
+        }
+
+        let Err(err) = resolve(
+            ConflictPolicy::SupersedeOlder,
+            new_fact,
+            &[beta],
+            contradictions,
+            |_| 1.0,
+        ) else {
+            panic!("missing existing fact must not be skipped");
+        };
+        assert!(err.is_internal());
+    }
 }
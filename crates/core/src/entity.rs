@@ -0,0 +1,333 @@
+//! Entity resolution over alias and same-as facts.
+//!
+//! Two kinds of predicate describe identity:
+//!
+//! - **Alias predicates** (`alias`, `has_alias`, `aka` by default): the fact
+//!   `(alice, alias, "Ally")` makes `Ally` another name for `alice`.
+//! - **Same-as predicates** (`same_as` by default): the fact
+//!   `(a.smith, same_as, alice)` merges the subject `a.smith` into `alice`.
+//!
+//! Subjects linked by live same-as facts form one entity. Its canonical
+//! subject is the member that is not merged into anything else (a sink of
+//! the same-as edges); ties and cycles resolve to the smallest name, so the
+//! choice is deterministic. Only live facts count — invalidating a same-as
+//! fact splits the entity again, while the facts themselves keep their
+//! history.
+
+use crate::{Fact, FactId, KronroeError, Result, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Alias predicates recognised when none have been registered or removed.
+pub const DEFAULT_ALIAS_PREDICATES: [&str; 3] = ["alias", "has_alias", "aka"];
+
+/// Same-as predicate recognised by default and used by
+/// [`TemporalGraph::merge_entities`].
+///
+/// [`TemporalGraph::merge_entities`]: crate::TemporalGraph::merge_entities
+pub const DEFAULT_SAME_AS_PREDICATE: &str = "same_as";
+
+/// What a predicate says about identity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EntityPredicateRole {
+    Alias,
+    SameAs,
+}
+
+impl EntityPredicateRole {
+    /// Registry encoding; `None` records that a predicate was unregistered
+    /// (needed to remove a default).
+    pub(crate) fn encode(role: Option<Self>) -> String {
+        let name = match role {
+            Some(EntityPredicateRole::Alias) => "alias",
+            Some(EntityPredicateRole::SameAs) => "same_as",
+            None => "none",
+        };
+        format!("{{\"role\":\"{name}\"}}")
+    }
+
+    pub(crate) fn decode(encoded: &str) -> Result<Option<Self>> {
+        let val = crate::json_read::JsonValue::parse_str(encoded)?;
+        match val.get("role").and_then(|v| v.as_str()) {
+            Some("alias") => Ok(Some(EntityPredicateRole::Alias)),
+            Some("same_as") => Ok(Some(EntityPredicateRole::SameAs)),
+            Some("none") => Ok(None),
+            Some(other) => Err(KronroeError::serialization(format!(
+                "unknown entity predicate role '{other}'"
+            ))),
+            None => Err(KronroeError::serialization(
+                "entity predicate entry missing 'role'",
+            )),
+        }
+    }
+}
+
+/// In-memory view of the live alias and same-as facts.
+#[derive(Debug)]
+pub(crate) struct EntityIndex {
+    alias_predicates: BTreeSet<String>,
+    same_as_predicates: BTreeSet<String>,
+    /// Live alias facts: fact -> (subject, alias).
+    aliases: BTreeMap<FactId, (String, String)>,
+    /// Live same-as facts: fact -> (merged subject, target subject).
+    links: BTreeMap<FactId, (String, String)>,
+    /// Linked subject -> canonical subject. Unlinked subjects are absent.
+    canonical: HashMap<String, String>,
+    /// Canonical subject -> members, canonical first.
+    members: HashMap<String, Vec<String>>,
+    /// Lower-cased alias -> canonical subjects it names.
+    names: HashMap<String, BTreeSet<String>>,
+}
+
+impl EntityIndex {
+    /// Default predicates overridden by the persisted registry entries.
+    pub(crate) fn new(registry: Vec<(String, String)>) -> Result<Self> {
+        let mut index = Self {
+            alias_predicates: DEFAULT_ALIAS_PREDICATES
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+            same_as_predicates: BTreeSet::from([DEFAULT_SAME_AS_PREDICATE.to_string()]),
+            aliases: BTreeMap::new(),
+            links: BTreeMap::new(),
+            canonical: HashMap::new(),
+            members: HashMap::new(),
+            names: HashMap::new(),
+        };
+        for (predicate, encoded) in registry {
+            let role = EntityPredicateRole::decode(&encoded).map_err(|e| {
+                KronroeError::storage(format!(
+                    "invalid entity predicate registry entry for '{predicate}': {e}"
+                ))
+            })?;
+            index.set_role(&predicate, role);
+        }
+        Ok(index)
+    }
+
+    pub(crate) fn role(&self, predicate: &str) -> Option<EntityPredicateRole> {
+        if self.alias_predicates.contains(predicate) {
+            Some(EntityPredicateRole::Alias)
+        } else if self.same_as_predicates.contains(predicate) {
+            Some(EntityPredicateRole::SameAs)
+        } else {
+            None
+        }
+    }
+
+    /// Change a predicate's role. Callers must [`rebuild`](Self::rebuild)
+    /// afterwards so existing facts are re-read under the new role.
+    pub(crate) fn set_role(&mut self, predicate: &str, role: Option<EntityPredicateRole>) {
+        self.alias_predicates.remove(predicate);
+        self.same_as_predicates.remove(predicate);
+        match role {
+            Some(EntityPredicateRole::Alias) => {
+                self.alias_predicates.insert(predicate.to_string());
+            }
+            Some(EntityPredicateRole::SameAs) => {
+                self.same_as_predicates.insert(predicate.to_string());
+            }
+            None => {}
+        }
+    }
+
+    pub(crate) fn alias_predicates(&self) -> Vec<String> {
+        self.alias_predicates.iter().cloned().collect()
+    }
+
+    pub(crate) fn same_as_predicates(&self) -> Vec<String> {
+        self.same_as_predicates.iter().cloned().collect()
+    }
+
+    /// Replace all tracked facts.
+    pub(crate) fn rebuild<'a>(&mut self, facts: impl IntoIterator<Item = &'a Fact>) {
+        self.aliases.clear();
+        self.links.clear();
+        for fact in facts {
+            self.track(fact);
+        }
+        self.derive();
+    }
+
+    /// Bring the index up to date with a written or invalidated fact.
+    pub(crate) fn observe(&mut self, fact: &Fact) {
+        let was_tracked =
+            self.aliases.remove(&fact.id).is_some() || self.links.remove(&fact.id).is_some();
+        if self.track(fact) || was_tracked {
+            self.derive();
+        }
+    }
+
+    fn track(&mut self, fact: &Fact) -> bool {
+        if fact.valid_to.is_some() || fact.expired_at.is_some() {
+            return false;
+        }
+        let (Value::Text(name) | Value::Entity(name)) = &fact.object else {
+            return false;
+        };
+        if name.is_empty() || *name == fact.subject {
+            return false;
+        }
+        let entry = (fact.subject.clone(), name.clone());
+        match self.role(&fact.predicate) {
+            Some(EntityPredicateRole::Alias) => self.aliases.insert(fact.id.clone(), entry),
+            Some(EntityPredicateRole::SameAs) => self.links.insert(fact.id.clone(), entry),
+            None => return false,
+        };
+        true
+    }
+
+    /// Recompute canonical subjects, members, and alias names.
+    fn derive(&mut self) {
+        let mut neighbours: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        let mut merged: BTreeSet<&str> = BTreeSet::new();
+        for (from, into) in self.links.values() {
+            neighbours.entry(from).or_default().insert(into);
+            neighbours.entry(into).or_default().insert(from);
+            merged.insert(from);
+        }
+
+        let mut canonical = HashMap::new();
+        let mut members = HashMap::new();
+        let mut seen: BTreeSet<&str> = BTreeSet::new();
+        for &start in neighbours.keys() {
+            if !seen.insert(start) {
+                continue;
+            }
+            let mut component = BTreeSet::from([start]);
+            let mut stack = vec![start];
+            while let Some(subject) = stack.pop() {
+                for &next in &neighbours[subject] {
+                    if seen.insert(next) {
+                        component.insert(next);
+                        stack.push(next);
+                    }
+                }
+            }
+            let root = component
+                .iter()
+                .find(|subject| !merged.contains(*subject))
+                .or_else(|| component.first())
+                .map(|subject| subject.to_string())
+                .unwrap_or_default();
+            let mut list = vec![root.clone()];
+            for &subject in &component {
+                canonical.insert(subject.to_string(), root.clone());
+                if subject != root {
+                    list.push(subject.to_string());
+                }
+            }
+            members.insert(root, list);
+        }
+
+        let mut names: HashMap<String, BTreeSet<String>> = HashMap::new();
+        for (subject, alias) in self.aliases.values() {
+            let root = canonical.get(subject).unwrap_or(subject).clone();
+            names.entry(alias.to_lowercase()).or_default().insert(root);
+        }
+
+        self.canonical = canonical;
+        self.members = members;
+        self.names = names;
+    }
+
+    /// Is `subject` merged with at least one other subject?
+    pub(crate) fn is_linked(&self, subject: &str) -> bool {
+        self.canonical.contains_key(subject)
+    }
+
+    pub(crate) fn canonical(&self, subject: &str) -> String {
+        self.canonical
+            .get(subject)
+            .cloned()
+            .unwrap_or_else(|| subject.to_string())
+    }
+
+    /// Every subject of the entity `subject` belongs to, canonical first.
+    pub(crate) fn members(&self, subject: &str) -> Vec<String> {
+        let root = self.canonical(subject);
+        self.members
+            .get(&root)
+            .cloned()
+            .unwrap_or_else(|| vec![root])
+    }
+
+    /// Canonical subjects that have `name` as an alias (case-insensitive).
+    pub(crate) fn alias_targets(&self, name: &str) -> Vec<String> {
+        self.names
+            .get(&name.to_lowercase())
+            .map(|targets| targets.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Aliases of every member of `subject`'s entity, sorted and deduplicated.
+    pub(crate) fn aliases_of(&self, subject: &str) -> Vec<String> {
+        let members: BTreeSet<String> = self.members(subject).into_iter().collect();
+        self.aliases
+            .values()
+            .filter(|(owner, _)| members.contains(owner))
+            .map(|(_, alias)| alias.clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KronroeTimestamp;
+
+    fn fact(subject: &str, predicate: &str, object: &str) -> Fact {
+        Fact::new(
+            subject,
+            predicate,
+            Value::Entity(object.to_string()),
+            KronroeTimestamp::now_utc(),
+        )
+    }
+
+    #[test]
+    fn same_as_chains_resolve_to_the_unmerged_sink() {
+        let mut index = EntityIndex::new(Vec::new()).unwrap();
+        let ab = fact("a", "same_as", "b");
+        index.observe(&ab);
+        index.observe(&fact("b", "same_as", "c"));
+        index.observe(&fact("z", "same_as", "c"));
+        index.observe(&fact("c", "aka", "Cee"));
+
+        assert_eq!(index.canonical("a"), "c");
+        assert_eq!(index.members("z"), vec!["c", "a", "b", "z"]);
+        assert_eq!(index.alias_targets("CEE"), vec!["c"]);
+        assert_eq!(index.aliases_of("a"), vec!["Cee"]);
+
+        let mut invalidated = ab.clone();
+        invalidated.valid_to = Some(KronroeTimestamp::now_utc());
+        index.observe(&invalidated);
+        assert!(!index.is_linked("a"));
+        assert_eq!(index.members("a"), vec!["a"]);
+    }
+
+    #[test]
+    fn cycles_pick_the_smallest_member_and_roles_round_trip() {
+        let mut index = EntityIndex::new(vec![(
+            "alias".to_string(),
+            EntityPredicateRole::encode(None),
+        )])
+        .unwrap();
+        index.observe(&fact("y", "same_as", "x"));
+        index.observe(&fact("x", "same_as", "y"));
+        index.observe(&fact("x", "alias", "ex"));
+        assert_eq!(index.canonical("y"), "x");
+        assert!(index.alias_targets("ex").is_empty());
+
+        for role in [
+            None,
+            Some(EntityPredicateRole::Alias),
+            Some(EntityPredicateRole::SameAs),
+        ] {
+            let encoded = EntityPredicateRole::encode(role);
+            assert_eq!(EntityPredicateRole::decode(&encoded).unwrap(), role);
+        }
+        assert!(EntityPredicateRole::decode("{\"role\":\"other\"}").is_err());
+    }
+}
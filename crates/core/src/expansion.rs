@@ -0,0 +1,192 @@
+//! Lexical query expansion from registered aliases and predicate synonyms.
+//!
+//! A predicate and its registered synonyms form one group: a query that
+//! mentions any member ("employer") is expanded with the others
+//! (`works_at`). A query that mentions a registered alias ("Ally") is
+//! expanded with the canonical subject it names (`alice`). Matching is on
+//! whole lower-cased words, so `works_at` matches "works at" and "Works-At".
+
+use crate::entity::EntityIndex;
+use crate::json_read::JsonValue;
+use crate::{KronroeError, Result};
+use std::collections::BTreeMap;
+
+/// Longest alias, in words, looked up in a query.
+const MAX_ALIAS_WORDS: usize = 3;
+
+/// Registered synonyms per predicate.
+#[derive(Debug, Default)]
+pub(crate) struct SynonymIndex {
+    synonyms: BTreeMap<String, Vec<String>>,
+}
+
+impl SynonymIndex {
+    /// Index built from the persisted registry entries.
+    pub(crate) fn new(registry: Vec<(String, String)>) -> Result<Self> {
+        let mut index = Self::default();
+        for (predicate, encoded) in registry {
+            let synonyms = decode(&encoded).map_err(|e| {
+                KronroeError::storage(format!(
+                    "invalid predicate synonym registry entry for '{predicate}': {e}"
+                ))
+            })?;
+            index.set(&predicate, synonyms);
+        }
+        Ok(index)
+    }
+
+    /// Replace the synonyms of `predicate`; an empty list removes them.
+    pub(crate) fn set(&mut self, predicate: &str, synonyms: Vec<String>) {
+        if synonyms.is_empty() {
+            self.synonyms.remove(predicate);
+        } else {
+            self.synonyms.insert(predicate.to_string(), synonyms);
+        }
+    }
+
+    pub(crate) fn synonyms(&self, predicate: &str) -> Vec<String> {
+        self.synonyms.get(predicate).cloned().unwrap_or_default()
+    }
+
+    /// Groups of a predicate followed by its synonyms.
+    fn groups(&self) -> impl Iterator<Item = Vec<&str>> {
+        self.synonyms.iter().map(|(predicate, synonyms)| {
+            std::iter::once(predicate.as_str())
+                .chain(synonyms.iter().map(String::as_str))
+                .collect()
+        })
+    }
+}
+
+/// Registry encoding of a synonym list.
+pub(crate) fn encode(synonyms: &[String]) -> String {
+    let mut buf = b"{\"synonyms\":[".to_vec();
+    for (i, synonym) in synonyms.iter().enumerate() {
+        if i > 0 {
+            buf.push(b',');
+        }
+        crate::json_write::write_string(&mut buf, synonym).expect("Vec<u8> write is infallible");
+    }
+    buf.extend_from_slice(b"]}");
+    String::from_utf8(buf).expect("JSON writer produces UTF-8")
+}
+
+fn decode(encoded: &str) -> Result<Vec<String>> {
+    let val = JsonValue::parse_str(encoded)?;
+    let items = val
+        .get("synonyms")
+        .and_then(JsonValue::as_array)
+        .ok_or_else(|| KronroeError::serialization("synonym entry missing 'synonyms'"))?;
+    items
+        .iter()
+        .map(|item| {
+            item.as_str()
+                .map(str::to_string)
+                .ok_or_else(|| KronroeError::serialization("synonym must be a string"))
+        })
+        .collect()
+}
+
+/// Lower-cased alphanumeric words of `text`.
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn mentions(query: &[String], term: &str) -> bool {
+    let term = words(term);
+    !term.is_empty()
+        && query
+            .windows(term.len())
+            .any(|window| window == term.as_slice())
+}
+
+/// Terms to add to `query`: the other members of every synonym group it
+/// mentions and the canonical subjects of the aliases it mentions, in that
+/// order, without duplicates or terms the query already contains.
+pub(crate) fn expand(query: &str, synonyms: &SynonymIndex, entities: &EntityIndex) -> Vec<String> {
+    let query_words = words(query);
+    let mut out: Vec<String> = Vec::new();
+    let push = |term: &str, out: &mut Vec<String>| {
+        if !mentions(&query_words, term) && !out.iter().any(|t| t == term) {
+            out.push(term.to_string());
+        }
+    };
+
+    for group in synonyms.groups() {
+        if group.iter().any(|member| mentions(&query_words, member)) {
+            for member in group {
+                push(member, &mut out);
+            }
+        }
+    }
+
+    for len in (1..=MAX_ALIAS_WORDS).rev() {
+        for window in query_words.windows(len) {
+            for subject in entities.alias_targets(&window.join(" ")) {
+                push(&subject, &mut out);
+            }
+        }
+    }
+    out
+}
+
+/// `terms` as one full-text query: implicit `OR`, multi-word terms quoted
+/// as phrases.
+#[cfg_attr(not(feature = "hybrid"), allow(dead_code))]
+pub(crate) fn to_query(terms: &[String]) -> String {
+    terms
+        .iter()
+        .map(|term| {
+            let term = term.replace('"', " ");
+            if term.split_whitespace().nth(1).is_some() {
+                format!("\"{term}\"")
+            } else {
+                term
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Fact, KronroeTimestamp};
+
+    #[test]
+    fn expands_synonym_groups_and_aliases() {
+        let mut synonyms = SynonymIndex::default();
+        synonyms.set(
+            "works_at",
+            vec!["employer".to_string(), "works for".to_string()],
+        );
+        let mut entities = EntityIndex::new(Vec::new()).unwrap();
+        let alias = Fact::new("alice", "aka", "Ally", KronroeTimestamp::now_utc());
+        entities.rebuild(std::iter::once(&alias));
+
+        assert_eq!(
+            expand("Who is Ally's employer?", &synonyms, &entities),
+            ["works_at", "works for", "alice"]
+        );
+        assert_eq!(
+            expand("where alice works at", &synonyms, &entities),
+            ["employer", "works for"]
+        );
+        assert_eq!(
+            expand("ally", &SynonymIndex::default(), &entities),
+            ["alice"]
+        );
+        assert!(expand("employment", &synonyms, &entities).is_empty());
+        assert_eq!(
+            to_query(&["works_at".to_string(), "works for".to_string()]),
+            "works_at \"works for\""
+        );
+
+        let encoded = encode(&["employer".to_string(), "say \"hi\"".to_string()]);
+        assert_eq!(decode(&encoded).unwrap(), ["employer", "say \"hi\""]);
+        assert!(decode("{}").is_err());
+    }
+}
@@ -0,0 +1,236 @@
+//! Why a fact matched a full-text query: matched terms per field and
+//! highlighted snippets of the object text.
+
+#[cfg(feature = "fulltext")]
+use crate::tokenizer::Tokenizer;
+#[cfg(feature = "fulltext")]
+use std::collections::HashSet;
+
+/// Marker inserted before a highlighted word in [`SearchExplanation::snippets`].
+pub const HIGHLIGHT_START: &str = "**";
+/// Marker inserted after a highlighted word in [`SearchExplanation::snippets`].
+pub const HIGHLIGHT_END: &str = "**";
+
+/// Object text up to this many characters is returned as a single snippet.
+#[cfg(feature = "fulltext")]
+const SNIPPET_MAX_CHARS: usize = 160;
+/// Characters of context kept on each side of a match in longer texts.
+#[cfg(feature = "fulltext")]
+const SNIPPET_CONTEXT_CHARS: usize = 60;
+#[cfg(feature = "fulltext")]
+const MAX_SNIPPETS: usize = 3;
+
+/// Part of a fact that full-text search indexes, usable as a query scope
+/// (`subject:alice`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SearchField {
+    Subject,
+    Predicate,
+    /// Aliases asserted for the fact's subject (`alias`, `has_alias`, `aka`).
+    Alias,
+    Object,
+}
+
+impl SearchField {
+    /// Name used in query scopes and serialized explanations.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SearchField::Subject => "subject",
+            SearchField::Predicate => "predicate",
+            SearchField::Alias => "alias",
+            SearchField::Object => "object",
+        }
+    }
+
+    #[cfg(feature = "fulltext")]
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        match name {
+            "subject" => Some(SearchField::Subject),
+            "predicate" => Some(SearchField::Predicate),
+            "alias" => Some(SearchField::Alias),
+            "object" => Some(SearchField::Object),
+            _ => None,
+        }
+    }
+}
+
+/// One query term found in a fact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TermMatch {
+    /// The term as it appeared in the tokenized query (a prefix for `acm*`).
+    pub query_term: String,
+    /// The indexed term it matched.
+    pub term: String,
+    /// Where in the fact the indexed term occurs.
+    pub field: SearchField,
+    /// Edits between `query_term` and `term`; non-zero for fuzzy matches.
+    pub edit_distance: u8,
+}
+
+impl TermMatch {
+    /// Did this match need a fuzzy substitution?
+    pub fn is_fuzzy(&self) -> bool {
+        self.edit_distance > 0
+    }
+}
+
+/// Evidence for a full-text hit, from
+/// [`TemporalGraph::search_scored_explained`] or
+/// [`TemporalGraph::explain_search_match`].
+///
+/// [`TemporalGraph::search_scored_explained`]: crate::TemporalGraph::search_scored_explained
+/// [`TemporalGraph::explain_search_match`]: crate::TemporalGraph::explain_search_match
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchExplanation {
+    /// Matched terms, one entry per indexed term and field, in query order.
+    pub matches: Vec<TermMatch>,
+    /// Object text with matched words wrapped in [`HIGHLIGHT_START`] /
+    /// [`HIGHLIGHT_END`]. Short texts give one snippet; longer texts give up
+    /// to three windows around matches, with `…` marking cut ends. Empty
+    /// when nothing matched in the object.
+    pub snippets: Vec<String>,
+}
+
+/// Highlight every word of `text` whose tokens include one of `terms`.
+#[cfg(feature = "fulltext")]
+pub(crate) fn highlight_snippets(
+    text: &str,
+    terms: &HashSet<&str>,
+    tokenizer: &dyn Tokenizer,
+) -> Vec<String> {
+    if terms.is_empty() {
+        return Vec::new();
+    }
+    let words = word_spans(text);
+    let matched: Vec<(usize, usize)> = words
+        .into_iter()
+        .filter(|&(start, end)| {
+            tokenizer
+                .tokenize(&text[start..end])
+                .iter()
+                .any(|token| terms.contains(token.as_str()))
+        })
+        .collect();
+    if matched.is_empty() {
+        return Vec::new();
+    }
+
+    if text.chars().count() <= SNIPPET_MAX_CHARS {
+        return vec![render(text, 0, text.len(), &matched)];
+    }
+
+    // Windows of context around each match, merged when they overlap.
+    let mut windows: Vec<(usize, usize)> = Vec::new();
+    for &(start, end) in &matched {
+        let from = back_chars(text, start, SNIPPET_CONTEXT_CHARS);
+        let to = forward_chars(text, end, SNIPPET_CONTEXT_CHARS);
+        match windows.last_mut() {
+            Some(last) if from <= last.1 => last.1 = last.1.max(to),
+            _ => windows.push((from, to)),
+        }
+    }
+    windows
+        .into_iter()
+        .take(MAX_SNIPPETS)
+        .map(|(from, to)| {
+            let mut snippet = String::new();
+            if from > 0 {
+                snippet.push('…');
+            }
+            snippet.push_str(render(text, from, to, &matched).trim());
+            if to < text.len() {
+                snippet.push('…');
+            }
+            snippet
+        })
+        .collect()
+}
+
+/// Byte ranges of maximal alphanumeric runs.
+#[cfg(feature = "fulltext")]
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (idx, ch) in text.char_indices() {
+        match (ch.is_alphanumeric(), start) {
+            (true, None) => start = Some(idx),
+            (false, Some(begin)) => {
+                spans.push((begin, idx));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        spans.push((begin, text.len()));
+    }
+    spans
+}
+
+#[cfg(feature = "fulltext")]
+fn render(text: &str, from: usize, to: usize, matched: &[(usize, usize)]) -> String {
+    let mut out = String::new();
+    let mut cursor = from;
+    for &(start, end) in matched {
+        if start < from || end > to {
+            continue;
+        }
+        out.push_str(&text[cursor..start]);
+        out.push_str(HIGHLIGHT_START);
+        out.push_str(&text[start..end]);
+        out.push_str(HIGHLIGHT_END);
+        cursor = end;
+    }
+    out.push_str(&text[cursor..to]);
+    out
+}
+
+#[cfg(feature = "fulltext")]
+fn back_chars(text: &str, from: usize, count: usize) -> usize {
+    text[..from]
+        .char_indices()
+        .rev()
+        .nth(count.saturating_sub(1))
+        .map_or(0, |(idx, _)| idx)
+}
+
+#[cfg(feature = "fulltext")]
+fn forward_chars(text: &str, from: usize, count: usize) -> usize {
+    text[from..]
+        .char_indices()
+        .nth(count)
+        .map_or(text.len(), |(idx, _)| from + idx)
+}
+
+#[cfg(all(test, feature = "fulltext"))]
+mod tests {
+    use super::*;
+    use crate::tokenizer::StandardTokenizer;
+
+    #[test]
+    fn short_text_is_highlighted_in_full_with_tokenizer_normalization() {
+        let tokenizer = StandardTokenizer::english();
+        let terms: HashSet<&str> = ["work", "cafe"].into_iter().collect();
+        assert_eq!(
+            highlight_snippets("Working at Café Zürich", &terms, &tokenizer),
+            vec!["**Working** at **Café** Zürich".to_string()]
+        );
+        assert!(highlight_snippets("nothing here", &terms, &tokenizer).is_empty());
+    }
+
+    #[test]
+    fn long_text_yields_merged_windows_around_matches() {
+        let tokenizer = StandardTokenizer::new();
+        let filler = "lorem ipsum ".repeat(20);
+        let text = format!("acme {filler}rust and acme again {filler}end");
+        let terms: HashSet<&str> = ["acme"].into_iter().collect();
+        let snippets = highlight_snippets(&text, &terms, &tokenizer);
+
+        assert_eq!(snippets.len(), 2);
+        assert!(snippets[0].starts_with("**acme** lorem"));
+        assert!(snippets[0].ends_with('…'));
+        assert!(snippets[1].starts_with('…'));
+        assert!(snippets[1].contains("rust and **acme** again"));
+        assert!(snippets.iter().all(|s| s.chars().count() < 160));
+    }
+}
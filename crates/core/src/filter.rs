@@ -0,0 +1,186 @@
+//! Search filters shared by the full-text and vector indexes.
+//!
+//! Both indexes keep a [`DocumentMeta`] per indexed fact, refreshed on every
+//! write, so a [`SearchFilter`] is evaluated inside the index.
+
+use crate::{Fact, KronroeTimestamp};
+
+/// Restrictions applied to search hits before the top `limit` are chosen,
+/// for [`TemporalGraph::search_scored_filtered`] and
+/// [`TemporalGraph::search_by_vector_filtered`].
+///
+/// Every set field must match; the default filter accepts every fact ever
+/// written, including invalidated ones.
+///
+/// ```rust,no_run
+/// # use kronroe::{SearchFilter, TemporalGraph};
+/// # let db = TemporalGraph::open_in_memory().unwrap();
+/// let filter = SearchFilter::new()
+///     .with_current_only()
+///     .with_excluded_predicates(["alias"])
+///     .with_min_confidence(0.5);
+/// let hits = db.search_scored_filtered("acme", 10, &filter).unwrap();
+/// ```
+///
+/// [`TemporalGraph::search_scored_filtered`]: crate::TemporalGraph::search_scored_filtered
+/// [`TemporalGraph::search_by_vector_filtered`]: crate::TemporalGraph::search_by_vector_filtered
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchFilter {
+    /// Only facts that are currently valid (see [`Fact::is_currently_valid`]).
+    pub current_only: bool,
+    /// Only facts valid in the world at this time (see [`Fact::was_valid_at`]).
+    pub valid_at: Option<KronroeTimestamp>,
+    /// Only facts valid at some point in the valid-time interval
+    /// `[start, end)`.
+    pub valid_during: Option<(KronroeTimestamp, KronroeTimestamp)>,
+    /// Only facts the database held at this time: recorded at or before it
+    /// and not yet expired.
+    pub known_at: Option<KronroeTimestamp>,
+    /// If non-empty, only facts with one of these predicates.
+    pub predicates: Vec<String>,
+    /// Never facts with one of these predicates.
+    pub excluded_predicates: Vec<String>,
+    /// Only facts with exactly this source.
+    pub source: Option<String>,
+    /// Only facts with at least this confidence.
+    pub min_confidence: Option<f32>,
+    /// Only facts whose subject starts with this string.
+    pub subject_prefix: Option<String>,
+}
+
+impl SearchFilter {
+    /// A filter that accepts every fact.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep only currently valid facts.
+    pub fn with_current_only(mut self) -> Self {
+        self.current_only = true;
+        self
+    }
+
+    /// Keep only facts valid at `at`.
+    pub fn with_valid_at(mut self, at: KronroeTimestamp) -> Self {
+        self.valid_at = Some(at);
+        self
+    }
+
+    /// Keep only facts valid at some point in `[start, end)`.
+    pub fn with_valid_during(mut self, start: KronroeTimestamp, end: KronroeTimestamp) -> Self {
+        self.valid_during = Some((start, end));
+        self
+    }
+
+    /// Keep only facts the database held at `at`.
+    pub fn with_known_at(mut self, at: KronroeTimestamp) -> Self {
+        self.known_at = Some(at);
+        self
+    }
+
+    /// Keep only facts with one of `predicates`.
+    pub fn with_predicates<I, S>(mut self, predicates: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.predicates = predicates.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Drop facts with one of `predicates`.
+    pub fn with_excluded_predicates<I, S>(mut self, predicates: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.excluded_predicates = predicates.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Keep only facts from `source`.
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Keep only facts with confidence of at least `min`.
+    pub fn with_min_confidence(mut self, min: f32) -> Self {
+        self.min_confidence = Some(min);
+        self
+    }
+
+    /// Keep only facts whose subject starts with `prefix`.
+    pub fn with_subject_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.subject_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Does `fact` pass every restriction?
+    pub fn matches(&self, fact: &Fact) -> bool {
+        self.accepts(&fact.subject, &DocumentMeta::of(fact))
+    }
+
+    pub(crate) fn is_unrestricted(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub(crate) fn accepts(&self, subject: &str, meta: &DocumentMeta) -> bool {
+        let open_at = |end: Option<KronroeTimestamp>, at| end.is_none_or(|end| end > at);
+        (!self.current_only || meta.is_current())
+            && self.valid_at.is_none_or(|at| {
+                meta.valid_from <= at && open_at(meta.valid_to, at) && open_at(meta.expired_at, at)
+            })
+            && self.valid_during.is_none_or(|(start, end)| {
+                meta.valid_from < end
+                    && open_at(meta.valid_to, start)
+                    && open_at(meta.expired_at, start)
+            })
+            && self
+                .known_at
+                .is_none_or(|at| meta.recorded_at <= at && open_at(meta.expired_at, at))
+            && (self.predicates.is_empty() || self.predicates.contains(&meta.predicate))
+            && !self.excluded_predicates.contains(&meta.predicate)
+            && self
+                .source
+                .as_ref()
+                .is_none_or(|source| meta.source.as_ref() == Some(source))
+            && self.min_confidence.is_none_or(|min| meta.confidence >= min)
+            && self
+                .subject_prefix
+                .as_ref()
+                .is_none_or(|prefix| subject.starts_with(prefix.as_str()))
+    }
+}
+
+/// The fact fields [`SearchFilter`] looks at, kept next to each indexed fact
+/// so filtering never has to load facts from storage.
+#[derive(Debug, Clone)]
+pub(crate) struct DocumentMeta {
+    predicate: String,
+    source: Option<String>,
+    confidence: f32,
+    valid_from: KronroeTimestamp,
+    valid_to: Option<KronroeTimestamp>,
+    recorded_at: KronroeTimestamp,
+    expired_at: Option<KronroeTimestamp>,
+}
+
+impl DocumentMeta {
+    pub(crate) fn of(fact: &Fact) -> Self {
+        Self {
+            predicate: fact.predicate.clone(),
+            source: fact.source.clone(),
+            confidence: fact.confidence,
+            valid_from: fact.valid_from,
+            valid_to: fact.valid_to,
+            recorded_at: fact.recorded_at,
+            expired_at: fact.expired_at,
+        }
+    }
+
+    /// Neither invalidated nor expired (see [`Fact::is_currently_valid`]).
+    pub(crate) fn is_current(&self) -> bool {
+        self.valid_to.is_none() && self.expired_at.is_none()
+    }
+}
@@ -0,0 +1,334 @@
+//! Typo-tolerant term matching for full-text search.
+//!
+//! [`FuzzyConfig`] controls how query terms that are missing from the index
+//! vocabulary are expanded to nearby indexed terms. Matching walks a trie of
+//! the vocabulary while computing one row of the edit-distance table per
+//! node, and prunes every branch whose best row entry already exceeds the
+//! allowed distance — so a lookup touches only the prefixes that can still
+//! match instead of scanning every term.
+
+use std::collections::BTreeMap;
+
+/// Typo tolerance for full-text queries, set with
+/// [`SearchConfig::with_fuzzy`].
+///
+/// Fuzziness is decided per query term: a term that occurs in the index is
+/// matched exactly, and only a term with no exact match is expanded to the
+/// indexed terms within `max_distance` edits. Each edit multiplies the BM25
+/// score of a fuzzy hit by `edit_penalty`, so fuzzy hits rank below equally
+/// relevant exact hits.
+///
+/// ```rust
+/// # use kronroe::{FuzzyConfig, SearchConfig};
+/// let config = SearchConfig::new().with_fuzzy(
+///     FuzzyConfig::new()
+///         .with_max_distance(2)
+///         .with_min_term_length(4),
+/// );
+/// ```
+///
+/// [`SearchConfig::with_fuzzy`]: crate::SearchConfig::with_fuzzy
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FuzzyConfig {
+    max_distance: u8,
+    transpositions: bool,
+    min_term_length: usize,
+    edit_penalty: f32,
+}
+
+impl FuzzyConfig {
+    /// Largest supported edit distance.
+    pub const MAX_DISTANCE: u8 = 2;
+
+    /// One edit, adjacent transpositions count as one edit, terms of three
+    /// or more characters, and a score penalty of `0.5` per edit.
+    pub fn new() -> Self {
+        Self {
+            max_distance: 1,
+            transpositions: true,
+            min_term_length: 3,
+            edit_penalty: 0.5,
+        }
+    }
+
+    /// Exact matching only.
+    pub fn disabled() -> Self {
+        Self::new().with_max_distance(0)
+    }
+
+    /// Maximum number of edits (insertions, deletions, substitutions, and —
+    /// if enabled — transpositions) between a query term and an indexed
+    /// term. Values above [`Self::MAX_DISTANCE`] are clamped.
+    pub fn with_max_distance(mut self, max_distance: u8) -> Self {
+        self.max_distance = max_distance.min(Self::MAX_DISTANCE);
+        self
+    }
+
+    /// Count swapping two adjacent characters (`alcie` → `alice`) as a
+    /// single edit (Damerau distance) rather than two.
+    pub fn with_transpositions(mut self, transpositions: bool) -> Self {
+        self.transpositions = transpositions;
+        self
+    }
+
+    /// Query terms shorter than this many characters are never expanded.
+    pub fn with_min_term_length(mut self, min_term_length: usize) -> Self {
+        self.min_term_length = min_term_length;
+        self
+    }
+
+    /// Score multiplier applied once per edit, clamped to `0.0..=1.0`.
+    pub fn with_edit_penalty(mut self, edit_penalty: f32) -> Self {
+        self.edit_penalty = if edit_penalty.is_nan() {
+            0.0
+        } else {
+            edit_penalty.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    pub fn max_distance(&self) -> u8 {
+        self.max_distance
+    }
+
+    pub fn transpositions(&self) -> bool {
+        self.transpositions
+    }
+
+    pub fn min_term_length(&self) -> usize {
+        self.min_term_length
+    }
+
+    pub fn edit_penalty(&self) -> f32 {
+        self.edit_penalty
+    }
+
+    /// Edit budget for `term`, or `None` if it must match exactly.
+    pub(crate) fn budget_for(&self, term: &str) -> Option<u8> {
+        (self.max_distance > 0 && term.chars().count() >= self.min_term_length)
+            .then_some(self.max_distance)
+    }
+
+    /// Score weight for a hit `distance` edits away from the query term.
+    pub(crate) fn weight(&self, distance: u8) -> f64 {
+        f64::from(self.edit_penalty).powi(i32::from(distance))
+    }
+}
+
+impl Default for FuzzyConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Character trie over the index vocabulary.
+#[derive(Debug, Default)]
+pub(crate) struct TermTrie {
+    root: TrieNode,
+}
+
+#[derive(Debug, Default)]
+struct TrieNode {
+    children: BTreeMap<char, TrieNode>,
+    terminal: bool,
+}
+
+impl TermTrie {
+    pub(crate) fn insert(&mut self, term: &str) {
+        let mut node = &mut self.root;
+        for ch in term.chars() {
+            node = node.children.entry(ch).or_default();
+        }
+        node.terminal = true;
+    }
+
+    /// Remove `term`, pruning branches that no longer lead to any term.
+    pub(crate) fn remove(&mut self, term: &str) {
+        fn remove_from(node: &mut TrieNode, chars: &[char]) {
+            match chars.split_first() {
+                None => node.terminal = false,
+                Some((ch, rest)) => {
+                    let Some(child) = node.children.get_mut(ch) else {
+                        return;
+                    };
+                    remove_from(child, rest);
+                    if !child.terminal && child.children.is_empty() {
+                        node.children.remove(ch);
+                    }
+                }
+            }
+        }
+        let chars: Vec<char> = term.chars().collect();
+        remove_from(&mut self.root, &chars);
+    }
+
+    /// Every term within `max_distance` edits of `query`, with its distance,
+    /// in lexicographic order.
+    pub(crate) fn matches(
+        &self,
+        query: &str,
+        max_distance: u8,
+        transpositions: bool,
+    ) -> Vec<(String, u8)> {
+        let walk = TrieWalk {
+            query: query.chars().collect(),
+            max_distance: usize::from(max_distance),
+            transpositions,
+        };
+        let first_row: Vec<usize> = (0..=walk.query.len()).collect();
+        let mut prefix = String::new();
+        let mut out = Vec::new();
+        for (&ch, child) in &self.root.children {
+            walk.visit(child, ch, None, &first_row, &[], &mut prefix, &mut out);
+        }
+        out
+    }
+}
+
+struct TrieWalk {
+    query: Vec<char>,
+    max_distance: usize,
+    transpositions: bool,
+}
+
+impl TrieWalk {
+    /// Compute the edit-distance row for `prefix + ch` from the rows of its
+    /// parent (`prev_row`) and grandparent (`prev_prev_row`, needed for
+    /// transpositions), then descend while the row can still reach the
+    /// budget.
+    #[allow(clippy::too_many_arguments)]
+    fn visit(
+        &self,
+        node: &TrieNode,
+        ch: char,
+        prev_ch: Option<char>,
+        prev_row: &[usize],
+        prev_prev_row: &[usize],
+        prefix: &mut String,
+        out: &mut Vec<(String, u8)>,
+    ) {
+        let mut row = Vec::with_capacity(prev_row.len());
+        row.push(prev_row[0] + 1);
+        for i in 1..prev_row.len() {
+            let cost = usize::from(self.query[i - 1] != ch);
+            let mut cell = (prev_row[i] + 1)
+                .min(row[i - 1] + 1)
+                .min(prev_row[i - 1] + cost);
+            if self.transpositions
+                && i > 1
+                && prev_ch == Some(self.query[i - 1])
+                && ch == self.query[i - 2]
+            {
+                cell = cell.min(prev_prev_row[i - 2] + 1);
+            }
+            row.push(cell);
+        }
+
+        prefix.push(ch);
+        let distance = row[row.len() - 1];
+        if node.terminal && distance <= self.max_distance {
+            out.push((prefix.clone(), distance as u8));
+        }
+        // Every later row entry is at least the current row's minimum
+        // (a transposition costs one more than an entry this row bounds).
+        if row
+            .iter()
+            .min()
+            .is_some_and(|&min| min <= self.max_distance)
+        {
+            for (&next, child) in &node.children {
+                self.visit(child, next, Some(ch), &row, prev_row, prefix, out);
+            }
+        }
+        prefix.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reference optimal-string-alignment distance.
+    fn osa_distance(a: &str, b: &str, transpositions: bool) -> usize {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        let mut d = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+        for (i, row) in d.iter_mut().enumerate() {
+            row[0] = i;
+        }
+        for (j, cell) in d[0].iter_mut().enumerate() {
+            *cell = j;
+        }
+        for i in 1..=a.len() {
+            for j in 1..=b.len() {
+                let cost = usize::from(a[i - 1] != b[j - 1]);
+                d[i][j] = (d[i - 1][j] + 1)
+                    .min(d[i][j - 1] + 1)
+                    .min(d[i - 1][j - 1] + cost);
+                if transpositions && i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1]
+                {
+                    d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+                }
+            }
+        }
+        d[a.len()][b.len()]
+    }
+
+    #[test]
+    fn trie_matches_agree_with_reference_distance() {
+        let vocabulary = [
+            "alice", "alcie", "alicia", "ali", "bob", "bobby", "acme", "acne", "zürich", "zurich",
+            "ab", "ba", "abc", "cab",
+        ];
+        let mut trie = TermTrie::default();
+        for term in vocabulary {
+            trie.insert(term);
+        }
+
+        for query in ["alice", "alcie", "bbo", "acem", "zurih", "ba", "x", ""] {
+            for max_distance in 0..=2u8 {
+                for transpositions in [false, true] {
+                    let mut expected: Vec<(String, u8)> = vocabulary
+                        .iter()
+                        .map(|term| (term, osa_distance(query, term, transpositions)))
+                        .filter(|(_, d)| *d <= usize::from(max_distance))
+                        .map(|(term, d)| (term.to_string(), d as u8))
+                        .collect();
+                    expected.sort();
+                    assert_eq!(
+                        trie.matches(query, max_distance, transpositions),
+                        expected,
+                        "query={query} max={max_distance} transpositions={transpositions}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn remove_prunes_terms_but_keeps_shared_prefixes() {
+        let mut trie = TermTrie::default();
+        trie.insert("acme");
+        trie.insert("acmeware");
+        trie.remove("acmeware");
+        trie.remove("missing");
+        assert_eq!(trie.matches("acmewar", 2, true), Vec::new());
+        assert_eq!(trie.matches("acm", 1, true), vec![("acme".to_string(), 1)]);
+        trie.remove("acme");
+        assert!(trie.root.children.is_empty());
+    }
+
+    #[test]
+    fn config_clamps_and_gates_by_term_length() {
+        let config = FuzzyConfig::new()
+            .with_max_distance(9)
+            .with_min_term_length(4)
+            .with_edit_penalty(2.0);
+        assert_eq!(config.max_distance(), FuzzyConfig::MAX_DISTANCE);
+        assert_eq!(config.edit_penalty(), 1.0);
+        assert_eq!(config.budget_for("abc"), None);
+        assert_eq!(config.budget_for("abcd"), Some(2));
+        assert_eq!(FuzzyConfig::disabled().budget_for("alice"), None);
+        assert_eq!(FuzzyConfig::new().weight(2), 0.25);
+    }
+}
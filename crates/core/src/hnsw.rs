@@ -0,0 +1,516 @@
+//! Hierarchical navigable small world (HNSW) graph for approximate
+//! nearest-neighbour vector search.
+//!
+//! Pure Rust with no dependencies, so it runs on every target the core crate
+//! supports. The graph stores only neighbour lists over integer slots; the
+//! vectors themselves stay in [`VectorIndex`](crate::vector::VectorIndex),
+//! which supplies distances through [`Points`] and closures.
+//!
+//! Level assignment uses a seeded generator, so the same insertion sequence
+//! always produces the same graph.
+//!
+//! # Complexity
+//! - `insert`: O(log n · ef_construction · M) distance evaluations
+//! - `remove`: O(M² · layers) — each neighbour of the removed node is
+//!   reconnected from the union of both neighbour lists
+//! - `search`: O(log n · ef · M)
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashSet};
+
+/// Upper bound on node levels; with the default `m` a level above 16 needs
+/// around 10¹⁹ entries.
+const MAX_LEVEL: usize = 16;
+const DEFAULT_SEED: u64 = 0x6b72_6f6e_726f_6531;
+
+/// Parameters of the approximate nearest-neighbour index used by
+/// [`TemporalGraph::search_by_vector`], set with [`VectorConfig::with_hnsw`].
+///
+/// ```rust
+/// # use kronroe::{HnswConfig, VectorConfig};
+/// let config = VectorConfig::new().with_hnsw(
+///     HnswConfig::new()
+///         .with_m(24)
+///         .with_ef_construction(300)
+///         .with_ef_search(128),
+/// );
+/// ```
+///
+/// Indexes smaller than [`exact_threshold`](Self::with_exact_threshold) are
+/// searched exhaustively, which is exact and fast at that size; the graph is
+/// built when the index first reaches the threshold.
+///
+/// [`TemporalGraph::search_by_vector`]: crate::TemporalGraph::search_by_vector
+/// [`VectorConfig::with_hnsw`]: crate::VectorConfig::with_hnsw
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HnswConfig {
+    m: usize,
+    ef_construction: usize,
+    ef_search: usize,
+    exact_threshold: usize,
+}
+
+impl HnswConfig {
+    /// `m = 16`, `ef_construction = 200`, `ef_search = 64`, and exact search
+    /// below 2 048 entries.
+    pub fn new() -> Self {
+        Self {
+            m: 16,
+            ef_construction: 200,
+            ef_search: 64,
+            exact_threshold: 2048,
+        }
+    }
+
+    /// Links per node on the upper layers (twice this on the bottom layer).
+    /// Higher values raise recall and memory use. Clamped to at least 2.
+    pub fn with_m(mut self, m: usize) -> Self {
+        self.m = m.max(2);
+        self
+    }
+
+    /// Candidate list size while inserting. Higher values build a better
+    /// graph more slowly. Clamped to at least 1.
+    pub fn with_ef_construction(mut self, ef_construction: usize) -> Self {
+        self.ef_construction = ef_construction.max(1);
+        self
+    }
+
+    /// Candidate list size while searching (raised to `k` when smaller).
+    /// Higher values raise recall and latency. Clamped to at least 1.
+    pub fn with_ef_search(mut self, ef_search: usize) -> Self {
+        self.ef_search = ef_search.max(1);
+        self
+    }
+
+    /// Search exhaustively while the index holds fewer entries than this.
+    /// `0` always uses the graph.
+    pub fn with_exact_threshold(mut self, exact_threshold: usize) -> Self {
+        self.exact_threshold = exact_threshold;
+        self
+    }
+
+    pub fn m(&self) -> usize {
+        self.m
+    }
+
+    pub fn ef_construction(&self) -> usize {
+        self.ef_construction
+    }
+
+    pub fn ef_search(&self) -> usize {
+        self.ef_search
+    }
+
+    pub fn exact_threshold(&self) -> usize {
+        self.exact_threshold
+    }
+}
+
+impl Default for HnswConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Distances between stored points; smaller is closer.
+pub(crate) trait Points {
+    fn distance(&self, a: u32, b: u32) -> f32;
+}
+
+/// A slot paired with its distance from the point being searched for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Scored {
+    pub(crate) distance: f32,
+    pub(crate) slot: u32,
+}
+
+impl Eq for Scored {}
+
+impl Ord for Scored {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.slot.cmp(&other.slot))
+    }
+}
+
+impl PartialOrd for Scored {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct HnswGraph {
+    m: usize,
+    ef_construction: usize,
+    /// Neighbour lists per slot, bottom layer first. Vacant slots have no
+    /// layers; links into them are skipped.
+    links: Vec<Vec<Vec<u32>>>,
+    entry: Option<u32>,
+    rng: u64,
+}
+
+impl HnswGraph {
+    pub(crate) fn new(config: &HnswConfig) -> Self {
+        Self {
+            m: config.m,
+            ef_construction: config.ef_construction,
+            links: Vec::new(),
+            entry: None,
+            rng: DEFAULT_SEED,
+        }
+    }
+
+    /// Was this graph built with the structural parameters of `config`?
+    pub(crate) fn matches_config(&self, config: &HnswConfig) -> bool {
+        self.m == config.m && self.ef_construction == config.ef_construction
+    }
+
+    /// Slots covered, including vacant ones.
+    pub(crate) fn slot_count(&self) -> usize {
+        self.links.len()
+    }
+
+    fn max_links(&self, layer: usize) -> usize {
+        if layer == 0 {
+            self.m * 2
+        } else {
+            self.m
+        }
+    }
+
+    fn is_live_at(&self, slot: u32, layer: usize) -> bool {
+        self.links
+            .get(slot as usize)
+            .is_some_and(|layers| layers.len() > layer)
+    }
+
+    /// Link `slot` into the graph. The slot must be vacant.
+    pub(crate) fn insert(&mut self, slot: u32, points: &impl Points) {
+        let level = self.random_level();
+        let index = slot as usize;
+        if self.links.len() <= index {
+            self.links.resize_with(index + 1, Vec::new);
+        }
+        self.links[index] = vec![Vec::new(); level + 1];
+
+        let Some(entry) = self.entry else {
+            self.entry = Some(slot);
+            return;
+        };
+        let distance = |other: u32| points.distance(slot, other);
+        let top = self.links[entry as usize].len() - 1;
+        let mut nearest = vec![Scored {
+            distance: distance(entry),
+            slot: entry,
+        }];
+        for layer in (level + 1..=top).rev() {
+            nearest = self.search_layer(&distance, &nearest, 1, layer);
+        }
+        for layer in (0..=level.min(top)).rev() {
+            nearest = self.search_layer(&distance, &nearest, self.ef_construction, layer);
+            // A reused slot can be reached through stale links to itself.
+            nearest.retain(|s| s.slot != slot);
+            let neighbours = select_neighbours(points, &nearest, self.m);
+            for &neighbour in &neighbours {
+                let max = self.max_links(layer);
+                let list = &mut self.links[neighbour as usize][layer];
+                list.push(slot);
+                if list.len() > max {
+                    self.prune(neighbour, layer, points);
+                }
+            }
+            self.links[index][layer] = neighbours;
+        }
+        if level > top {
+            self.entry = Some(slot);
+        }
+    }
+
+    /// Unlink `slot`, reconnecting each neighbour that linked back to it.
+    pub(crate) fn remove(&mut self, slot: u32, points: &impl Points) {
+        let Some(layers) = self.links.get_mut(slot as usize).map(std::mem::take) else {
+            return;
+        };
+        for (layer, neighbours) in layers.iter().enumerate() {
+            for &neighbour in neighbours {
+                let Some(list) = self
+                    .links
+                    .get(neighbour as usize)
+                    .and_then(|layers| layers.get(layer))
+                else {
+                    continue;
+                };
+                if !list.contains(&slot) {
+                    continue;
+                }
+                let mut candidates: Vec<u32> = list
+                    .iter()
+                    .chain(neighbours)
+                    .copied()
+                    .filter(|&c| c != slot && c != neighbour && self.is_live_at(c, layer))
+                    .collect();
+                candidates.sort_unstable();
+                candidates.dedup();
+                let mut scored: Vec<Scored> = candidates
+                    .into_iter()
+                    .map(|c| Scored {
+                        distance: points.distance(neighbour, c),
+                        slot: c,
+                    })
+                    .collect();
+                scored.sort();
+                let max = self.max_links(layer);
+                self.links[neighbour as usize][layer] = select_neighbours(points, &scored, max);
+            }
+        }
+        if self.entry == Some(slot) {
+            self.entry = self
+                .links
+                .iter()
+                .enumerate()
+                .filter(|(_, layers)| !layers.is_empty())
+                .max_by(|a, b| a.1.len().cmp(&b.1.len()).then(b.0.cmp(&a.0)))
+                .map(|(slot, _)| slot as u32);
+        }
+    }
+
+    /// Approximate nearest slots to the point `distance` measures from,
+    /// nearest first, from a beam of `ef` candidates on the bottom layer.
+    pub(crate) fn search(&self, distance: &impl Fn(u32) -> f32, ef: usize) -> Vec<Scored> {
+        let Some(entry) = self.entry else {
+            return Vec::new();
+        };
+        let mut nearest = vec![Scored {
+            distance: distance(entry),
+            slot: entry,
+        }];
+        for layer in (1..self.links[entry as usize].len()).rev() {
+            nearest = self.search_layer(distance, &nearest, 1, layer);
+        }
+        self.search_layer(distance, &nearest, ef.max(1), 0)
+    }
+
+    fn search_layer(
+        &self,
+        distance: &impl Fn(u32) -> f32,
+        entry_points: &[Scored],
+        ef: usize,
+        layer: usize,
+    ) -> Vec<Scored> {
+        let mut visited: HashSet<u32> = entry_points.iter().map(|s| s.slot).collect();
+        let mut candidates: BinaryHeap<Reverse<Scored>> =
+            entry_points.iter().copied().map(Reverse).collect();
+        let mut results: BinaryHeap<Scored> = entry_points.iter().copied().collect();
+        while results.len() > ef {
+            results.pop();
+        }
+
+        while let Some(Reverse(current)) = candidates.pop() {
+            if results.len() >= ef && results.peek().is_some_and(|far| current > *far) {
+                break;
+            }
+            let Some(neighbours) = self
+                .links
+                .get(current.slot as usize)
+                .and_then(|layers| layers.get(layer))
+            else {
+                continue;
+            };
+            for &neighbour in neighbours {
+                if !visited.insert(neighbour) || !self.is_live_at(neighbour, layer) {
+                    continue;
+                }
+                let scored = Scored {
+                    distance: distance(neighbour),
+                    slot: neighbour,
+                };
+                if results.len() < ef || results.peek().is_some_and(|far| scored < *far) {
+                    candidates.push(Reverse(scored));
+                    results.push(scored);
+                    if results.len() > ef {
+                        results.pop();
+                    }
+                }
+            }
+        }
+        results.into_sorted_vec()
+    }
+
+    /// Re-select `slot`'s neighbours on `layer` after it gained a link.
+    fn prune(&mut self, slot: u32, layer: usize, points: &impl Points) {
+        let mut scored: Vec<Scored> = self.links[slot as usize][layer]
+            .iter()
+            .copied()
+            .filter(|&c| self.is_live_at(c, layer))
+            .map(|c| Scored {
+                distance: points.distance(slot, c),
+                slot: c,
+            })
+            .collect();
+        scored.sort();
+        let max = self.max_links(layer);
+        self.links[slot as usize][layer] = select_neighbours(points, &scored, max);
+    }
+
+    fn random_level(&mut self) -> usize {
+        // SplitMix64, mapped to (0, 1].
+        self.rng = self.rng.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.rng;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        let uniform = ((z >> 11) as f64 + 1.0) / (1u64 << 53) as f64;
+        let level = -uniform.ln() / (self.m as f64).ln();
+        (level as usize).min(MAX_LEVEL)
+    }
+
+    /// Append the graph to a checkpoint buffer (little-endian).
+    pub(crate) fn write_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&(self.m as u32).to_le_bytes());
+        out.extend_from_slice(&(self.ef_construction as u32).to_le_bytes());
+        out.extend_from_slice(&self.rng.to_le_bytes());
+        out.extend_from_slice(&self.entry.unwrap_or(u32::MAX).to_le_bytes());
+        out.extend_from_slice(&(self.links.len() as u32).to_le_bytes());
+        for layers in &self.links {
+            out.push(layers.len() as u8);
+            for list in layers {
+                out.extend_from_slice(&(list.len() as u32).to_le_bytes());
+                for &neighbour in list {
+                    out.extend_from_slice(&neighbour.to_le_bytes());
+                }
+            }
+        }
+    }
+
+    /// Inverse of [`write_to`](Self::write_to); `None` on malformed input.
+    pub(crate) fn read_from(input: &mut &[u8]) -> Option<Self> {
+        let m = read_u32(input)? as usize;
+        let ef_construction = read_u32(input)? as usize;
+        let rng = u64::from_le_bytes(take(input, 8)?.try_into().ok()?);
+        let entry = Some(read_u32(input)?).filter(|&slot| slot != u32::MAX);
+        let slots = read_u32(input)? as usize;
+        let mut links = Vec::with_capacity(slots.min(input.len()));
+        for _ in 0..slots {
+            let layer_count = *take(input, 1)?.first()? as usize;
+            let mut layers = Vec::with_capacity(layer_count);
+            for _ in 0..layer_count {
+                let len = read_u32(input)? as usize;
+                let list = (0..len)
+                    .map(|_| read_u32(input))
+                    .collect::<Option<Vec<u32>>>()?;
+                if list.iter().any(|&n| n as usize >= slots) {
+                    return None;
+                }
+                layers.push(list);
+            }
+            links.push(layers);
+        }
+        if entry.is_some_and(|slot| links.get(slot as usize).is_none_or(Vec::is_empty)) {
+            return None;
+        }
+        Some(Self {
+            m,
+            ef_construction,
+            links,
+            entry,
+            rng,
+        })
+    }
+}
+
+/// Choose up to `max` neighbours from `candidates` (nearest first), skipping
+/// a candidate that is closer to an already chosen neighbour than to the
+/// base point, then filling any remaining room with the skipped ones. This
+/// keeps links spread across directions instead of clustered.
+fn select_neighbours(points: &impl Points, candidates: &[Scored], max: usize) -> Vec<u32> {
+    let mut chosen: Vec<u32> = Vec::with_capacity(max);
+    let mut skipped = Vec::new();
+    for candidate in candidates {
+        if chosen.len() >= max {
+            break;
+        }
+        let diverse = chosen
+            .iter()
+            .all(|&kept| points.distance(candidate.slot, kept) > candidate.distance);
+        if diverse {
+            chosen.push(candidate.slot);
+        } else {
+            skipped.push(candidate.slot);
+        }
+    }
+    for slot in skipped {
+        if chosen.len() >= max {
+            break;
+        }
+        chosen.push(slot);
+    }
+    chosen
+}
+
+pub(crate) fn take<'a>(input: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if input.len() < len {
+        return None;
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Some(head)
+}
+
+pub(crate) fn read_u32(input: &mut &[u8]) -> Option<u32> {
+    Some(u32::from_le_bytes(take(input, 4)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Points on a line: distance is the absolute difference.
+    struct Line(Vec<f32>);
+
+    impl Points for Line {
+        fn distance(&self, a: u32, b: u32) -> f32 {
+            (self.0[a as usize] - self.0[b as usize]).abs()
+        }
+    }
+
+    #[test]
+    fn graph_finds_neighbours_survives_removal_and_round_trips() {
+        let line = Line((0..300).map(|i| i as f32).collect());
+        let config = HnswConfig::new().with_m(4).with_ef_construction(32);
+        let mut graph = HnswGraph::new(&config);
+        for slot in 0..300 {
+            graph.insert(slot, &line);
+        }
+        let values = line.0.clone();
+        let near = |target: f32| {
+            let values = values.clone();
+            move |slot: u32| (values[slot as usize] - target).abs()
+        };
+        let top: Vec<u32> = graph
+            .search(&near(150.2), 8)
+            .iter()
+            .map(|s| s.slot)
+            .collect();
+        assert_eq!(&top[..3], &[150, 151, 149]);
+
+        for slot in (0..300).step_by(3) {
+            graph.remove(slot, &line);
+        }
+        let top: Vec<u32> = graph
+            .search(&near(150.2), 8)
+            .iter()
+            .map(|s| s.slot)
+            .collect();
+        assert_eq!(&top[..3], &[151, 149, 152]);
+
+        let mut bytes = Vec::new();
+        graph.write_to(&mut bytes);
+        let mut input = bytes.as_slice();
+        assert_eq!(HnswGraph::read_from(&mut input), Some(graph));
+        assert!(input.is_empty());
+        assert_eq!(HnswGraph::read_from(&mut &bytes[..bytes.len() - 1]), None);
+    }
+}
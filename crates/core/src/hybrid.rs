@@ -1,8 +1,13 @@
-//! Two-stage hybrid retrieval with intent-gated temporal reranking.
+//! Hybrid retrieval: channel fusion and intent-gated temporal reranking.
 //!
-//! Promoted from the private eval harness (Experiment 01, 11 benchmark passes).
-//! The winning configuration uses RRF fusion (stage 0, in [`TemporalGraph::search_hybrid`])
-//! followed by a two-stage reranker:
+//! [`TemporalGraph::search_hybrid`](crate::TemporalGraph::search_hybrid) fuses
+//! the full-text and vector channels with one of the [`FusionStrategy`]s:
+//! weighted Reciprocal Rank Fusion, or a linear blend of min-max or z-score
+//! normalized channel scores.
+//!
+//! The default strategy was promoted from the private eval harness
+//! (Experiment 01, 11 benchmark passes): RRF fusion followed by a two-stage
+//! reranker:
 //!
 //! - **Stage 1:** Semantic-dominant candidate pruning
 //! - **Stage 2:** Temporal feasibility filtering + intent-weighted rerank
@@ -11,9 +16,15 @@
 //! kind of time query they're making. For timeless/semantic queries, an adaptive
 //! vector-dominance path adjusts weights based on the signal balance in the top
 //! candidates.
+//!
+//! The reranker is pluggable: implement [`Reranker`] (e.g. an in-process
+//! cross-encoder or business rules) and set [`HybridSearchParams::reranker`].
+//! [`IntentGatedReranker`] is the built-in two-stage reranker.
 
-use crate::{Fact, KronroeSpan, KronroeTimestamp};
+use crate::{Fact, FactId, KronroeError, KronroeSpan, KronroeTimestamp, Result, SearchFilter};
 use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
 
 // ---------------------------------------------------------------------------
 // Public types
@@ -21,21 +32,93 @@ use std::cmp::Ordering;
 
 /// Score breakdown for one hybrid retrieval hit.
 ///
-/// The `final_score` reflects the pre-rerank RRF fusion score (text + vector
-/// contributions). The two-stage reranker uses a composite of `final_score`,
-/// `text_rrf_contrib`, `vector_rrf_contrib`, and temporal feasibility signals
-/// to determine final ordering — the returned `final_score` is *not* the
-/// reranker's sort key.
+/// The `final_score` reflects the fusion score (text + vector contributions).
+/// Results are ordered by `rerank_score`, descending. Under
+/// [`FusionStrategy::TwoStageRerank`], [`IntentGatedReranker`] sets it to a
+/// composite of the channel contributions and `temporal_adjustment`; the
+/// other strategies leave it equal to `final_score`.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
 pub struct HybridScoreBreakdown {
-    /// RRF fusion score (sum of text + vector contributions).
+    /// Fusion score (sum of text + vector contributions).
     pub final_score: f64,
-    /// Text-channel contribution from weighted RRF.
+    /// Text-channel contribution: weighted RRF, or the weighted normalized
+    /// BM25 score under linear fusion.
     pub text_rrf_contrib: f64,
-    /// Vector-channel contribution from weighted RRF.
+    /// Vector-channel contribution: weighted RRF, or the weighted normalized
+    /// similarity under linear fusion.
     pub vector_rrf_contrib: f64,
-    /// Temporal contribution (currently always 0.0; reserved for future use).
+    /// Intent-weighted temporal signal added to `rerank_score`
+    /// (`0.0` for timeless queries).
     pub temporal_adjustment: f64,
+    /// Temporal feasibility signal for the query, roughly in
+    /// `[-1.0, 1.1]`: positive when the fact fits the temporal constraint
+    /// (`0.0` for timeless queries).
+    pub temporal_signal: f64,
+    /// Score that determined the final order.
+    pub rerank_score: f64,
+    /// What stage-1 semantic pruning decided for this hit.
+    pub stage1: Stage1Decision,
+}
+
+impl HybridScoreBreakdown {
+    fn fused(text: f64, vector: f64) -> Self {
+        Self {
+            final_score: text + vector,
+            text_rrf_contrib: text,
+            vector_rrf_contrib: vector,
+            temporal_adjustment: 0.0,
+            temporal_signal: 0.0,
+            rerank_score: text + vector,
+            stage1: Stage1Decision::NotApplied,
+        }
+    }
+}
+
+/// Stage-1 pruning decision of the two-stage reranker for one hit.
+///
+/// Stage 1 sorts candidates by a semantic-dominant score and keeps the top
+/// `window` (20 for timeless queries, 14 otherwise); everything else is
+/// dropped before stage 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Stage1Decision {
+    /// Stage 1 did not run: fused order or a custom [`Reranker`].
+    #[default]
+    NotApplied,
+    /// Kept at semantic rank `rank` (0-based) of `window` slots.
+    Kept { rank: usize, window: usize },
+    /// Kept although temporally infeasible, because no candidate in the
+    /// window fit the temporal constraint.
+    KeptInfeasible { rank: usize, window: usize },
+}
+
+impl Stage1Decision {
+    /// Stable name used by bindings: `not_applied`, `kept`,
+    /// `kept_infeasible`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::NotApplied => "not_applied",
+            Self::Kept { .. } => "kept",
+            Self::KeptInfeasible { .. } => "kept_infeasible",
+        }
+    }
+
+    /// Semantic rank in stage 1, when it ran.
+    pub fn rank(self) -> Option<usize> {
+        match self {
+            Self::NotApplied => None,
+            Self::Kept { rank, .. } | Self::KeptInfeasible { rank, .. } => Some(rank),
+        }
+    }
+
+    /// Size of the stage-1 window, when it ran.
+    pub fn window(self) -> Option<usize> {
+        match self {
+            Self::NotApplied => None,
+            Self::Kept { window, .. } | Self::KeptInfeasible { window, .. } => Some(window),
+        }
+    }
 }
 
 /// The caller's temporal intent classification.
@@ -76,11 +159,168 @@ pub enum TemporalOperator {
     Unknown,
 }
 
+/// How the text and vector channels are combined into one ranking.
+///
+/// Selected with [`HybridSearchParams::fusion`]. Names used by bindings and
+/// the eval harness round-trip through [`name`](FusionStrategy::name) and
+/// [`FromStr`](std::str::FromStr): `two_stage`, `rrf`, `min_max`, `z_score`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum FusionStrategy {
+    /// Weighted RRF, then the two-stage intent-gated temporal reranker.
+    #[default]
+    TwoStageRerank,
+    /// Weighted Reciprocal Rank Fusion alone, ordered by fused score.
+    /// Ignores temporal intent.
+    Rrf,
+    /// Weighted sum of channel scores rescaled to `[0, 1]` by the best and
+    /// worst candidate of each channel. Ignores temporal intent.
+    MinMax,
+    /// Weighted sum of channel scores standardized to z-scores within each
+    /// channel's candidates. Ignores temporal intent.
+    ZScore,
+}
+
+impl FusionStrategy {
+    /// Every strategy, in declaration order.
+    pub const ALL: [FusionStrategy; 4] = [
+        FusionStrategy::TwoStageRerank,
+        FusionStrategy::Rrf,
+        FusionStrategy::MinMax,
+        FusionStrategy::ZScore,
+    ];
+
+    /// Stable snake_case name, e.g. `"min_max"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            FusionStrategy::TwoStageRerank => "two_stage",
+            FusionStrategy::Rrf => "rrf",
+            FusionStrategy::MinMax => "min_max",
+            FusionStrategy::ZScore => "z_score",
+        }
+    }
+}
+
+impl std::str::FromStr for FusionStrategy {
+    type Err = KronroeError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        FusionStrategy::ALL
+            .into_iter()
+            .find(|strategy| strategy.name() == s)
+            .ok_or_else(|| KronroeError::invalid_input(format!("unknown fusion strategy '{s}'")))
+    }
+}
+
+/// What a [`Reranker`] knows about the query being answered.
+#[non_exhaustive]
+pub struct RerankContext<'a> {
+    /// The full-text query (the first sub-query of a multi-query search).
+    pub text_query: &'a str,
+    /// The vector query (the first sub-query embedding of a multi-query
+    /// search; empty when none has one).
+    pub vector_query: &'a [f32],
+    /// Every sub-query with its optional embedding; one entry for
+    /// [`TemporalGraph::search_hybrid`](crate::TemporalGraph::search_hybrid).
+    pub queries: &'a [(&'a str, Option<&'a [f32]>)],
+    /// Number of results the caller asked for.
+    pub k: usize,
+    /// Caller's temporal intent classification.
+    pub intent: TemporalIntent,
+    /// Temporal operator hint.
+    pub operator: TemporalOperator,
+    /// Query time for temporal intents; `None` means now.
+    pub at: Option<KronroeTimestamp>,
+    /// Valid-time interval for historical intents
+    /// (see [`HybridSearchParams::valid_interval`]).
+    pub valid_interval: Option<(KronroeTimestamp, KronroeTimestamp)>,
+    /// Transaction-time bound (see [`HybridSearchParams::known_at`]).
+    pub known_at: Option<KronroeTimestamp>,
+    temporal_signal: &'a dyn Fn(&Fact) -> f64,
+}
+
+impl<'a> RerankContext<'a> {
+    pub(crate) fn new(
+        queries: &'a [(&'a str, Option<&'a [f32]>)],
+        params: &HybridSearchParams,
+        at: Option<KronroeTimestamp>,
+        temporal_signal: &'a dyn Fn(&Fact) -> f64,
+    ) -> Self {
+        Self {
+            text_query: queries.first().map_or("", |(text, _)| text),
+            vector_query: queries
+                .iter()
+                .find_map(|(_, vector)| *vector)
+                .unwrap_or(&[]),
+            queries,
+            k: params.k,
+            intent: params.intent,
+            operator: params.operator,
+            at,
+            valid_interval: params.valid_interval,
+            known_at: params.known_at,
+            temporal_signal,
+        }
+    }
+
+    /// Intent-gated temporal feasibility of `fact` for this query, roughly
+    /// in `[-1.0, 1.1]`: positive when the fact fits the temporal
+    /// constraint, negative when it does not, `0.0` for timeless queries.
+    ///
+    /// With the `uncertainty` feature, this uses the graph's effective
+    /// confidence (per-predicate age decay and source weights).
+    pub fn temporal_signal(&self, fact: &Fact) -> f64 {
+        (self.temporal_signal)(fact)
+    }
+}
+
+/// Reorders fused hybrid candidates.
+///
+/// Receives every fused candidate (up to
+/// [`candidate_window`](HybridSearchParams::candidate_window)) with its
+/// [`HybridScoreBreakdown`], best fused score first, and returns them in
+/// final order. Candidates may be dropped; the result is truncated to
+/// [`RerankContext::k`]. Errors abort the search.
+pub trait Reranker: Send + Sync {
+    /// Reorder `candidates` for the query described by `ctx`.
+    fn rerank(
+        &self,
+        ctx: &RerankContext<'_>,
+        candidates: Vec<(Fact, HybridScoreBreakdown)>,
+    ) -> Result<Vec<(Fact, HybridScoreBreakdown)>>;
+}
+
+/// The built-in two-stage reranker: semantic pruning, then temporal
+/// feasibility rerank.
+///
+/// For timeless queries: adaptive vector-dominance reranking based on the
+/// signal balance in the top-5 candidates.
+///
+/// For temporal queries:
+/// - Stage 1: sort by semantic-dominant score, prune to top-14 candidates
+/// - Stage 2: filter to temporally feasible candidates, rerank by
+///   semantic + intent-weighted temporal signal
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IntentGatedReranker;
+
+impl Reranker for IntentGatedReranker {
+    fn rerank(
+        &self,
+        ctx: &RerankContext<'_>,
+        candidates: Vec<(Fact, HybridScoreBreakdown)>,
+    ) -> Result<Vec<(Fact, HybridScoreBreakdown)>> {
+        Ok(rerank_two_stage(candidates, ctx.k, ctx.intent, |fact| {
+            ctx.temporal_signal(fact)
+        }))
+    }
+}
+
 /// Parameters for the stable hybrid search API.
 ///
 /// Defaults match the eval-proven winning configuration:
-/// `rank_constant=60`, `text_weight=0.8`, `vector_weight=0.2`.
-#[derive(Debug, Clone)]
+/// `rank_constant=60`, `text_weight=0.8`, `vector_weight=0.2`, fused with
+/// [`FusionStrategy::TwoStageRerank`].
+#[derive(Clone)]
 pub struct HybridSearchParams {
     /// Number of results to return.
     pub k: usize,
@@ -88,6 +328,8 @@ pub struct HybridSearchParams {
     pub candidate_window: usize,
     /// RRF rank constant (denominator offset).
     pub rank_constant: usize,
+    /// How the two channels are combined.
+    pub fusion: FusionStrategy,
     /// Relative weight of the lexical (full-text) channel.
     pub text_weight: f32,
     /// Relative weight of the vector (embedding) channel.
@@ -96,6 +338,47 @@ pub struct HybridSearchParams {
     pub intent: TemporalIntent,
     /// Temporal operator hint (used with `HistoricalPoint`).
     pub operator: TemporalOperator,
+    /// Embedding space searched by the vector channel.
+    pub vector_space: String,
+    /// Valid-time interval `[start, end)` for historical intents. When set,
+    /// the vector channel and the temporal signal use the interval instead
+    /// of the single query time: [`TemporalOperator::Before`] and
+    /// [`TemporalOperator::After`] compare against its start and end, `By`
+    /// against its end, and every other operator requires overlap.
+    /// Ignored for `Timeless` and `CurrentState` intents.
+    pub valid_interval: Option<(KronroeTimestamp, KronroeTimestamp)>,
+    /// Transaction-time bound: only facts the database held at this time
+    /// (recorded at or before it and not yet expired) are candidates.
+    pub known_at: Option<KronroeTimestamp>,
+    /// Add a lexical list per query for the expansion terms from
+    /// [`TemporalGraph::expand_query`](crate::TemporalGraph::expand_query)
+    /// (registered aliases and predicate synonyms).
+    pub expand_query: bool,
+    /// Custom reranker applied to the fused candidates of any
+    /// [`FusionStrategy`]. `None` uses [`IntentGatedReranker`] for
+    /// [`FusionStrategy::TwoStageRerank`] and keeps the fused order for the
+    /// other strategies.
+    pub reranker: Option<Arc<dyn Reranker>>,
+}
+
+impl std::fmt::Debug for HybridSearchParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HybridSearchParams")
+            .field("k", &self.k)
+            .field("candidate_window", &self.candidate_window)
+            .field("rank_constant", &self.rank_constant)
+            .field("fusion", &self.fusion)
+            .field("text_weight", &self.text_weight)
+            .field("vector_weight", &self.vector_weight)
+            .field("intent", &self.intent)
+            .field("operator", &self.operator)
+            .field("vector_space", &self.vector_space)
+            .field("valid_interval", &self.valid_interval)
+            .field("known_at", &self.known_at)
+            .field("expand_query", &self.expand_query)
+            .field("reranker", &self.reranker.as_ref().map(|_| "dyn Reranker"))
+            .finish()
+    }
 }
 
 impl Default for HybridSearchParams {
@@ -104,12 +387,145 @@ impl Default for HybridSearchParams {
             k: 10,
             candidate_window: 50,
             rank_constant: 60,
+            fusion: FusionStrategy::default(),
             text_weight: 0.8,
             vector_weight: 0.2,
             intent: TemporalIntent::default(),
             operator: TemporalOperator::default(),
+            vector_space: crate::DEFAULT_EMBEDDING_SPACE.to_string(),
+            valid_interval: None,
+            known_at: None,
+            expand_query: false,
+            reranker: None,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Fusion
+// ---------------------------------------------------------------------------
+
+/// Per-candidate contribution of one channel, weighted.
+///
+/// `ranked` is ordered best first. Candidates the channel did not return
+/// contribute the returned `absent` value: nothing for RRF, the channel's
+/// lowest normalized score for linear fusion, so missing from a channel is
+/// never better than ranking last in it.
+fn channel_contributions(
+    ranked: &[(FactId, f64)],
+    weight: f64,
+    params: &HybridSearchParams,
+) -> (HashMap<FactId, f64>, f64) {
+    let normalized: Vec<f64> = match params.fusion {
+        FusionStrategy::TwoStageRerank | FusionStrategy::Rrf => {
+            let rank_constant = params.rank_constant as f64;
+            (0..ranked.len())
+                .map(|rank| 1.0 / (rank_constant + (rank + 1) as f64))
+                .collect()
+        }
+        FusionStrategy::MinMax => {
+            let (min, max) = ranked
+                .iter()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (_, s)| {
+                    (lo.min(*s), hi.max(*s))
+                });
+            ranked
+                .iter()
+                .map(|(_, s)| {
+                    if max > min {
+                        (s - min) / (max - min)
+                    } else {
+                        1.0
+                    }
+                })
+                .collect()
+        }
+        FusionStrategy::ZScore => {
+            let n = ranked.len().max(1) as f64;
+            let mean = ranked.iter().map(|(_, s)| s).sum::<f64>() / n;
+            let std = (ranked.iter().map(|(_, s)| (s - mean).powi(2)).sum::<f64>() / n).sqrt();
+            ranked
+                .iter()
+                .map(|(_, s)| if std > 0.0 { (s - mean) / std } else { 0.0 })
+                .collect()
+        }
+    };
+    let absent = match params.fusion {
+        FusionStrategy::TwoStageRerank | FusionStrategy::Rrf => 0.0,
+        FusionStrategy::MinMax | FusionStrategy::ZScore => {
+            let lowest = normalized.iter().copied().fold(f64::INFINITY, f64::min);
+            if lowest.is_finite() {
+                weight * lowest
+            } else {
+                0.0
+            }
+        }
+    };
+    let contributions = ranked
+        .iter()
+        .zip(normalized)
+        .map(|((id, _), n)| (id.clone(), weight * n))
+        .collect();
+    (contributions, absent)
+}
+
+/// Fuse the text and vector channels (each ordered best first, with raw
+/// BM25 / similarity scores) with `params.fusion`.
+///
+/// Returns up to `candidate_window` candidates ordered by fused score,
+/// ties broken by fact ID.
+#[cfg(test)]
+pub(crate) fn fuse(
+    text: &[(FactId, f64)],
+    vector: &[(FactId, f64)],
+    params: &HybridSearchParams,
+) -> Vec<(FactId, HybridScoreBreakdown)> {
+    fuse_lists(&[text], &[vector], params)
+}
+
+/// [`fuse`] over any number of ranked lists per channel, in one pass: a
+/// candidate's channel contribution is the sum of its contributions to
+/// that channel's lists.
+pub(crate) fn fuse_lists(
+    text: &[&[(FactId, f64)]],
+    vector: &[&[(FactId, f64)]],
+    params: &HybridSearchParams,
+) -> Vec<(FactId, HybridScoreBreakdown)> {
+    let channel = |lists: &[&[(FactId, f64)]], weight: f32| {
+        lists
+            .iter()
+            .map(|ranked| channel_contributions(ranked, weight as f64, params))
+            .collect::<Vec<_>>()
+    };
+    let sum = |contributions: &[(HashMap<FactId, f64>, f64)], id: &FactId| {
+        contributions
+            .iter()
+            .map(|(contrib, absent)| contrib.get(id).copied().unwrap_or(*absent))
+            .sum::<f64>()
+    };
+    let text_contrib = channel(text, params.text_weight);
+    let vector_contrib = channel(vector, params.vector_weight);
+
+    let mut fused: Vec<(FactId, HybridScoreBreakdown)> = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    for (id, _) in text.iter().chain(vector).flat_map(|ranked| ranked.iter()) {
+        if !seen.insert(id) {
+            continue;
         }
+        let t = sum(&text_contrib, id);
+        let v = sum(&vector_contrib, id);
+        fused.push((id.clone(), HybridScoreBreakdown::fused(t, v)));
     }
+
+    // Sort by fused score descending, FactId ascending for deterministic ties.
+    fused.sort_by(|(a_id, a), (b_id, b)| {
+        b.final_score
+            .partial_cmp(&a.final_score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a_id.cmp(b_id))
+    });
+    fused.truncate(params.candidate_window);
+    fused
 }
 
 // ---------------------------------------------------------------------------
@@ -127,9 +543,13 @@ pub(crate) fn intent_gated_temporal_signal(
     intent: TemporalIntent,
     op: TemporalOperator,
     at: Option<KronroeTimestamp>,
+    interval: Option<(KronroeTimestamp, KronroeTimestamp)>,
 ) -> f64 {
     let t = at.unwrap_or_else(KronroeTimestamp::now_utc);
     let conf = fact.confidence.clamp(0.2, 1.0) as f64;
+    if let Some((start, end)) = interval.filter(|_| is_historical(intent)) {
+        return interval_signal(fact, op, start, end, conf);
+    }
 
     match intent {
         TemporalIntent::Timeless => 0.0,
@@ -191,8 +611,8 @@ pub(crate) fn intent_gated_temporal_signal(
         },
 
         TemporalIntent::HistoricalInterval => {
-            // Operator is intentionally ignored for intervals — overlap check
-            // against ±90 day window is the only signal. Matches eval runner.
+            // Without an explicit interval the operator is ignored — overlap
+            // with a ±90 day window is the only signal. Matches eval runner.
             let _ = op;
             let start = t - KronroeSpan::days(90);
             let end = t + KronroeSpan::days(90);
@@ -214,6 +634,7 @@ pub(crate) fn intent_gated_temporal_signal_with_uncertainty(
     intent: TemporalIntent,
     op: TemporalOperator,
     at: Option<KronroeTimestamp>,
+    interval: Option<(KronroeTimestamp, KronroeTimestamp)>,
     engine: &crate::uncertainty::UncertaintyEngine,
 ) -> f64 {
     if matches!(intent, TemporalIntent::Timeless) {
@@ -223,6 +644,9 @@ pub(crate) fn intent_gated_temporal_signal_with_uncertainty(
     let t = at.unwrap_or_else(KronroeTimestamp::now_utc);
     let eff = engine.effective_confidence(fact, t);
     let conf = eff.value.clamp(0.2, 1.0) as f64;
+    if let Some((start, end)) = interval.filter(|_| is_historical(intent)) {
+        return interval_signal(fact, op, start, end, conf);
+    }
 
     match intent {
         TemporalIntent::Timeless => 0.0,
@@ -298,6 +722,100 @@ pub(crate) fn intent_gated_temporal_signal_with_uncertainty(
     }
 }
 
+/// Filters for the text and vector channels of a hybrid search.
+///
+/// Both apply the transaction-time bound. The vector channel also keeps
+/// only facts valid at the query time (currently valid when `at` is
+/// `None`), or, for historical intents with a valid-time interval, facts
+/// overlapping it — unless the operator looks outside the interval.
+pub(crate) fn channel_filters(
+    params: &HybridSearchParams,
+    at: Option<KronroeTimestamp>,
+) -> (SearchFilter, SearchFilter) {
+    let interval = params
+        .valid_interval
+        .filter(|_| is_historical(params.intent));
+    let mut vector = match (interval, params.operator) {
+        (Some(_), TemporalOperator::Before | TemporalOperator::By | TemporalOperator::After) => {
+            SearchFilter::new()
+        }
+        (Some((start, end)), _) => SearchFilter::new().with_valid_during(start, end),
+        (None, _) => match at {
+            Some(t) => SearchFilter::new().with_valid_at(t),
+            None => SearchFilter::new().with_current_only(),
+        },
+    };
+    let mut text = SearchFilter::new();
+    if let Some(t) = params.known_at {
+        text = text.with_known_at(t);
+        vector = vector.with_known_at(t);
+    }
+    (text, vector)
+}
+
+fn is_historical(intent: TemporalIntent) -> bool {
+    matches!(
+        intent,
+        TemporalIntent::HistoricalPoint | TemporalIntent::HistoricalInterval
+    )
+}
+
+/// Was `fact` valid at some point in `[start, end)`?
+/// Matches [`SearchFilter::with_valid_during`](crate::SearchFilter::with_valid_during).
+fn overlaps(fact: &Fact, start: KronroeTimestamp, end: KronroeTimestamp) -> bool {
+    fact.valid_from < end
+        && fact.valid_to.is_none_or(|t| t > start)
+        && fact.expired_at.is_none_or(|t| t > start)
+}
+
+/// Temporal signal for a historical intent with an explicit valid-time
+/// interval `[start, end)`, on the same scale as the point signals.
+fn interval_signal(
+    fact: &Fact,
+    op: TemporalOperator,
+    start: KronroeTimestamp,
+    end: KronroeTimestamp,
+    conf: f64,
+) -> f64 {
+    match op {
+        TemporalOperator::Before => {
+            if fact.valid_from < start && fact.was_valid_at(start) {
+                1.1 * conf
+            } else if fact.valid_from < start {
+                0.3 * conf
+            } else {
+                -1.0
+            }
+        }
+        TemporalOperator::By => {
+            if overlaps(fact, start, end) {
+                1.05 * conf
+            } else if fact.valid_from < end {
+                0.2 * conf
+            } else {
+                -1.0
+            }
+        }
+        TemporalOperator::After => {
+            if fact.valid_from >= end {
+                1.0 * conf
+            } else {
+                -0.8
+            }
+        }
+        TemporalOperator::AsOf
+        | TemporalOperator::During
+        | TemporalOperator::Current
+        | TemporalOperator::Unknown => {
+            if overlaps(fact, start, end) {
+                1.0 * conf
+            } else {
+                -1.0
+            }
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Two-stage reranker
 // ---------------------------------------------------------------------------
@@ -307,7 +825,7 @@ fn semantic_core(b: &HybridScoreBreakdown) -> f64 {
     (0.75 * b.vector_rrf_contrib) + (0.25 * b.text_rrf_contrib) + (0.02 * b.final_score)
 }
 
-fn rerank_two_stage_internal(
+fn rerank_two_stage(
     mut hits: Vec<(Fact, HybridScoreBreakdown)>,
     k: usize,
     intent: TemporalIntent,
@@ -325,12 +843,15 @@ fn rerank_two_stage_internal(
             .then_with(|| fa.id.cmp(&fb.id))
     });
 
-    let stage1_n = if matches!(intent, TemporalIntent::Timeless) {
+    let window = if matches!(intent, TemporalIntent::Timeless) {
         20
     } else {
         14
     };
-    hits.truncate(stage1_n.min(hits.len()));
+    hits.truncate(window.min(hits.len()));
+    for (rank, (_, b)) in hits.iter_mut().enumerate() {
+        b.stage1 = Stage1Decision::Kept { rank, window };
+    }
 
     if matches!(intent, TemporalIntent::Timeless) {
         let sample = hits.iter().take(5);
@@ -346,85 +867,50 @@ fn rerank_two_stage_internal(
             (0.60_f64, 0.40_f64)
         };
 
-        hits.sort_by(|(fa, a), (fb, b)| {
-            let sa =
-                (wv * a.vector_rrf_contrib) + (wt * a.text_rrf_contrib) + (0.02 * a.final_score);
-            let sb =
+        for (_, b) in &mut hits {
+            b.rerank_score =
                 (wv * b.vector_rrf_contrib) + (wt * b.text_rrf_contrib) + (0.02 * b.final_score);
-            sb.partial_cmp(&sa)
-                .unwrap_or(Ordering::Equal)
-                .then_with(|| fa.id.cmp(&fb.id))
-        });
+        }
+        sort_by_rerank_score(&mut hits);
         hits.truncate(k);
         return hits;
     }
 
-    let feasible_only: Vec<(Fact, HybridScoreBreakdown)> = hits
-        .iter()
-        .filter(|(f, _)| temporal_signal(f) > 0.0)
-        .cloned()
-        .collect();
-    if !feasible_only.is_empty() {
-        hits = feasible_only;
-    }
-
     let weight = match intent {
         TemporalIntent::CurrentState => 0.10,
         TemporalIntent::HistoricalPoint => 0.22,
         TemporalIntent::HistoricalInterval => 0.20,
         TemporalIntent::Timeless => 0.0,
     };
+    for (fact, b) in &mut hits {
+        b.temporal_signal = temporal_signal(fact);
+        b.temporal_adjustment = weight * b.temporal_signal;
+        b.rerank_score = semantic_core(b) + b.temporal_adjustment;
+    }
 
-    hits.sort_by(|(fa, a), (fb, b)| {
-        let sa = semantic_core(a) + (weight * temporal_signal(fa));
-        let sb = semantic_core(b) + (weight * temporal_signal(fb));
-        sb.partial_cmp(&sa)
-            .unwrap_or(Ordering::Equal)
-            .then_with(|| fa.id.cmp(&fb.id))
-    });
+    if hits.iter().any(|(_, b)| b.temporal_signal > 0.0) {
+        hits.retain(|(_, b)| b.temporal_signal > 0.0);
+    } else {
+        for (_, b) in &mut hits {
+            if let Stage1Decision::Kept { rank, window } = b.stage1 {
+                b.stage1 = Stage1Decision::KeptInfeasible { rank, window };
+            }
+        }
+    }
 
+    sort_by_rerank_score(&mut hits);
     hits.truncate(k);
     hits
 }
 
-/// Two-stage reranker: semantic pruning → temporal feasibility rerank.
-///
-/// For timeless queries: adaptive vector-dominance reranking based on the
-/// signal balance in the top-5 candidates.
-///
-/// For temporal queries:
-/// - Stage 1: sort by semantic-dominant score, prune to top-14 candidates
-/// - Stage 2: filter to temporally feasible candidates, rerank by
-///   semantic + intent-weighted temporal signal
-///
-/// `k` controls the final output size.
-#[cfg_attr(feature = "uncertainty", allow(dead_code))]
-pub(crate) fn rerank_two_stage(
-    hits: Vec<(Fact, HybridScoreBreakdown)>,
-    k: usize,
-    intent: TemporalIntent,
-    op: TemporalOperator,
-    at: Option<KronroeTimestamp>,
-) -> Vec<(Fact, HybridScoreBreakdown)> {
-    rerank_two_stage_internal(hits, k, intent, |fact| {
-        intent_gated_temporal_signal(fact, intent, op, at)
-    })
-}
-
-/// Like [`rerank_two_stage`] but uses the uncertainty engine for temporal
-/// signal computation — per-predicate age decay and source-weighted confidence.
-#[cfg(feature = "uncertainty")]
-pub(crate) fn rerank_two_stage_with_uncertainty(
-    hits: Vec<(Fact, HybridScoreBreakdown)>,
-    k: usize,
-    intent: TemporalIntent,
-    op: TemporalOperator,
-    at: Option<KronroeTimestamp>,
-    engine: &crate::uncertainty::UncertaintyEngine,
-) -> Vec<(Fact, HybridScoreBreakdown)> {
-    rerank_two_stage_internal(hits, k, intent, |fact| {
-        intent_gated_temporal_signal_with_uncertainty(fact, intent, op, at, engine)
-    })
+/// Sort by `rerank_score` descending, `FactId` ascending for ties.
+fn sort_by_rerank_score(hits: &mut [(Fact, HybridScoreBreakdown)]) {
+    hits.sort_by(|(fa, a), (fb, b)| {
+        b.rerank_score
+            .partial_cmp(&a.rerank_score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| fa.id.cmp(&fb.id))
+    });
 }
 
 // ---------------------------------------------------------------------------
@@ -453,6 +939,7 @@ mod tests {
             expired_at: None,
             confidence,
             source: None,
+            metadata: Default::default(),
         }
     }
 
@@ -468,6 +955,7 @@ mod tests {
             TemporalIntent::Timeless,
             TemporalOperator::Current,
             None,
+            None,
         );
         assert!((signal - 0.0).abs() < f64::EPSILON);
     }
@@ -480,6 +968,7 @@ mod tests {
             TemporalIntent::CurrentState,
             TemporalOperator::Current,
             Some(dt(2025, 1, 1)),
+            None,
         );
         assert!(
             signal > 0.0,
@@ -495,6 +984,7 @@ mod tests {
             TemporalIntent::CurrentState,
             TemporalOperator::Current,
             Some(dt(2025, 1, 1)),
+            None,
         );
         assert!(
             signal < 0.0,
@@ -510,6 +1000,7 @@ mod tests {
             TemporalIntent::HistoricalPoint,
             TemporalOperator::AsOf,
             Some(dt(2023, 6, 1)),
+            None,
         );
         assert!(
             (signal - 1.0).abs() < f64::EPSILON,
@@ -525,6 +1016,7 @@ mod tests {
             TemporalIntent::HistoricalPoint,
             TemporalOperator::AsOf,
             Some(dt(2024, 1, 1)),
+            None,
         );
         assert!(
             (signal - -1.0).abs() < f64::EPSILON,
@@ -541,6 +1033,7 @@ mod tests {
             TemporalIntent::HistoricalInterval,
             TemporalOperator::During,
             Some(dt(2024, 1, 1)),
+            None,
         );
         assert!(
             (signal - 1.0).abs() < f64::EPSILON,
@@ -557,10 +1050,105 @@ mod tests {
             TemporalIntent::HistoricalInterval,
             TemporalOperator::During,
             Some(dt(2024, 1, 1)),
+            None,
         );
         assert!(
             (signal - -1.0).abs() < f64::EPSILON,
             "non-overlapping fact should score -1.0, got {signal}"
         );
     }
+
+    fn fuse_with(
+        fusion: FusionStrategy,
+        text: &[(FactId, f64)],
+        vector: &[(FactId, f64)],
+    ) -> Vec<(FactId, HybridScoreBreakdown)> {
+        let params = HybridSearchParams {
+            fusion,
+            text_weight: 0.5,
+            vector_weight: 0.5,
+            ..HybridSearchParams::default()
+        };
+        fuse(text, vector, &params)
+    }
+
+    #[test]
+    fn fusion_strategies_normalize_channel_scores() {
+        let (a, b, c) = (FactId::new(), FactId::new(), FactId::new());
+        // `a` wins text by a wide BM25 margin; `b` and `c` only appear in
+        // the vector channel, where `b` is far ahead.
+        let text = [(a.clone(), 12.0), (b.clone(), 2.0)];
+        let vector = [(b.clone(), 0.9), (c.clone(), 0.1)];
+
+        let rrf = fuse_with(FusionStrategy::Rrf, &text, &vector);
+        assert_eq!(rrf[0].0, b, "b ranks in both lists");
+        let (_, c_rrf) = rrf.iter().find(|(id, _)| *id == c).unwrap();
+        assert_eq!(c_rrf.text_rrf_contrib, 0.0);
+        assert!((c_rrf.vector_rrf_contrib - 0.5 / 62.0).abs() < 1e-12);
+
+        let min_max = fuse_with(FusionStrategy::MinMax, &text, &vector);
+        let score = |hits: &[(FactId, HybridScoreBreakdown)], id: &FactId| {
+            hits.iter().find(|(h, _)| h == id).unwrap().1
+        };
+        // a: text 1.0, absent from vector → lowest vector value 0.0.
+        assert!((score(&min_max, &a).final_score - 0.5).abs() < 1e-12);
+        // b: text 0.0, vector 1.0.
+        assert!((score(&min_max, &b).final_score - 0.5).abs() < 1e-12);
+        assert!((score(&min_max, &c).final_score - 0.0).abs() < 1e-12);
+
+        let z = fuse_with(FusionStrategy::ZScore, &text, &vector);
+        // Two-point channels standardize to ±1; absent takes the channel low.
+        assert!((score(&z, &a).text_rrf_contrib - 0.5).abs() < 1e-12);
+        assert!((score(&z, &a).vector_rrf_contrib + 0.5).abs() < 1e-12);
+        assert!((score(&z, &c).text_rrf_contrib + 0.5).abs() < 1e-12);
+        for hits in [&rrf, &min_max, &z] {
+            assert!(hits
+                .windows(2)
+                .all(|w| w[0].1.final_score >= w[1].1.final_score));
+        }
+    }
+
+    #[test]
+    fn fusion_strategy_names_round_trip() {
+        for strategy in FusionStrategy::ALL {
+            assert_eq!(strategy.name().parse::<FusionStrategy>().unwrap(), strategy);
+        }
+        assert!("borda".parse::<FusionStrategy>().is_err());
+        assert_eq!(
+            HybridSearchParams::default().fusion,
+            FusionStrategy::TwoStageRerank
+        );
+    }
+
+    #[test]
+    fn rerank_reports_signal_adjustment_and_stage1_fallback() {
+        let old = make_fact("old", dt(2020, 1, 1), Some(dt(2021, 1, 1)), 1.0);
+        let older = make_fact("older", dt(2019, 1, 1), Some(dt(2020, 1, 1)), 1.0);
+        let hits = vec![
+            (old.clone(), HybridScoreBreakdown::fused(0.02, 0.01)),
+            (older, HybridScoreBreakdown::fused(0.01, 0.01)),
+        ];
+        let signal = |fact: &Fact| {
+            intent_gated_temporal_signal(
+                fact,
+                TemporalIntent::HistoricalPoint,
+                TemporalOperator::AsOf,
+                Some(dt(2024, 1, 1)),
+                None,
+            )
+        };
+
+        // Neither fact was valid in 2024: both are kept as a fallback.
+        let ranked = rerank_two_stage(hits, 5, TemporalIntent::HistoricalPoint, signal);
+        assert_eq!(ranked.len(), 2);
+        for (fact, b) in &ranked {
+            assert_eq!(b.temporal_signal, signal(fact));
+            assert!((b.temporal_adjustment - 0.22 * b.temporal_signal).abs() < 1e-12);
+            assert!((b.rerank_score - (semantic_core(b) + b.temporal_adjustment)).abs() < 1e-12);
+            assert_eq!(b.stage1.name(), "kept_infeasible");
+        }
+        assert_eq!(ranked[0].0.id, old.id);
+        assert_eq!(ranked[0].1.stage1.rank(), Some(0));
+        assert_eq!(Stage1Decision::NotApplied.window(), None);
+    }
 }
@@ -7,6 +7,10 @@
 use std::collections::BTreeMap;
 
 /// A parsed JSON value.
+///
+/// Also the payload of [`Value::Json`](crate::Value::Json), re-exported as
+/// `kronroe::JsonValue`. Object keys are kept in sorted order, so two objects
+/// with the same members compare equal regardless of input key order.
 #[derive(Debug, Clone, PartialEq)]
 pub enum JsonValue {
     Null,
@@ -105,6 +109,60 @@ impl JsonValue {
     }
 }
 
+impl JsonValue {
+    /// Serialize this value as compact JSON.
+    pub fn to_json_string(&self) -> String {
+        let mut buf = Vec::new();
+        crate::json_write::write_json_value(&mut buf, self).expect("Vec<u8> write is infallible");
+        // SAFETY: the writer only emits ASCII structure plus raw UTF-8 from
+        // `String` values.
+        unsafe { String::from_utf8_unchecked(buf) }
+    }
+}
+
+impl std::fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_json_string())
+    }
+}
+
+/// Decode standard (RFC 4648) base64. Padding is optional; whitespace is not
+/// accepted.
+pub fn decode_base64(input: &str) -> Result<Vec<u8>, ParseError> {
+    fn sextet(c: u8) -> Option<u32> {
+        match c {
+            b'A'..=b'Z' => Some(u32::from(c - b'A')),
+            b'a'..=b'z' => Some(u32::from(c - b'a') + 26),
+            b'0'..=b'9' => Some(u32::from(c - b'0') + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+
+    let trimmed = input.trim_end_matches('=');
+    if input.len() - trimmed.len() > 2 || trimmed.len() % 4 == 1 {
+        return Err(ParseError("invalid base64 length".into()));
+    }
+    let mut out = Vec::with_capacity(trimmed.len() * 3 / 4);
+    for chunk in trimmed.as_bytes().chunks(4) {
+        let mut n = 0u32;
+        for (i, c) in chunk.iter().enumerate() {
+            let v = sextet(*c)
+                .ok_or_else(|| ParseError(format!("invalid base64 character '{}'", *c as char)))?;
+            n |= v << (18 - 6 * i);
+        }
+        out.push((n >> 16) as u8);
+        if chunk.len() > 2 {
+            out.push((n >> 8) as u8);
+        }
+        if chunk.len() > 3 {
+            out.push(n as u8);
+        }
+    }
+    Ok(out)
+}
+
 /// A JSON parse error.
 #[derive(Debug, Clone)]
 pub struct ParseError(pub String);
@@ -394,8 +452,8 @@ mod tests {
     fn parse_numbers() {
         assert_eq!(JsonValue::parse_str("42").unwrap(), JsonValue::Number(42.0));
         assert_eq!(
-            JsonValue::parse_str("-3.14").unwrap(),
-            JsonValue::Number(-3.14)
+            JsonValue::parse_str("-2.75").unwrap(),
+            JsonValue::Number(-2.75)
         );
         assert_eq!(
             JsonValue::parse_str("1e10").unwrap(),
@@ -507,4 +565,15 @@ mod tests {
             Some("alice:works_at:kf_01234")
         );
     }
+
+    #[test]
+    fn base64_round_trip() {
+        for len in 0..8 {
+            let bytes: Vec<u8> = (0..len).map(|i| (i * 37 + 200) as u8).collect();
+            let encoded = crate::json_write::encode_base64(&bytes);
+            assert_eq!(decode_base64(&encoded).unwrap(), bytes);
+        }
+        assert!(decode_base64("Zm9v!").is_err());
+        assert!(decode_base64("Z").is_err());
+    }
 }
@@ -66,7 +66,7 @@ pub fn write_kv_string(w: &mut impl Write, key: &str, value: &str) -> io::Result
 }
 
 /// Helper: write `"key":value` where value is an f64.
-#[cfg_attr(not(test), allow(dead_code))]
+#[allow(dead_code)]
 pub fn write_kv_f64(w: &mut impl Write, key: &str, value: f64) -> io::Result<()> {
     write_string(w, key)?;
     w.write_all(b":")?;
@@ -88,7 +88,7 @@ pub fn write_kv_u64(w: &mut impl Write, key: &str, value: u64) -> io::Result<()>
 }
 
 /// Helper: write `"key":value` where value is a bool.
-#[cfg_attr(not(test), allow(dead_code))]
+#[allow(dead_code)]
 pub fn write_kv_bool(w: &mut impl Write, key: &str, value: bool) -> io::Result<()> {
     write_string(w, key)?;
     w.write_all(b":")?;
@@ -109,6 +109,66 @@ pub fn write_kv_option_string(
     }
 }
 
+/// Write a parsed [`JsonValue`](crate::json_read::JsonValue) back out as compact JSON.
+///
+/// Non-finite numbers (which the parser never produces) are written as `null`.
+pub fn write_json_value(w: &mut impl Write, value: &crate::json_read::JsonValue) -> io::Result<()> {
+    use crate::json_read::JsonValue;
+    match value {
+        JsonValue::Null => write_null(w),
+        JsonValue::Bool(b) => write_bool(w, *b),
+        JsonValue::Number(n) if n.is_finite() => write_f64(w, *n),
+        JsonValue::Number(_) => write_null(w),
+        JsonValue::Str(s) => write_string(w, s),
+        JsonValue::Array(items) => {
+            w.write_all(b"[")?;
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    w.write_all(b",")?;
+                }
+                write_json_value(w, item)?;
+            }
+            w.write_all(b"]")
+        }
+        JsonValue::Object(map) => {
+            w.write_all(b"{")?;
+            for (i, (key, item)) in map.iter().enumerate() {
+                if i > 0 {
+                    w.write_all(b",")?;
+                }
+                write_string(w, key)?;
+                w.write_all(b":")?;
+                write_json_value(w, item)?;
+            }
+            w.write_all(b"}")
+        }
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encode bytes as standard (RFC 4648, padded) base64.
+pub fn encode_base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64_ALPHABET[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -137,7 +197,7 @@ mod tests {
     fn number_formatting() {
         assert_eq!(ser(|w| write_f64(w, 42.0)), "42");
         assert_eq!(ser(|w| write_f64(w, -1.0)), "-1");
-        assert_eq!(ser(|w| write_f64(w, 3.14)), "3.14");
+        assert_eq!(ser(|w| write_f64(w, 2.5)), "2.5");
         assert_eq!(ser(|w| write_f64(w, 0.0)), "0");
     }
 
@@ -151,6 +211,23 @@ mod tests {
     fn bool_and_null() {
         assert_eq!(ser(|w| write_bool(w, true)), "true");
         assert_eq!(ser(|w| write_bool(w, false)), "false");
-        assert_eq!(ser(|w| write_null(w)), "null");
+        assert_eq!(ser(write_null), "null");
+    }
+
+    #[test]
+    fn json_value_round_trip() {
+        use crate::json_read::JsonValue;
+        let input = r#"{"a":[1,2.5,"x",null,true],"b":{"c":"\"q\""}}"#;
+        let parsed = JsonValue::parse_str(input).unwrap();
+        assert_eq!(ser(|w| write_json_value(w, &parsed)), input);
+    }
+
+    #[test]
+    fn base64_padding() {
+        assert_eq!(encode_base64(b""), "");
+        assert_eq!(encode_base64(b"f"), "Zg==");
+        assert_eq!(encode_base64(b"fo"), "Zm8=");
+        assert_eq!(encode_base64(b"foo"), "Zm9v");
+        assert_eq!(encode_base64(&[0xff, 0xfe, 0x00, 0x01]), "//4AAQ==");
     }
 }
@@ -66,6 +66,11 @@ impl KronroeTimestamp {
         format!("{year:04}-{month:02}-{day:02}")
     }
 
+    /// UTC calendar `(year, month, day)` of this instant.
+    pub(crate) fn utc_ymd(self) -> (i32, u32, u32) {
+        civil_from_days(self.unix_micros.div_euclid(MICROS_PER_DAY))
+    }
+
     pub fn from_utc_components(
         year: i32,
         month: u32,
@@ -1,136 +1,152 @@
+//! BM25 full-text engine: positional postings and the query language.
+//!
+//! Query syntax (all of it optional — a plain list of words is ranked as a
+//! bag of terms, exactly as before the syntax existed):
+//!
+//! | Syntax | Meaning |
+//! |---|---|
+//! | `alice acme` | either term (implicit `OR`), ranked by BM25 |
+//! | `"new york"` | phrase: terms adjacent, in order, in the same field |
+//! | `acm*` | prefix: any indexed term starting with `acm` |
+//! | `a AND b`, `a OR b` | boolean operators (`AND` binds tighter than `OR`) |
+//! | `NOT a`, `-a` | exclude matches of `a` from the enclosing group |
+//! | `( ... )` | grouping |
+//! | `subject:`, `predicate:`, `object:`, `alias:` | restrict a term, phrase, prefix, or group to one field |
+//!
+//! Operators must be upper case; a word that tokenizes into several terms
+//! (e.g. `works_at`) is matched as a phrase. Malformed input never fails:
+//! unbalanced quotes and parentheses are closed implicitly.
+//!
+//! Single terms that are not in the vocabulary are matched fuzzily according
+//! to a [`FuzzyConfig`]; phrases, prefixes, and excluded clauses are always
+//! exact.
+
+use crate::explain::{SearchField, TermMatch};
+use crate::fuzzy::{FuzzyConfig, TermTrie};
+use crate::tokenizer::Tokenizer;
 use crate::FactId;
 use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 const BM25_K1: f64 = 1.2;
 const BM25_B: f64 = 0.75;
 
-#[derive(Debug, Clone)]
-pub(crate) struct LexicalDocument {
-    pub(crate) id: FactId,
-    pub(crate) content: String,
-}
+/// Upper bound on the number of vocabulary terms a single prefix query
+/// expands to, so `a*` stays cheap on large vocabularies.
+const MAX_PREFIX_EXPANSIONS: usize = 128;
 
-impl LexicalDocument {
-    pub(crate) fn new(id: FactId, content: String) -> Self {
-        Self { id, content }
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Position {
+    field: SearchField,
+    offset: u32,
 }
 
 #[derive(Debug, Clone)]
 struct IndexedDocument {
-    id: FactId,
+    terms: Vec<String>,
     length: usize,
 }
 
+/// BM25 index with positional postings that supports incremental upserts
+/// and removals.
+///
+/// Postings are keyed by term, so a query only touches the postings lists of
+/// its own terms; prefix queries walk a contiguous range of the sorted
+/// vocabulary, and fuzzy lookups walk a trie of it.
 #[derive(Debug, Default)]
-struct LexicalIndex {
-    documents: Vec<IndexedDocument>,
-    postings: HashMap<String, Vec<(usize, usize)>>,
-    doc_freqs: HashMap<String, usize>,
-    vocabulary: Vec<String>,
-    avg_doc_len: f64,
+pub(crate) struct LexicalIndex {
+    documents: HashMap<FactId, IndexedDocument>,
+    postings: BTreeMap<String, HashMap<FactId, Vec<Position>>>,
+    vocabulary: TermTrie,
+    total_doc_len: usize,
 }
 
 impl LexicalIndex {
-    fn build(docs: &[LexicalDocument]) -> Self {
-        let mut documents = Vec::with_capacity(docs.len());
-        let mut postings: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
-        let mut doc_freqs: HashMap<String, usize> = HashMap::new();
-        let mut total_doc_len = 0usize;
+    /// Insert or replace the document for `id`, given as runs of tokens per
+    /// field. Runs are separated by a positional gap, so phrases never span
+    /// two runs.
+    pub(crate) fn upsert<'a>(
+        &mut self,
+        id: FactId,
+        runs: impl IntoIterator<Item = (SearchField, &'a [String])>,
+    ) {
+        self.remove(&id);
 
-        for doc in docs {
-            let tokens = tokenize(&doc.content);
-            let doc_idx = documents.len();
-            let mut term_freqs: HashMap<String, usize> = HashMap::new();
-            for token in &tokens {
-                *term_freqs.entry(token.clone()).or_insert(0) += 1;
-            }
-
-            for (term, tf) in &term_freqs {
-                postings
-                    .entry(term.clone())
+        let mut positions: BTreeMap<&str, Vec<Position>> = BTreeMap::new();
+        let mut offset = 0u32;
+        let mut length = 0usize;
+        for (field, tokens) in runs {
+            for token in tokens {
+                positions
+                    .entry(token.as_str())
                     .or_default()
-                    .push((doc_idx, *tf));
-                *doc_freqs.entry(term.clone()).or_insert(0) += 1;
+                    .push(Position { field, offset });
+                offset += 1;
             }
+            offset += 1;
+            length += tokens.len();
+        }
 
-            total_doc_len += tokens.len();
-            documents.push(IndexedDocument {
-                id: doc.id.clone(),
-                length: tokens.len(),
-            });
+        let mut terms = Vec::with_capacity(positions.len());
+        for (term, term_positions) in positions {
+            let postings = self.postings.entry(term.to_string()).or_default();
+            if postings.is_empty() {
+                self.vocabulary.insert(term);
+            }
+            postings.insert(id.clone(), term_positions);
+            terms.push(term.to_string());
         }
 
-        let mut vocabulary: Vec<String> = doc_freqs.keys().cloned().collect();
-        vocabulary.sort();
+        self.total_doc_len += length;
+        self.documents.insert(id, IndexedDocument { terms, length });
+    }
 
-        let avg_doc_len = if documents.is_empty() {
-            1.0
-        } else {
-            total_doc_len as f64 / documents.len() as f64
+    /// Remove the document for `id`, returning whether it was present.
+    pub(crate) fn remove(&mut self, id: &FactId) -> bool {
+        let Some(doc) = self.documents.remove(id) else {
+            return false;
         };
-
-        Self {
-            documents,
-            postings,
-            doc_freqs,
-            vocabulary,
-            avg_doc_len,
+        for term in &doc.terms {
+            if let Some(postings) = self.postings.get_mut(term) {
+                postings.remove(id);
+                if postings.is_empty() {
+                    self.postings.remove(term);
+                    self.vocabulary.remove(term);
+                }
+            }
         }
+        self.total_doc_len -= doc.length;
+        true
     }
 
-    fn search(&self, query: &str, limit: usize) -> Vec<(FactId, f32)> {
-        if limit == 0 {
-            return Vec::new();
-        }
-
-        let query_terms = tokenize(query);
-        if query_terms.is_empty() {
-            return Vec::new();
-        }
-
-        let exact = self.search_terms(&query_terms, limit);
-        if !exact.is_empty() {
-            return exact;
+    fn avg_doc_len(&self) -> f64 {
+        if self.documents.is_empty() {
+            1.0
+        } else {
+            self.total_doc_len as f64 / self.documents.len() as f64
         }
+    }
 
-        let fuzzy_terms = self.fuzzy_terms(&query_terms);
-        if fuzzy_terms.is_empty() {
+    /// Rank documents for a parsed query, expanding unknown terms as
+    /// allowed by `fuzzy`. Documents rejected by `accept` are dropped before
+    /// the top `limit` are chosen.
+    pub(crate) fn search(
+        &self,
+        query: &Query,
+        fuzzy: &FuzzyConfig,
+        limit: usize,
+        accept: impl Fn(&FactId) -> bool,
+    ) -> Vec<(FactId, f32)> {
+        if limit == 0 {
             return Vec::new();
         }
 
-        self.search_terms(&fuzzy_terms, limit)
-    }
-
-    fn search_terms(&self, query_terms: &[String], limit: usize) -> Vec<(FactId, f32)> {
-        let mut scores: HashMap<usize, f64> = HashMap::new();
-        let total_docs = self.documents.len() as f64;
-        let avg_doc_len = self.avg_doc_len.max(1.0);
-
-        for query_term in query_terms {
-            let Some(postings) = self.postings.get(query_term) else {
-                continue;
-            };
-            let df = *self.doc_freqs.get(query_term).unwrap_or(&0) as f64;
-            if df == 0.0 {
-                continue;
-            }
-
-            let idf = (1.0 + (total_docs - df + 0.5) / (df + 0.5)).ln();
-            for &(doc_idx, tf) in postings {
-                let doc_len = self.documents[doc_idx].length as f64;
-                let tf = tf as f64;
-                let norm = tf + BM25_K1 * (1.0 - BM25_B + BM25_B * (doc_len / avg_doc_len));
-                let score = idf * (tf * (BM25_K1 + 1.0) / norm);
-                *scores.entry(doc_idx).or_insert(0.0) += score;
-            }
-        }
+        let scores = self.evaluate(query, Some(fuzzy));
 
         let mut hits: Vec<(FactId, f32)> = scores
             .into_iter()
-            .filter(|(_, score)| *score > 0.0)
-            .map(|(doc_idx, score)| (self.documents[doc_idx].id.clone(), score as f32))
+            .filter(|(doc_id, score)| *score > 0.0 && accept(doc_id))
+            .map(|(doc_id, score)| (doc_id.clone(), score as f32))
             .collect();
 
         hits.sort_by(|(a_id, a_score), (b_id, b_score)| {
@@ -143,117 +159,614 @@ impl LexicalIndex {
         hits
     }
 
-    fn fuzzy_terms(&self, query_terms: &[String]) -> Vec<String> {
+    fn bm25(&self, df: usize, tf: usize, doc_id: &FactId) -> f64 {
+        let total_docs = self.documents.len() as f64;
+        let avg_doc_len = self.avg_doc_len().max(1.0);
+        let df = df as f64;
+        let idf = (1.0 + (total_docs - df + 0.5) / (df + 0.5)).ln();
+        let doc_len = self.documents[doc_id].length as f64;
+        let tf = tf as f64;
+        let norm = tf + BM25_K1 * (1.0 - BM25_B + BM25_B * (doc_len / avg_doc_len));
+        idf * (tf * (BM25_K1 + 1.0) / norm)
+    }
+
+    /// Score every document matching `query`; `fuzzy` is `None` inside
+    /// excluded clauses, which only ever remove exact matches.
+    fn evaluate(&self, query: &Query, fuzzy: Option<&FuzzyConfig>) -> HashMap<&FactId, f64> {
+        match query {
+            Query::Term { field, term } => match fuzzy {
+                Some(fuzzy) if !self.postings.contains_key(term) => {
+                    self.fuzzy_term_scores(term, *field, fuzzy)
+                }
+                _ => self.term_scores(term, *field),
+            },
+            Query::Prefix { field, prefix } => {
+                let mut scores = HashMap::new();
+                for term in self.prefix_expansions(prefix) {
+                    for (doc_id, score) in self.term_scores(term, *field) {
+                        *scores.entry(doc_id).or_insert(0.0) += score;
+                    }
+                }
+                scores
+            }
+            Query::Phrase { field, terms } => self.phrase_scores(terms, *field),
+            Query::Group {
+                op,
+                include,
+                exclude,
+            } => {
+                let mut scores: HashMap<&FactId, f64> = HashMap::new();
+                for (idx, clause) in include.iter().enumerate() {
+                    let clause_scores = self.evaluate(clause, fuzzy);
+                    match op {
+                        GroupOp::Or => {
+                            for (doc_id, score) in clause_scores {
+                                *scores.entry(doc_id).or_insert(0.0) += score;
+                            }
+                        }
+                        GroupOp::And if idx == 0 => scores = clause_scores,
+                        GroupOp::And => {
+                            scores.retain(|doc_id, _| clause_scores.contains_key(doc_id));
+                            for (doc_id, score) in scores.iter_mut() {
+                                *score += clause_scores[doc_id];
+                            }
+                        }
+                    }
+                    if scores.is_empty() && *op == GroupOp::And {
+                        return scores;
+                    }
+                }
+                for clause in exclude {
+                    if scores.is_empty() {
+                        break;
+                    }
+                    let excluded = self.evaluate(clause, None);
+                    scores.retain(|doc_id, _| !excluded.contains_key(doc_id));
+                }
+                scores
+            }
+        }
+    }
+
+    /// Indexed terms starting with `prefix`, at most
+    /// [`MAX_PREFIX_EXPANSIONS`] of them.
+    fn prefix_expansions<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a String> {
+        self.postings
+            .range::<str, _>((
+                std::ops::Bound::Included(prefix),
+                std::ops::Bound::Unbounded,
+            ))
+            .take_while(move |(term, _)| term.starts_with(prefix))
+            .take(MAX_PREFIX_EXPANSIONS)
+            .map(|(term, _)| term)
+    }
+
+    fn term_scores(&self, term: &str, field: Option<SearchField>) -> HashMap<&FactId, f64> {
+        let Some(postings) = self.postings.get(term) else {
+            return HashMap::new();
+        };
+        let df = postings.len();
+        postings
+            .iter()
+            .filter_map(|(doc_id, positions)| {
+                let tf = match field {
+                    Some(field) => positions.iter().filter(|p| p.field == field).count(),
+                    None => positions.len(),
+                };
+                (tf > 0).then(|| (doc_id, self.bm25(df, tf, doc_id)))
+            })
+            .collect()
+    }
+
+    fn phrase_scores(&self, terms: &[String], field: Option<SearchField>) -> HashMap<&FactId, f64> {
+        let mut term_postings = Vec::with_capacity(terms.len());
+        for term in terms {
+            match self.postings.get(term) {
+                Some(postings) => term_postings.push(postings),
+                None => return HashMap::new(),
+            }
+        }
+        let Some(first) = term_postings.first() else {
+            return HashMap::new();
+        };
+
+        let mut scores = HashMap::new();
+        for doc_id in first.keys() {
+            let occurrences = Self::phrase_starts(&term_postings, field, doc_id).len();
+            if occurrences > 0 {
+                let score = term_postings
+                    .iter()
+                    .map(|postings| self.bm25(postings.len(), occurrences, doc_id))
+                    .sum();
+                scores.insert(doc_id, score);
+            }
+        }
+        scores
+    }
+
+    /// Positions in `doc_id` where the phrase whose per-term postings are
+    /// `term_postings` starts.
+    fn phrase_starts(
+        term_postings: &[&HashMap<FactId, Vec<Position>>],
+        field: Option<SearchField>,
+        doc_id: &FactId,
+    ) -> Vec<Position> {
+        let Some((first, rest)) = term_postings.split_first() else {
+            return Vec::new();
+        };
+        let Some(start_positions) = first.get(doc_id) else {
+            return Vec::new();
+        };
+        let Some(rest_positions) = rest
+            .iter()
+            .map(|postings| postings.get(doc_id))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Vec::new();
+        };
+        start_positions
+            .iter()
+            .filter(|start| field.is_none_or(|field| start.field == field))
+            .filter(|start| {
+                rest_positions.iter().enumerate().all(|(i, positions)| {
+                    let want = Position {
+                        field: start.field,
+                        offset: start.offset + i as u32 + 1,
+                    };
+                    positions.contains(&want)
+                })
+            })
+            .copied()
+            .collect()
+    }
+
+    /// The included terms of `query` found in document `id`, one entry per
+    /// indexed term and field, in query order. Uses the same exact, prefix,
+    /// phrase, and fuzzy rules as [`Self::search`].
+    pub(crate) fn explain(
+        &self,
+        query: &Query,
+        fuzzy: &FuzzyConfig,
+        id: &FactId,
+    ) -> Vec<TermMatch> {
+        let mut matches = Vec::new();
+        self.collect_matches(query, fuzzy, id, &mut matches);
         let mut seen = HashSet::new();
-        let mut fuzzy_terms = Vec::new();
-
-        for query_term in query_terms {
-            for vocab_term in &self.vocabulary {
-                if is_edit_distance_le_one(query_term, vocab_term)
-                    && seen.insert(vocab_term.clone())
-                {
-                    fuzzy_terms.push(vocab_term.clone());
+        matches.retain(|m: &TermMatch| seen.insert((m.term.clone(), m.field)));
+        matches
+    }
+
+    fn collect_matches(
+        &self,
+        query: &Query,
+        fuzzy: &FuzzyConfig,
+        id: &FactId,
+        out: &mut Vec<TermMatch>,
+    ) {
+        match query {
+            Query::Term { field, term } => {
+                if self.postings.contains_key(term) {
+                    self.push_term_matches(term, term, 0, *field, id, out);
+                } else if let Some(budget) = fuzzy.budget_for(term) {
+                    for (variant, distance) in
+                        self.vocabulary
+                            .matches(term, budget, fuzzy.transpositions())
+                    {
+                        self.push_term_matches(term, &variant, distance, *field, id, out);
+                    }
+                }
+            }
+            Query::Prefix { field, prefix } => {
+                for term in self.prefix_expansions(prefix) {
+                    self.push_term_matches(prefix, term, 0, *field, id, out);
+                }
+            }
+            Query::Phrase { field, terms } => {
+                let Some(term_postings) = terms
+                    .iter()
+                    .map(|term| self.postings.get(term))
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    return;
+                };
+                let mut fields: Vec<SearchField> = Self::phrase_starts(&term_postings, *field, id)
+                    .into_iter()
+                    .map(|start| start.field)
+                    .collect();
+                fields.sort();
+                fields.dedup();
+                for field in fields {
+                    for term in terms {
+                        out.push(TermMatch {
+                            query_term: term.clone(),
+                            term: term.clone(),
+                            field,
+                            edit_distance: 0,
+                        });
+                    }
+                }
+            }
+            Query::Group { include, .. } => {
+                for clause in include {
+                    self.collect_matches(clause, fuzzy, id, out);
                 }
             }
         }
+    }
 
-        fuzzy_terms
+    fn push_term_matches(
+        &self,
+        query_term: &str,
+        term: &str,
+        edit_distance: u8,
+        field: Option<SearchField>,
+        id: &FactId,
+        out: &mut Vec<TermMatch>,
+    ) {
+        let Some(positions) = self
+            .postings
+            .get(term)
+            .and_then(|postings| postings.get(id))
+        else {
+            return;
+        };
+        let mut fields: Vec<SearchField> = positions
+            .iter()
+            .map(|position| position.field)
+            .filter(|found| field.is_none_or(|field| *found == field))
+            .collect();
+        fields.sort();
+        fields.dedup();
+        for field in fields {
+            out.push(TermMatch {
+                query_term: query_term.to_string(),
+                term: term.to_string(),
+                field,
+                edit_distance,
+            });
+        }
     }
-}
 
-pub(crate) fn search_scored(
-    docs: &[LexicalDocument],
-    query: &str,
-    limit: usize,
-) -> Vec<(FactId, f32)> {
-    if docs.is_empty() || limit == 0 || query.trim().is_empty() {
-        return Vec::new();
+    /// Score a term missing from the vocabulary through its near matches.
+    /// A document reached through several variants keeps its best score.
+    fn fuzzy_term_scores(
+        &self,
+        term: &str,
+        field: Option<SearchField>,
+        fuzzy: &FuzzyConfig,
+    ) -> HashMap<&FactId, f64> {
+        let mut scores: HashMap<&FactId, f64> = HashMap::new();
+        let Some(budget) = fuzzy.budget_for(term) else {
+            return scores;
+        };
+        for (variant, distance) in self
+            .vocabulary
+            .matches(term, budget, fuzzy.transpositions())
+        {
+            let weight = fuzzy.weight(distance);
+            for (doc_id, score) in self.term_scores(&variant, field) {
+                let entry = scores.entry(doc_id).or_insert(0.0);
+                *entry = entry.max(score * weight);
+            }
+        }
+        scores
     }
-    LexicalIndex::build(docs).search(query, limit)
 }
 
-pub(crate) fn tokenize(text: &str) -> Vec<String> {
-    let mut tokens = Vec::new();
-    let mut current = String::new();
+// ---------------------------------------------------------------------------
+// Query language
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GroupOp {
+    And,
+    Or,
+}
+
+/// Parsed, tokenized full-text query.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Query {
+    Term {
+        field: Option<SearchField>,
+        term: String,
+    },
+    Phrase {
+        field: Option<SearchField>,
+        terms: Vec<String>,
+    },
+    Prefix {
+        field: Option<SearchField>,
+        prefix: String,
+    },
+    /// Documents matching the `include` clauses (all of them for `And`, any
+    /// for `Or`) that match none of the `exclude` clauses.
+    Group {
+        op: GroupOp,
+        include: Vec<Query>,
+        exclude: Vec<Query>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Lexeme {
+    LParen,
+    RParen,
+    And,
+    Or,
+    Not,
+    SearchField(SearchField),
+    Quoted(String),
+    Word(String),
+}
+
+fn lex_query(input: &str) -> Vec<Lexeme> {
+    let mut lexemes = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&ch) = chars.peek() {
+        match ch {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                lexemes.push(Lexeme::LParen);
+            }
+            ')' => {
+                chars.next();
+                lexemes.push(Lexeme::RParen);
+            }
+            '"' => {
+                chars.next();
+                let phrase: String = chars.by_ref().take_while(|&c| c != '"').collect();
+                lexemes.push(Lexeme::Quoted(phrase));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || matches!(c, '(' | ')' | '"') {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                lex_word(&word, &mut lexemes);
+            }
+        }
+    }
+    lexemes
+}
 
-    for ch in text.chars() {
-        if ch.is_alphanumeric() {
-            current.extend(ch.to_lowercase());
-        } else if !current.is_empty() {
-            tokens.push(std::mem::take(&mut current));
+fn lex_word(word: &str, lexemes: &mut Vec<Lexeme>) {
+    match word {
+        "AND" => lexemes.push(Lexeme::And),
+        "OR" => lexemes.push(Lexeme::Or),
+        "NOT" => lexemes.push(Lexeme::Not),
+        _ if word.len() > 1 && word.starts_with('-') => {
+            lexemes.push(Lexeme::Not);
+            lex_word(&word[1..], lexemes);
         }
+        _ => match word
+            .split_once(':')
+            .and_then(|(name, rest)| Some((SearchField::parse(name)?, rest)))
+        {
+            Some((field, rest)) => {
+                lexemes.push(Lexeme::SearchField(field));
+                if !rest.is_empty() {
+                    lex_word(rest, lexemes);
+                }
+            }
+            None => lexemes.push(Lexeme::Word(word.to_string())),
+        },
     }
+}
 
-    if !current.is_empty() {
-        tokens.push(current);
+/// Parse `input` into a [`Query`], tokenizing every term with `tokenizer`.
+///
+/// Returns `None` when nothing searchable is left (empty input, only
+/// stopwords, or only exclusions).
+pub(crate) fn parse_query(input: &str, tokenizer: &dyn Tokenizer) -> Option<Query> {
+    let mut parser = QueryParser {
+        lexemes: lex_query(input),
+        pos: 0,
+        tokenizer,
+    };
+    let mut query = parser.parse_or(None);
+    // Stray closing parentheses: keep parsing what follows.
+    while parser.pos < parser.lexemes.len() {
+        parser.pos += 1;
+        let rest = parser.parse_or(None);
+        query = combine(GroupOp::Or, [query, rest].into_iter().flatten().collect());
+    }
+    match query? {
+        Clause {
+            negated: false,
+            query,
+        } => Some(query),
+        Clause { negated: true, .. } => None,
     }
+    .filter(|query| !matches!(query, Query::Group { include, .. } if include.is_empty()))
+}
+
+#[derive(Debug)]
+struct Clause {
+    negated: bool,
+    query: Query,
+}
 
-    tokens
+struct QueryParser<'a> {
+    lexemes: Vec<Lexeme>,
+    pos: usize,
+    tokenizer: &'a dyn Tokenizer,
 }
 
-fn is_edit_distance_le_one(left: &str, right: &str) -> bool {
-    if left == right {
-        return true;
+impl QueryParser<'_> {
+    fn peek(&self) -> Option<&Lexeme> {
+        self.lexemes.get(self.pos)
     }
 
-    let left_chars: Vec<char> = left.chars().collect();
-    let right_chars: Vec<char> = right.chars().collect();
-    let left_len = left_chars.len();
-    let right_len = right_chars.len();
+    fn parse_or(&mut self, field: Option<SearchField>) -> Option<Clause> {
+        let mut clauses = Vec::new();
+        loop {
+            match self.peek() {
+                None | Some(Lexeme::RParen) => break,
+                Some(Lexeme::Or) | Some(Lexeme::And) => {
+                    self.pos += 1;
+                }
+                _ => clauses.extend(self.parse_and(field)),
+            }
+        }
+        combine(GroupOp::Or, clauses)
+    }
 
-    if left_len.abs_diff(right_len) > 1 {
-        return false;
+    fn parse_and(&mut self, field: Option<SearchField>) -> Option<Clause> {
+        let mut clauses: Vec<Clause> = self.parse_unary(field).into_iter().collect();
+        while self.peek() == Some(&Lexeme::And) {
+            self.pos += 1;
+            if matches!(self.peek(), None | Some(Lexeme::RParen) | Some(Lexeme::Or)) {
+                break;
+            }
+            clauses.extend(self.parse_unary(field));
+        }
+        combine(GroupOp::And, clauses)
     }
 
-    if left_len == right_len {
-        let mismatches: Vec<usize> = left_chars
-            .iter()
-            .zip(right_chars.iter())
-            .enumerate()
-            .filter_map(|(idx, (l, r))| if l != r { Some(idx) } else { None })
-            .collect();
+    fn parse_unary(&mut self, field: Option<SearchField>) -> Option<Clause> {
+        if self.peek() == Some(&Lexeme::Not) {
+            self.pos += 1;
+            return self.parse_unary(field).map(|clause| Clause {
+                negated: !clause.negated,
+                query: clause.query,
+            });
+        }
+        self.parse_primary(field)
+    }
 
-        return match mismatches.as_slice() {
-            [_] => true,
-            [first, second]
-                if *second == *first + 1
-                    && left_chars[*first] == right_chars[*second]
-                    && left_chars[*second] == right_chars[*first] =>
-            {
-                true
+    fn parse_primary(&mut self, field: Option<SearchField>) -> Option<Clause> {
+        let lexeme = self.lexemes.get(self.pos)?.clone();
+        self.pos += 1;
+        let query = match lexeme {
+            Lexeme::LParen => {
+                let inner = self.parse_or(field);
+                if self.peek() == Some(&Lexeme::RParen) {
+                    self.pos += 1;
+                }
+                return inner;
             }
-            _ => false,
+            Lexeme::SearchField(scoped) => return self.parse_primary(Some(scoped)),
+            Lexeme::Quoted(text) => self.phrase(self.tokenizer.tokenize(&text), field)?,
+            Lexeme::Word(word) => self.word(&word, field)?,
+            Lexeme::RParen | Lexeme::And | Lexeme::Or | Lexeme::Not => return None,
         };
+        Some(Clause {
+            negated: false,
+            query,
+        })
     }
 
-    let (shorter, longer) = if left_len < right_len {
-        (&left_chars, &right_chars)
-    } else {
-        (&right_chars, &left_chars)
-    };
-
-    let mut i = 0usize;
-    let mut j = 0usize;
-    let mut skipped = false;
-    while i < shorter.len() && j < longer.len() {
-        if shorter[i] == longer[j] {
-            i += 1;
-            j += 1;
-            continue;
+    fn word(&self, word: &str, field: Option<SearchField>) -> Option<Query> {
+        let Some(stem) = word.strip_suffix('*') else {
+            return self.phrase(self.tokenizer.tokenize(word), field);
+        };
+        let mut terms = self.tokenizer.tokenize(stem);
+        let prefix = Query::Prefix {
+            field,
+            prefix: terms.pop()?,
+        };
+        if terms.is_empty() {
+            return Some(prefix);
         }
-        if skipped {
-            return false;
+        let leading = self.phrase(terms, field)?;
+        Some(Query::Group {
+            op: GroupOp::And,
+            include: vec![leading, prefix],
+            exclude: Vec::new(),
+        })
+    }
+
+    fn phrase(&self, mut terms: Vec<String>, field: Option<SearchField>) -> Option<Query> {
+        match terms.len() {
+            0 => None,
+            1 => Some(Query::Term {
+                field,
+                term: terms.pop()?,
+            }),
+            _ => Some(Query::Phrase { field, terms }),
         }
-        skipped = true;
-        j += 1;
     }
+}
 
-    true
+/// Build a group from parsed clauses, unwrapping single positive clauses.
+fn combine(op: GroupOp, clauses: Vec<Clause>) -> Option<Clause> {
+    if clauses.len() <= 1 {
+        return clauses.into_iter().next();
+    }
+    let (excluded, included): (Vec<Clause>, Vec<Clause>) =
+        clauses.into_iter().partition(|clause| clause.negated);
+    Some(Clause {
+        negated: false,
+        query: Query::Group {
+            op,
+            include: included.into_iter().map(|clause| clause.query).collect(),
+            exclude: excluded.into_iter().map(|clause| clause.query).collect(),
+        },
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tokenizer::{StandardTokenizer, Tokenizer};
+
+    fn tokenize(text: &str) -> Vec<String> {
+        StandardTokenizer::new().tokenize(text)
+    }
+
+    struct LexicalDocument {
+        id: FactId,
+        content: String,
+    }
+
+    impl LexicalDocument {
+        fn new(id: FactId, content: String) -> Self {
+            Self { id, content }
+        }
+    }
+
+    fn query(text: &str) -> Query {
+        parse_query(text, &StandardTokenizer::new()).expect("searchable query")
+    }
+
+    fn search(index: &LexicalIndex, text: &str, limit: usize) -> Vec<(FactId, f32)> {
+        search_with(index, text, &FuzzyConfig::new(), limit)
+    }
+
+    fn search_with(
+        index: &LexicalIndex,
+        text: &str,
+        fuzzy: &FuzzyConfig,
+        limit: usize,
+    ) -> Vec<(FactId, f32)> {
+        match parse_query(text, &StandardTokenizer::new()) {
+            Some(query) => index.search(&query, fuzzy, limit, |_| true),
+            None => Vec::new(),
+        }
+    }
+
+    fn search_scored(docs: &[LexicalDocument], text: &str, limit: usize) -> Vec<(FactId, f32)> {
+        let mut index = LexicalIndex::default();
+        for doc in docs {
+            let tokens = tokenize(&doc.content);
+            index.upsert(doc.id.clone(), [(SearchField::Object, tokens.as_slice())]);
+        }
+        search(&index, text, limit)
+    }
+
+    fn search_ids(docs: &[LexicalDocument], text: &str) -> Vec<FactId> {
+        search_scored(docs, text, 10)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect()
+    }
 
     fn doc_with(id: &str, content: &str) -> LexicalDocument {
         LexicalDocument::new(
@@ -321,6 +834,30 @@ mod tests {
         assert_eq!(typo_ids, vec![docs[0].id.clone()]);
     }
 
+    #[test]
+    fn remove_drops_postings_and_document_frequency() {
+        let docs = vec![doc_with("a", "rust tokio"), doc_with("b", "rust serde")];
+        let mut index = LexicalIndex::default();
+        for doc in &docs {
+            let tokens = tokenize(&doc.content);
+            index.upsert(doc.id.clone(), [(SearchField::Object, tokens.as_slice())]);
+        }
+        let before = search(&index, "rust", 10);
+
+        assert!(index.remove(&docs[1].id));
+        assert!(!index.remove(&docs[1].id));
+        assert!(search(&index, "serde", 10).is_empty());
+        assert!(search(&index, "serd*", 10).is_empty());
+        let after = search(&index, "rust", 10);
+        assert_eq!(after.len(), 1);
+        assert!(after[0].1 > before[0].1, "idf rises as the term gets rarer");
+
+        let go = tokenize("go");
+        index.upsert(docs[0].id.clone(), [(SearchField::Object, go.as_slice())]);
+        assert!(search(&index, "rust", 10).is_empty());
+        assert_eq!(search(&index, "go", 10)[0].0, docs[0].id);
+    }
+
     #[test]
     fn empty_query_and_zero_limit_return_empty_results() {
         let docs = vec![doc_with("fact-a", "alice works_at Acme ally works at")];
@@ -328,4 +865,197 @@ mod tests {
         assert!(search_scored(&docs, "Acme", 0).is_empty());
         assert!(search_scored(&[], "Acme", 10).is_empty());
     }
+
+    #[test]
+    fn parser_builds_boolean_phrase_prefix_and_field_clauses() {
+        let term = |field, term: &str| Query::Term {
+            field,
+            term: term.to_string(),
+        };
+        assert_eq!(query("alice"), term(None, "alice"));
+        assert_eq!(
+            query("\"New York\""),
+            Query::Phrase {
+                field: None,
+                terms: vec!["new".to_string(), "york".to_string()],
+            }
+        );
+        assert_eq!(
+            query("subject:acm*"),
+            Query::Prefix {
+                field: Some(SearchField::Subject),
+                prefix: "acm".to_string(),
+            }
+        );
+        assert_eq!(
+            query("a OR b AND NOT c"),
+            Query::Group {
+                op: GroupOp::Or,
+                include: vec![
+                    term(None, "a"),
+                    Query::Group {
+                        op: GroupOp::And,
+                        include: vec![term(None, "b")],
+                        exclude: vec![term(None, "c")],
+                    },
+                ],
+                exclude: Vec::new(),
+            }
+        );
+        assert_eq!(
+            query("predicate:(works -lives)"),
+            Query::Group {
+                op: GroupOp::Or,
+                include: vec![term(Some(SearchField::Predicate), "works")],
+                exclude: vec![term(Some(SearchField::Predicate), "lives")],
+            }
+        );
+        // Unknown field names and lower-case operators are plain words.
+        assert_eq!(
+            query("at:10"),
+            Query::Phrase {
+                field: None,
+                terms: vec!["at".to_string(), "10".to_string()],
+            }
+        );
+        assert_eq!(
+            query("x and y"),
+            Query::Group {
+                op: GroupOp::Or,
+                include: vec![term(None, "x"), term(None, "and"), term(None, "y")],
+                exclude: Vec::new(),
+            }
+        );
+        // Malformed input degrades instead of failing.
+        assert_eq!(query("(alice"), term(None, "alice"));
+        assert_eq!(query("alice)"), term(None, "alice"));
+        assert!(parse_query("NOT alice", &StandardTokenizer::new()).is_none());
+        assert!(parse_query("\"\" ()", &StandardTokenizer::new()).is_none());
+    }
+
+    #[test]
+    fn phrases_require_adjacent_terms_in_one_field() {
+        let mut index = LexicalIndex::default();
+        let docs = [doc_with("a", ""), doc_with("b", ""), doc_with("c", "")];
+        let words = |text: &str| tokenize(text);
+        let (new_york, york_new) = (words("new york city"), words("york is new"));
+        let (new, york) = (words("new"), words("york"));
+        index.upsert(
+            docs[0].id.clone(),
+            [(SearchField::Object, new_york.as_slice())],
+        );
+        index.upsert(
+            docs[1].id.clone(),
+            [(SearchField::Object, york_new.as_slice())],
+        );
+        index.upsert(
+            docs[2].id.clone(),
+            [
+                (SearchField::Subject, new.as_slice()),
+                (SearchField::Object, york.as_slice()),
+            ],
+        );
+
+        let ids: Vec<FactId> = search(&index, "\"new york\"", 10)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(ids, vec![docs[0].id.clone()]);
+        assert_eq!(search(&index, "new york", 10).len(), 3);
+        assert_eq!(search(&index, "\"york city\"", 10)[0].0, docs[0].id);
+    }
+
+    #[test]
+    fn boolean_operators_and_prefixes_filter_matches() {
+        let docs = vec![
+            doc_with("a", "alice acme rust"),
+            doc_with("b", "bob acme go"),
+            doc_with("c", "carol acmeware rust"),
+        ];
+
+        assert_eq!(search_ids(&docs, "acme AND rust"), vec![docs[0].id.clone()]);
+        assert_eq!(search_ids(&docs, "acme -rust"), vec![docs[1].id.clone()]);
+        assert_eq!(
+            search_ids(&docs, "(alice OR carol) AND NOT acme"),
+            vec![docs[2].id.clone()]
+        );
+        assert_eq!(search_ids(&docs, "acm*").len(), 3);
+        assert_eq!(search_ids(&docs, "acmew*"), vec![docs[2].id.clone()]);
+        // Boolean misses do not fall back to typo matching of other terms.
+        assert!(search_ids(&docs, "alice AND go").is_empty());
+    }
+
+    #[test]
+    fn field_scopes_restrict_matches_and_term_frequency() {
+        let mut index = LexicalIndex::default();
+        let docs = [doc_with("a", ""), doc_with("b", "")];
+        let (alice, bob) = (tokenize("alice"), tokenize("bob"));
+        let knows = tokenize("knows");
+        index.upsert(
+            docs[0].id.clone(),
+            [
+                (SearchField::Subject, alice.as_slice()),
+                (SearchField::Predicate, knows.as_slice()),
+                (SearchField::Object, bob.as_slice()),
+            ],
+        );
+        index.upsert(
+            docs[1].id.clone(),
+            [
+                (SearchField::Subject, bob.as_slice()),
+                (SearchField::Predicate, knows.as_slice()),
+                (SearchField::Object, alice.as_slice()),
+            ],
+        );
+
+        let subject_bob: Vec<FactId> = search(&index, "subject:bob", 10)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(subject_bob, vec![docs[1].id.clone()]);
+        assert_eq!(search(&index, "object:bob", 10)[0].0, docs[0].id);
+        assert!(search(&index, "alias:bob", 10).is_empty());
+        assert_eq!(search(&index, "predicate:kno*", 10).len(), 2);
+    }
+
+    #[test]
+    fn fuzzy_matching_is_per_term_configurable_and_penalized() {
+        let docs = [
+            doc_with("a", "alice acme"),
+            doc_with("b", "alcie initech"),
+            doc_with("c", "alistair acme"),
+        ];
+        let mut index = LexicalIndex::default();
+        for doc in &docs {
+            let tokens = tokenize(&doc.content);
+            index.upsert(doc.id.clone(), [(SearchField::Object, tokens.as_slice())]);
+        }
+        let ids = |hits: Vec<(FactId, f32)>| -> Vec<FactId> {
+            hits.into_iter().map(|(id, _)| id).collect()
+        };
+
+        // "acme" matches exactly while the typo "aliec" is expanded on its
+        // own, instead of the whole query falling back to fuzzy matching.
+        let hits = search(&index, "aliec AND acme", 10);
+        assert_eq!(ids(hits), vec![docs[0].id.clone()]);
+
+        // Without transpositions "aliec" is two edits from both spellings.
+        let no_swaps = FuzzyConfig::new().with_transpositions(false);
+        assert!(search_with(&index, "aliec", &no_swaps, 10).is_empty());
+        let two_edits = no_swaps.with_max_distance(2);
+        assert_eq!(search_with(&index, "aliec", &two_edits, 10).len(), 2);
+
+        // Fuzzy hits score below exact hits for the same document shape.
+        let exact = search(&index, "alice", 10)[0].1;
+        let fuzzy = search(&index, "alicx", 10);
+        assert_eq!(fuzzy.len(), 1);
+        assert!(fuzzy[0].1 < exact);
+
+        let short_terms = FuzzyConfig::new().with_min_term_length(6);
+        assert!(search_with(&index, "alicx", &short_terms, 10).is_empty());
+        assert!(search_with(&index, "alicx", &FuzzyConfig::disabled(), 10).is_empty());
+
+        // Excluded terms are exact: "-aliec" excludes nothing.
+        assert_eq!(search(&index, "acme -aliec", 10).len(), 2);
+    }
 }
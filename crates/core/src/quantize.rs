@@ -0,0 +1,353 @@
+//! Compact encodings for embeddings.
+//!
+//! Two independent settings, both chosen per database through
+//! [`VectorConfig`](crate::VectorConfig):
+//!
+//! - [`Quantization`] decides how the in-memory vector index holds each
+//!   embedding. Quantized codes are used to shortlist candidates (and to
+//!   build the HNSW graph); the shortlist is then re-scored against the
+//!   full-precision embeddings kept by storage, so the final top-k scores are
+//!   exact scores under the space's [`Metric`](crate::Metric).
+//! - [`EmbeddingEncoding`] decides how new embeddings are written to the
+//!   append log. Both encodings are always readable.
+
+use crate::metric::{l2_norm, Metric};
+
+/// How the vector index holds embeddings in memory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Quantization {
+    /// Full-precision `f32` values, 4 bytes per dimension. Scores are exact
+    /// without re-scoring.
+    #[default]
+    None,
+    /// Symmetric per-vector int8 scalar quantization: 1 byte per dimension
+    /// plus one scale factor. Cosine similarity over the codes is usually
+    /// within about 0.01 of the exact value.
+    Int8,
+    /// One sign bit per dimension, compared by Hamming distance. A coarse
+    /// pre-filter that relies on a large shortlist (see
+    /// [`VectorConfig::with_rescore_multiplier`](crate::VectorConfig::with_rescore_multiplier))
+    /// and works best on high-dimensional embeddings.
+    Binary,
+}
+
+impl Quantization {
+    fn tag(self) -> u64 {
+        match self {
+            Quantization::None => 0,
+            Quantization::Int8 => 1,
+            Quantization::Binary => 2,
+        }
+    }
+
+    pub(crate) fn encode(self, embedding: Vec<f32>) -> Codes {
+        match self {
+            Quantization::None => Codes::F32(embedding),
+            Quantization::Int8 => {
+                let max = embedding.iter().fold(0.0f32, |max, x| max.max(x.abs()));
+                let scale = if max > 0.0 { max / 127.0 } else { 0.0 };
+                let codes = embedding
+                    .iter()
+                    .map(|x| {
+                        if scale > 0.0 {
+                            (x / scale).round().clamp(-127.0, 127.0) as i8
+                        } else {
+                            0
+                        }
+                    })
+                    .collect();
+                Codes::Int8 { codes, scale }
+            }
+            Quantization::Binary => Codes::Binary {
+                bits: sign_bits(&embedding),
+                dim: embedding.len(),
+            },
+        }
+    }
+}
+
+/// How embeddings are written to the append log.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum EmbeddingEncoding {
+    /// A JSON array of decimal numbers, readable by every Kronroe version.
+    #[default]
+    Json,
+    /// Base64 of the little-endian `f32` bytes: lossless and roughly half
+    /// the size of the JSON array. Files using it cannot be opened by
+    /// versions older than this encoding.
+    Binary,
+}
+
+/// An embedding as held by the vector index.
+#[derive(Debug, Clone)]
+pub(crate) enum Codes {
+    F32(Vec<f32>),
+    Int8 { codes: Vec<i8>, scale: f32 },
+    Binary { bits: Vec<u64>, dim: usize },
+}
+
+/// A query prepared once for scoring against many [`Codes`].
+pub(crate) struct Query<'a> {
+    values: &'a [f32],
+    norm: f32,
+    bits: Vec<u64>,
+}
+
+impl<'a> Query<'a> {
+    pub(crate) fn new(values: &'a [f32], quantization: Quantization) -> Self {
+        Self {
+            values,
+            norm: l2_norm(values),
+            bits: match quantization {
+                Quantization::Binary => sign_bits(values),
+                _ => Vec::new(),
+            },
+        }
+    }
+
+    pub(crate) fn norm(&self) -> f32 {
+        self.norm
+    }
+}
+
+impl Codes {
+    /// Euclidean norm of the embedding the codes stand for.
+    pub(crate) fn norm(&self) -> f32 {
+        match self {
+            Codes::F32(values) => l2_norm(values),
+            Codes::Int8 { codes, scale } => {
+                let sum: i64 = codes.iter().map(|&c| i64::from(c) * i64::from(c)).sum();
+                (sum as f32).sqrt() * scale
+            }
+            Codes::Binary { dim, .. } => (*dim as f32).sqrt(),
+        }
+    }
+
+    /// (Approximate) `metric` score against a query. `norm` is this entry's
+    /// [`norm`](Self::norm), cached by the caller.
+    pub(crate) fn similarity_to(&self, norm: f32, query: &Query<'_>, metric: Metric) -> f32 {
+        match self {
+            Codes::F32(values) => metric.score(query.values, query.norm, values, norm),
+            Codes::Int8 { codes, scale } => {
+                if codes.len() != query.values.len() {
+                    return 0.0;
+                }
+                int8_score(query.values, query.norm, codes, *scale, norm, metric)
+            }
+            Codes::Binary { bits, dim } => binary_score(bits, &query.bits, *dim, metric),
+        }
+    }
+
+    /// (Approximate) `metric` score between two entries of one index.
+    pub(crate) fn similarity(
+        &self,
+        norm: f32,
+        other: &Codes,
+        other_norm: f32,
+        metric: Metric,
+    ) -> f32 {
+        match (self, other) {
+            (Codes::F32(a), Codes::F32(b)) => metric.score(a, norm, b, other_norm),
+            (
+                Codes::Int8 {
+                    codes: a,
+                    scale: sa,
+                },
+                Codes::Int8 {
+                    codes: b,
+                    scale: sb,
+                },
+            ) => {
+                if a.len() != b.len() {
+                    return 0.0;
+                }
+                let a: Vec<f32> = a.iter().map(|&c| f32::from(c) * sa).collect();
+                int8_score(&a, norm, b, *sb, other_norm, metric)
+            }
+            (Codes::Binary { bits: a, dim }, Codes::Binary { bits: b, .. }) => {
+                binary_score(a, b, *dim, metric)
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Hash of the codes, seeded with the quantization that produced them.
+    pub(crate) fn fingerprint(&self, mut hash: u64) -> u64 {
+        let mut mix = |word: u64| {
+            hash ^= word;
+            hash = hash.wrapping_mul(0x100000001b3);
+        };
+        match self {
+            Codes::F32(values) => values.iter().for_each(|v| mix(u64::from(v.to_bits()))),
+            Codes::Int8 { codes, scale } => {
+                mix(Quantization::Int8.tag());
+                mix(u64::from(scale.to_bits()));
+                codes.iter().for_each(|&c| mix(u64::from(c as u8)));
+            }
+            Codes::Binary { bits, dim } => {
+                mix(Quantization::Binary.tag());
+                mix(*dim as u64);
+                bits.iter().for_each(|&word| mix(word));
+            }
+        }
+        hash
+    }
+}
+
+fn sign_bits(values: &[f32]) -> Vec<u64> {
+    let mut bits = vec![0u64; values.len().div_ceil(64)];
+    for (i, value) in values.iter().enumerate() {
+        if *value > 0.0 {
+            bits[i / 64] |= 1 << (i % 64);
+        }
+    }
+    bits
+}
+
+/// Score full-precision `values` against int8 `codes`, dequantizing each
+/// component as it is read.
+fn int8_score(
+    values: &[f32],
+    values_norm: f32,
+    codes: &[i8],
+    scale: f32,
+    codes_norm: f32,
+    metric: Metric,
+) -> f32 {
+    match metric {
+        Metric::Cosine => {
+            if values_norm == 0.0 || codes_norm == 0.0 {
+                return 0.0;
+            }
+            int8_dot(values, codes) * scale / (values_norm * codes_norm)
+        }
+        Metric::Dot => int8_dot(values, codes) * scale,
+        Metric::L2 => {
+            let squared: f32 = values
+                .iter()
+                .zip(codes)
+                .map(|(v, &c)| {
+                    let d = v - f32::from(c) * scale;
+                    d * d
+                })
+                .sum();
+            -squared.sqrt()
+        }
+        Metric::Hamming => {
+            let differing: u32 = values
+                .iter()
+                .zip(codes)
+                .map(|(v, &c)| u32::from((*v > 0.0) != (c > 0)))
+                .sum();
+            -(differing as f32)
+        }
+    }
+}
+
+fn int8_dot(values: &[f32], codes: &[i8]) -> f32 {
+    values
+        .iter()
+        .zip(codes)
+        .map(|(v, &c)| v * f32::from(c))
+        .sum()
+}
+
+/// Exact for [`Metric::Hamming`]; for the other metrics the sign agreement
+/// `1 - 2 * hamming / dim` (1.0 when every sign agrees, -1.0 when none do)
+/// stands in as a coarse proxy that re-scoring corrects.
+fn binary_score(a: &[u64], b: &[u64], dim: usize, metric: Metric) -> f32 {
+    if a.len() != b.len() || dim == 0 {
+        return 0.0;
+    }
+    let differing: u32 = a.iter().zip(b).map(|(x, y)| (x ^ y).count_ones()).sum();
+    match metric {
+        Metric::Hamming => -(differing as f32),
+        _ => 1.0 - 2.0 * differing as f32 / dim as f32,
+    }
+}
+
+/// Encode `values` as base64 of their little-endian bytes.
+pub(crate) fn encode_f32le(values: &[f32]) -> String {
+    let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+    crate::json_write::encode_base64(&bytes)
+}
+
+/// Inverse of [`encode_f32le`].
+pub(crate) fn decode_f32le(encoded: &str) -> crate::Result<Vec<f32>> {
+    let bytes = crate::json_read::decode_base64(encoded)
+        .map_err(|e| crate::KronroeError::serialization(format!("embedding bytes: {e}")))?;
+    if bytes.len() % 4 != 0 {
+        return Err(crate::KronroeError::serialization(
+            "embedding bytes are not a whole number of f32 values",
+        ));
+    }
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::cosine_with_norms;
+
+    fn sample(seed: u32, dim: usize) -> Vec<f32> {
+        (0..dim)
+            .map(|i| (seed as f32 * 0.37 + i as f32 * 1.91).sin() * 2.0)
+            .collect()
+    }
+
+    #[test]
+    fn quantized_similarities_track_exact_cosine() {
+        let a = sample(1, 96);
+        let b = sample(2, 96);
+        let exact = cosine_with_norms(&a, l2_norm(&a), &b, l2_norm(&b));
+
+        let int8 = Quantization::Int8.encode(b.clone());
+        let query = Query::new(&a, Quantization::Int8);
+        assert!((int8.similarity_to(int8.norm(), &query, Metric::Cosine) - exact).abs() < 0.01);
+        let int8_a = Quantization::Int8.encode(a.clone());
+        assert!(
+            (int8_a.similarity(int8_a.norm(), &int8, int8.norm(), Metric::Cosine) - exact).abs()
+                < 0.02
+        );
+
+        let binary = Quantization::Binary.encode(a.clone());
+        let same = Query::new(&a, Quantization::Binary);
+        assert_eq!(
+            binary.similarity_to(binary.norm(), &same, Metric::Cosine),
+            1.0
+        );
+        let negated: Vec<f32> = a.iter().map(|x| -x).collect();
+        let opposite = Query::new(&negated, Quantization::Binary);
+        assert_eq!(
+            binary.similarity_to(binary.norm(), &opposite, Metric::Cosine),
+            -1.0
+        );
+
+        let zero = Quantization::Int8.encode(vec![0.0; 4]);
+        assert_eq!(zero.similarity_to(zero.norm(), &query, Metric::Cosine), 0.0);
+
+        for metric in [Metric::Dot, Metric::L2] {
+            let exact = metric.score(&a, l2_norm(&a), &b, l2_norm(&b));
+            let approx = int8.similarity_to(int8.norm(), &query, metric);
+            assert!((approx - exact).abs() < 0.05 * exact.abs().max(1.0));
+        }
+        let differing = a
+            .iter()
+            .zip(&negated)
+            .filter(|(x, y)| (**x > 0.0) != (**y > 0.0));
+        assert_eq!(
+            binary.similarity_to(binary.norm(), &opposite, Metric::Hamming),
+            -(differing.count() as f32)
+        );
+    }
+
+    #[test]
+    fn f32le_encoding_round_trips_exactly() {
+        let values = vec![0.1f32, -3.5e-8, f32::MAX, 0.0, 1.0 / 3.0];
+        assert_eq!(decode_f32le(&encode_f32le(&values)).unwrap(), values);
+        assert!(decode_f32le("AAA=").is_err());
+    }
+}
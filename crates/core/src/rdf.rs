@@ -0,0 +1,1189 @@
+//! RDF export/import for Kronroe facts (N-Quads and Turtle-star).
+//!
+//! Every fact is written as an RDF-star statement node: a fact IRI that points
+//! at the quoted `<< subject predicate object >>` triple and carries the
+//! bi-temporal metadata (`validFrom`, `validTo`, `recordedAt`, `expiredAt`),
+//! `confidence`, and `source` as annotations. Each [`Fact::metadata`] entry is
+//! one more annotation whose predicate is `kr:meta/{key}` and whose object
+//! uses the term mapping below. Currently valid facts are also
+//! asserted as plain triples so standard triple stores see the current state
+//! without understanding the annotations.
+//!
+//! A per-fact statement node (instead of annotating the quoted triple
+//! directly) keeps repeated assertions of the same `(s, p, o)` — e.g. a value
+//! that held twice at different times — distinct after a round trip.
+//!
+//! Term mapping:
+//!
+//! | Kronroe | RDF |
+//! |---|---|
+//! | subject | `<{base}entity/{name}>` |
+//! | predicate | `<{base}predicate/{name}>` |
+//! | [`Value::Entity`] | `<{base}entity/{name}>` |
+//! | [`Value::Text`] | `"..."` (`xsd:string`) |
+//! | [`Value::Number`] | `"..."^^xsd:double` |
+//! | [`Value::Boolean`] | `"..."^^xsd:boolean` |
+//! | [`Value::Timestamp`] | `"..."^^xsd:dateTime` |
+//! | [`Value::Integer`] | `"..."^^xsd:integer` |
+//! | [`Value::Bytes`] | `"..."^^xsd:base64Binary` |
+//! | [`Value::Json`] | `"..."^^rdf:JSON` |
+//!
+//! Names are percent-encoded into IRIs. On import, IRIs outside the base
+//! namespace are kept verbatim as entity / predicate names, and plain triples
+//! without a matching statement node become new facts valid from the import
+//! time.
+
+use crate::{Fact, FactId, JsonValue, KronroeError, KronroeTimestamp, Result, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+
+/// Namespace of the Kronroe annotation vocabulary.
+pub const KRONROE_RDF_NS: &str = "https://kronroe.dev/ns#";
+
+const XSD_NS: &str = "http://www.w3.org/2001/XMLSchema#";
+const RDF_NS: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const RDF_JSON: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON";
+
+const P_STATEMENT: &str = "statement";
+const P_VALID_FROM: &str = "validFrom";
+const P_VALID_TO: &str = "validTo";
+const P_RECORDED_AT: &str = "recordedAt";
+const P_EXPIRED_AT: &str = "expiredAt";
+const P_CONFIDENCE: &str = "confidence";
+const P_SOURCE: &str = "source";
+/// Prefix of per-key metadata annotation predicates (`kr:meta/{key}`).
+const P_META: &str = "meta/";
+
+// ---------------------------------------------------------------------------
+// Public types
+// ---------------------------------------------------------------------------
+
+/// Serialization syntax for RDF export/import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RdfFormat {
+    /// Line-based N-Quads with RDF-star quoted triples (`<< s p o >>`).
+    NQuads,
+    /// Turtle-star with `kr:` / `xsd:` prefixes.
+    TurtleStar,
+}
+
+/// IRI configuration shared by export and import.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RdfOptions {
+    /// Base IRI for minted entity, predicate, and fact IRIs
+    /// (default: `https://kronroe.dev/id/`).
+    pub base_iri: String,
+    /// Named graph for exported N-Quads. `None` writes the default graph.
+    /// Ignored for Turtle-star and on import.
+    pub graph_iri: Option<String>,
+}
+
+impl Default for RdfOptions {
+    fn default() -> Self {
+        Self {
+            base_iri: "https://kronroe.dev/id/".to_string(),
+            graph_iri: None,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Export
+// ---------------------------------------------------------------------------
+
+/// Serialize facts into the requested RDF syntax.
+pub(crate) fn write_facts(facts: &[Fact], format: RdfFormat, options: &RdfOptions) -> String {
+    match format {
+        RdfFormat::NQuads => write_nquads(facts, options),
+        RdfFormat::TurtleStar => write_turtle_star(facts, options),
+    }
+}
+
+fn write_nquads(facts: &[Fact], options: &RdfOptions) -> String {
+    let graph = options
+        .graph_iri
+        .as_ref()
+        .map(|g| format!(" <{g}>"))
+        .unwrap_or_default();
+    let kr = |local: &str| format!("<{KRONROE_RDF_NS}{local}>");
+
+    let mut out = String::new();
+    for fact in facts {
+        let triple = statement_terms(fact, options);
+        let node = fact_iri(fact, options);
+        let _ = writeln!(out, "{node} {} << {triple} >>{graph} .", kr(P_STATEMENT));
+        for (local, object) in annotations(fact) {
+            let _ = writeln!(out, "{node} {} {}{graph} .", kr(local), object.full());
+        }
+        for (predicate, object) in metadata_annotations(fact, options) {
+            let _ = writeln!(out, "{node} {predicate} {object}{graph} .");
+        }
+        if fact.is_currently_valid() {
+            let _ = writeln!(out, "{triple}{graph} .");
+        }
+    }
+    out
+}
+
+fn write_turtle_star(facts: &[Fact], options: &RdfOptions) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "@prefix kr: <{KRONROE_RDF_NS}> .");
+    let _ = writeln!(out, "@prefix rdf: <{RDF_NS}> .");
+    let _ = writeln!(out, "@prefix xsd: <{XSD_NS}> .");
+
+    for fact in facts {
+        let triple = statement_terms(fact, options);
+        out.push('\n');
+        if fact.is_currently_valid() {
+            let _ = writeln!(out, "{triple} .");
+        }
+        let _ = write!(
+            out,
+            "{} kr:{P_STATEMENT} << {triple} >>",
+            fact_iri(fact, options)
+        );
+        for (local, object) in annotations(fact) {
+            let _ = write!(out, " ;\n    kr:{local} {}", object.prefixed());
+        }
+        // Metadata keys are arbitrary strings, so they always use full IRIs
+        // rather than `kr:` prefixed names.
+        for (predicate, object) in metadata_annotations(fact, options) {
+            let _ = write!(out, " ;\n    {predicate} {object}");
+        }
+        out.push_str(" .\n");
+    }
+    out
+}
+
+/// A typed literal that can be written with either full or prefixed datatype.
+struct TypedLiteral {
+    lexical: String,
+    /// Local name in the XSD namespace (or `rdf:JSON`); `None` for plain
+    /// strings.
+    xsd_type: Option<&'static str>,
+}
+
+impl TypedLiteral {
+    fn xsd(lexical: String, xsd_type: &'static str) -> Self {
+        Self {
+            lexical,
+            xsd_type: Some(xsd_type),
+        }
+    }
+
+    fn full(&self) -> String {
+        match self.xsd_type {
+            Some("JSON") => format!("\"{}\"^^<{RDF_JSON}>", escape_literal(&self.lexical)),
+            Some(t) => format!("\"{}\"^^<{XSD_NS}{t}>", escape_literal(&self.lexical)),
+            None => format!("\"{}\"", escape_literal(&self.lexical)),
+        }
+    }
+
+    fn prefixed(&self) -> String {
+        match self.xsd_type {
+            Some("JSON") => format!("\"{}\"^^rdf:JSON", escape_literal(&self.lexical)),
+            Some(t) => format!("\"{}\"^^xsd:{t}", escape_literal(&self.lexical)),
+            None => format!("\"{}\"", escape_literal(&self.lexical)),
+        }
+    }
+}
+
+fn date_time_literal(ts: KronroeTimestamp) -> TypedLiteral {
+    TypedLiteral::xsd(ts.to_rfc3339_z(), "dateTime")
+}
+
+fn annotations(fact: &Fact) -> Vec<(&'static str, TypedLiteral)> {
+    let mut out = vec![(P_VALID_FROM, date_time_literal(fact.valid_from))];
+    if let Some(t) = fact.valid_to {
+        out.push((P_VALID_TO, date_time_literal(t)));
+    }
+    out.push((P_RECORDED_AT, date_time_literal(fact.recorded_at)));
+    if let Some(t) = fact.expired_at {
+        out.push((P_EXPIRED_AT, date_time_literal(t)));
+    }
+    out.push((
+        P_CONFIDENCE,
+        TypedLiteral::xsd(format!("{:?}", fact.confidence), "double"),
+    ));
+    if let Some(source) = &fact.source {
+        out.push((
+            P_SOURCE,
+            TypedLiteral {
+                lexical: source.clone(),
+                xsd_type: None,
+            },
+        ));
+    }
+    out
+}
+
+/// `(predicate IRI, object term)` pairs for a fact's metadata qualifiers.
+fn metadata_annotations(fact: &Fact, options: &RdfOptions) -> Vec<(String, String)> {
+    fact.metadata
+        .iter()
+        .map(|(key, value)| {
+            (
+                format!("<{KRONROE_RDF_NS}{P_META}{}>", percent_encode(key)),
+                value_term(value, options),
+            )
+        })
+        .collect()
+}
+
+/// `<s> <p> o` for a fact, without the terminating dot.
+fn statement_terms(fact: &Fact, options: &RdfOptions) -> String {
+    let subject = minted_iri(&options.base_iri, "entity", &fact.subject);
+    let predicate = minted_iri(&options.base_iri, "predicate", &fact.predicate);
+    let object = value_term(&fact.object, options);
+    format!("{subject} {predicate} {object}")
+}
+
+/// Full-form RDF term for a value in object position.
+fn value_term(value: &Value, options: &RdfOptions) -> String {
+    match value {
+        Value::Entity(name) => minted_iri(&options.base_iri, "entity", name),
+        Value::Text(s) => TypedLiteral {
+            lexical: s.clone(),
+            xsd_type: None,
+        }
+        .full(),
+        Value::Number(n) => TypedLiteral::xsd(format_double(*n), "double").full(),
+        Value::Boolean(b) => TypedLiteral::xsd(b.to_string(), "boolean").full(),
+        Value::Timestamp(t) => date_time_literal(*t).full(),
+        Value::Integer(n) => TypedLiteral::xsd(n.to_string(), "integer").full(),
+        Value::Bytes(bytes) => {
+            TypedLiteral::xsd(crate::json_write::encode_base64(bytes), "base64Binary").full()
+        }
+        Value::Json(json) => TypedLiteral::xsd(json.to_json_string(), "JSON").full(),
+    }
+}
+
+fn fact_iri(fact: &Fact, options: &RdfOptions) -> String {
+    minted_iri(&options.base_iri, "fact", fact.id.as_str())
+}
+
+fn minted_iri(base: &str, kind: &str, name: &str) -> String {
+    format!("<{base}{kind}/{}>", percent_encode(name))
+}
+
+fn format_double(n: f64) -> String {
+    if n.is_nan() {
+        "NaN".to_string()
+    } else if n.is_infinite() {
+        if n > 0.0 { "INF" } else { "-INF" }.to_string()
+    } else {
+        format!("{n:?}")
+    }
+}
+
+fn escape_literal(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 || c == '\u{7f}' => {
+                let _ = write!(out, "\\u{:04X}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn percent_encode(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for byte in name.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            out.push(byte as char);
+        } else {
+            let _ = write!(out, "%{byte:02X}");
+        }
+    }
+    out
+}
+
+fn percent_decode(encoded: &str) -> String {
+    let bytes = encoded.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+            if let Some(b) = hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                out.push(b);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+// ---------------------------------------------------------------------------
+// Import
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Term {
+    Iri(String),
+    Blank(String),
+    Literal {
+        lexical: String,
+        datatype: Option<String>,
+    },
+    Quoted(Box<Triple>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Triple {
+    subject: Term,
+    predicate: Term,
+    object: Term,
+}
+
+/// Facts parsed from an RDF document, split by where they came from.
+pub(crate) struct ParsedFacts {
+    /// Facts from statement nodes, with their annotated IDs and timestamps.
+    pub(crate) annotated: Vec<Fact>,
+    /// Facts from plain triples, with fresh IDs, valid from `now`.
+    pub(crate) plain: Vec<Fact>,
+}
+
+/// Parse RDF input into facts, annotated statements first.
+#[cfg(test)]
+pub(crate) fn parse_facts(
+    input: &str,
+    format: RdfFormat,
+    options: &RdfOptions,
+    now: KronroeTimestamp,
+) -> Result<Vec<Fact>> {
+    let parsed = parse_document(input, format, options, now)?;
+    Ok(parsed.annotated.into_iter().chain(parsed.plain).collect())
+}
+
+/// Parse RDF input into facts.
+///
+/// Statement nodes (anything with a `kr:statement` quoted triple, or a quoted
+/// triple used directly as a subject) become facts with their annotated
+/// timestamps, confidence, and source. Plain triples not covered by a
+/// statement node become facts valid from `now`.
+pub(crate) fn parse_document(
+    input: &str,
+    format: RdfFormat,
+    options: &RdfOptions,
+    now: KronroeTimestamp,
+) -> Result<ParsedFacts> {
+    let triples = Parser::new(input, format).parse_document()?;
+
+    let statement_pred = format!("{KRONROE_RDF_NS}{P_STATEMENT}");
+    // Node key -> quoted statement, in first-seen order.
+    let mut node_order: Vec<String> = Vec::new();
+    let mut statements: HashMap<String, Triple> = HashMap::new();
+    let mut node_iris: HashMap<String, String> = HashMap::new();
+    for triple in &triples {
+        let key = match (&triple.subject, &triple.predicate, &triple.object) {
+            (subject, Term::Iri(p), Term::Quoted(quoted)) if *p == statement_pred => {
+                let key = node_key(subject);
+                if let Term::Iri(iri) = subject {
+                    node_iris.insert(key.clone(), iri.clone());
+                }
+                statements.insert(key.clone(), (**quoted).clone());
+                key
+            }
+            (Term::Quoted(quoted), _, _) => {
+                let key = node_key(&triple.subject);
+                statements.insert(key.clone(), (**quoted).clone());
+                key
+            }
+            _ => continue,
+        };
+        if !node_order.contains(&key) {
+            node_order.push(key);
+        }
+    }
+
+    let mut annotations: HashMap<String, HashMap<String, Term>> = HashMap::new();
+    for triple in &triples {
+        let key = node_key(&triple.subject);
+        if !statements.contains_key(&key) {
+            continue;
+        }
+        if let Term::Iri(p) = &triple.predicate {
+            if let Some(local) = p.strip_prefix(KRONROE_RDF_NS) {
+                annotations
+                    .entry(key)
+                    .or_default()
+                    .insert(local.to_string(), triple.object.clone());
+            }
+        }
+    }
+
+    let mut facts = Vec::new();
+    let mut plain = Vec::new();
+    let mut annotated: HashSet<String> = HashSet::new();
+    for key in &node_order {
+        let statement = &statements[key];
+        annotated.insert(node_key(&Term::Quoted(Box::new(statement.clone()))));
+        let mut fact = fact_from_triple(statement, options, now)?;
+        if let Some(iri) = node_iris.get(key) {
+            let fact_prefix = format!("{}fact/", options.base_iri);
+            if let Some(id) = iri.strip_prefix(&fact_prefix) {
+                if let Ok(id) = FactId::parse(&percent_decode(id)) {
+                    fact.id = id;
+                }
+            }
+        }
+        if let Some(props) = annotations.get(key) {
+            apply_annotations(&mut fact, props, options)?;
+        }
+        facts.push(fact);
+    }
+
+    for triple in &triples {
+        if statements.contains_key(&node_key(&triple.subject))
+            || annotated.contains(&node_key(&Term::Quoted(Box::new(triple.clone()))))
+        {
+            continue;
+        }
+        plain.push(fact_from_triple(triple, options, now)?);
+    }
+
+    Ok(ParsedFacts {
+        annotated: facts,
+        plain,
+    })
+}
+
+fn node_key(term: &Term) -> String {
+    match term {
+        Term::Iri(iri) => format!("<{iri}>"),
+        Term::Blank(label) => format!("_:{label}"),
+        Term::Literal { lexical, datatype } => {
+            format!("{lexical:?}^^{}", datatype.as_deref().unwrap_or(""))
+        }
+        Term::Quoted(t) => format!(
+            "<< {} {} {} >>",
+            node_key(&t.subject),
+            node_key(&t.predicate),
+            node_key(&t.object)
+        ),
+    }
+}
+
+fn fact_from_triple(triple: &Triple, options: &RdfOptions, now: KronroeTimestamp) -> Result<Fact> {
+    let subject = match &triple.subject {
+        Term::Iri(iri) => unmint(&options.base_iri, "entity", iri),
+        Term::Blank(label) => format!("_:{label}"),
+        other => {
+            return Err(KronroeError::invalid_input(format!(
+                "RDF subject must be an IRI or blank node, got {}",
+                node_key(other)
+            )))
+        }
+    };
+    let predicate = match &triple.predicate {
+        Term::Iri(iri) => unmint(&options.base_iri, "predicate", iri),
+        other => {
+            return Err(KronroeError::invalid_input(format!(
+                "RDF predicate must be an IRI, got {}",
+                node_key(other)
+            )))
+        }
+    };
+    let object = match &triple.object {
+        Term::Iri(iri) => Value::Entity(unmint(&options.base_iri, "entity", iri)),
+        Term::Blank(label) => Value::Entity(format!("_:{label}")),
+        Term::Literal { lexical, datatype } => literal_value(lexical, datatype.as_deref())?,
+        Term::Quoted(_) => {
+            return Err(KronroeError::invalid_input(
+                "quoted triples are not supported in object position",
+            ))
+        }
+    };
+
+    let mut fact = Fact::new(subject, predicate, object, now);
+    fact.recorded_at = now;
+    Ok(fact)
+}
+
+fn unmint(base: &str, kind: &str, iri: &str) -> String {
+    let prefix = format!("{base}{kind}/");
+    match iri.strip_prefix(&prefix) {
+        Some(name) => percent_decode(name),
+        None => iri.to_string(),
+    }
+}
+
+fn literal_value(lexical: &str, datatype: Option<&str>) -> Result<Value> {
+    if datatype == Some(RDF_JSON) {
+        return JsonValue::parse_str(lexical)
+            .map(Value::Json)
+            .map_err(|e| KronroeError::invalid_input(format!("invalid rdf:JSON literal: {e}")));
+    }
+    let local = datatype.and_then(|dt| dt.strip_prefix(XSD_NS));
+    match local {
+        Some("double" | "float" | "decimal") => parse_double(lexical).map(Value::Number),
+        Some("integer" | "int" | "long" | "short") => lexical
+            .trim_start_matches('+')
+            .parse::<i64>()
+            .map(Value::Integer)
+            .or_else(|_| parse_double(lexical).map(Value::Number)),
+        Some("dateTime") => KronroeTimestamp::parse_rfc3339(lexical)
+            .map(Value::Timestamp)
+            .map_err(|e| KronroeError::invalid_input(format!("invalid xsd:dateTime: {e}"))),
+        Some("base64Binary") => crate::json_read::decode_base64(lexical)
+            .map(Value::Bytes)
+            .map_err(|e| KronroeError::invalid_input(format!("invalid xsd:base64Binary: {e}"))),
+        Some("boolean") => match lexical {
+            "true" | "1" => Ok(Value::Boolean(true)),
+            "false" | "0" => Ok(Value::Boolean(false)),
+            other => Err(KronroeError::invalid_input(format!(
+                "invalid xsd:boolean literal: {other}"
+            ))),
+        },
+        _ => Ok(Value::Text(lexical.to_string())),
+    }
+}
+
+fn parse_double(lexical: &str) -> Result<f64> {
+    match lexical {
+        "INF" | "+INF" => Ok(f64::INFINITY),
+        "-INF" => Ok(f64::NEG_INFINITY),
+        "NaN" => Ok(f64::NAN),
+        other => other
+            .parse::<f64>()
+            .map_err(|_| KronroeError::invalid_input(format!("invalid numeric literal: {other}"))),
+    }
+}
+
+fn apply_annotations(
+    fact: &mut Fact,
+    props: &HashMap<String, Term>,
+    options: &RdfOptions,
+) -> Result<()> {
+    let timestamp = |name: &str| -> Result<Option<KronroeTimestamp>> {
+        match props.get(name) {
+            Some(Term::Literal { lexical, .. }) => KronroeTimestamp::parse_rfc3339(lexical)
+                .map(Some)
+                .map_err(|e| KronroeError::invalid_input(format!("kr:{name}: {e}"))),
+            Some(other) => Err(KronroeError::invalid_input(format!(
+                "kr:{name} must be a literal, got {}",
+                node_key(other)
+            ))),
+            None => Ok(None),
+        }
+    };
+
+    if let Some(t) = timestamp(P_VALID_FROM)? {
+        fact.valid_from = t;
+    }
+    fact.valid_to = timestamp(P_VALID_TO)?;
+    if let Some(t) = timestamp(P_RECORDED_AT)? {
+        fact.recorded_at = t;
+    }
+    fact.expired_at = timestamp(P_EXPIRED_AT)?;
+
+    if let Some(term) = props.get(P_CONFIDENCE) {
+        let Term::Literal { lexical, .. } = term else {
+            return Err(KronroeError::invalid_input(
+                "kr:confidence must be a literal".to_string(),
+            ));
+        };
+        let confidence = parse_double(lexical)? as f32;
+        if !confidence.is_finite() {
+            return Err(KronroeError::invalid_input(
+                "kr:confidence must be finite".to_string(),
+            ));
+        }
+        fact.confidence = confidence.clamp(0.0, 1.0);
+    }
+    if let Some(Term::Literal { lexical, .. }) = props.get(P_SOURCE) {
+        fact.source = Some(lexical.clone());
+    }
+    for (local, term) in props {
+        let Some(key) = local.strip_prefix(P_META) else {
+            continue;
+        };
+        let value = match term {
+            Term::Iri(iri) => Value::Entity(unmint(&options.base_iri, "entity", iri)),
+            Term::Literal { lexical, datatype } => literal_value(lexical, datatype.as_deref())?,
+            other => {
+                return Err(KronroeError::invalid_input(format!(
+                    "kr:meta/{key} must be an IRI or literal, got {}",
+                    node_key(other)
+                )))
+            }
+        };
+        fact.metadata.insert(percent_decode(key), value);
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Parser
+// ---------------------------------------------------------------------------
+
+/// Lenient parser for the N-Quads-star / Turtle-star subset Kronroe emits,
+/// plus the common Turtle shorthands (`@prefix`, `;`, `,`, `a`, bare numbers
+/// and booleans). Graph labels in N-Quads are accepted and ignored.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    line: usize,
+    format: RdfFormat,
+    prefixes: HashMap<String, String>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str, format: RdfFormat) -> Self {
+        Self {
+            input,
+            pos: 0,
+            line: 1,
+            format,
+            prefixes: HashMap::new(),
+        }
+    }
+
+    fn error(&self, msg: impl std::fmt::Display) -> KronroeError {
+        KronroeError::invalid_input(format!("RDF parse error at line {}: {msg}", self.line))
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_ws(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.bump();
+            } else if c == '#' {
+                while let Some(c) = self.bump() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_ws();
+        if self.rest().starts_with(token) {
+            for _ in token.chars() {
+                self.bump();
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<()> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected '{token}'")))
+        }
+    }
+
+    fn parse_document(mut self) -> Result<Vec<Triple>> {
+        let mut triples = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.peek().is_none() {
+                return Ok(triples);
+            }
+            if self.try_directive()? {
+                continue;
+            }
+            self.parse_statement(&mut triples)?;
+        }
+    }
+
+    fn try_directive(&mut self) -> Result<bool> {
+        let rest = self.rest();
+        let sparql_style = rest
+            .get(..7)
+            .is_some_and(|head| head.eq_ignore_ascii_case("PREFIX "));
+        if !(rest.starts_with("@prefix") || sparql_style) {
+            return Ok(false);
+        }
+        let keyword_len = if sparql_style { 6 } else { 7 };
+        for _ in 0..keyword_len {
+            self.bump();
+        }
+        self.skip_ws();
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c == ':' {
+                break;
+            }
+            if c.is_whitespace() {
+                return Err(self.error("malformed prefix name"));
+            }
+            self.bump();
+        }
+        let name = self.input[start..self.pos].to_string();
+        self.expect(":")?;
+        self.skip_ws();
+        let Term::Iri(iri) = self.parse_iri()? else {
+            unreachable!("parse_iri always returns an IRI term");
+        };
+        self.prefixes.insert(name, iri);
+        if !sparql_style {
+            self.expect(".")?;
+        }
+        Ok(true)
+    }
+
+    fn parse_statement(&mut self, triples: &mut Vec<Triple>) -> Result<()> {
+        let subject = self.parse_term()?;
+        loop {
+            let predicate = self.parse_predicate()?;
+            loop {
+                let object = self.parse_term()?;
+                triples.push(Triple {
+                    subject: subject.clone(),
+                    predicate: predicate.clone(),
+                    object,
+                });
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            if self.eat(";") {
+                // Turtle allows a trailing ';' before the final '.'.
+                if self.eat(".") {
+                    return Ok(());
+                }
+                continue;
+            }
+            if self.eat(".") {
+                return Ok(());
+            }
+            if self.format == RdfFormat::NQuads {
+                // Optional graph label.
+                self.parse_term()?;
+                return self.expect(".");
+            }
+            return Err(self.error("expected '.', ';' or ','"));
+        }
+    }
+
+    fn parse_predicate(&mut self) -> Result<Term> {
+        self.skip_ws();
+        let rest = self.rest();
+        if rest.starts_with('a') && rest[1..].starts_with(|c: char| c.is_whitespace()) {
+            self.bump();
+            return Ok(Term::Iri(RDF_TYPE.to_string()));
+        }
+        self.parse_term()
+    }
+
+    fn parse_term(&mut self) -> Result<Term> {
+        self.skip_ws();
+        let rest = self.rest();
+        if rest.starts_with("<<") {
+            self.bump();
+            self.bump();
+            let subject = self.parse_term()?;
+            let predicate = self.parse_predicate()?;
+            let object = self.parse_term()?;
+            self.expect(">>")?;
+            return Ok(Term::Quoted(Box::new(Triple {
+                subject,
+                predicate,
+                object,
+            })));
+        }
+        match self.peek() {
+            None => Err(self.error("unexpected end of input")),
+            Some('<') => self.parse_iri(),
+            Some('"') => self.parse_literal(),
+            Some('_') if rest.starts_with("_:") => {
+                self.bump();
+                self.bump();
+                Ok(Term::Blank(self.take_name()))
+            }
+            Some(c) if c.is_ascii_digit() || matches!(c, '+' | '-' | '.') => {
+                let lexical = self.take_name();
+                let datatype = if lexical.contains(['e', 'E']) {
+                    "double"
+                } else if lexical.contains('.') {
+                    "decimal"
+                } else {
+                    "integer"
+                };
+                Ok(Term::Literal {
+                    lexical,
+                    datatype: Some(format!("{XSD_NS}{datatype}")),
+                })
+            }
+            Some(_) => {
+                let name = self.take_name();
+                if name == "true" || name == "false" {
+                    return Ok(Term::Literal {
+                        lexical: name,
+                        datatype: Some(format!("{XSD_NS}boolean")),
+                    });
+                }
+                self.resolve_prefixed(&name).map(Term::Iri)
+            }
+        }
+    }
+
+    /// Read a bare token (prefixed name, blank node label, or number). A
+    /// trailing '.' is left in place as the statement terminator.
+    fn take_name(&mut self) -> String {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || "<>\"{}()[];,#".contains(c) {
+                break;
+            }
+            self.bump();
+        }
+        while self.pos > start && self.input[..self.pos].ends_with('.') {
+            self.pos -= 1;
+        }
+        self.input[start..self.pos].to_string()
+    }
+
+    fn resolve_prefixed(&self, name: &str) -> Result<String> {
+        let Some((prefix, local)) = name.split_once(':') else {
+            return Err(self.error(format!("unexpected token '{name}'")));
+        };
+        self.prefixes
+            .get(prefix)
+            .map(|ns| format!("{ns}{local}"))
+            .ok_or_else(|| self.error(format!("undeclared prefix '{prefix}:'")))
+    }
+
+    fn parse_iri(&mut self) -> Result<Term> {
+        self.expect("<")?;
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c == '>' {
+                let iri = self.input[start..self.pos].to_string();
+                self.bump();
+                return Ok(Term::Iri(iri));
+            }
+            if c.is_whitespace() {
+                break;
+            }
+            self.bump();
+        }
+        Err(self.error("unterminated IRI"))
+    }
+
+    fn parse_literal(&mut self) -> Result<Term> {
+        let long = self.rest().starts_with("\"\"\"");
+        let delimiter = if long { "\"\"\"" } else { "\"" };
+        self.expect(delimiter)?;
+
+        let mut lexical = String::new();
+        loop {
+            if self.rest().starts_with(delimiter) {
+                for _ in delimiter.chars() {
+                    self.bump();
+                }
+                break;
+            }
+            if !long && self.peek() == Some('\n') {
+                return Err(self.error("newline in string literal"));
+            }
+            match self.bump() {
+                None => return Err(self.error("unterminated string literal")),
+                Some('\\') => lexical.push(self.parse_escape()?),
+                Some(c) => lexical.push(c),
+            }
+        }
+
+        let datatype = if self.rest().starts_with("^^") {
+            self.bump();
+            self.bump();
+            match self.parse_term()? {
+                Term::Iri(iri) => Some(iri),
+                _ => return Err(self.error("literal datatype must be an IRI")),
+            }
+        } else if self.rest().starts_with('@') {
+            // Language-tagged strings import as plain text.
+            self.bump();
+            self.take_name();
+            None
+        } else {
+            None
+        };
+        Ok(Term::Literal { lexical, datatype })
+    }
+
+    fn parse_escape(&mut self) -> Result<char> {
+        let c = self
+            .bump()
+            .ok_or_else(|| self.error("unterminated escape"))?;
+        let hex_len = match c {
+            't' => return Ok('\t'),
+            'b' => return Ok('\u{8}'),
+            'n' => return Ok('\n'),
+            'r' => return Ok('\r'),
+            'f' => return Ok('\u{c}'),
+            '"' | '\'' | '\\' => return Ok(c),
+            'u' => 4,
+            'U' => 8,
+            other => return Err(self.error(format!("invalid escape '\\{other}'"))),
+        };
+        let end = self.pos + hex_len;
+        let hex = self
+            .input
+            .get(self.pos..end)
+            .ok_or_else(|| self.error("truncated unicode escape"))?;
+        let ch = u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.error(format!("invalid unicode escape '{hex}'")))?;
+        self.pos = end;
+        Ok(ch)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> KronroeTimestamp {
+        s.parse().unwrap()
+    }
+
+    fn sample_facts() -> Vec<Fact> {
+        let mut closed = Fact::new(
+            "alice",
+            "works_at",
+            Value::Entity("Acme Corp".into()),
+            dt("2020-01-01T00:00:00Z"),
+        )
+        .with_confidence(0.75)
+        .with_source("api:linkedin")
+        .with_metadata("model", "extractor v2")
+        .with_metadata("turn", 7_i64)
+        .with_metadata("evidence doc", Value::Entity("doc/1".into()));
+        closed.valid_to = Some(dt("2023-06-01T00:00:00Z"));
+        closed.expired_at = Some(dt("2023-06-02T00:00:00Z"));
+
+        vec![
+            closed,
+            Fact::new("alice", "age", 34.5, dt("2024-01-01T00:00:00Z")),
+            Fact::new("alice", "is_admin", true, dt("2024-01-01T00:00:00Z")),
+            Fact::new(
+                "alice",
+                "bio",
+                "Says \"hi\"\nand \\ leaves",
+                dt("2024-01-01T00:00:00Z"),
+            ),
+        ]
+    }
+
+    fn assert_same(a: &Fact, b: &Fact) {
+        assert_eq!(a.id, b.id);
+        assert_eq!(a.subject, b.subject);
+        assert_eq!(a.predicate, b.predicate);
+        assert_eq!(a.object.to_string(), b.object.to_string());
+        assert_eq!(
+            std::mem::discriminant(&a.object),
+            std::mem::discriminant(&b.object)
+        );
+        assert_eq!(a.valid_from, b.valid_from);
+        assert_eq!(a.valid_to, b.valid_to);
+        assert_eq!(a.recorded_at, b.recorded_at);
+        assert_eq!(a.expired_at, b.expired_at);
+        assert_eq!(a.confidence, b.confidence);
+        assert_eq!(a.source, b.source);
+        assert_eq!(a.metadata, b.metadata);
+    }
+
+    #[test]
+    fn nquads_round_trip_preserves_every_field() {
+        let facts = sample_facts();
+        let options = RdfOptions::default();
+        let text = write_facts(&facts, RdfFormat::NQuads, &options);
+        let parsed = parse_facts(
+            &text,
+            RdfFormat::NQuads,
+            &options,
+            KronroeTimestamp::now_utc(),
+        )
+        .unwrap();
+        assert_eq!(parsed.len(), facts.len());
+        for (a, b) in facts.iter().zip(&parsed) {
+            assert_same(a, b);
+        }
+    }
+
+    #[test]
+    fn turtle_star_round_trip_preserves_every_field() {
+        let facts = sample_facts();
+        let options = RdfOptions::default();
+        let text = write_facts(&facts, RdfFormat::TurtleStar, &options);
+        assert!(text.starts_with("@prefix kr:"));
+        let parsed = parse_facts(
+            &text,
+            RdfFormat::TurtleStar,
+            &options,
+            KronroeTimestamp::now_utc(),
+        )
+        .unwrap();
+        assert_eq!(parsed.len(), facts.len());
+        for (a, b) in facts.iter().zip(&parsed) {
+            assert_same(a, b);
+        }
+    }
+
+    #[test]
+    fn export_maps_value_variants_to_rdf_terms() {
+        let facts = sample_facts();
+        let text = write_facts(&facts, RdfFormat::NQuads, &RdfOptions::default());
+        assert!(text.contains("<https://kronroe.dev/id/entity/Acme%20Corp>"));
+        assert!(text.contains("\"34.5\"^^<http://www.w3.org/2001/XMLSchema#double>"));
+        assert!(text.contains("\"true\"^^<http://www.w3.org/2001/XMLSchema#boolean>"));
+        assert!(text.contains("\"Says \\\"hi\\\"\\nand \\\\ leaves\""));
+        assert!(text.contains(
+            "<https://kronroe.dev/ns#validTo> \"2023-06-01T00:00:00Z\"^^<http://www.w3.org/2001/XMLSchema#dateTime>"
+        ));
+    }
+
+    #[test]
+    fn only_current_facts_are_asserted_as_plain_triples() {
+        let facts = sample_facts();
+        let text = write_facts(&facts, RdfFormat::NQuads, &RdfOptions::default());
+        let plain_works_at = text
+            .lines()
+            .filter(|l| l.starts_with("<https://kronroe.dev/id/entity/alice>"))
+            .filter(|l| l.contains("predicate/works_at"))
+            .count();
+        assert_eq!(plain_works_at, 0, "closed fact must only appear quoted");
+        let plain = text
+            .lines()
+            .filter(|l| l.starts_with("<https://kronroe.dev/id/entity/alice>"))
+            .count();
+        assert_eq!(plain, 3);
+    }
+
+    #[test]
+    fn nquads_graph_label_is_written_and_ignored_on_import() {
+        let facts = sample_facts();
+        let options = RdfOptions {
+            graph_iri: Some("https://example.org/g".to_string()),
+            ..RdfOptions::default()
+        };
+        let text = write_facts(&facts, RdfFormat::NQuads, &options);
+        assert!(text
+            .lines()
+            .all(|l| l.ends_with("<https://example.org/g> .")));
+        let parsed = parse_facts(
+            &text,
+            RdfFormat::NQuads,
+            &options,
+            KronroeTimestamp::now_utc(),
+        )
+        .unwrap();
+        assert_eq!(parsed.len(), facts.len());
+    }
+
+    #[test]
+    fn plain_foreign_triples_import_as_new_facts() {
+        let now = dt("2025-01-01T00:00:00Z");
+        let input = r#"
+            @prefix ex: <https://example.org/> .
+            @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+            ex:bob ex:knows ex:carol , ex:dave ;
+                ex:height 1.8 ;
+                ex:name "Bob"@en .
+        "#;
+        let facts = parse_facts(input, RdfFormat::TurtleStar, &RdfOptions::default(), now).unwrap();
+        assert_eq!(facts.len(), 4);
+        assert!(facts.iter().all(|f| f.valid_from == now));
+        assert_eq!(facts[0].subject, "https://example.org/bob");
+        assert!(matches!(&facts[1].object, Value::Entity(e) if e == "https://example.org/dave"));
+        assert!(matches!(facts[2].object, Value::Number(n) if (n - 1.8).abs() < 1e-12));
+        assert!(matches!(&facts[3].object, Value::Text(s) if s == "Bob"));
+    }
+
+    #[test]
+    fn non_ascii_prefixed_names_do_not_split_characters() {
+        let input = "@prefix ex: <https://example.org/> .\nex:café ex:p ex:o .\n";
+        let facts = parse_facts(
+            input,
+            RdfFormat::TurtleStar,
+            &RdfOptions::default(),
+            KronroeTimestamp::now_utc(),
+        )
+        .unwrap();
+        assert_eq!(facts.len(), 1);
+        assert_eq!(facts[0].subject, "https://example.org/café");
+    }
+
+    #[test]
+    fn quoted_subject_annotations_are_accepted() {
+        let input = r#"
+            @prefix kr: <https://kronroe.dev/ns#> .
+            @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+            << <https://example.org/a> <https://example.org/p> "x" >>
+                kr:validFrom "2021-02-03T00:00:00Z"^^xsd:dateTime ;
+                kr:confidence 0.5 .
+        "#;
+        let facts = parse_facts(
+            input,
+            RdfFormat::TurtleStar,
+            &RdfOptions::default(),
+            KronroeTimestamp::now_utc(),
+        )
+        .unwrap();
+        assert_eq!(facts.len(), 1);
+        assert_eq!(facts[0].valid_from, dt("2021-02-03T00:00:00Z"));
+        assert_eq!(facts[0].confidence, 0.5);
+    }
+
+    #[test]
+    fn malformed_input_reports_line() {
+        let err = parse_facts(
+            "<a> <b> <c> .\n<a> <b> \"unterminated .\n",
+            RdfFormat::NQuads,
+            &RdfOptions::default(),
+            KronroeTimestamp::now_utc(),
+        )
+        .unwrap_err();
+        assert!(err.is_invalid_input());
+        assert!(err.message().contains("line 2"), "{}", err.message());
+    }
+
+    #[test]
+    fn percent_encoding_round_trips_unicode_names() {
+        let name = "Zoë / Müller #1";
+        assert_eq!(percent_decode(&percent_encode(name)), name);
+    }
+}
@@ -0,0 +1,687 @@
+//! Incrementally maintained full-text index over stored facts.
+//!
+//! [`SearchIndex`] wraps the BM25 [`LexicalIndex`] with the bookkeeping
+//! needed to keep it in sync with fact writes: each fact's own tokens are
+//! kept separately from its subject's alias tokens, so asserting an alias
+//! re-indexes only that subject's documents.
+//!
+//! The index can be checkpointed to a sidecar file. A checkpoint records a
+//! fingerprint of the fact-id set it covers; fact content is immutable once
+//! written (only validity windows change), so a matching fingerprint means
+//! the checkpoint is still exact and tokenization can be skipped on open.
+
+use crate::checkpoint;
+use crate::explain::{highlight_snippets, SearchExplanation, SearchField};
+pub(crate) use crate::filter::DocumentMeta;
+use crate::filter::SearchFilter;
+use crate::fuzzy::FuzzyConfig;
+use crate::json_read::JsonValue;
+use crate::json_write;
+use crate::lexical::{parse_query, LexicalIndex};
+use crate::tokenizer::{StandardTokenizer, Tokenizer};
+use crate::{FactId, KronroeError, Result};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+
+const CHECKPOINT_FORMAT: &str = "kronroe-search-index-v2";
+
+#[derive(Debug)]
+struct DocumentTokens {
+    subject: String,
+    runs: Vec<(SearchField, Vec<String>)>,
+}
+
+/// Full-text search settings for a database, passed to
+/// [`TemporalGraph::open_with_search_config`].
+///
+/// ```rust,no_run
+/// # use kronroe::{SearchConfig, StandardTokenizer, TemporalGraph};
+/// let config = SearchConfig::new()
+///     .with_tokenizer(StandardTokenizer::english())
+///     .with_checkpoint("memory.kronroe.search");
+/// let db = TemporalGraph::open_with_search_config("memory.kronroe", config).unwrap();
+/// ```
+///
+/// The tokenizer is not persisted in the database: open it with the same
+/// configuration every time (a different tokenizer re-indexes every fact).
+///
+/// [`TemporalGraph::open_with_search_config`]: crate::TemporalGraph::open_with_search_config
+#[derive(Clone)]
+pub struct SearchConfig {
+    pub(crate) tokenizer: Arc<dyn Tokenizer>,
+    pub(crate) fuzzy: FuzzyConfig,
+    pub(crate) checkpoint: Option<String>,
+}
+
+impl SearchConfig {
+    /// Default settings: [`StandardTokenizer::new`], [`FuzzyConfig::new`],
+    /// and no checkpoint.
+    pub fn new() -> Self {
+        Self {
+            tokenizer: Arc::new(StandardTokenizer::new()),
+            fuzzy: FuzzyConfig::new(),
+            checkpoint: None,
+        }
+    }
+
+    /// Tokenizer applied to fact text and queries.
+    pub fn with_tokenizer(mut self, tokenizer: impl Tokenizer + 'static) -> Self {
+        self.tokenizer = Arc::new(tokenizer);
+        self
+    }
+
+    /// Typo tolerance for query terms missing from the index.
+    pub fn with_fuzzy(mut self, fuzzy: FuzzyConfig) -> Self {
+        self.fuzzy = fuzzy;
+        self
+    }
+
+    /// Seed the index from a checkpoint written by
+    /// [`TemporalGraph::save_search_checkpoint`].
+    ///
+    /// The checkpoint is only used if it was written with the same tokenizer
+    /// and covers exactly the facts currently stored; a missing, stale, or
+    /// unreadable checkpoint is ignored and the index is rebuilt.
+    ///
+    /// [`TemporalGraph::save_search_checkpoint`]: crate::TemporalGraph::save_search_checkpoint
+    pub fn with_checkpoint(mut self, path: impl Into<String>) -> Self {
+        self.checkpoint = Some(path.into());
+        self
+    }
+}
+
+impl Default for SearchConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for SearchConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SearchConfig")
+            .field("tokenizer", &self.tokenizer.id())
+            .field("fuzzy", &self.fuzzy)
+            .field("checkpoint", &self.checkpoint)
+            .finish()
+    }
+}
+
+/// Fingerprint of the fact-id set covered by an index: `(count, hash)`.
+///
+/// The hash is an order-independent sum of per-id FNV-1a hashes.
+pub(crate) type IndexFingerprint = (usize, u64);
+
+pub(crate) struct SearchIndex {
+    tokenizer: Arc<dyn Tokenizer>,
+    fuzzy: FuzzyConfig,
+    lexical: LexicalIndex,
+    documents: HashMap<FactId, DocumentTokens>,
+    subject_docs: HashMap<String, BTreeSet<FactId>>,
+    /// subject -> alias fact -> alias tokens.
+    aliases: HashMap<String, BTreeMap<FactId, Vec<String>>>,
+    /// Filterable fact fields. Not checkpointed (validity windows change
+    /// after a fact is written); refreshed from storage on open.
+    meta: HashMap<FactId, DocumentMeta>,
+}
+
+impl SearchIndex {
+    pub(crate) fn new(tokenizer: Arc<dyn Tokenizer>) -> Self {
+        Self {
+            tokenizer,
+            fuzzy: FuzzyConfig::new(),
+            lexical: LexicalIndex::default(),
+            documents: HashMap::new(),
+            subject_docs: HashMap::new(),
+            aliases: HashMap::new(),
+            meta: HashMap::new(),
+        }
+    }
+
+    /// Fuzzy matching is a query-time setting, so it is not part of
+    /// checkpoints and can be changed on an existing index.
+    pub(crate) fn set_fuzzy(&mut self, fuzzy: FuzzyConfig) {
+        self.fuzzy = fuzzy;
+    }
+
+    /// Insert or replace the document for a fact.
+    ///
+    /// `fields` is the fact's own searchable text per field (without
+    /// aliases); `alias` is set when the fact itself names an alias for
+    /// `subject`.
+    pub(crate) fn upsert(
+        &mut self,
+        id: FactId,
+        subject: &str,
+        fields: &[(SearchField, &str)],
+        alias: Option<&str>,
+        meta: DocumentMeta,
+    ) {
+        self.meta.insert(id.clone(), meta);
+        let runs = fields
+            .iter()
+            .map(|(field, text)| (*field, self.tokenizer.tokenize(text)))
+            .filter(|(_, tokens)| !tokens.is_empty())
+            .collect();
+        self.upsert_runs(
+            id,
+            subject.to_string(),
+            runs,
+            alias.map(|alias| self.tokenizer.tokenize(alias)),
+        );
+    }
+
+    fn upsert_runs(
+        &mut self,
+        id: FactId,
+        subject: String,
+        runs: Vec<(SearchField, Vec<String>)>,
+        alias: Option<Vec<String>>,
+    ) {
+        let previous_alias = self.forget(&id);
+        let aliases_changed = previous_alias.is_some() || alias.is_some();
+
+        if let Some(alias) = alias {
+            self.aliases
+                .entry(subject.clone())
+                .or_default()
+                .insert(id.clone(), alias);
+        }
+        self.subject_docs
+            .entry(subject.clone())
+            .or_default()
+            .insert(id.clone());
+        self.documents.insert(
+            id.clone(),
+            DocumentTokens {
+                subject: subject.clone(),
+                runs,
+            },
+        );
+
+        if aliases_changed {
+            self.reindex_subject(&subject);
+        } else {
+            self.index_document(&id);
+        }
+    }
+
+    /// Does `id` currently contribute alias tokens to its subject?
+    pub(crate) fn is_alias_document(&self, id: &FactId) -> bool {
+        self.documents
+            .get(id)
+            .and_then(|doc| self.aliases.get(&doc.subject))
+            .is_some_and(|aliases| aliases.contains_key(id))
+    }
+
+    /// Drop every trace of `id`, returning its alias tokens if it had any.
+    ///
+    /// The subject's other documents are not re-indexed here; callers that
+    /// remove an alias must do so.
+    fn forget(&mut self, id: &FactId) -> Option<Vec<String>> {
+        let doc = self.documents.remove(id)?;
+        self.lexical.remove(id);
+        if let Some(ids) = self.subject_docs.get_mut(&doc.subject) {
+            ids.remove(id);
+            if ids.is_empty() {
+                self.subject_docs.remove(&doc.subject);
+            }
+        }
+        let aliases = self.aliases.get_mut(&doc.subject)?;
+        let removed = aliases.remove(id);
+        if aliases.is_empty() {
+            self.aliases.remove(&doc.subject);
+        }
+        removed
+    }
+
+    fn index_document(&mut self, id: &FactId) {
+        let Some(doc) = self.documents.get(id) else {
+            return;
+        };
+        let own = doc
+            .runs
+            .iter()
+            .map(|(field, tokens)| (*field, tokens.as_slice()));
+        let aliases = self
+            .aliases
+            .get(&doc.subject)
+            .into_iter()
+            .flat_map(|aliases| aliases.values())
+            .map(|alias| (SearchField::Alias, alias.as_slice()));
+        self.lexical.upsert(id.clone(), own.chain(aliases));
+    }
+
+    fn reindex_subject(&mut self, subject: &str) {
+        let ids: Vec<FactId> = self
+            .subject_docs
+            .get(subject)
+            .map(|ids| ids.iter().cloned().collect())
+            .unwrap_or_default();
+        for id in &ids {
+            self.index_document(id);
+        }
+    }
+
+    /// Record the filterable fields of an already indexed fact.
+    pub(crate) fn set_meta(&mut self, id: FactId, meta: DocumentMeta) {
+        self.meta.insert(id, meta);
+    }
+
+    /// Run a query in the syntax documented in [`crate::lexical`], keeping
+    /// only documents accepted by `filter`.
+    pub(crate) fn search(
+        &self,
+        query: &str,
+        limit: usize,
+        filter: &SearchFilter,
+    ) -> Vec<(FactId, f32)> {
+        let Some(query) = parse_query(query, self.tokenizer.as_ref()) else {
+            return Vec::new();
+        };
+        if filter.is_unrestricted() {
+            return self.lexical.search(&query, &self.fuzzy, limit, |_| true);
+        }
+        self.lexical.search(&query, &self.fuzzy, limit, |id| {
+            match (self.documents.get(id), self.meta.get(id)) {
+                (Some(doc), Some(meta)) => filter.accepts(&doc.subject, meta),
+                _ => false,
+            }
+        })
+    }
+
+    /// Explain why document `id` matches `query`. `object_text` is the
+    /// fact's object as indexed, used for highlighted snippets.
+    pub(crate) fn explain(
+        &self,
+        query: &str,
+        id: &FactId,
+        object_text: Option<&str>,
+    ) -> SearchExplanation {
+        let Some(query) = parse_query(query, self.tokenizer.as_ref()) else {
+            return SearchExplanation::default();
+        };
+        let matches = self.lexical.explain(&query, &self.fuzzy, id);
+        let object_terms: HashSet<&str> = matches
+            .iter()
+            .filter(|m| m.field == SearchField::Object)
+            .map(|m| m.term.as_str())
+            .collect();
+        let snippets = object_text
+            .map(|text| highlight_snippets(text, &object_terms, self.tokenizer.as_ref()))
+            .unwrap_or_default();
+        SearchExplanation { matches, snippets }
+    }
+
+    pub(crate) fn fingerprint(&self) -> IndexFingerprint {
+        fingerprint(self.documents.keys())
+    }
+
+    /// Serialize the index as a checkpoint document.
+    pub(crate) fn to_checkpoint_json(&self) -> String {
+        let (count, hash) = self.fingerprint();
+        let mut ids: Vec<&FactId> = self.documents.keys().collect();
+        ids.sort();
+
+        let docs = ids
+            .into_iter()
+            .map(|id| {
+                let doc = &self.documents[id];
+                let mut entry = BTreeMap::new();
+                entry.insert("id".to_string(), JsonValue::Str(id.as_str().to_string()));
+                entry.insert("subject".to_string(), JsonValue::Str(doc.subject.clone()));
+                let runs = doc
+                    .runs
+                    .iter()
+                    .map(|(field, tokens)| {
+                        let mut run = BTreeMap::new();
+                        run.insert(
+                            "field".to_string(),
+                            JsonValue::Str(field.as_str().to_string()),
+                        );
+                        run.insert("tokens".to_string(), string_array(tokens));
+                        JsonValue::Object(run)
+                    })
+                    .collect();
+                entry.insert("runs".to_string(), JsonValue::Array(runs));
+                if let Some(alias) = self
+                    .aliases
+                    .get(&doc.subject)
+                    .and_then(|aliases| aliases.get(id))
+                {
+                    entry.insert("alias".to_string(), string_array(alias));
+                }
+                JsonValue::Object(entry)
+            })
+            .collect();
+
+        let mut root = BTreeMap::new();
+        root.insert(
+            "format".to_string(),
+            JsonValue::Str(CHECKPOINT_FORMAT.to_string()),
+        );
+        root.insert("tokenizer".to_string(), JsonValue::Str(self.tokenizer.id()));
+        root.insert("count".to_string(), JsonValue::Number(count as f64));
+        root.insert(
+            "fingerprint".to_string(),
+            JsonValue::Str(format!("{hash:016x}")),
+        );
+        root.insert("docs".to_string(), JsonValue::Array(docs));
+
+        let mut out = Vec::new();
+        json_write::write_json_value(&mut out, &JsonValue::Object(root))
+            .expect("writing JSON to a Vec cannot fail");
+        String::from_utf8(out).expect("JSON writer emits UTF-8")
+    }
+
+    /// Rebuild an index from a checkpoint document.
+    ///
+    /// Returns `Ok(None)` when the checkpoint is well-formed but was taken
+    /// over a different fact set than `expected` or with another tokenizer.
+    pub(crate) fn from_checkpoint_json(
+        input: &[u8],
+        tokenizer: Arc<dyn Tokenizer>,
+        expected: IndexFingerprint,
+    ) -> Result<Option<Self>> {
+        let root = JsonValue::parse(input)
+            .map_err(|e| KronroeError::serialization(format!("search checkpoint: {e}")))?;
+        if root.get("format").and_then(JsonValue::as_str) != Some(CHECKPOINT_FORMAT) {
+            return Err(checkpoint_error("unsupported format"));
+        }
+        let count = root
+            .get("count")
+            .and_then(JsonValue::as_u64)
+            .ok_or_else(|| checkpoint_error("missing count"))?;
+        let hash = root
+            .get("fingerprint")
+            .and_then(JsonValue::as_str)
+            .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+            .ok_or_else(|| checkpoint_error("missing fingerprint"))?;
+        let tokenizer_id = root
+            .get("tokenizer")
+            .and_then(JsonValue::as_str)
+            .ok_or_else(|| checkpoint_error("missing tokenizer"))?;
+        if (count as usize, hash) != expected || tokenizer_id != tokenizer.id() {
+            return Ok(None);
+        }
+
+        let docs = root
+            .get("docs")
+            .and_then(JsonValue::as_array)
+            .ok_or_else(|| checkpoint_error("missing docs"))?;
+        let mut index = Self::new(tokenizer);
+        for doc in docs {
+            let id = doc
+                .get("id")
+                .and_then(JsonValue::as_str)
+                .and_then(|id| FactId::parse(id).ok())
+                .ok_or_else(|| checkpoint_error("invalid document id"))?;
+            let subject = doc
+                .get("subject")
+                .and_then(JsonValue::as_str)
+                .ok_or_else(|| checkpoint_error("missing document subject"))?;
+            let runs = doc
+                .get("runs")
+                .and_then(JsonValue::as_array)
+                .and_then(|runs| runs.iter().map(parse_run).collect::<Option<Vec<_>>>())
+                .ok_or_else(|| checkpoint_error("invalid document runs"))?;
+            let alias = match doc.get("alias") {
+                Some(alias) => Some(
+                    parse_string_array(alias)
+                        .ok_or_else(|| checkpoint_error("invalid document alias"))?,
+                ),
+                None => None,
+            };
+            index.insert_unindexed(id, subject.to_string(), runs, alias);
+        }
+        if index.fingerprint() != expected {
+            return Ok(None);
+        }
+        let subjects: Vec<String> = index.subject_docs.keys().cloned().collect();
+        for subject in &subjects {
+            index.reindex_subject(subject);
+        }
+        Ok(Some(index))
+    }
+
+    /// Record a document without touching the lexical index; used while
+    /// loading a checkpoint, which indexes every subject once at the end.
+    fn insert_unindexed(
+        &mut self,
+        id: FactId,
+        subject: String,
+        runs: Vec<(SearchField, Vec<String>)>,
+        alias: Option<Vec<String>>,
+    ) {
+        if let Some(alias) = alias {
+            self.aliases
+                .entry(subject.clone())
+                .or_default()
+                .insert(id.clone(), alias);
+        }
+        self.subject_docs
+            .entry(subject.clone())
+            .or_default()
+            .insert(id.clone());
+        self.documents.insert(id, DocumentTokens { subject, runs });
+    }
+}
+
+pub(crate) fn fingerprint<'a>(ids: impl IntoIterator<Item = &'a FactId>) -> IndexFingerprint {
+    let mut count = 0usize;
+    let mut hash = 0u64;
+    for id in ids {
+        count += 1;
+        hash = hash.wrapping_add(checkpoint::fnv1a(id.as_str().as_bytes()));
+    }
+    (count, hash)
+}
+
+fn string_array(items: &[String]) -> JsonValue {
+    JsonValue::Array(items.iter().cloned().map(JsonValue::Str).collect())
+}
+
+fn parse_string_array(value: &JsonValue) -> Option<Vec<String>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|item| item.as_str().map(str::to_string))
+        .collect()
+}
+
+fn parse_run(value: &JsonValue) -> Option<(SearchField, Vec<String>)> {
+    let field = SearchField::parse(value.get("field")?.as_str()?)?;
+    Some((field, parse_string_array(value.get("tokens")?)?))
+}
+
+fn checkpoint_error(detail: &str) -> KronroeError {
+    KronroeError::serialization(format!("search checkpoint: {detail}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Fact, KronroeTimestamp};
+
+    fn standard() -> Arc<dyn Tokenizer> {
+        Arc::new(StandardTokenizer::new())
+    }
+
+    fn id(n: u64) -> FactId {
+        FactId::from_parts(1_742_355_200_000, 0, n)
+    }
+
+    fn put(
+        index: &mut SearchIndex,
+        n: u64,
+        subject: &str,
+        predicate: &str,
+        object: &str,
+        alias: Option<&str>,
+    ) {
+        let fact = Fact::new(subject, predicate, object, KronroeTimestamp::now_utc());
+        put_fact(index, n, &fact, alias);
+    }
+
+    fn put_fact(index: &mut SearchIndex, n: u64, fact: &Fact, alias: Option<&str>) {
+        let object = match &fact.object {
+            crate::Value::Text(text) => text.as_str(),
+            _ => "",
+        };
+        let fields = [
+            (SearchField::Subject, fact.subject.as_str()),
+            (SearchField::Predicate, fact.predicate.as_str()),
+            (SearchField::Object, object),
+        ];
+        index.upsert(id(n), &fact.subject, &fields, alias, DocumentMeta::of(fact));
+    }
+
+    fn hit_ids(index: &SearchIndex, query: &str) -> Vec<FactId> {
+        index
+            .search(query, 10, &SearchFilter::new())
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    #[test]
+    fn alias_upsert_reindexes_existing_subject_documents() {
+        let mut index = SearchIndex::new(standard());
+        put(&mut index, 1, "alice", "works_at", "Acme", None);
+        assert!(hit_ids(&index, "ally").is_empty());
+
+        put(&mut index, 2, "alice", "alias", "ally", Some("ally"));
+        assert_eq!(hit_ids(&index, "ally"), vec![id(2), id(1)]);
+        assert_eq!(hit_ids(&index, "alias:ally"), vec![id(2), id(1)]);
+        assert_eq!(hit_ids(&index, "object:ally"), vec![id(2)]);
+
+        // Replacing the alias fact with a non-alias document drops the alias
+        // from the rest of the subject.
+        put(&mut index, 2, "alice", "note", "hello", None);
+        assert!(hit_ids(&index, "ally").is_empty());
+        assert_eq!(index.fingerprint().0, 2);
+    }
+
+    #[test]
+    fn checkpoint_roundtrip_preserves_scores() {
+        let mut index = SearchIndex::new(standard());
+        put(&mut index, 1, "alice", "works_at", "Acme", None);
+        put(&mut index, 2, "alice", "alias", "ally", Some("ally"));
+        put(&mut index, 3, "bob", "works_at", "Acme Industries", None);
+
+        let json = index.to_checkpoint_json();
+        let restored =
+            SearchIndex::from_checkpoint_json(json.as_bytes(), standard(), index.fingerprint())
+                .unwrap()
+                .expect("fingerprint matches");
+        for query in ["ally acme", "\"works at\" AND alias:ally", "acm*"] {
+            let filter = SearchFilter::new();
+            assert_eq!(
+                restored.search(query, 10, &filter),
+                index.search(query, 10, &filter)
+            );
+        }
+        assert_eq!(restored.fingerprint(), index.fingerprint());
+    }
+
+    #[test]
+    fn checkpoint_for_a_different_fact_set_or_tokenizer_is_ignored() {
+        let mut index = SearchIndex::new(standard());
+        put(&mut index, 1, "alice", "works_at", "Acme", None);
+        let json = index.to_checkpoint_json();
+
+        let stale = fingerprint([&id(1), &id(2)]);
+        assert!(
+            SearchIndex::from_checkpoint_json(json.as_bytes(), standard(), stale)
+                .unwrap()
+                .is_none()
+        );
+        let stemming: Arc<dyn Tokenizer> = Arc::new(StandardTokenizer::english());
+        assert!(
+            SearchIndex::from_checkpoint_json(json.as_bytes(), stemming, index.fingerprint())
+                .unwrap()
+                .is_none()
+        );
+        assert!(SearchIndex::from_checkpoint_json(b"{}", standard(), index.fingerprint()).is_err());
+    }
+
+    #[test]
+    fn filters_apply_before_the_limit() {
+        let now = KronroeTimestamp::now_utc();
+        let day = crate::KronroeSpan::days(1);
+        let mut index = SearchIndex::new(standard());
+        // Three stale hits that outscore the live one on "acme".
+        for n in 1..=3 {
+            let mut stale = Fact::new("alice", "works_at", "Acme Acme", now - day - day);
+            stale.valid_to = Some(now - day);
+            stale.expired_at = Some(now - day);
+            put_fact(&mut index, n, &stale, None);
+        }
+        let live = Fact::new("alice", "works_at", "Acme", now - day)
+            .with_confidence(0.4)
+            .with_source("chat:7");
+        put_fact(&mut index, 4, &live, None);
+        put(&mut index, 5, "bob", "likes", "Acme", None);
+
+        let ids = |index: &SearchIndex, filter: SearchFilter| -> Vec<FactId> {
+            let mut ids: Vec<FactId> = index
+                .search("acme", 1, &filter)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
+            ids.sort();
+            ids
+        };
+        assert!(ids(&index, SearchFilter::new())
+            .iter()
+            .all(|id| *id <= self::id(3)));
+        let current = SearchFilter::new().with_current_only();
+        assert_eq!(
+            ids(&index, current.clone().with_subject_prefix("ali")),
+            vec![id(4)]
+        );
+        assert_eq!(
+            ids(&index, current.clone().with_source("chat:7")),
+            vec![id(4)]
+        );
+        assert_eq!(
+            ids(
+                &index,
+                current.clone().with_excluded_predicates(["works_at"])
+            ),
+            vec![id(5)]
+        );
+        assert_eq!(
+            ids(&index, current.clone().with_predicates(["likes"])),
+            vec![id(5)]
+        );
+        assert_eq!(ids(&index, current.with_min_confidence(0.5)), vec![id(5)]);
+        assert_eq!(
+            ids(
+                &index,
+                SearchFilter::new()
+                    .with_valid_at(now - day - day)
+                    .with_predicates(["works_at"])
+                    .with_min_confidence(0.9)
+            )
+            .len(),
+            1
+        );
+        assert!(ids(
+            &index,
+            SearchFilter::new().with_known_at(now - crate::KronroeSpan::days(365))
+        )
+        .is_empty());
+
+        // Invalidation arrives as an upsert with new metadata.
+        let mut ended = live.clone();
+        ended.valid_to = Some(now);
+        put_fact(&mut index, 4, &ended, None);
+        assert!(ids(
+            &index,
+            SearchFilter::new()
+                .with_current_only()
+                .with_source("chat:7")
+        )
+        .is_empty());
+    }
+}
@@ -1,4 +1,6 @@
 #[cfg(feature = "contradiction")]
+use crate::contradiction::CheckedWrite;
+#[cfg(all(test, feature = "contradiction"))]
 use crate::contradiction::Contradiction;
 use crate::storage_append_log::AppendLogBackend;
 use crate::storage_observability::{
@@ -221,18 +223,16 @@ impl KronroeStorage {
         &self,
         subject: &str,
         predicate: &str,
-        fact: &Fact,
         reject_on_conflict: bool,
         check: F,
-    ) -> Result<Vec<Contradiction>>
+    ) -> Result<CheckedWrite>
     where
-        F: FnOnce(&[Fact]) -> Result<Vec<Contradiction>>,
+        F: FnOnce(&[Fact]) -> Result<CheckedWrite>,
     {
         let started_at = storage_now();
         let result = self.backend.write_fact_with_contradiction_check(
             subject,
             predicate,
-            fact,
             reject_on_conflict,
             check,
         );
@@ -242,13 +242,33 @@ impl KronroeStorage {
             result.as_ref().map(|(_, rows)| *rows).unwrap_or(0),
             result.is_ok(),
         );
-        result.map(|(contradictions, _)| contradictions)
+        result.map(|(checked, _)| checked)
+    }
+
+    #[cfg(feature = "contradiction")]
+    pub(crate) fn load_contradiction_resolutions(&self, prefix: &str) -> Result<Vec<String>> {
+        let started_at = storage_now();
+        let result = Ok(self.backend.load_contradiction_resolutions(prefix));
+        self.record(
+            StorageOperation::LoadContradictionResolutions,
+            started_at,
+            result.as_ref().map(|rows| rows.len()).unwrap_or(0),
+            result.is_ok(),
+        );
+        result
     }
 
     #[cfg(feature = "vector")]
-    pub(crate) fn write_fact_with_embedding(&self, fact: &Fact, embedding: &[f32]) -> Result<()> {
+    pub(crate) fn write_fact_with_embedding(
+        &self,
+        fact: &Fact,
+        space: &str,
+        embedding: &[f32],
+    ) -> Result<()> {
         let started_at = storage_now();
-        let result = self.backend.write_fact_with_embedding(fact, embedding);
+        let result = self
+            .backend
+            .write_fact_with_embedding(fact, space, embedding);
         self.record(
             StorageOperation::WriteFactWithEmbedding,
             started_at,
@@ -259,9 +279,71 @@ impl KronroeStorage {
     }
 
     #[cfg(feature = "vector")]
-    pub(crate) fn embedding_rows(&self) -> Result<Vec<(FactId, Vec<f32>)>> {
+    pub(crate) fn write_embeddings(
+        &self,
+        space: &str,
+        rows: &[(FactId, Vec<f32>)],
+        on_written: impl FnMut(usize) -> Result<()>,
+    ) -> Result<()> {
         let started_at = storage_now();
-        let result = self.backend.embedding_rows();
+        let result = self.backend.write_embeddings(space, rows, on_written);
+        self.record(
+            StorageOperation::WriteEmbeddings,
+            started_at,
+            0,
+            result.is_ok(),
+        );
+        result
+    }
+
+    #[cfg(feature = "vector")]
+    pub(crate) fn remove_embedding(&self, space: &str, fact_id: &FactId) -> Result<bool> {
+        let started_at = storage_now();
+        let result = self.backend.remove_embedding(space, fact_id);
+        self.record(
+            StorageOperation::RemoveEmbedding,
+            started_at,
+            0,
+            result.is_ok(),
+        );
+        result
+    }
+
+    #[cfg(feature = "vector")]
+    pub(crate) fn set_embedding_encoding(&self, encoding: crate::EmbeddingEncoding) {
+        self.backend.set_embedding_encoding(encoding);
+    }
+
+    #[cfg(feature = "vector")]
+    pub(crate) fn embeddings(&self, space: &str, ids: &[FactId]) -> Result<Vec<Option<Vec<f32>>>> {
+        let started_at = storage_now();
+        let result = self.backend.embeddings(space, ids);
+        self.record(
+            StorageOperation::ReadEmbeddings,
+            started_at,
+            ids.len(),
+            result.is_ok(),
+        );
+        result
+    }
+
+    #[cfg(feature = "vector")]
+    pub(crate) fn embedding_spaces(&self) -> Result<Vec<String>> {
+        let started_at = storage_now();
+        let result = self.backend.embedding_spaces();
+        self.record(
+            StorageOperation::EmbeddingSpaces,
+            started_at,
+            result.as_ref().map(|spaces| spaces.len()).unwrap_or(0),
+            result.is_ok(),
+        );
+        result
+    }
+
+    #[cfg(feature = "vector")]
+    pub(crate) fn embedding_rows(&self, space: &str) -> Result<Vec<(FactId, Vec<f32>)>> {
+        let started_at = storage_now();
+        let result = self.backend.embedding_rows(space);
         self.record(
             StorageOperation::EmbeddingRows,
             started_at,
@@ -366,12 +448,108 @@ impl KronroeStorage {
         );
         result
     }
+
+    pub(crate) fn load_entity_predicate_registry_entries(&self) -> Result<Vec<(String, String)>> {
+        let started_at = storage_now();
+        let result = Ok(self.backend.load_entity_predicate_registry_entries());
+        self.record(
+            StorageOperation::LoadEntityPredicateRegistryEntries,
+            started_at,
+            result.as_ref().map(|rows| rows.len()).unwrap_or(0),
+            result.is_ok(),
+        );
+        result
+    }
+
+    pub(crate) fn write_entity_predicate_registry_entry(
+        &self,
+        predicate: &str,
+        encoded: &str,
+    ) -> Result<()> {
+        let started_at = storage_now();
+        let result = self
+            .backend
+            .write_entity_predicate_registry_entry(predicate, encoded);
+        self.record(
+            StorageOperation::WriteEntityPredicateRegistryEntry,
+            started_at,
+            0,
+            result.is_ok(),
+        );
+        result
+    }
+
+    #[cfg(feature = "fulltext")]
+    pub(crate) fn load_predicate_synonym_registry_entries(&self) -> Result<Vec<(String, String)>> {
+        let started_at = storage_now();
+        let result = Ok(self.backend.load_predicate_synonym_registry_entries());
+        self.record(
+            StorageOperation::LoadPredicateSynonymRegistryEntries,
+            started_at,
+            result.as_ref().map(|rows| rows.len()).unwrap_or(0),
+            result.is_ok(),
+        );
+        result
+    }
+
+    #[cfg(feature = "fulltext")]
+    pub(crate) fn write_predicate_synonym_registry_entry(
+        &self,
+        predicate: &str,
+        encoded: &str,
+    ) -> Result<()> {
+        let started_at = storage_now();
+        let result = self
+            .backend
+            .write_predicate_synonym_registry_entry(predicate, encoded);
+        self.record(
+            StorageOperation::WritePredicateSynonymRegistryEntry,
+            started_at,
+            0,
+            result.is_ok(),
+        );
+        result
+    }
+
+    #[cfg(feature = "vector")]
+    pub(crate) fn load_embedding_space_registry_entries(&self) -> Result<Vec<(String, String)>> {
+        let started_at = storage_now();
+        let result = Ok(self.backend.load_embedding_space_registry_entries());
+        self.record(
+            StorageOperation::LoadEmbeddingSpaceRegistryEntries,
+            started_at,
+            result.as_ref().map(|rows| rows.len()).unwrap_or(0),
+            result.is_ok(),
+        );
+        result
+    }
+
+    #[cfg(feature = "vector")]
+    pub(crate) fn write_embedding_space_registry_entry(
+        &self,
+        space: &str,
+        encoded: &str,
+    ) -> Result<()> {
+        let started_at = storage_now();
+        let result = self
+            .backend
+            .write_embedding_space_registry_entry(space, encoded);
+        self.record(
+            StorageOperation::WriteEmbeddingSpaceRegistryEntry,
+            started_at,
+            0,
+            result.is_ok(),
+        );
+        result
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::storage_observability::{StorageEvent, StorageObserver, StorageOperation};
+    #[cfg(feature = "vector")]
+    use crate::DEFAULT_EMBEDDING_SPACE;
     use crate::{KronroeSpan, Value};
     use std::sync::{Arc, Mutex};
 
@@ -413,12 +591,12 @@ mod tests {
 
         let first = build_fact("alice", "interest", "Rust");
         storage
-            .write_fact_with_embedding(&first, &[1.0, 0.0, 0.0])
+            .write_fact_with_embedding(&first, DEFAULT_EMBEDDING_SPACE, &[1.0, 0.0, 0.0])
             .unwrap();
 
         let second = build_fact("alice", "interest", "Python");
         let error = storage
-            .write_fact_with_embedding(&second, &[0.0, 1.0])
+            .write_fact_with_embedding(&second, DEFAULT_EMBEDDING_SPACE, &[0.0, 1.0])
             .unwrap_err();
         assert!(error.is_invalid_embedding());
 
@@ -429,7 +607,7 @@ mod tests {
             "failed embedding write must not add fact row"
         );
 
-        let embeddings = storage.embedding_rows().unwrap();
+        let embeddings = storage.embedding_rows(DEFAULT_EMBEDDING_SPACE).unwrap();
         assert_eq!(
             embeddings.len(),
             1,
@@ -560,8 +738,8 @@ mod tests {
 
         let incoming = build_fact("alice", "works_at", "TechCorp");
         let contradictions = storage
-            .write_fact_with_contradiction_check("alice", "works_at", &incoming, false, |facts| {
-                Ok(vec![Contradiction {
+            .write_fact_with_contradiction_check("alice", "works_at", false, |facts| {
+                let contradictions = vec![Contradiction {
                     existing_fact_id: facts[0].id.to_string(),
                     conflicting_fact_id: incoming.id.to_string(),
                     subject: "alice".into(),
@@ -571,9 +749,12 @@ mod tests {
                     severity: crate::ConflictSeverity::High,
                     confidence_delta: 0.0,
                     suggested_resolution: crate::SuggestedResolution::ManualReview,
-                }])
+                    applied_resolution: None,
+                }];
+                Ok(CheckedWrite::unresolved(incoming.clone(), contradictions))
             })
-            .unwrap();
+            .unwrap()
+            .contradictions;
         assert_eq!(contradictions.len(), 1);
 
         let events = observer.events.lock().unwrap();
@@ -631,13 +812,13 @@ mod tests {
 
         let incoming = build_fact("timeline", "role", "candidate");
         let contradictions = storage
-            .write_fact_with_contradiction_check("timeline", "role", &incoming, false, |facts| {
+            .write_fact_with_contradiction_check("timeline", "role", false, |facts| {
                 assert_eq!(
                     facts.len(),
                     1,
                     "only the live transaction row should be checked"
                 );
-                Ok(vec![Contradiction {
+                let contradictions = vec![Contradiction {
                     existing_fact_id: facts[0].id.to_string(),
                     conflicting_fact_id: incoming.id.to_string(),
                     subject: "timeline".into(),
@@ -647,9 +828,12 @@ mod tests {
                     severity: crate::ConflictSeverity::High,
                     confidence_delta: 0.0,
                     suggested_resolution: crate::SuggestedResolution::ManualReview,
-                }])
+                    applied_resolution: None,
+                }];
+                Ok(CheckedWrite::unresolved(incoming.clone(), contradictions))
             })
-            .unwrap();
+            .unwrap()
+            .contradictions;
         assert_eq!(contradictions.len(), 1);
 
         let events = observer.events.lock().unwrap();
@@ -755,12 +939,12 @@ mod tests {
 
         let first = build_fact("alice", "interest", "Rust");
         storage
-            .write_fact_with_embedding(&first, &[1.0, 0.0, 0.0])
+            .write_fact_with_embedding(&first, DEFAULT_EMBEDDING_SPACE, &[1.0, 0.0, 0.0])
             .unwrap();
 
         let second = build_fact("alice", "interest", "Python");
         let error = storage
-            .write_fact_with_embedding(&second, &[0.0, 1.0])
+            .write_fact_with_embedding(&second, DEFAULT_EMBEDDING_SPACE, &[0.0, 1.0])
             .unwrap_err();
         assert!(error.is_invalid_embedding());
 
@@ -771,7 +955,7 @@ mod tests {
             "failed append-log embedding write must not add fact row"
         );
 
-        let embeddings = storage.embedding_rows().unwrap();
+        let embeddings = storage.embedding_rows(DEFAULT_EMBEDDING_SPACE).unwrap();
         assert_eq!(
             embeddings.len(),
             1,
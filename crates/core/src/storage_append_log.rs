@@ -1,6 +1,10 @@
 use crate::json_read::JsonValue;
 use crate::json_write;
+#[cfg(feature = "vector")]
+use crate::quantize::{decode_f32le, encode_f32le, EmbeddingEncoding};
 use crate::storage::{fact_row_key, StoredFactRow, SCHEMA_VERSION};
+#[cfg(feature = "vector")]
+use crate::vector::DEFAULT_EMBEDDING_SPACE;
 use crate::{Fact, FactId, KronroeError, KronroeTimestamp, Result};
 use std::collections::{BTreeMap, BTreeSet};
 #[cfg(not(target_arch = "wasm32"))]
@@ -52,6 +56,19 @@ enum AppendLogRecord {
         source: String,
         encoded: String,
     },
+    UpsertEntityPredicateRegistryEntry {
+        predicate: String,
+        encoded: String,
+    },
+    UpsertPredicateSynonymRegistryEntry {
+        predicate: String,
+        encoded: String,
+    },
+    #[cfg(feature = "vector")]
+    UpsertEmbeddingSpaceRegistryEntry {
+        space: String,
+        encoded: String,
+    },
     UpsertFact {
         key: String,
         fact: Fact,
@@ -65,12 +82,42 @@ enum AppendLogRecord {
     UpsertFactWithEmbedding {
         key: String,
         fact: Fact,
+        space: String,
+        embedding: Vec<f32>,
+        encoding: EmbeddingEncoding,
+    },
+    /// Embedding for an existing fact, written after the fact itself.
+    #[cfg(feature = "vector")]
+    UpsertEmbedding {
+        fact_id: String,
+        space: String,
         embedding: Vec<f32>,
+        encoding: EmbeddingEncoding,
+    },
+    /// Drop a fact's embedding from one space; the fact itself stays.
+    #[cfg(feature = "vector")]
+    RemoveEmbedding {
+        fact_id: String,
+        space: String,
     },
     ReplaceFact {
         key: String,
         fact: Fact,
     },
+    /// A contradiction-checked write with its automatic resolution: the new
+    /// fact, the existing facts it closed or retracted, and the audit entry,
+    /// applied together.
+    ResolveContradiction {
+        key: String,
+        fact: Fact,
+        updated: Vec<(String, Fact)>,
+        resolution_key: String,
+        resolution: String,
+    },
+    UpsertContradictionResolution {
+        key: String,
+        encoded: String,
+    },
 }
 
 // -- Kronroe-native JSON codec for AppendLogRecord --
@@ -113,6 +160,28 @@ impl AppendLogRecord {
                 write_kv_string(w, "encoded", encoded)?;
                 w.write_all(b"}}")?;
             }
+            AppendLogRecord::UpsertEntityPredicateRegistryEntry { predicate, encoded } => {
+                w.write_all(b"{\"UpsertEntityPredicateRegistryEntry\":{")?;
+                write_kv_string(w, "predicate", predicate)?;
+                w.write_all(b",")?;
+                write_kv_string(w, "encoded", encoded)?;
+                w.write_all(b"}}")?;
+            }
+            AppendLogRecord::UpsertPredicateSynonymRegistryEntry { predicate, encoded } => {
+                w.write_all(b"{\"UpsertPredicateSynonymRegistryEntry\":{")?;
+                write_kv_string(w, "predicate", predicate)?;
+                w.write_all(b",")?;
+                write_kv_string(w, "encoded", encoded)?;
+                w.write_all(b"}}")?;
+            }
+            #[cfg(feature = "vector")]
+            AppendLogRecord::UpsertEmbeddingSpaceRegistryEntry { space, encoded } => {
+                w.write_all(b"{\"UpsertEmbeddingSpaceRegistryEntry\":{")?;
+                write_kv_string(w, "space", space)?;
+                w.write_all(b",")?;
+                write_kv_string(w, "encoded", encoded)?;
+                w.write_all(b"}}")?;
+            }
             AppendLogRecord::UpsertFact { key, fact } => {
                 w.write_all(b"{\"UpsertFact\":{")?;
                 write_kv_string(w, "key", key)?;
@@ -141,7 +210,9 @@ impl AppendLogRecord {
             AppendLogRecord::UpsertFactWithEmbedding {
                 key,
                 fact,
+                space,
                 embedding,
+                encoding,
             } => {
                 w.write_all(b"{\"UpsertFactWithEmbedding\":{")?;
                 write_kv_string(w, "key", key)?;
@@ -149,16 +220,37 @@ impl AppendLogRecord {
                 write_string(w, "fact")?;
                 w.write_all(b":")?;
                 fact.write_json(w)?;
-                w.write_all(b",")?;
-                write_string(w, "embedding")?;
-                w.write_all(b":[")?;
-                for (i, v) in embedding.iter().enumerate() {
-                    if i > 0 {
-                        w.write_all(b",")?;
-                    }
-                    write_f32(w, *v)?;
+                // The default space is implied, so older builds can replay it.
+                if space != DEFAULT_EMBEDDING_SPACE {
+                    w.write_all(b",")?;
+                    write_kv_string(w, "space", space)?;
                 }
-                w.write_all(b"]}}")?;
+                w.write_all(b",")?;
+                write_embedding(w, embedding, *encoding)?;
+                w.write_all(b"}}")?;
+            }
+            #[cfg(feature = "vector")]
+            AppendLogRecord::UpsertEmbedding {
+                fact_id,
+                space,
+                embedding,
+                encoding,
+            } => {
+                w.write_all(b"{\"UpsertEmbedding\":{")?;
+                write_kv_string(w, "fact_id", fact_id)?;
+                w.write_all(b",")?;
+                write_kv_string(w, "space", space)?;
+                w.write_all(b",")?;
+                write_embedding(w, embedding, *encoding)?;
+                w.write_all(b"}}")?;
+            }
+            #[cfg(feature = "vector")]
+            AppendLogRecord::RemoveEmbedding { fact_id, space } => {
+                w.write_all(b"{\"RemoveEmbedding\":{")?;
+                write_kv_string(w, "fact_id", fact_id)?;
+                w.write_all(b",")?;
+                write_kv_string(w, "space", space)?;
+                w.write_all(b"}}")?;
             }
             AppendLogRecord::ReplaceFact { key, fact } => {
                 w.write_all(b"{\"ReplaceFact\":{")?;
@@ -169,6 +261,47 @@ impl AppendLogRecord {
                 fact.write_json(w)?;
                 w.write_all(b"}}")?;
             }
+            AppendLogRecord::ResolveContradiction {
+                key,
+                fact,
+                updated,
+                resolution_key,
+                resolution,
+            } => {
+                w.write_all(b"{\"ResolveContradiction\":{")?;
+                write_kv_string(w, "key", key)?;
+                w.write_all(b",")?;
+                write_string(w, "fact")?;
+                w.write_all(b":")?;
+                fact.write_json(w)?;
+                w.write_all(b",")?;
+                write_string(w, "updated")?;
+                w.write_all(b":[")?;
+                for (i, (key, fact)) in updated.iter().enumerate() {
+                    if i > 0 {
+                        w.write_all(b",")?;
+                    }
+                    w.write_all(b"{")?;
+                    write_kv_string(w, "key", key)?;
+                    w.write_all(b",")?;
+                    write_string(w, "fact")?;
+                    w.write_all(b":")?;
+                    fact.write_json(w)?;
+                    w.write_all(b"}")?;
+                }
+                w.write_all(b"],")?;
+                write_kv_string(w, "resolution_key", resolution_key)?;
+                w.write_all(b",")?;
+                write_kv_string(w, "resolution", resolution)?;
+                w.write_all(b"}}")?;
+            }
+            AppendLogRecord::UpsertContradictionResolution { key, encoded } => {
+                w.write_all(b"{\"UpsertContradictionResolution\":{")?;
+                write_kv_string(w, "key", key)?;
+                w.write_all(b",")?;
+                write_kv_string(w, "encoded", encoded)?;
+                w.write_all(b"}}")?;
+            }
         }
         Ok(())
     }
@@ -255,6 +388,61 @@ impl AppendLogRecord {
                     })?)?;
                 Ok(Some(AppendLogRecord::ReplaceFact { key, fact }))
             }
+            "ResolveContradiction" => {
+                let get_str = |val: &JsonValue, field: &str| {
+                    val.get(field)
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string)
+                        .ok_or_else(|| {
+                            KronroeError::serialization(format!(
+                                "ResolveContradiction missing '{field}'"
+                            ))
+                        })
+                };
+                let get_fact = |val: &JsonValue| {
+                    Fact::from_json(val.get("fact").ok_or_else(|| {
+                        KronroeError::serialization("ResolveContradiction missing 'fact'")
+                    })?)
+                };
+                let updated = inner
+                    .get("updated")
+                    .and_then(|v| v.as_array())
+                    .ok_or_else(|| {
+                        KronroeError::serialization("ResolveContradiction missing 'updated'")
+                    })?
+                    .iter()
+                    .map(|row| Ok((get_str(row, "key")?, get_fact(row)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Some(AppendLogRecord::ResolveContradiction {
+                    key: get_str(inner, "key")?,
+                    fact: get_fact(inner)?,
+                    updated,
+                    resolution_key: get_str(inner, "resolution_key")?,
+                    resolution: get_str(inner, "resolution")?,
+                }))
+            }
+            "UpsertContradictionResolution" => {
+                let key = inner
+                    .get("key")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| {
+                        KronroeError::serialization("UpsertContradictionResolution missing 'key'")
+                    })?
+                    .to_string();
+                let encoded = inner
+                    .get("encoded")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| {
+                        KronroeError::serialization(
+                            "UpsertContradictionResolution missing 'encoded'",
+                        )
+                    })?
+                    .to_string();
+                Ok(Some(AppendLogRecord::UpsertContradictionResolution {
+                    key,
+                    encoded,
+                }))
+            }
             #[cfg(feature = "vector")]
             "UpsertFactWithEmbedding" => {
                 let key = inner
@@ -267,25 +455,58 @@ impl AppendLogRecord {
                 let fact = Fact::from_json(inner.get("fact").ok_or_else(|| {
                     KronroeError::serialization("UpsertFactWithEmbedding missing 'fact'")
                 })?)?;
-                let embedding = inner
-                    .get("embedding")
-                    .and_then(|v| v.as_array())
-                    .ok_or_else(|| {
-                        KronroeError::serialization("UpsertFactWithEmbedding missing 'embedding'")
-                    })?
-                    .iter()
-                    .map(|v| {
-                        v.as_f32().ok_or_else(|| {
-                            KronroeError::serialization("embedding element is not a number")
-                        })
-                    })
-                    .collect::<Result<Vec<f32>>>()?;
+                let space = inner
+                    .get("space")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or(DEFAULT_EMBEDDING_SPACE)
+                    .to_string();
+                let (embedding, encoding) = parse_embedding(inner, "UpsertFactWithEmbedding")?;
                 Ok(Some(AppendLogRecord::UpsertFactWithEmbedding {
                     key,
                     fact,
+                    space,
+                    embedding,
+                    encoding,
+                }))
+            }
+            #[cfg(feature = "vector")]
+            "UpsertEmbedding" => {
+                let fact_id = inner
+                    .get("fact_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| {
+                        KronroeError::serialization("UpsertEmbedding missing 'fact_id'")
+                    })?
+                    .to_string();
+                let space = inner
+                    .get("space")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| KronroeError::serialization("UpsertEmbedding missing 'space'"))?
+                    .to_string();
+                let (embedding, encoding) = parse_embedding(inner, "UpsertEmbedding")?;
+                Ok(Some(AppendLogRecord::UpsertEmbedding {
+                    fact_id,
+                    space,
                     embedding,
+                    encoding,
                 }))
             }
+            #[cfg(feature = "vector")]
+            "RemoveEmbedding" => {
+                let fact_id = inner
+                    .get("fact_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| {
+                        KronroeError::serialization("RemoveEmbedding missing 'fact_id'")
+                    })?
+                    .to_string();
+                let space = inner
+                    .get("space")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| KronroeError::serialization("RemoveEmbedding missing 'space'"))?
+                    .to_string();
+                Ok(Some(AppendLogRecord::RemoveEmbedding { fact_id, space }))
+            }
             #[cfg(feature = "contradiction")]
             "UpsertPredicateRegistryEntry" => {
                 let predicate = inner
@@ -361,6 +582,79 @@ impl AppendLogRecord {
                     encoded,
                 }))
             }
+            "UpsertEntityPredicateRegistryEntry" => {
+                let predicate = inner
+                    .get("predicate")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| {
+                        KronroeError::serialization(
+                            "UpsertEntityPredicateRegistryEntry missing 'predicate'",
+                        )
+                    })?
+                    .to_string();
+                let encoded = inner
+                    .get("encoded")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| {
+                        KronroeError::serialization(
+                            "UpsertEntityPredicateRegistryEntry missing 'encoded'",
+                        )
+                    })?
+                    .to_string();
+                Ok(Some(AppendLogRecord::UpsertEntityPredicateRegistryEntry {
+                    predicate,
+                    encoded,
+                }))
+            }
+            "UpsertPredicateSynonymRegistryEntry" => {
+                let predicate = inner
+                    .get("predicate")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| {
+                        KronroeError::serialization(
+                            "UpsertPredicateSynonymRegistryEntry missing 'predicate'",
+                        )
+                    })?
+                    .to_string();
+                let encoded = inner
+                    .get("encoded")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| {
+                        KronroeError::serialization(
+                            "UpsertPredicateSynonymRegistryEntry missing 'encoded'",
+                        )
+                    })?
+                    .to_string();
+                Ok(Some(AppendLogRecord::UpsertPredicateSynonymRegistryEntry {
+                    predicate,
+                    encoded,
+                }))
+            }
+            #[cfg(feature = "vector")]
+            "UpsertEmbeddingSpaceRegistryEntry" => {
+                let space = inner
+                    .get("space")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| {
+                        KronroeError::serialization(
+                            "UpsertEmbeddingSpaceRegistryEntry missing 'space'",
+                        )
+                    })?
+                    .to_string();
+                let encoded = inner
+                    .get("encoded")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| {
+                        KronroeError::serialization(
+                            "UpsertEmbeddingSpaceRegistryEntry missing 'encoded'",
+                        )
+                    })?
+                    .to_string();
+                Ok(Some(AppendLogRecord::UpsertEmbeddingSpaceRegistryEntry {
+                    space,
+                    encoded,
+                }))
+            }
             _other => {
                 // Unknown record types are silently skipped during replay
                 // to support forward compatibility — older builds can open
@@ -371,6 +665,53 @@ impl AppendLogRecord {
     }
 }
 
+/// Write an embedding as `"embedding":[...]` or `"embedding_f32le":"..."`.
+#[cfg(feature = "vector")]
+fn write_embedding(
+    w: &mut impl std::io::Write,
+    embedding: &[f32],
+    encoding: EmbeddingEncoding,
+) -> std::io::Result<()> {
+    use json_write::*;
+    match encoding {
+        EmbeddingEncoding::Json => {
+            write_string(w, "embedding")?;
+            w.write_all(b":[")?;
+            for (i, v) in embedding.iter().enumerate() {
+                if i > 0 {
+                    w.write_all(b",")?;
+                }
+                write_f32(w, *v)?;
+            }
+            w.write_all(b"]")
+        }
+        EmbeddingEncoding::Binary => {
+            write_kv_string(w, "embedding_f32le", &encode_f32le(embedding))
+        }
+    }
+}
+
+/// Read the embedding field written by [`write_embedding`], in either encoding.
+#[cfg(feature = "vector")]
+fn parse_embedding(inner: &JsonValue, variant: &str) -> Result<(Vec<f32>, EmbeddingEncoding)> {
+    if let Some(values) = inner.get("embedding").and_then(|v| v.as_array()) {
+        let embedding = values
+            .iter()
+            .map(|v| {
+                v.as_f32()
+                    .ok_or_else(|| KronroeError::serialization("embedding element is not a number"))
+            })
+            .collect::<Result<Vec<f32>>>()?;
+        return Ok((embedding, EmbeddingEncoding::Json));
+    }
+    match inner.get("embedding_f32le").and_then(|v| v.as_str()) {
+        Some(encoded) => Ok((decode_f32le(encoded)?, EmbeddingEncoding::Binary)),
+        None => Err(KronroeError::serialization(format!(
+            "{variant} missing 'embedding'"
+        ))),
+    }
+}
+
 /// Authoritative append-log replay state.
 ///
 /// Record order is the source of truth. Every index here is derived by replaying
@@ -386,10 +727,20 @@ struct AppendLogState {
     volatility_registry: BTreeMap<String, String>,
     #[cfg(feature = "uncertainty")]
     source_weight_registry: BTreeMap<String, String>,
+    entity_predicate_registry: BTreeMap<String, String>,
+    predicate_synonym_registry: BTreeMap<String, String>,
+    /// Audit entries of automatic contradiction resolutions, keyed
+    /// `subject:predicate:fact_id` like fact rows.
+    contradiction_resolutions: BTreeMap<String, String>,
+    /// Embedding dimension per space, fixed by the space's first embedding.
+    #[cfg(feature = "vector")]
+    embedding_dims: BTreeMap<String, usize>,
+    /// Per-space settings (the metric), which may precede any embedding.
     #[cfg(feature = "vector")]
-    embedding_dim: Option<usize>,
+    embedding_space_registry: BTreeMap<String, String>,
+    /// Space -> fact id -> embedding.
     #[cfg(feature = "vector")]
-    embeddings: BTreeMap<String, Vec<f32>>,
+    embeddings: BTreeMap<String, BTreeMap<String, Vec<f32>>>,
     facts: BTreeMap<String, Fact>,
     fact_key_by_id: BTreeMap<String, String>,
     facts_by_subject_predicate: BTreeMap<String, BTreeSet<String>>,
@@ -476,10 +827,47 @@ impl AppendLogState {
     }
 
     #[cfg(feature = "vector")]
-    fn apply_embedding_upsert(&mut self, fact_id: &FactId, embedding: Vec<f32>) {
-        self.embedding_dim.get_or_insert(embedding.len());
+    fn apply_embedding_upsert(&mut self, fact_id: &str, space: String, embedding: Vec<f32>) {
+        self.embedding_dims
+            .entry(space.clone())
+            .or_insert(embedding.len());
         self.embeddings
-            .insert(fact_id.as_str().to_string(), embedding);
+            .entry(space)
+            .or_default()
+            .insert(fact_id.to_string(), embedding);
+    }
+
+    /// A space whose last embedding is removed ceases to exist, dimension
+    /// included.
+    #[cfg(feature = "vector")]
+    fn apply_embedding_removal(&mut self, fact_id: &str, space: &str) {
+        let Some(rows) = self.embeddings.get_mut(space) else {
+            return;
+        };
+        rows.remove(fact_id);
+        if rows.is_empty() {
+            self.embeddings.remove(space);
+            self.embedding_dims.remove(space);
+        }
+    }
+
+    /// Reject an embedding that is empty or does not match its space.
+    #[cfg(feature = "vector")]
+    fn check_embedding(&self, space: &str, embedding: &[f32]) -> Result<()> {
+        if embedding.is_empty() {
+            return Err(KronroeError::invalid_embedding(
+                "embedding must not be empty",
+            ));
+        }
+        if let Some(&expected_dim) = self.embedding_dims.get(space) {
+            if embedding.len() != expected_dim {
+                return Err(KronroeError::invalid_embedding(format!(
+                    "embedding dimension mismatch in space '{space}': expected {expected_dim}, got {}",
+                    embedding.len()
+                )));
+            }
+        }
+        Ok(())
     }
 
     fn apply_record(&mut self, record: AppendLogRecord) {
@@ -502,10 +890,37 @@ impl AppendLogState {
             AppendLogRecord::UpsertSourceWeightRegistryEntry { source, encoded } => {
                 self.source_weight_registry.insert(source, encoded);
             }
+            AppendLogRecord::UpsertEntityPredicateRegistryEntry { predicate, encoded } => {
+                self.entity_predicate_registry.insert(predicate, encoded);
+            }
+            AppendLogRecord::UpsertPredicateSynonymRegistryEntry { predicate, encoded } => {
+                self.predicate_synonym_registry.insert(predicate, encoded);
+            }
+            #[cfg(feature = "vector")]
+            AppendLogRecord::UpsertEmbeddingSpaceRegistryEntry { space, encoded } => {
+                self.embedding_space_registry.insert(space, encoded);
+            }
             AppendLogRecord::UpsertFact { key, fact }
             | AppendLogRecord::ReplaceFact { key, fact } => {
                 self.apply_fact_upsert(key, fact);
             }
+            AppendLogRecord::ResolveContradiction {
+                key,
+                fact,
+                updated,
+                resolution_key,
+                resolution,
+            } => {
+                for (key, fact) in updated {
+                    self.apply_fact_upsert(key, fact);
+                }
+                self.apply_fact_upsert(key, fact);
+                self.contradiction_resolutions
+                    .insert(resolution_key, resolution);
+            }
+            AppendLogRecord::UpsertContradictionResolution { key, encoded } => {
+                self.contradiction_resolutions.insert(key, encoded);
+            }
             AppendLogRecord::UpsertFactAndIdempotency {
                 key,
                 fact,
@@ -519,11 +934,26 @@ impl AppendLogState {
             AppendLogRecord::UpsertFactWithEmbedding {
                 key,
                 fact,
+                space,
                 embedding,
+                ..
             } => {
-                self.apply_embedding_upsert(&fact.id, embedding);
+                self.apply_embedding_upsert(fact.id.as_str(), space, embedding);
                 self.apply_fact_upsert(key, fact);
             }
+            #[cfg(feature = "vector")]
+            AppendLogRecord::UpsertEmbedding {
+                fact_id,
+                space,
+                embedding,
+                ..
+            } => {
+                self.apply_embedding_upsert(&fact_id, space, embedding);
+            }
+            #[cfg(feature = "vector")]
+            AppendLogRecord::RemoveEmbedding { fact_id, space } => {
+                self.apply_embedding_removal(&fact_id, &space);
+            }
         }
     }
 }
@@ -654,6 +1084,9 @@ impl AppendLogWriteGuard {
 pub(crate) struct AppendLogBackend {
     mode: AppendLogMode,
     state: Mutex<AppendLogState>,
+    /// Encoding for embeddings written from now on (including compaction).
+    #[cfg(feature = "vector")]
+    embedding_encoding: Mutex<EmbeddingEncoding>,
 }
 
 impl AppendLogBackend {
@@ -672,6 +1105,8 @@ impl AppendLogBackend {
                 _guard: guard,
             },
             state: Mutex::new(state),
+            #[cfg(feature = "vector")]
+            embedding_encoding: Mutex::new(EmbeddingEncoding::default()),
         })
     }
 
@@ -686,6 +1121,8 @@ impl AppendLogBackend {
         Self {
             mode: AppendLogMode::InMemory,
             state: Mutex::new(AppendLogState::default()),
+            #[cfg(feature = "vector")]
+            embedding_encoding: Mutex::new(EmbeddingEncoding::default()),
         }
     }
 
@@ -843,7 +1280,7 @@ impl AppendLogBackend {
         if let AppendLogMode::OnDisk { path, .. } = &self.mode {
             let state = self.state.lock().unwrap();
             let temp_path = append_log_temp_path(path);
-            let write_result = Self::write_compacted_state(&temp_path, &state);
+            let write_result = self.write_compacted_state(&temp_path, &state);
             if let Err(error) = write_result {
                 let _ = fs::remove_file(&temp_path);
                 return Err(error);
@@ -861,8 +1298,12 @@ impl AppendLogBackend {
 
     #[cfg(not(target_arch = "wasm32"))]
     #[cfg_attr(not(test), allow(dead_code))]
-    fn write_compacted_state(path: &Path, state: &AppendLogState) -> Result<()> {
-        let records = compaction_records(state)?;
+    fn write_compacted_state(&self, path: &Path, state: &AppendLogState) -> Result<()> {
+        let records = compaction_records(
+            state,
+            #[cfg(feature = "vector")]
+            *self.embedding_encoding.lock().unwrap(),
+        )?;
         let mut file = File::create(path).map_err(|error| {
             KronroeError::storage(format!(
                 "append-log compaction create failed for {}: {error}",
@@ -959,6 +1400,82 @@ impl AppendLogBackend {
         Ok(())
     }
 
+    pub(crate) fn load_entity_predicate_registry_entries(&self) -> Vec<(String, String)> {
+        let state = self.state.lock().unwrap();
+        state
+            .entity_predicate_registry
+            .iter()
+            .map(|(predicate, encoded)| (predicate.clone(), encoded.clone()))
+            .collect()
+    }
+
+    pub(crate) fn write_entity_predicate_registry_entry(
+        &self,
+        predicate: &str,
+        encoded: &str,
+    ) -> Result<()> {
+        let record = AppendLogRecord::UpsertEntityPredicateRegistryEntry {
+            predicate: predicate.to_string(),
+            encoded: encoded.to_string(),
+        };
+        let mut state = self.state.lock().unwrap();
+        self.append_record(&record)?;
+        state.apply_record(record);
+        Ok(())
+    }
+
+    #[cfg(feature = "fulltext")]
+    pub(crate) fn load_predicate_synonym_registry_entries(&self) -> Vec<(String, String)> {
+        let state = self.state.lock().unwrap();
+        state
+            .predicate_synonym_registry
+            .iter()
+            .map(|(predicate, encoded)| (predicate.clone(), encoded.clone()))
+            .collect()
+    }
+
+    #[cfg(feature = "fulltext")]
+    pub(crate) fn write_predicate_synonym_registry_entry(
+        &self,
+        predicate: &str,
+        encoded: &str,
+    ) -> Result<()> {
+        let record = AppendLogRecord::UpsertPredicateSynonymRegistryEntry {
+            predicate: predicate.to_string(),
+            encoded: encoded.to_string(),
+        };
+        let mut state = self.state.lock().unwrap();
+        self.append_record(&record)?;
+        state.apply_record(record);
+        Ok(())
+    }
+
+    #[cfg(feature = "vector")]
+    pub(crate) fn load_embedding_space_registry_entries(&self) -> Vec<(String, String)> {
+        let state = self.state.lock().unwrap();
+        state
+            .embedding_space_registry
+            .iter()
+            .map(|(space, encoded)| (space.clone(), encoded.clone()))
+            .collect()
+    }
+
+    #[cfg(feature = "vector")]
+    pub(crate) fn write_embedding_space_registry_entry(
+        &self,
+        space: &str,
+        encoded: &str,
+    ) -> Result<()> {
+        let record = AppendLogRecord::UpsertEmbeddingSpaceRegistryEntry {
+            space: space.to_string(),
+            encoded: encoded.to_string(),
+        };
+        let mut state = self.state.lock().unwrap();
+        self.append_record(&record)?;
+        state.apply_record(record);
+        Ok(())
+    }
+
     pub(crate) fn scan_facts(&self, prefix: &str) -> (Vec<StoredFactRow>, usize) {
         let state = self.state.lock().unwrap();
         if let Some(keys) = state.facts_by_subject_predicate.get(prefix) {
@@ -1124,40 +1641,127 @@ impl AppendLogBackend {
     }
 
     #[cfg(feature = "vector")]
-    pub(crate) fn write_fact_with_embedding(&self, fact: &Fact, embedding: &[f32]) -> Result<()> {
-        if embedding.is_empty() {
-            return Err(KronroeError::invalid_embedding(
-                "embedding must not be empty",
-            ));
-        }
-
+    pub(crate) fn write_fact_with_embedding(
+        &self,
+        fact: &Fact,
+        space: &str,
+        embedding: &[f32],
+    ) -> Result<()> {
         let mut state = self.state.lock().unwrap();
-        if let Some(expected_dim) = state.embedding_dim {
-            if embedding.len() != expected_dim {
-                return Err(KronroeError::invalid_embedding(format!(
-                    "embedding dimension mismatch: expected {expected_dim}, got {}",
-                    embedding.len()
-                )));
-            }
-        }
+        state.check_embedding(space, embedding)?;
 
         let key = fact_row_key(&fact.subject, &fact.predicate, &fact.id);
         let record = AppendLogRecord::UpsertFactWithEmbedding {
             key,
             fact: fact.clone(),
+            space: space.to_string(),
             embedding: embedding.to_vec(),
+            encoding: *self.embedding_encoding.lock().unwrap(),
         };
         self.append_record(&record)?;
         state.apply_record(record);
         Ok(())
     }
 
+    /// Attach or replace embeddings of existing facts in `space`.
+    ///
+    /// Every row is validated before any is written; each row is then
+    /// durable once its record is appended, and `on_written` is called with
+    /// its position right after, so a failure partway through leaves the
+    /// caller's view in step with the rows already persisted.
     #[cfg(feature = "vector")]
-    pub(crate) fn embedding_rows(&self) -> Result<Vec<(FactId, Vec<f32>)>> {
-        let state = self.state.lock().unwrap();
-        state
+    pub(crate) fn write_embeddings(
+        &self,
+        space: &str,
+        rows: &[(FactId, Vec<f32>)],
+        mut on_written: impl FnMut(usize) -> Result<()>,
+    ) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        let mut dim = state.embedding_dims.get(space).copied();
+        for (fact_id, embedding) in rows {
+            if !state.fact_key_by_id.contains_key(fact_id.as_str()) {
+                return Err(KronroeError::not_found(format!(
+                    "fact id {}",
+                    fact_id.as_str()
+                )));
+            }
+            state.check_embedding(space, embedding)?;
+            let expected = *dim.get_or_insert(embedding.len());
+            if embedding.len() != expected {
+                return Err(KronroeError::invalid_embedding(format!(
+                    "embedding dimension mismatch in space '{space}': expected {expected}, got {}",
+                    embedding.len()
+                )));
+            }
+        }
+
+        let encoding = *self.embedding_encoding.lock().unwrap();
+        for (position, (fact_id, embedding)) in rows.iter().enumerate() {
+            let record = AppendLogRecord::UpsertEmbedding {
+                fact_id: fact_id.as_str().to_string(),
+                space: space.to_string(),
+                embedding: embedding.clone(),
+                encoding,
+            };
+            self.append_record(&record)?;
+            state.apply_record(record);
+            on_written(position)?;
+        }
+        Ok(())
+    }
+
+    /// Remove `fact_id`'s embedding from `space`. Returns false, writing
+    /// nothing, if it has none there.
+    #[cfg(feature = "vector")]
+    pub(crate) fn remove_embedding(&self, space: &str, fact_id: &FactId) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        let present = state
             .embeddings
+            .get(space)
+            .is_some_and(|rows| rows.contains_key(fact_id.as_str()));
+        if !present {
+            return Ok(false);
+        }
+        let record = AppendLogRecord::RemoveEmbedding {
+            fact_id: fact_id.as_str().to_string(),
+            space: space.to_string(),
+        };
+        self.append_record(&record)?;
+        state.apply_record(record);
+        Ok(true)
+    }
+
+    #[cfg(feature = "vector")]
+    pub(crate) fn set_embedding_encoding(&self, encoding: EmbeddingEncoding) {
+        *self.embedding_encoding.lock().unwrap() = encoding;
+    }
+
+    /// Stored embeddings in `space` for `ids`, in order; `None` for ids
+    /// without one.
+    #[cfg(feature = "vector")]
+    pub(crate) fn embeddings(&self, space: &str, ids: &[FactId]) -> Result<Vec<Option<Vec<f32>>>> {
+        let state = self.state.lock().unwrap();
+        let stored = state.embeddings.get(space);
+        Ok(ids
             .iter()
+            .map(|id| stored.and_then(|rows| rows.get(id.as_str()).cloned()))
+            .collect())
+    }
+
+    /// Names of the spaces holding at least one embedding, sorted.
+    #[cfg(feature = "vector")]
+    pub(crate) fn embedding_spaces(&self) -> Result<Vec<String>> {
+        let state = self.state.lock().unwrap();
+        Ok(state.embeddings.keys().cloned().collect())
+    }
+
+    #[cfg(feature = "vector")]
+    pub(crate) fn embedding_rows(&self, space: &str) -> Result<Vec<(FactId, Vec<f32>)>> {
+        let state = self.state.lock().unwrap();
+        let Some(rows) = state.embeddings.get(space) else {
+            return Ok(Vec::new());
+        };
+        rows.iter()
             .map(|(fact_id, embedding)| {
                 FactId::parse(fact_id)
                     .map(|id| (id, embedding.clone()))
@@ -1175,12 +1779,11 @@ impl AppendLogBackend {
         &self,
         subject: &str,
         predicate: &str,
-        fact: &Fact,
         reject_on_conflict: bool,
         check: F,
-    ) -> Result<(Vec<crate::contradiction::Contradiction>, usize)>
+    ) -> Result<(crate::contradiction::CheckedWrite, usize)>
     where
-        F: FnOnce(&[Fact]) -> Result<Vec<crate::contradiction::Contradiction>>,
+        F: FnOnce(&[Fact]) -> Result<crate::contradiction::CheckedWrite>,
     {
         let prefix = AppendLogState::subject_predicate_prefix(subject, predicate);
         let mut state = self.state.lock().unwrap();
@@ -1205,19 +1808,51 @@ impl AppendLogBackend {
                 )
             };
 
-        let contradictions = check(&existing)?;
-        if reject_on_conflict && !contradictions.is_empty() {
-            return Err(KronroeError::contradiction_rejected(contradictions));
+        let checked = check(&existing)?;
+        if reject_on_conflict && !checked.contradictions.is_empty() {
+            return Err(KronroeError::contradiction_rejected(checked.contradictions));
         }
 
+        let fact = &checked.fact;
         let key = fact_row_key(&fact.subject, &fact.predicate, &fact.id);
-        let record = AppendLogRecord::UpsertFact {
-            key,
-            fact: fact.clone(),
+        let record = match &checked.resolution {
+            None => AppendLogRecord::UpsertFact {
+                key,
+                fact: fact.clone(),
+            },
+            Some(resolution) => AppendLogRecord::ResolveContradiction {
+                resolution_key: key.clone(),
+                key,
+                fact: fact.clone(),
+                updated: checked
+                    .updated
+                    .iter()
+                    .map(|fact| {
+                        (
+                            fact_row_key(&fact.subject, &fact.predicate, &fact.id),
+                            fact.clone(),
+                        )
+                    })
+                    .collect(),
+                resolution: resolution.to_json_string(),
+            },
         };
         self.append_record(&record)?;
         state.apply_record(record);
-        Ok((contradictions, rows_scanned))
+        Ok((checked, rows_scanned))
+    }
+
+    /// Encoded audit entries of the automatic contradiction resolutions for
+    /// `subject:predicate:`.
+    #[cfg(feature = "contradiction")]
+    pub(crate) fn load_contradiction_resolutions(&self, prefix: &str) -> Vec<String> {
+        let state = self.state.lock().unwrap();
+        state
+            .contradiction_resolutions
+            .range(prefix.to_string()..)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(_, encoded)| encoded.clone())
+            .collect()
     }
 }
 
@@ -1325,7 +1960,10 @@ fn sync_parent_directory(path: &Path) -> Result<()> {
 }
 
 #[cfg_attr(not(test), allow(dead_code))]
-fn compaction_records(state: &AppendLogState) -> Result<Vec<AppendLogRecord>> {
+fn compaction_records(
+    state: &AppendLogState,
+    #[cfg(feature = "vector")] encoding: EmbeddingEncoding,
+) -> Result<Vec<AppendLogRecord>> {
     let mut records = Vec::new();
     records.push(AppendLogRecord::Header {
         magic: APPEND_LOG_MAGIC.to_string(),
@@ -1358,13 +1996,48 @@ fn compaction_records(state: &AppendLogState) -> Result<Vec<AppendLogRecord>> {
         });
     }
 
+    for (predicate, encoded) in &state.entity_predicate_registry {
+        records.push(AppendLogRecord::UpsertEntityPredicateRegistryEntry {
+            predicate: predicate.clone(),
+            encoded: encoded.clone(),
+        });
+    }
+
+    for (predicate, encoded) in &state.predicate_synonym_registry {
+        records.push(AppendLogRecord::UpsertPredicateSynonymRegistryEntry {
+            predicate: predicate.clone(),
+            encoded: encoded.clone(),
+        });
+    }
+
+    #[cfg(feature = "vector")]
+    for (space, encoded) in &state.embedding_space_registry {
+        records.push(AppendLogRecord::UpsertEmbeddingSpaceRegistryEntry {
+            space: space.clone(),
+            encoded: encoded.clone(),
+        });
+    }
+
+    for (key, encoded) in &state.contradiction_resolutions {
+        records.push(AppendLogRecord::UpsertContradictionResolution {
+            key: key.clone(),
+            encoded: encoded.clone(),
+        });
+    }
+
     for (key, fact) in &state.facts {
         #[cfg(feature = "vector")]
-        if let Some(embedding) = state.embeddings.get(fact.id.as_str()) {
+        if let Some(embedding) = state
+            .embeddings
+            .get(DEFAULT_EMBEDDING_SPACE)
+            .and_then(|rows| rows.get(fact.id.as_str()))
+        {
             records.push(AppendLogRecord::UpsertFactWithEmbedding {
                 key: key.clone(),
                 fact: fact.clone(),
+                space: DEFAULT_EMBEDDING_SPACE.to_string(),
                 embedding: embedding.clone(),
+                encoding,
             });
             continue;
         }
@@ -1392,5 +2065,20 @@ fn compaction_records(state: &AppendLogState) -> Result<Vec<AppendLogRecord>> {
         });
     }
 
+    #[cfg(feature = "vector")]
+    for (space, rows) in &state.embeddings {
+        if space == DEFAULT_EMBEDDING_SPACE {
+            continue;
+        }
+        for (fact_id, embedding) in rows {
+            records.push(AppendLogRecord::UpsertEmbedding {
+                fact_id: fact_id.clone(),
+                space: space.clone(),
+                embedding: embedding.clone(),
+                encoding,
+            });
+        }
+    }
+
     Ok(records)
 }
@@ -128,12 +128,12 @@ struct OperationSummary {
 
 fn graph_in_memory(observer: Arc<dyn StorageObserver>) -> TemporalGraph {
     let storage = KronroeStorage::open_in_memory_with_observer(observer).unwrap();
-    TemporalGraph::init(storage).unwrap()
+    TemporalGraph::init(storage, Default::default(), Default::default()).unwrap()
 }
 
 fn graph_on_disk(path: &str, observer: Arc<dyn StorageObserver>) -> TemporalGraph {
     let storage = KronroeStorage::open_with_observer(path, observer).unwrap();
-    TemporalGraph::init(storage).unwrap()
+    TemporalGraph::init(storage, Default::default(), Default::default()).unwrap()
 }
 
 fn summarize_operations(events: Vec<StorageEvent>) -> Vec<OperationSummary> {
@@ -11,10 +11,20 @@ pub(crate) enum StorageOperation {
     WriteFactAndIdempotency,
     #[cfg(feature = "contradiction")]
     ContradictionCheckedWrite,
+    #[cfg(feature = "contradiction")]
+    LoadContradictionResolutions,
     #[cfg(feature = "vector")]
     WriteFactWithEmbedding,
     #[cfg(feature = "vector")]
     EmbeddingRows,
+    #[cfg(feature = "vector")]
+    ReadEmbeddings,
+    #[cfg(feature = "vector")]
+    WriteEmbeddings,
+    #[cfg(feature = "vector")]
+    RemoveEmbedding,
+    #[cfg(feature = "vector")]
+    EmbeddingSpaces,
     #[cfg_attr(not(test), allow(dead_code))]
     Compact,
     #[cfg(feature = "contradiction")]
@@ -29,6 +39,16 @@ pub(crate) enum StorageOperation {
     WriteVolatilityRegistryEntry,
     #[cfg(feature = "uncertainty")]
     WriteSourceWeightRegistryEntry,
+    LoadEntityPredicateRegistryEntries,
+    WriteEntityPredicateRegistryEntry,
+    #[cfg(feature = "fulltext")]
+    LoadPredicateSynonymRegistryEntries,
+    #[cfg(feature = "fulltext")]
+    WritePredicateSynonymRegistryEntry,
+    #[cfg(feature = "vector")]
+    LoadEmbeddingSpaceRegistryEntries,
+    #[cfg(feature = "vector")]
+    WriteEmbeddingSpaceRegistryEntry,
 }
 
 #[allow(dead_code)]
@@ -28,31 +28,70 @@
 //! let facts_then = db.facts_at("alice", "works_at", past).unwrap();
 //! ```
 
+mod aggregate;
+#[cfg(any(feature = "fulltext", feature = "vector"))]
+mod checkpoint;
+mod entity;
 mod error;
+#[cfg(feature = "fulltext")]
+mod expansion;
+mod explain;
 mod fact_id;
+#[cfg(any(feature = "fulltext", feature = "vector"))]
+mod filter;
+#[cfg(feature = "fulltext")]
+mod fuzzy;
+#[cfg(feature = "vector")]
+mod hnsw;
 pub(crate) mod json_read;
 pub(crate) mod json_write;
 mod kronroe_time;
 #[cfg(feature = "fulltext")]
 mod lexical;
+#[cfg(feature = "vector")]
+mod metric;
+#[cfg(feature = "vector")]
+mod quantize;
+#[cfg(feature = "rdf")]
+mod rdf;
+#[cfg(feature = "fulltext")]
+mod search_index;
 mod storage;
 mod storage_append_log;
 #[cfg(test)]
 mod storage_benchmarks;
 mod storage_observability;
+#[cfg(feature = "fulltext")]
+mod tokenizer;
 #[cfg(feature = "vector")]
 mod vector;
+#[cfg(feature = "vector")]
+pub use hnsw::HnswConfig;
+#[cfg(feature = "vector")]
+pub use metric::Metric;
+#[cfg(feature = "vector")]
+pub use quantize::{EmbeddingEncoding, Quantization};
+#[cfg(feature = "vector")]
+pub use vector::{VectorConfig, DEFAULT_EMBEDDING_SPACE};
 
-#[cfg(all(feature = "hybrid-experimental", feature = "vector"))]
+#[cfg(feature = "hybrid")]
 mod hybrid;
-#[cfg(all(feature = "hybrid-experimental", feature = "vector"))]
-pub use hybrid::{HybridScoreBreakdown, HybridSearchParams, TemporalIntent, TemporalOperator};
+#[cfg(feature = "hybrid")]
+pub use hybrid::{
+    FusionStrategy, HybridScoreBreakdown, HybridSearchParams, IntentGatedReranker, RerankContext,
+    Reranker, Stage1Decision, TemporalIntent, TemporalOperator,
+};
+#[cfg(feature = "hybrid")]
+mod temporal_query;
+#[cfg(feature = "hybrid")]
+pub use temporal_query::TemporalQuery;
 
 #[cfg(feature = "contradiction")]
 mod contradiction;
 #[cfg(feature = "contradiction")]
 pub use contradiction::{
-    ConflictPolicy, ConflictSeverity, Contradiction, PredicateCardinality, SuggestedResolution,
+    AppliedResolution, ConflictPolicy, ConflictSeverity, Contradiction, ContradictionResolution,
+    PredicateCardinality, SuggestedResolution,
 };
 
 #[cfg(feature = "uncertainty")]
@@ -60,22 +99,45 @@ mod uncertainty;
 #[cfg(feature = "uncertainty")]
 pub use uncertainty::{EffectiveConfidence, PredicateVolatility, SourceWeight};
 
+#[cfg(any(feature = "fulltext", feature = "vector"))]
+pub use filter::SearchFilter;
+#[cfg(feature = "fulltext")]
+pub use fuzzy::FuzzyConfig;
+#[cfg(feature = "rdf")]
+pub use rdf::{RdfFormat, RdfOptions, KRONROE_RDF_NS};
+#[cfg(feature = "fulltext")]
+pub use search_index::SearchConfig;
+#[cfg(feature = "fulltext")]
+pub use tokenizer::{StandardTokenizer, Stemmer, Tokenizer, ENGLISH_STOPWORDS};
+
+pub use aggregate::{AggregateBucket, AggregateResult, AggregateSpec, AggregateStats, TimeBucket};
+pub use entity::{DEFAULT_ALIAS_PREDICATES, DEFAULT_SAME_AS_PREDICATE};
 pub use error::{ErrorCode, ErrorContext, KronroeError, OptionContext};
+pub use explain::{SearchExplanation, SearchField, TermMatch, HIGHLIGHT_END, HIGHLIGHT_START};
 pub use fact_id::{FactId, FactIdParseError};
+pub use json_read::{JsonValue, ParseError as JsonParseError};
 pub use kronroe_time::{
     default_clock, FixedClock, KronroeClock, KronroeSpan, KronroeTimestamp, SystemClock,
 };
-#[cfg(all(feature = "hybrid-experimental", feature = "vector"))]
-use std::cmp::Ordering;
-#[cfg(any(
-    feature = "fulltext",
-    all(feature = "hybrid-experimental", feature = "vector")
-))]
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use storage::{KronroeStorage, SCHEMA_VERSION};
 
 pub type Result<T> = std::result::Result<T, KronroeError>;
 
+/// Full-text settings threaded through `TemporalGraph::init`.
+#[cfg(feature = "fulltext")]
+type SearchInit = SearchConfig;
+#[cfg(not(feature = "fulltext"))]
+#[derive(Default)]
+struct SearchInit;
+
+/// Vector settings threaded through `TemporalGraph::init`.
+#[cfg(feature = "vector")]
+type VectorInit = VectorConfig;
+#[cfg(not(feature = "vector"))]
+#[derive(Default)]
+struct VectorInit;
+
 // ---------------------------------------------------------------------------
 // Core types
 // ---------------------------------------------------------------------------
@@ -83,7 +145,11 @@ pub type Result<T> = std::result::Result<T, KronroeError>;
 /// The value stored in a fact's object position.
 ///
 /// A fact's object can be a scalar value or a reference to another entity.
-#[derive(Debug, Clone)]
+///
+/// `PartialEq` is structural and variant-sensitive: `Integer(3)` and
+/// `Number(3.0)` are not equal. Use [`Value::same_value`] to compare the
+/// quantities instead.
+#[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     /// A text string.
     Text(String),
@@ -93,6 +159,14 @@ pub enum Value {
     Boolean(bool),
     /// A reference to another entity by name or ID.
     Entity(String),
+    /// A point in time. Orders chronologically, unlike RFC 3339 text.
+    Timestamp(KronroeTimestamp),
+    /// An exact 64-bit integer (no `f64` precision loss above 2^53).
+    Integer(i64),
+    /// Opaque binary payload. Not indexed for full-text search.
+    Bytes(Vec<u8>),
+    /// A structured JSON document.
+    Json(JsonValue),
 }
 
 impl From<&str> for Value {
@@ -115,6 +189,43 @@ impl From<bool> for Value {
         Value::Boolean(b)
     }
 }
+impl From<KronroeTimestamp> for Value {
+    fn from(t: KronroeTimestamp) -> Self {
+        Value::Timestamp(t)
+    }
+}
+impl From<i64> for Value {
+    fn from(n: i64) -> Self {
+        Value::Integer(n)
+    }
+}
+impl From<Vec<u8>> for Value {
+    fn from(bytes: Vec<u8>) -> Self {
+        Value::Bytes(bytes)
+    }
+}
+impl From<JsonValue> for Value {
+    fn from(json: JsonValue) -> Self {
+        Value::Json(json)
+    }
+}
+
+impl Value {
+    /// Do `self` and `other` hold the same value?
+    ///
+    /// Like `==`, except that `Integer` and `Number` compare numerically:
+    /// `Integer(3)` and `Number(3.0)` are the same value, `Integer(3)` and
+    /// `Number(3.5)` are not. Metadata lookups use this so facts written
+    /// as `Number` before `Integer` existed still match.
+    pub fn same_value(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Integer(i), Value::Number(n)) | (Value::Number(n), Value::Integer(i)) => {
+                n.fract() == 0.0 && *n as i64 == *i && *i as f64 == *n
+            }
+            _ => self == other,
+        }
+    }
+}
 
 impl std::fmt::Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -122,12 +233,21 @@ impl std::fmt::Display for Value {
             Value::Text(s) | Value::Entity(s) => write!(f, "{s}"),
             Value::Number(n) => write!(f, "{n}"),
             Value::Boolean(b) => write!(f, "{b}"),
+            Value::Timestamp(t) => write!(f, "{}", t.to_rfc3339_z()),
+            Value::Integer(n) => write!(f, "{n}"),
+            Value::Bytes(bytes) => write!(f, "{}", json_write::encode_base64(bytes)),
+            Value::Json(json) => write!(f, "{json}"),
         }
     }
 }
 
 // -- Kronroe-native JSON codec for Value --
 // Format: {"type":"Text","value":"..."} (serde-compatible internally-tagged)
+//
+// Payloads that JSON cannot carry natively use a string encoding:
+// `Timestamp` is RFC 3339, `Integer` is decimal text (the JSON reader parses
+// numbers as f64, which would lose precision above 2^53), and `Bytes` is
+// padded base64.
 impl Value {
     /// Write this value as JSON to a writer.
     pub fn write_json(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
@@ -158,23 +278,64 @@ impl Value {
                 w.write_all(b",")?;
                 write_kv_string(w, "value", s)?;
             }
+            Value::Timestamp(t) => {
+                write_kv_string(w, "type", "Timestamp")?;
+                w.write_all(b",")?;
+                write_kv_string(w, "value", &t.to_rfc3339_z())?;
+            }
+            Value::Integer(n) => {
+                write_kv_string(w, "type", "Integer")?;
+                w.write_all(b",")?;
+                write_kv_string(w, "value", &n.to_string())?;
+            }
+            Value::Bytes(bytes) => {
+                write_kv_string(w, "type", "Bytes")?;
+                w.write_all(b",")?;
+                write_kv_string(w, "value", &encode_base64(bytes))?;
+            }
+            Value::Json(json) => {
+                write_kv_string(w, "type", "Json")?;
+                w.write_all(b",")?;
+                write_string(w, "value")?;
+                w.write_all(b":")?;
+                write_json_value(w, json)?;
+            }
         }
         w.write_all(b"}")
     }
 
-    pub(crate) fn from_json(val: &json_read::JsonValue) -> Result<Self> {
+    /// Serialize this value as its tagged JSON form, e.g.
+    /// `{"type":"Integer","value":"42"}`.
+    pub fn to_json_string(&self) -> String {
+        let mut buf = Vec::new();
+        self.write_json(&mut buf)
+            .expect("Vec<u8> write is infallible");
+        // SAFETY: write_json produces valid UTF-8 (see Fact::to_json_string).
+        unsafe { String::from_utf8_unchecked(buf) }
+    }
+
+    /// Parse a value from its tagged JSON form (see [`Value::to_json_string`]).
+    ///
+    /// Bindings use this to accept typed objects without duplicating the codec.
+    pub fn from_json_str(input: &str) -> Result<Self> {
+        let json = JsonValue::parse_str(input)
+            .map_err(|e| KronroeError::invalid_input(format!("invalid value JSON: {e}")))?;
+        Self::from_json(&json)
+    }
+
+    /// Parse a value from its tagged JSON DOM form.
+    pub fn from_json(val: &JsonValue) -> Result<Self> {
         let typ = val
             .get("type")
             .and_then(|v| v.as_str())
             .ok_or_else(|| KronroeError::serialization("Value missing 'type' field"))?;
+        let get_str = |variant: &str| -> Result<&str> {
+            val.get("value").and_then(|v| v.as_str()).ok_or_else(|| {
+                KronroeError::serialization(format!("Value::{variant} missing 'value'"))
+            })
+        };
         match typ {
-            "Text" => {
-                let s = val
-                    .get("value")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| KronroeError::serialization("Value::Text missing 'value'"))?;
-                Ok(Value::Text(s.to_string()))
-            }
+            "Text" => Ok(Value::Text(get_str("Text")?.to_string())),
             "Number" => {
                 let n = val
                     .get("value")
@@ -189,13 +350,34 @@ impl Value {
                     .ok_or_else(|| KronroeError::serialization("Value::Boolean missing 'value'"))?;
                 Ok(Value::Boolean(b))
             }
-            "Entity" => {
-                let s = val
-                    .get("value")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| KronroeError::serialization("Value::Entity missing 'value'"))?;
-                Ok(Value::Entity(s.to_string()))
-            }
+            "Entity" => Ok(Value::Entity(get_str("Entity")?.to_string())),
+            "Timestamp" => KronroeTimestamp::parse_rfc3339(get_str("Timestamp")?)
+                .map(Value::Timestamp)
+                .map_err(|e| KronroeError::serialization(format!("Value::Timestamp: {e}"))),
+            "Integer" => match val.get("value") {
+                Some(json_read::JsonValue::Str(s)) => s
+                    .parse::<i64>()
+                    .map(Value::Integer)
+                    .map_err(|e| KronroeError::serialization(format!("Value::Integer: {e}"))),
+                // Accept plain JSON numbers from hand-written input when they
+                // are exactly representable.
+                Some(json_read::JsonValue::Number(n))
+                    if n.fract() == 0.0 && n.abs() <= (1_i64 << 53) as f64 =>
+                {
+                    Ok(Value::Integer(*n as i64))
+                }
+                _ => Err(KronroeError::serialization(
+                    "Value::Integer missing or inexact 'value'",
+                )),
+            },
+            "Bytes" => json_read::decode_base64(get_str("Bytes")?)
+                .map(Value::Bytes)
+                .map_err(|e| KronroeError::serialization(format!("Value::Bytes: {e}"))),
+            "Json" => val
+                .get("value")
+                .cloned()
+                .map(Value::Json)
+                .ok_or_else(|| KronroeError::serialization("Value::Json missing 'value'")),
             other => Err(KronroeError::serialization(format!(
                 "unknown Value type: {other}"
             ))),
@@ -240,6 +422,9 @@ pub struct Fact {
     pub confidence: f32,
     /// Where this fact came from (conversation ID, document ID, etc.).
     pub source: Option<String>,
+    /// Free-form qualifiers keyed by name (extraction model, conversation
+    /// turn, locale, evidence snippet, ...). Empty for most facts.
+    pub metadata: BTreeMap<String, Value>,
 }
 
 impl Fact {
@@ -261,6 +446,7 @@ impl Fact {
             expired_at: None,
             confidence: 1.0,
             source: None,
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -289,6 +475,13 @@ impl Fact {
         self
     }
 
+    /// Return a copy with one metadata qualifier set, replacing any previous
+    /// value for `key`.
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
     /// Is this fact currently valid (valid time is open, not expired)?
     pub fn is_currently_valid(&self) -> bool {
         self.valid_to.is_none() && self.expired_at.is_none()
@@ -348,6 +541,22 @@ impl Fact {
         write_kv_f32(w, "confidence", self.confidence)?;
         w.write_all(b",")?;
         write_kv_option_string(w, "source", &self.source)?;
+        // Omitted when empty so facts without qualifiers keep their
+        // pre-metadata encoding byte for byte.
+        if !self.metadata.is_empty() {
+            w.write_all(b",")?;
+            write_string(w, "metadata")?;
+            w.write_all(b":{")?;
+            for (i, (key, value)) in self.metadata.iter().enumerate() {
+                if i > 0 {
+                    w.write_all(b",")?;
+                }
+                write_string(w, key)?;
+                w.write_all(b":")?;
+                value.write_json(w)?;
+            }
+            w.write_all(b"}")?;
+        }
         w.write_all(b"}")
     }
 
@@ -404,6 +613,21 @@ impl Fact {
         let source = val
             .get("source")
             .and_then(|v| v.as_str().map(|s| s.to_string()));
+        let mut metadata = BTreeMap::new();
+        match val.get("metadata") {
+            Some(json_read::JsonValue::Object(entries)) => {
+                for (key, value) in entries {
+                    let value = Value::from_json(value).map_err(|e| {
+                        KronroeError::serialization(format!("Fact metadata '{key}': {e}"))
+                    })?;
+                    metadata.insert(key.clone(), value);
+                }
+            }
+            Some(v) if !v.is_null() => {
+                return Err(KronroeError::serialization("Fact metadata: not an object"))
+            }
+            _ => {}
+        }
 
         Ok(Self {
             id,
@@ -416,6 +640,7 @@ impl Fact {
             expired_at,
             confidence,
             source,
+            metadata,
         })
     }
 }
@@ -440,14 +665,26 @@ impl Fact {
 /// ```
 pub struct TemporalGraph {
     storage: KronroeStorage,
-    /// In-memory vector index cache. Rebuilt from persisted embedding rows on
-    /// every [`init`] call, then kept in sync by [`assert_fact_with_embedding`].
-    /// Persisted storage is the source of truth; this cache is a read-optimised
-    /// view of them.
+    /// In-memory vector index cache, one index per embedding space. Rebuilt
+    /// from persisted embedding rows on every [`init`] call, then kept in sync
+    /// by [`assert_fact_with_embedding`] and
+    /// [`backfill_embeddings_in_space`]. Persisted storage is the source of
+    /// truth; this cache is a read-optimised view of them.
     ///
     /// [`assert_fact_with_embedding`]: TemporalGraph::assert_fact_with_embedding
+    /// [`backfill_embeddings_in_space`]: TemporalGraph::backfill_embeddings_in_space
     #[cfg(feature = "vector")]
-    vector_index: std::sync::Mutex<vector::VectorIndex>,
+    vector_spaces: std::sync::Mutex<vector::VectorSpaces>,
+    /// Full-text index, built from the stored facts (or a matching
+    /// checkpoint) on open and kept in sync by every fact write.
+    #[cfg(feature = "fulltext")]
+    search_index: std::sync::Mutex<search_index::SearchIndex>,
+    /// Live alias and same-as facts, built on open and kept in sync by every
+    /// fact write; resolves names to canonical subjects.
+    entity_index: std::sync::Mutex<entity::EntityIndex>,
+    /// Registered predicate synonyms for lexical query expansion.
+    #[cfg(feature = "fulltext")]
+    synonym_index: std::sync::Mutex<expansion::SynonymIndex>,
     #[cfg(feature = "contradiction")]
     contradiction_detector: std::sync::Mutex<contradiction::ContradictionDetector>,
     #[cfg(feature = "uncertainty")]
@@ -464,7 +701,53 @@ impl TemporalGraph {
     /// append-log backend.
     pub fn open(path: &str) -> Result<Self> {
         let storage = KronroeStorage::open(path)?;
-        Self::init(storage)
+        Self::init(storage, Default::default(), Default::default())
+    }
+
+    /// Open a database with custom full-text settings: a [`Tokenizer`] and an
+    /// optional index checkpoint. See [`SearchConfig`].
+    #[cfg(feature = "fulltext")]
+    pub fn open_with_search_config(path: &str, config: SearchConfig) -> Result<Self> {
+        let storage = KronroeStorage::open(path)?;
+        Self::init(storage, config, Default::default())
+    }
+
+    /// In-memory variant of [`open_with_search_config`].
+    ///
+    /// [`open_with_search_config`]: TemporalGraph::open_with_search_config
+    #[cfg(feature = "fulltext")]
+    pub fn open_in_memory_with_search_config(config: SearchConfig) -> Result<Self> {
+        let storage = KronroeStorage::open_in_memory()?;
+        Self::init(storage, config, Default::default())
+    }
+
+    /// Open a database with custom vector search settings: approximate
+    /// nearest-neighbour parameters and an optional graph checkpoint. See
+    /// [`VectorConfig`].
+    #[cfg(feature = "vector")]
+    pub fn open_with_vector_config(path: &str, config: VectorConfig) -> Result<Self> {
+        let storage = KronroeStorage::open(path)?;
+        Self::init(storage, Default::default(), config)
+    }
+
+    /// In-memory variant of [`open_with_vector_config`].
+    ///
+    /// [`open_with_vector_config`]: TemporalGraph::open_with_vector_config
+    #[cfg(feature = "vector")]
+    pub fn open_in_memory_with_vector_config(config: VectorConfig) -> Result<Self> {
+        let storage = KronroeStorage::open_in_memory()?;
+        Self::init(storage, Default::default(), config)
+    }
+
+    /// Open a database with both custom full-text and vector settings.
+    #[cfg(all(feature = "fulltext", feature = "vector"))]
+    pub fn open_with_config(
+        path: &str,
+        search: SearchConfig,
+        vector: VectorConfig,
+    ) -> Result<Self> {
+        let storage = KronroeStorage::open(path)?;
+        Self::init(storage, search, vector)
     }
 
     /// Create an in-memory Kronroe database (no file I/O).
@@ -476,20 +759,39 @@ impl TemporalGraph {
     /// the append-log backend.
     pub fn open_in_memory() -> Result<Self> {
         let storage = KronroeStorage::open_in_memory()?;
-        Self::init(storage)
+        Self::init(storage, Default::default(), Default::default())
     }
 
-    fn init(storage: KronroeStorage) -> Result<Self> {
+    fn init(storage: KronroeStorage, search: SearchInit, vector: VectorInit) -> Result<Self> {
         let stored_version = storage.initialize_schema()?;
         match stored_version {
             v if v == SCHEMA_VERSION => {}
             found => return Err(KronroeError::schema_mismatch(found, SCHEMA_VERSION)),
         }
         #[cfg(feature = "vector")]
-        let vector_index = {
-            let idx = Self::rebuild_vector_index_from_storage(&storage)?;
-            std::sync::Mutex::new(idx)
+        let vector_spaces = {
+            storage.set_embedding_encoding(vector.log_encoding);
+            let spaces = Self::rebuild_vector_index_from_storage(&storage, vector)?;
+            std::sync::Mutex::new(spaces)
+        };
+        #[cfg(not(feature = "vector"))]
+        let VectorInit = vector;
+        let entity_index = {
+            let mut index =
+                entity::EntityIndex::new(storage.load_entity_predicate_registry_entries()?)?;
+            let rows = storage.scan_facts("")?;
+            index.rebuild(rows.iter().map(|row| &row.fact));
+            index
         };
+        #[cfg(feature = "fulltext")]
+        let search_index =
+            std::sync::Mutex::new(Self::build_search_index(&storage, search, &entity_index)?);
+        #[cfg(not(feature = "fulltext"))]
+        let SearchInit = search;
+        #[cfg(feature = "fulltext")]
+        let synonym_index = std::sync::Mutex::new(expansion::SynonymIndex::new(
+            storage.load_predicate_synonym_registry_entries()?,
+        )?);
         #[cfg(feature = "contradiction")]
         let contradiction_detector = {
             let mut det = contradiction::ContradictionDetector::new();
@@ -532,7 +834,12 @@ impl TemporalGraph {
         Ok(Self {
             storage,
             #[cfg(feature = "vector")]
-            vector_index,
+            vector_spaces,
+            #[cfg(feature = "fulltext")]
+            search_index,
+            entity_index: std::sync::Mutex::new(entity_index),
+            #[cfg(feature = "fulltext")]
+            synonym_index,
             #[cfg(feature = "contradiction")]
             contradiction_detector,
             #[cfg(feature = "uncertainty")]
@@ -540,21 +847,144 @@ impl TemporalGraph {
         })
     }
 
+    /// Build the full-text index for every stored fact, preferring a
+    /// checkpoint when one matches the stored fact set and tokenizer.
+    #[cfg(feature = "fulltext")]
+    fn build_search_index(
+        storage: &KronroeStorage,
+        config: SearchConfig,
+        entities: &entity::EntityIndex,
+    ) -> Result<search_index::SearchIndex> {
+        let rows = storage.scan_facts("")?;
+        if let Some(path) = &config.checkpoint {
+            let expected = search_index::fingerprint(rows.iter().map(|row| &row.fact.id));
+            if let Ok(bytes) = std::fs::read(path) {
+                if let Ok(Some(mut index)) = search_index::SearchIndex::from_checkpoint_json(
+                    &bytes,
+                    config.tokenizer.clone(),
+                    expected,
+                ) {
+                    index.set_fuzzy(config.fuzzy);
+                    for row in &rows {
+                        index.set_meta(
+                            row.fact.id.clone(),
+                            search_index::DocumentMeta::of(&row.fact),
+                        );
+                        // The alias predicate set may have changed since the
+                        // checkpoint was written.
+                        let is_alias = Self::is_alias_fact(entities, &row.fact);
+                        if is_alias || index.is_alias_document(&row.fact.id) {
+                            Self::add_to_search_index(&mut index, &row.fact, is_alias);
+                        }
+                    }
+                    return Ok(index);
+                }
+            }
+        }
+
+        let mut index = search_index::SearchIndex::new(config.tokenizer);
+        index.set_fuzzy(config.fuzzy);
+        for row in &rows {
+            let is_alias = Self::is_alias_fact(entities, &row.fact);
+            Self::add_to_search_index(&mut index, &row.fact, is_alias);
+        }
+        Ok(index)
+    }
+
+    #[cfg(feature = "fulltext")]
+    fn is_alias_fact(entities: &entity::EntityIndex, fact: &Fact) -> bool {
+        entities.role(&fact.predicate) == Some(entity::EntityPredicateRole::Alias)
+    }
+
+    #[cfg(feature = "fulltext")]
+    fn add_to_search_index(index: &mut search_index::SearchIndex, fact: &Fact, is_alias: bool) {
+        let alias = match &fact.object {
+            Value::Text(alias) | Value::Entity(alias) if is_alias => Some(alias.as_str()),
+            _ => None,
+        };
+        let fields = Self::search_document_fields(fact);
+        let fields: Vec<(SearchField, &str)> = fields
+            .iter()
+            .map(|(field, text)| (*field, text.as_str()))
+            .collect();
+        index.upsert(
+            fact.id.clone(),
+            &fact.subject,
+            &fields,
+            alias,
+            search_index::DocumentMeta::of(fact),
+        );
+    }
+
+    /// Bring the in-memory entity, full-text, and vector filter indexes up to
+    /// date with a fact that has just been committed to storage.
+    fn index_fact(&self, fact: &Fact) -> Result<()> {
+        #[cfg(feature = "vector")]
+        self.vector_spaces()?.observe(fact);
+        let mut entities = self.entities()?;
+        entities.observe(fact);
+        #[cfg(feature = "fulltext")]
+        {
+            let mut index = self
+                .search_index
+                .lock()
+                .map_err(|_| KronroeError::internal("search index lock poisoned"))?;
+            Self::add_to_search_index(&mut index, fact, Self::is_alias_fact(&entities, fact));
+        }
+        Ok(())
+    }
+
+    fn entities(&self) -> Result<std::sync::MutexGuard<'_, entity::EntityIndex>> {
+        self.entity_index
+            .lock()
+            .map_err(|_| KronroeError::internal("entity index lock poisoned"))
+    }
+
     /// Read every persisted embedding from storage and build a fresh in-memory
-    /// [`VectorIndex`] cache.
+    /// [`VectorIndex`] per embedding space, reusing HNSW graphs from the
+    /// configured checkpoint when they match the stored embeddings.
     ///
     /// Called once from [`init`].  If the database was created before the
     /// `embeddings` table existed (old-format file), `TableDoesNotExist` is
     /// handled gracefully — the method returns an empty index and the table
     /// is created by the preceding `open_table` call in `init`.
     #[cfg(feature = "vector")]
-    fn rebuild_vector_index_from_storage(storage: &KronroeStorage) -> Result<vector::VectorIndex> {
-        let mut idx = vector::VectorIndex::new();
-        for (fact_id, embedding) in storage.embedding_rows()? {
-            idx.insert(fact_id, embedding)?;
+    fn rebuild_vector_index_from_storage(
+        storage: &KronroeStorage,
+        config: VectorConfig,
+    ) -> Result<vector::VectorSpaces> {
+        let checkpoint = config
+            .checkpoint
+            .as_ref()
+            .and_then(|path| std::fs::read(path).ok());
+        let rows = storage
+            .embedding_spaces()?
+            .into_iter()
+            .map(|space| {
+                let rows = storage.embedding_rows(&space)?;
+                Ok((space, rows))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let metrics = storage
+            .load_embedding_space_registry_entries()?
+            .into_iter()
+            .map(|(space, encoded)| Ok((space, Metric::decode(&encoded)?)))
+            .collect::<Result<_>>()?;
+        let mut spaces =
+            vector::VectorSpaces::from_rows(rows, metrics, config, checkpoint.as_deref())?;
+        if !spaces.is_empty() {
+            for row in storage.scan_facts("")? {
+                spaces.observe(&row.fact);
+            }
         }
+        Ok(spaces)
+    }
 
-        Ok(idx)
+    #[cfg(feature = "vector")]
+    fn vector_spaces(&self) -> Result<std::sync::MutexGuard<'_, vector::VectorSpaces>> {
+        self.vector_spaces
+            .lock()
+            .map_err(|_| KronroeError::internal("vector index lock poisoned"))
     }
     fn build_fact(
         subject: &str,
@@ -601,6 +1031,7 @@ impl TemporalGraph {
         let fact = Self::build_fact(subject, predicate, object.into(), valid_from, 1.0, None)?;
         let fact_id = fact.id.clone();
         self.storage.write_fact(&fact)?;
+        self.index_fact(&fact)?;
         Ok(fact_id)
     }
 
@@ -628,6 +1059,7 @@ impl TemporalGraph {
         )?;
         let fact_id = fact.id.clone();
         self.storage.write_fact(&fact)?;
+        self.index_fact(&fact)?;
         Ok(fact_id)
     }
 
@@ -657,6 +1089,7 @@ impl TemporalGraph {
         )?;
         let fact_id = fact.id.clone();
         self.storage.write_fact(&fact)?;
+        self.index_fact(&fact)?;
         Ok(fact_id)
     }
 
@@ -678,110 +1111,591 @@ impl TemporalGraph {
         }
 
         let fact = Self::build_fact(subject, predicate, object.into(), valid_from, 1.0, None)?;
-        self.storage
-            .write_fact_and_idempotency(idempotency_key, &fact)
+        let fact_id = self
+            .storage
+            .write_fact_and_idempotency(idempotency_key, &fact)?;
+        if fact_id == fact.id {
+            self.index_fact(&fact)?;
+        }
+        Ok(fact_id)
+    }
+
+    /// Assert a caller-built [`Fact`], keeping its id, valid-time window,
+    /// confidence, source, and [`metadata`](Fact::metadata).
+    ///
+    /// Use this when a fact carries more than the positional `assert_fact_*`
+    /// variants can express, e.g. qualifiers:
+    ///
+    /// ```rust,no_run
+    /// # use kronroe::{Fact, KronroeTimestamp, TemporalGraph};
+    /// # let db = TemporalGraph::open_in_memory().unwrap();
+    /// let fact = Fact::new("alice", "works_at", "Acme", KronroeTimestamp::now_utc())
+    ///     .with_source("episode:conv-42")
+    ///     .with_metadata("model", "extractor-v2")
+    ///     .with_metadata("turn", 7_i64);
+    /// db.assert_prepared_fact(fact).unwrap();
+    /// ```
+    ///
+    /// Transaction time is owned by the database: `recorded_at` is reset to
+    /// now and `expired_at` is cleared.
+    pub fn assert_prepared_fact(&self, fact: Fact) -> Result<FactId> {
+        let fact = Self::prepare_fact(fact)?;
+        let fact_id = fact.id.clone();
+        self.storage.write_fact(&fact)?;
+        self.index_fact(&fact)?;
+        Ok(fact_id)
+    }
+
+    /// Idempotent variant of [`assert_prepared_fact`].
+    ///
+    /// If `idempotency_key` has already been used, returns the original
+    /// [`FactId`] and ignores `fact`.
+    ///
+    /// [`assert_prepared_fact`]: TemporalGraph::assert_prepared_fact
+    pub fn assert_prepared_fact_idempotent(
+        &self,
+        idempotency_key: &str,
+        fact: Fact,
+    ) -> Result<FactId> {
+        if let Some(existing_id) = self.storage.get_idempotency(idempotency_key)? {
+            return Ok(existing_id);
+        }
+        let fact = Self::prepare_fact(fact)?;
+        let fact_id = self
+            .storage
+            .write_fact_and_idempotency(idempotency_key, &fact)?;
+        if fact_id == fact.id {
+            self.index_fact(&fact)?;
+        }
+        Ok(fact_id)
+    }
+
+    fn prepare_fact(mut fact: Fact) -> Result<Fact> {
+        Self::validate_fact(&mut fact)?;
+        fact.recorded_at = KronroeTimestamp::now_utc();
+        fact.expired_at = None;
+        Ok(fact)
+    }
+
+    /// Check a caller-built fact's confidence and metadata, leaving its
+    /// timestamps alone.
+    fn validate_fact(fact: &mut Fact) -> Result<()> {
+        if !fact.confidence.is_finite() {
+            return Err(KronroeError::search(
+                "confidence must be finite and in [0.0, 1.0], got non-finite value",
+            ));
+        }
+        fact.confidence = fact.confidence.clamp(0.0, 1.0);
+        if fact.metadata.keys().any(|key| key.is_empty()) {
+            return Err(KronroeError::invalid_input(
+                "metadata keys must not be empty",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Get every fact (any time, any validity) whose metadata has `key` set to
+    /// exactly `value` — the equivalent of `where metadata.<key> = <value>`.
+    ///
+    /// Comparison uses [`Value::same_value`], so `Integer` and `Number`
+    /// qualifiers match when they hold the same number.
+    pub fn facts_with_metadata(&self, key: &str, value: &Value) -> Result<Vec<Fact>> {
+        self.scan_prefix("", |fact| {
+            fact.metadata
+                .get(key)
+                .is_some_and(|stored| stored.same_value(value))
+        })
+    }
+
+    /// Aggregate the numeric values of `(subject, predicate)` over a
+    /// valid-time window, optionally rolled up into calendar buckets.
+    ///
+    /// The value at any instant follows [`facts_at`] semantics (latest
+    /// `valid_from` wins when several facts overlap); see [`AggregateStats`]
+    /// for how min/max/avg/last are defined. Non-numeric facts are ignored.
+    /// Like [`facts_at`], `subject` may be any name of an entity, and the
+    /// facts of every merged subject are aggregated together.
+    ///
+    /// ```rust,no_run
+    /// # use kronroe::{AggregateSpec, KronroeTimestamp, TemporalGraph, TimeBucket};
+    /// # let db = TemporalGraph::open_in_memory().unwrap();
+    /// let from: KronroeTimestamp = "2024-01-01T00:00:00Z".parse().unwrap();
+    /// let to: KronroeTimestamp = "2024-07-01T00:00:00Z".parse().unwrap();
+    /// let monthly = db
+    ///     .aggregate("alice", "weight_kg", AggregateSpec::new(from, to).with_bucket(TimeBucket::Month))
+    ///     .unwrap();
+    /// for bucket in &monthly.buckets {
+    ///     println!("{} avg={:?}", bucket.start, bucket.stats.avg);
+    /// }
+    /// ```
+    ///
+    /// [`facts_at`]: TemporalGraph::facts_at
+    pub fn aggregate(
+        &self,
+        subject: &str,
+        predicate: &str,
+        spec: AggregateSpec,
+    ) -> Result<AggregateResult> {
+        let mut facts = Vec::new();
+        for subject in self.query_subjects(subject)? {
+            facts.extend(
+                self.scan_prefix(&format!("{subject}:{predicate}:"), |fact| {
+                    fact.subject == subject && fact.predicate == predicate
+                })?,
+            );
+        }
+        aggregate::aggregate(&facts, &spec)
     }
 
     /// Get all currently valid facts for `(subject, predicate)`.
     ///
     /// A fact is currently valid if both `valid_to` and `expired_at` are `None`.
+    /// `subject` may be any name of an entity (see [`resolve_entity`]); facts
+    /// of every merged subject are returned, canonical subject first.
+    ///
+    /// [`resolve_entity`]: TemporalGraph::resolve_entity
     pub fn current_facts(&self, subject: &str, predicate: &str) -> Result<Vec<Fact>> {
-        Ok(self
-            .storage
-            .current_facts(subject, predicate)?
-            .into_iter()
-            .map(|row| row.fact)
-            .collect())
+        let mut facts = Vec::new();
+        for subject in self.query_subjects(subject)? {
+            facts.extend(
+                self.storage
+                    .current_facts(&subject, predicate)?
+                    .into_iter()
+                    .map(|row| row.fact),
+            );
+        }
+        Ok(facts)
     }
 
     /// Get all facts valid at a given point in time for `(subject, predicate)`.
     ///
     /// Uses the **valid time** axis: queries when something was true in the
-    /// world, regardless of when it was recorded.
+    /// world, regardless of when it was recorded. Like [`current_facts`],
+    /// `subject` may be any name of an entity.
+    ///
+    /// [`current_facts`]: TemporalGraph::current_facts
     pub fn facts_at(
         &self,
         subject: &str,
         predicate: &str,
         at: KronroeTimestamp,
     ) -> Result<Vec<Fact>> {
-        Ok(self
-            .storage
-            .facts_at(subject, predicate, at)?
-            .into_iter()
-            .map(|row| row.fact)
-            .collect())
+        let mut facts = Vec::new();
+        for subject in self.query_subjects(subject)? {
+            facts.extend(
+                self.storage
+                    .facts_at(&subject, predicate, at)?
+                    .into_iter()
+                    .map(|row| row.fact),
+            );
+        }
+        Ok(facts)
     }
 
     /// Get every fact ever recorded for an entity, across all predicates.
+    ///
+    /// `subject` may be any name of the entity; facts of every merged
+    /// subject are returned, canonical subject first.
     pub fn all_facts_about(&self, subject: &str) -> Result<Vec<Fact>> {
-        let prefix = format!("{}:", subject);
-        self.scan_prefix(&prefix, |_| true)
+        let mut facts = Vec::new();
+        for subject in self.query_subjects(subject)? {
+            facts.extend(self.scan_prefix(&format!("{}:", subject), |_| true)?);
+        }
+        Ok(facts)
     }
 
-    /// Full-text search over entity names, aliases, predicates, and string values.
+    /// Resolve any name of an entity — a subject, a subject merged into
+    /// another, or an alias — to its canonical subject.
     ///
-    /// Phase 0 implementation: builds an in-memory index at query time.
-    /// This keeps search self-contained while we validate relevance behavior.
-    pub fn search(&self, query: &str, limit: usize) -> Result<Vec<Fact>> {
-        self.search_scored(query, limit)
-            .map(|scored| scored.into_iter().map(|(fact, _)| fact).collect())
+    /// A name that has facts of its own resolves to its own entity even if
+    /// another subject also uses it as an alias. Aliases are matched
+    /// case-insensitively. Returns `None` for a name the graph does not know,
+    /// and an error when an alias names several distinct entities.
+    ///
+    /// ```rust
+    /// # use kronroe::{KronroeTimestamp, TemporalGraph};
+    /// let db = TemporalGraph::open_in_memory().unwrap();
+    /// let now = KronroeTimestamp::now_utc();
+    /// db.assert_fact("alice", "aka", "Ally", now).unwrap();
+    /// db.assert_fact("a.smith", "works_at", "Acme", now).unwrap();
+    /// db.merge_entities("a.smith", "alice", now).unwrap();
+    ///
+    /// assert_eq!(db.resolve_entity("ally").unwrap().as_deref(), Some("alice"));
+    /// assert_eq!(db.resolve_entity("a.smith").unwrap().as_deref(), Some("alice"));
+    /// assert_eq!(db.current_facts("Ally", "works_at").unwrap().len(), 1);
+    /// ```
+    pub fn resolve_entity(&self, name: &str) -> Result<Option<String>> {
+        Ok(self
+            .resolve_members(name)?
+            .map(|mut members| members.remove(0)))
     }
 
-    /// Full-text search returning facts with Kronroe BM25 relevance scores.
-    ///
-    /// Each result is a `(Fact, f32)` pair where the `f32` is the BM25 score
-    /// from the full-text engine. Higher scores indicate stronger lexical
-    /// relevance to the query. Scores are comparable within a single query's
-    /// result set but not across different queries.
-    pub fn search_scored(&self, query: &str, limit: usize) -> Result<Vec<(Fact, f32)>> {
-        #[cfg(not(feature = "fulltext"))]
-        {
-            let _ = (query, limit);
-            Err(KronroeError::search(
-                "fulltext feature is disabled for this build".to_string(),
-            ))
+    /// Every subject of the entity `name` resolves to, canonical subject
+    /// first. Empty when the name is unknown.
+    pub fn entity_subjects(&self, name: &str) -> Result<Vec<String>> {
+        Ok(self.resolve_members(name)?.unwrap_or_default())
+    }
+
+    /// Live aliases of every subject of the entity `name` resolves to,
+    /// sorted.
+    pub fn entity_aliases(&self, name: &str) -> Result<Vec<String>> {
+        match self.resolve_entity(name)? {
+            Some(canonical) => Ok(self.entities()?.aliases_of(&canonical)),
+            None => Ok(Vec::new()),
         }
+    }
 
-        #[cfg(feature = "fulltext")]
-        {
-            if query.trim().is_empty() || limit == 0 {
-                return Ok(Vec::new());
+    /// Merge the entity `from` into the entity `into`, valid from `at`.
+    ///
+    /// Records a same-as fact from `from`'s canonical subject to `into`'s,
+    /// so both names — and all their aliases — resolve to `into`'s canonical
+    /// subject, and entity queries return the facts of both. No fact is
+    /// rewritten: invalidating the returned fact undoes the merge, and
+    /// earlier history stays as recorded.
+    pub fn merge_entities(&self, from: &str, into: &str, at: KronroeTimestamp) -> Result<FactId> {
+        let from = self
+            .resolve_entity(from)?
+            .unwrap_or_else(|| from.to_string());
+        let into = self
+            .resolve_entity(into)?
+            .unwrap_or_else(|| into.to_string());
+        if from == into {
+            return Err(KronroeError::invalid_input(format!(
+                "'{from}' and '{into}' are already the same entity"
+            )));
+        }
+        let predicate = {
+            let entities = self.entities()?;
+            let predicates = entities.same_as_predicates();
+            if predicates.iter().any(|p| p == DEFAULT_SAME_AS_PREDICATE) {
+                DEFAULT_SAME_AS_PREDICATE.to_string()
+            } else {
+                predicates.into_iter().next().ok_or_else(|| {
+                    KronroeError::invalid_input("no same-as predicate is registered")
+                })?
             }
+        };
+        self.assert_fact(&from, &predicate, Value::Entity(into), at)
+    }
 
-            let facts = self.scan_prefix("", |_| true)?;
-            if facts.is_empty() {
-                return Ok(Vec::new());
-            }
+    /// Register `predicate` as naming an alias of the fact's subject
+    /// (`(alice, nickname, "Ally")`). Aliases are searchable with the
+    /// `alias:` field scope and resolve with [`resolve_entity`].
+    ///
+    /// The registration is persisted to the database and survives reopens.
+    ///
+    /// [`resolve_entity`]: TemporalGraph::resolve_entity
+    pub fn register_alias_predicate(&self, predicate: &str) -> Result<()> {
+        self.set_entity_predicate_role(predicate, Some(entity::EntityPredicateRole::Alias))
+    }
 
-            let aliases_by_subject = self.alias_map(&facts);
-            let docs: Vec<lexical::LexicalDocument> = facts
-                .iter()
-                .map(|fact| {
-                    lexical::LexicalDocument::new(
-                        fact.id.clone(),
-                        Self::search_document_content(
-                            fact,
-                            aliases_by_subject.get(fact.subject.as_str()),
-                        ),
-                    )
-                })
-                .collect();
-            let top_docs = lexical::search_scored(&docs, query, limit);
+    /// Register `predicate` as merging the fact's subject into the subject
+    /// named by its object (`(a.smith, same_person_as, alice)`).
+    ///
+    /// The registration is persisted to the database and survives reopens.
+    pub fn register_same_as_predicate(&self, predicate: &str) -> Result<()> {
+        self.set_entity_predicate_role(predicate, Some(entity::EntityPredicateRole::SameAs))
+    }
 
-            let facts_by_id: HashMap<FactId, Fact> =
-                facts.into_iter().map(|f| (f.id.clone(), f)).collect();
-            let mut results = Vec::new();
+    /// Stop treating `predicate` as an alias or same-as predicate, including
+    /// the defaults. Existing facts are kept and become ordinary facts.
+    pub fn unregister_entity_predicate(&self, predicate: &str) -> Result<()> {
+        self.set_entity_predicate_role(predicate, None)
+    }
 
-            for (fact_id, score) in top_docs {
-                if let Some(fact) = facts_by_id.get(&fact_id) {
-                    results.push((fact.clone(), score));
-                }
+    /// Predicates currently treated as aliases, sorted.
+    pub fn alias_predicates(&self) -> Result<Vec<String>> {
+        Ok(self.entities()?.alias_predicates())
+    }
+
+    /// Predicates currently treated as same-as links, sorted.
+    pub fn same_as_predicates(&self) -> Result<Vec<String>> {
+        Ok(self.entities()?.same_as_predicates())
+    }
+
+    /// Register `synonyms` for `predicate` (e.g. `"employer"` for
+    /// `works_at`), replacing any previous list; an empty list removes them.
+    ///
+    /// Synonyms drive lexical query expansion (see [`expand_query`]). The
+    /// registration is persisted to the database and survives reopens.
+    ///
+    /// [`expand_query`]: TemporalGraph::expand_query
+    #[cfg(feature = "fulltext")]
+    pub fn register_predicate_synonyms<I, S>(&self, predicate: &str, synonyms: I) -> Result<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        if predicate.is_empty() {
+            return Err(KronroeError::invalid_input("predicate must not be empty"));
+        }
+        let synonyms: Vec<String> = synonyms.into_iter().map(Into::into).collect();
+        if synonyms.iter().any(|s| s.trim().is_empty()) {
+            return Err(KronroeError::invalid_input("synonyms must not be empty"));
+        }
+        self.storage
+            .write_predicate_synonym_registry_entry(predicate, &expansion::encode(&synonyms))?;
+        self.synonyms()?.set(predicate, synonyms);
+        Ok(())
+    }
+
+    /// Synonyms registered for `predicate`, in registration order.
+    #[cfg(feature = "fulltext")]
+    pub fn predicate_synonyms(&self, predicate: &str) -> Result<Vec<String>> {
+        Ok(self.synonyms()?.synonyms(predicate))
+    }
+
+    /// Lexical expansion terms for `query`: the other members of every
+    /// predicate synonym group it mentions, then the canonical subjects of
+    /// the aliases it mentions. Terms the query already contains are left
+    /// out. Used by [`HybridSearchParams::expand_query`].
+    ///
+    /// ```rust
+    /// # use kronroe::{KronroeTimestamp, TemporalGraph};
+    /// let db = TemporalGraph::open_in_memory().unwrap();
+    /// db.assert_fact("alice", "aka", "Ally", KronroeTimestamp::now_utc()).unwrap();
+    /// db.register_predicate_synonyms("works_at", ["employer"]).unwrap();
+    ///
+    /// assert_eq!(db.expand_query("Ally's employer").unwrap(), ["works_at", "alice"]);
+    /// ```
+    #[cfg(feature = "fulltext")]
+    pub fn expand_query(&self, query: &str) -> Result<Vec<String>> {
+        let synonyms = self.synonyms()?;
+        let entities = self.entities()?;
+        Ok(expansion::expand(query, &synonyms, &entities))
+    }
+
+    #[cfg(feature = "fulltext")]
+    fn synonyms(&self) -> Result<std::sync::MutexGuard<'_, expansion::SynonymIndex>> {
+        self.synonym_index
+            .lock()
+            .map_err(|_| KronroeError::internal("synonym index lock poisoned"))
+    }
+
+    fn set_entity_predicate_role(
+        &self,
+        predicate: &str,
+        role: Option<entity::EntityPredicateRole>,
+    ) -> Result<()> {
+        if predicate.is_empty() {
+            return Err(KronroeError::invalid_input("predicate must not be empty"));
+        }
+        self.storage.write_entity_predicate_registry_entry(
+            predicate,
+            &entity::EntityPredicateRole::encode(role),
+        )?;
+        let rows = self.storage.scan_facts("")?;
+        let mut entities = self.entities()?;
+        entities.set_role(predicate, role);
+        entities.rebuild(rows.iter().map(|row| &row.fact));
+        #[cfg(feature = "fulltext")]
+        {
+            let mut index = self
+                .search_index
+                .lock()
+                .map_err(|_| KronroeError::internal("search index lock poisoned"))?;
+            for row in rows.iter().filter(|row| row.fact.predicate == predicate) {
+                let is_alias = Self::is_alias_fact(&entities, &row.fact);
+                Self::add_to_search_index(&mut index, &row.fact, is_alias);
+            }
+        }
+        Ok(())
+    }
+
+    /// Subjects of the entity `name` names, canonical first, or `None` if
+    /// the name is unknown.
+    fn resolve_members(&self, name: &str) -> Result<Option<Vec<String>>> {
+        let targets = {
+            let entities = self.entities()?;
+            if entities.is_linked(name) {
+                return Ok(Some(entities.members(name)));
+            }
+            entities.alias_targets(name)
+        };
+        let has_own_facts = !self.storage.scan_facts(&format!("{}:", name))?.is_empty();
+        if has_own_facts {
+            return Ok(Some(vec![name.to_string()]));
+        }
+        match targets.as_slice() {
+            [] => Ok(None),
+            [target] => Ok(Some(self.entities()?.members(target))),
+            _ => Err(KronroeError::invalid_input(format!(
+                "'{name}' is an alias of several entities: {}",
+                targets.join(", ")
+            ))),
+        }
+    }
+
+    /// Subjects to read for an entity query on `name`: its resolved entity's
+    /// subjects, or `name` itself when it is unknown.
+    fn query_subjects(&self, name: &str) -> Result<Vec<String>> {
+        {
+            let entities = self.entities()?;
+            if entities.is_linked(name) {
+                return Ok(entities.members(name));
+            }
+            if entities.alias_targets(name).is_empty() {
+                return Ok(vec![name.to_string()]);
+            }
+        }
+        Ok(self
+            .resolve_members(name)?
+            .unwrap_or_else(|| vec![name.to_string()]))
+    }
+
+    /// Full-text search over entity names, aliases, predicates, and string values.
+    ///
+    /// The index is maintained incrementally as facts are written, so a query
+    /// only touches the postings for its own terms.
+    ///
+    /// Plain words are ranked as a bag of terms. The query may also use
+    /// `"quoted phrases"`, `prefix*` wildcards, `AND` / `OR` / `NOT` (or a
+    /// leading `-`), parentheses, and field scopes (`subject:`, `predicate:`,
+    /// `object:`, `alias:`):
+    ///
+    /// ```text
+    /// alias:ally AND "works at" -object:(acme*)
+    /// ```
+    pub fn search(&self, query: &str, limit: usize) -> Result<Vec<Fact>> {
+        self.search_scored(query, limit)
+            .map(|scored| scored.into_iter().map(|(fact, _)| fact).collect())
+    }
+
+    /// Full-text search returning facts with Kronroe BM25 relevance scores.
+    ///
+    /// Each result is a `(Fact, f32)` pair where the `f32` is the BM25 score
+    /// from the full-text engine. Higher scores indicate stronger lexical
+    /// relevance to the query. Scores are comparable within a single query's
+    /// result set but not across different queries.
+    ///
+    /// Every fact ever written is searched, including invalidated ones; use
+    /// [`Self::search_scored_filtered`] to restrict the candidates.
+    pub fn search_scored(&self, query: &str, limit: usize) -> Result<Vec<(Fact, f32)>> {
+        #[cfg(not(feature = "fulltext"))]
+        {
+            let _ = (query, limit);
+            Err(KronroeError::search(
+                "fulltext feature is disabled for this build".to_string(),
+            ))
+        }
+
+        #[cfg(feature = "fulltext")]
+        self.search_scored_filtered(query, limit, &SearchFilter::new())
+    }
+
+    /// Like [`Self::search_scored`], keeping only facts accepted by `filter`.
+    ///
+    /// The filter runs inside the ranking loop, before the top `limit` hits
+    /// are chosen, so rejected facts never use up the result budget.
+    #[cfg(feature = "fulltext")]
+    pub fn search_scored_filtered(
+        &self,
+        query: &str,
+        limit: usize,
+        filter: &SearchFilter,
+    ) -> Result<Vec<(Fact, f32)>> {
+        if query.trim().is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let hits = self
+            .search_index
+            .lock()
+            .map_err(|_| KronroeError::internal("search index lock poisoned"))?
+            .search(query, limit, filter);
+
+        let mut results = Vec::with_capacity(hits.len());
+        for (fact_id, score) in hits {
+            if let Some(row) = self.storage.fact_by_id(&fact_id)? {
+                results.push((row.fact, score));
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Like [`Self::search_scored_filtered`], also explaining each hit: the
+    /// matched terms with their fields and fuzziness, and highlighted
+    /// snippets of the object text.
+    #[cfg(feature = "fulltext")]
+    pub fn search_scored_explained(
+        &self,
+        query: &str,
+        limit: usize,
+        filter: &SearchFilter,
+    ) -> Result<Vec<(Fact, f32, SearchExplanation)>> {
+        if query.trim().is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let index = self
+            .search_index
+            .lock()
+            .map_err(|_| KronroeError::internal("search index lock poisoned"))?;
+        let hits = index.search(query, limit, filter);
+
+        let mut results = Vec::with_capacity(hits.len());
+        for (fact_id, score) in hits {
+            if let Some(row) = self.storage.fact_by_id(&fact_id)? {
+                let explanation = Self::explain_with_index(&index, query, &row.fact);
+                results.push((row.fact, score, explanation));
             }
+        }
+
+        Ok(results)
+    }
+
+    /// Explain why `fact` matches the full-text `query`, whichever search
+    /// returned it (including hybrid search). Facts that do not match give an
+    /// empty explanation.
+    pub fn explain_search_match(&self, query: &str, fact: &Fact) -> Result<SearchExplanation> {
+        #[cfg(not(feature = "fulltext"))]
+        {
+            let _ = (query, fact);
+            Err(KronroeError::search(
+                "fulltext feature is disabled for this build".to_string(),
+            ))
+        }
 
-            Ok(results)
+        #[cfg(feature = "fulltext")]
+        {
+            let index = self
+                .search_index
+                .lock()
+                .map_err(|_| KronroeError::internal("search index lock poisoned"))?;
+            Ok(Self::explain_with_index(&index, query, fact))
         }
     }
 
+    #[cfg(feature = "fulltext")]
+    fn explain_with_index(
+        index: &search_index::SearchIndex,
+        query: &str,
+        fact: &Fact,
+    ) -> SearchExplanation {
+        let object_text = Self::search_document_fields(fact)
+            .into_iter()
+            .find(|(field, _)| *field == SearchField::Object)
+            .map(|(_, text)| text);
+        index.explain(query, &fact.id, object_text.as_deref())
+    }
+
+    /// Write the full-text index to `path` so a later open with
+    /// [`SearchConfig::with_checkpoint`] can skip re-tokenizing every fact.
+    ///
+    /// The file is written to a temporary sibling and renamed into place.
+    /// Checkpoints are a cache: the index is always kept in sync with writes
+    /// in memory, so call this whenever it is convenient (e.g. on shutdown).
+    #[cfg(feature = "fulltext")]
+    pub fn save_search_checkpoint(&self, path: &str) -> Result<()> {
+        let json = self
+            .search_index
+            .lock()
+            .map_err(|_| KronroeError::internal("search index lock poisoned"))?
+            .to_checkpoint_json();
+        checkpoint::write_checkpoint_file(path, json.as_bytes(), "search")
+    }
+
     /// Invalidate a fact by closing both its valid-time and transaction-time
     /// windows (sets `valid_to` and `expired_at` to `at`).
     ///
@@ -798,6 +1712,7 @@ impl TemporalGraph {
                 fact.valid_to = Some(at);
                 fact.expired_at = Some(at);
                 self.storage.replace_fact_row(&row.key, &fact)?;
+                self.index_fact(&fact)?;
                 Ok(())
             }
             _ => Err(KronroeError::not_found(format!(
@@ -976,15 +1891,21 @@ impl TemporalGraph {
     /// - **Warn**: stores the fact, returns `(fact_id, contradictions)`.
     /// - **Reject**: if contradictions exist, returns
     ///   `Err(ContradictionRejected(...))` and does **not** store the fact.
+    /// - **SupersedeOlder**, **PreferHigherConfidence**,
+    ///   **PreferTrustedSource**: stores the fact and resolves each
+    ///   contradiction in the same write, closing or retracting the losing
+    ///   fact (possibly the new one). Each returned contradiction carries its
+    ///   [`AppliedResolution`]; see [`contradiction_resolutions`] for the
+    ///   persisted audit trail.
     ///
     /// For predicates not registered as singletons, this behaves identically
     /// to [`assert_fact`].
     ///
     /// # Atomicity
     ///
-    /// The contradiction check and the write happen inside a single storage
-    /// backend write boundary. This is race-free: no concurrent writer can insert a conflicting fact
-    /// between the check and the insert.
+    /// The contradiction check, the resolution and the write happen inside a
+    /// single storage backend write boundary. This is race-free: no concurrent
+    /// writer can insert a conflicting fact between the check and the insert.
     ///
     /// Note: the predicate's conflict policy is read from the in-memory
     /// detector *before* opening the write transaction. A concurrent
@@ -996,6 +1917,7 @@ impl TemporalGraph {
     /// Strict singleton enforcement only applies when callers use this method.
     ///
     /// [`assert_fact`]: TemporalGraph::assert_fact
+    /// [`contradiction_resolutions`]: TemporalGraph::contradiction_resolutions
     #[cfg(feature = "contradiction")]
     pub fn assert_fact_checked(
         &self,
@@ -1004,38 +1926,101 @@ impl TemporalGraph {
         object: impl Into<Value>,
         valid_from: KronroeTimestamp,
     ) -> Result<(FactId, Vec<Contradiction>)> {
-        let object = object.into();
+        let fact = Self::build_fact(subject, predicate, object.into(), valid_from, 1.0, None)?;
+        self.write_fact_checked(fact)
+    }
+
+    /// [`assert_fact_checked`] for a caller-built [`Fact`], so confidence and
+    /// source take part in [`ConflictPolicy::PreferHigherConfidence`] and
+    /// [`ConflictPolicy::PreferTrustedSource`]. Transaction time is reset as
+    /// in [`assert_prepared_fact`].
+    ///
+    /// [`assert_fact_checked`]: TemporalGraph::assert_fact_checked
+    /// [`assert_prepared_fact`]: TemporalGraph::assert_prepared_fact
+    #[cfg(feature = "contradiction")]
+    pub fn assert_prepared_fact_checked(&self, fact: Fact) -> Result<(FactId, Vec<Contradiction>)> {
+        self.write_fact_checked(Self::prepare_fact(fact)?)
+    }
 
+    #[cfg(feature = "contradiction")]
+    fn write_fact_checked(&self, fact: Fact) -> Result<(FactId, Vec<Contradiction>)> {
         let det = self
             .contradiction_detector
             .lock()
             .map_err(|e| KronroeError::internal(e.to_string()))?;
-        let policy = det.policy_for(predicate);
-        let is_singleton = det.is_singleton(predicate);
+        let policy = det.policy_for(&fact.predicate);
+        let is_singleton = det.is_singleton(&fact.predicate);
         drop(det); // Release detector lock before I/O.
 
+        let fact_id = fact.id.clone();
         if !is_singleton || matches!(policy, ConflictPolicy::Allow) {
-            let fact_id = self.assert_fact(subject, predicate, object, valid_from)?;
+            self.storage.write_fact(&fact)?;
+            self.index_fact(&fact)?;
             return Ok((fact_id, Vec::new()));
         }
 
-        let fact = Self::build_fact(subject, predicate, object, valid_from, 1.0, None)?;
-        let fact_id = fact.id.clone();
+        // Snapshot source weights so no other lock is taken inside the
+        // storage write boundary.
+        #[cfg(feature = "uncertainty")]
+        let engine = if policy == ConflictPolicy::PreferTrustedSource {
+            Some(
+                self.uncertainty_engine
+                    .lock()
+                    .map_err(|_| KronroeError::internal("uncertainty engine lock poisoned"))?
+                    .clone(),
+            )
+        } else {
+            None
+        };
+        #[cfg(feature = "uncertainty")]
+        let source_weight = |source: Option<&str>| {
+            source
+                .and_then(|source| engine.as_ref()?.source_weight_for(source))
+                .map_or(1.0, |weight| weight.weight)
+        };
+        #[cfg(not(feature = "uncertainty"))]
+        let source_weight = |_: Option<&str>| 1.0;
+
         let reject_on_conflict = matches!(policy, ConflictPolicy::Reject);
-        let contradictions = self.storage.write_fact_with_contradiction_check(
-            subject,
-            predicate,
-            &fact,
+        let (subject, predicate) = (fact.subject.clone(), fact.predicate.clone());
+        let checked = self.storage.write_fact_with_contradiction_check(
+            &subject,
+            &predicate,
             reject_on_conflict,
             |existing| {
                 let det = self
                     .contradiction_detector
                     .lock()
                     .map_err(|e| KronroeError::internal(e.to_string()))?;
-                Ok(det.check_against(&fact, existing))
+                let contradictions = det.check_against(&fact, existing);
+                contradiction::resolve(policy, fact, existing, contradictions, source_weight)
             },
         )?;
-        Ok((fact_id, contradictions))
+        for updated in &checked.updated {
+            self.index_fact(updated)?;
+        }
+        self.index_fact(&checked.fact)?;
+        Ok((fact_id, checked.contradictions))
+    }
+
+    /// Audit trail of the automatic contradiction resolutions applied to
+    /// `(subject, predicate)`, oldest first.
+    #[cfg(feature = "contradiction")]
+    pub fn contradiction_resolutions(
+        &self,
+        subject: &str,
+        predicate: &str,
+    ) -> Result<Vec<ContradictionResolution>> {
+        let prefix = format!("{subject}:{predicate}:");
+        let mut resolutions = self
+            .storage
+            .load_contradiction_resolutions(&prefix)?
+            .iter()
+            .map(|encoded| ContradictionResolution::from_json_str(encoded))
+            .collect::<Result<Vec<_>>>()?;
+        resolutions.retain(|r| r.subject == subject && r.predicate == predicate);
+        resolutions.sort_by_key(|r| r.resolved_at);
+        Ok(resolutions)
     }
 
     /// Assert a fact and durably persist its embedding in a single ACID transaction.
@@ -1058,7 +2043,7 @@ impl TemporalGraph {
     /// Returns [`KronroeError::InvalidEmbedding`] if:
     /// - `embedding` is empty, or
     /// - `embedding.len()` differs from the dimension established by the first
-    ///   embedding ever inserted into this database.
+    ///   embedding ever inserted into the same embedding space.
     ///
     /// [`assert_fact`]: TemporalGraph::assert_fact
     /// [`search_by_vector`]: TemporalGraph::search_by_vector
@@ -1071,32 +2056,244 @@ impl TemporalGraph {
         valid_from: KronroeTimestamp,
         embedding: Vec<f32>,
     ) -> Result<FactId> {
+        self.assert_fact_with_embedding_in_space(
+            subject,
+            predicate,
+            object,
+            valid_from,
+            DEFAULT_EMBEDDING_SPACE,
+            embedding,
+        )
+    }
+
+    /// Like [`assert_fact_with_embedding`], with the embedding stored in the
+    /// named `space` instead of [`DEFAULT_EMBEDDING_SPACE`].
+    ///
+    /// Each space has its own dimension (fixed by its first embedding) and
+    /// its own index, so embeddings from different models can live side by
+    /// side. Space names are 1–64 ASCII letters, digits, `-`, `_`, `.`, or
+    /// `:` (e.g. `"minilm-384"`).
+    ///
+    /// [`assert_fact_with_embedding`]: TemporalGraph::assert_fact_with_embedding
+    #[cfg(feature = "vector")]
+    pub fn assert_fact_with_embedding_in_space(
+        &self,
+        subject: &str,
+        predicate: &str,
+        object: impl Into<Value>,
+        valid_from: KronroeTimestamp,
+        space: &str,
+        embedding: Vec<f32>,
+    ) -> Result<FactId> {
+        let fact = Self::build_fact(subject, predicate, object.into(), valid_from, 1.0, None)?;
+        self.write_fact_with_embedding(fact, space, embedding)
+    }
+
+    /// [`assert_prepared_fact`] with an embedding in
+    /// [`DEFAULT_EMBEDDING_SPACE`], committed together with the fact so a
+    /// failed write leaves neither behind.
+    ///
+    /// [`assert_prepared_fact`]: TemporalGraph::assert_prepared_fact
+    #[cfg(feature = "vector")]
+    pub fn assert_prepared_fact_with_embedding(
+        &self,
+        fact: Fact,
+        embedding: Vec<f32>,
+    ) -> Result<FactId> {
+        let fact = Self::prepare_fact(fact)?;
+        self.write_fact_with_embedding(fact, DEFAULT_EMBEDDING_SPACE, embedding)
+    }
+
+    #[cfg(feature = "vector")]
+    fn write_fact_with_embedding(
+        &self,
+        fact: Fact,
+        space: &str,
+        embedding: Vec<f32>,
+    ) -> Result<FactId> {
+        vector::validate_space_name(space)?;
         if embedding.is_empty() {
             return Err(KronroeError::invalid_embedding(
                 "embedding must not be empty",
             ));
         }
 
-        let fact = Self::build_fact(subject, predicate, object.into(), valid_from, 1.0, None)?;
         let fact_id = fact.id.clone();
-        self.storage.write_fact_with_embedding(&fact, &embedding)?;
+        self.storage
+            .write_fact_with_embedding(&fact, space, &embedding)?;
+        self.index_fact(&fact)?;
 
         // Update the in-memory cache after the durable commit.
         // If the process crashes between commit() and here the cache is rebuilt
         // correctly from storage on the next open().
-        self.vector_index
-            .lock()
-            .map_err(|_| KronroeError::internal("vector index lock poisoned"))?
-            .insert(fact_id.clone(), embedding)?;
+        self.vector_spaces()?.insert(space, &fact, embedding)?;
 
         Ok(fact_id)
     }
 
+    /// Attach or replace the embedding of an existing fact in
+    /// [`DEFAULT_EMBEDDING_SPACE`], e.g. one asserted with
+    /// [`assert_fact`](TemporalGraph::assert_fact) or re-embedded after a
+    /// model change. The write is persisted in the append log.
+    #[cfg(feature = "vector")]
+    pub fn set_embedding(&self, fact_id: impl AsRef<str>, embedding: Vec<f32>) -> Result<()> {
+        let fact_id = self.resolve_fact_id_input(fact_id.as_ref())?;
+        self.backfill_embeddings([(fact_id, embedding)])?;
+        Ok(())
+    }
+
+    /// [`backfill_embeddings_in_space`] for [`DEFAULT_EMBEDDING_SPACE`]:
+    /// attach or replace many embeddings at once, validating all of them
+    /// before writing any. Returns the number written.
+    ///
+    /// [`backfill_embeddings_in_space`]: TemporalGraph::backfill_embeddings_in_space
+    #[cfg(feature = "vector")]
+    pub fn backfill_embeddings(
+        &self,
+        rows: impl IntoIterator<Item = (FactId, Vec<f32>)>,
+    ) -> Result<usize> {
+        self.backfill_embeddings_in_space(DEFAULT_EMBEDDING_SPACE, rows)
+    }
+
+    /// Remove a fact's embedding from [`DEFAULT_EMBEDDING_SPACE`], leaving
+    /// the fact itself untouched; it is no longer returned by vector search.
+    /// Returns false if the fact had no embedding there.
+    #[cfg(feature = "vector")]
+    pub fn remove_embedding(&self, fact_id: impl AsRef<str>) -> Result<bool> {
+        self.remove_embedding_in_space(DEFAULT_EMBEDDING_SPACE, fact_id)
+    }
+
+    /// [`remove_embedding`](TemporalGraph::remove_embedding) for `space`.
+    /// Removing a space's last embedding removes the space, so it may be
+    /// reused with a different dimension.
+    #[cfg(feature = "vector")]
+    pub fn remove_embedding_in_space(&self, space: &str, fact_id: impl AsRef<str>) -> Result<bool> {
+        vector::validate_space_name(space)?;
+        let fact_id = self.resolve_fact_id_input(fact_id.as_ref())?;
+        if self.storage.fact_by_id(&fact_id)?.is_none() {
+            return Err(KronroeError::not_found(format!(
+                "fact id {}",
+                fact_id.as_str()
+            )));
+        }
+        let mut spaces = self.vector_spaces()?;
+        let removed = self.storage.remove_embedding(space, &fact_id)?;
+        spaces.remove(space, &fact_id);
+        Ok(removed)
+    }
+
+    /// Facts without an embedding in [`DEFAULT_EMBEDDING_SPACE`], i.e. the
+    /// ones vector search cannot return, in storage order. Includes
+    /// invalidated facts, which point-in-time searches can still return;
+    /// filter on [`Fact::is_currently_valid`] to backfill current facts only.
+    #[cfg(feature = "vector")]
+    pub fn facts_missing_embeddings(&self) -> Result<Vec<Fact>> {
+        self.facts_missing_embeddings_in_space(DEFAULT_EMBEDDING_SPACE)
+    }
+
+    /// [`facts_missing_embeddings`](TemporalGraph::facts_missing_embeddings)
+    /// for `space`.
+    #[cfg(feature = "vector")]
+    pub fn facts_missing_embeddings_in_space(&self, space: &str) -> Result<Vec<Fact>> {
+        vector::validate_space_name(space)?;
+        let rows = self.storage.scan_facts("")?;
+        let spaces = self.vector_spaces()?;
+        Ok(rows
+            .into_iter()
+            .map(|row| row.fact)
+            .filter(|fact| !spaces.contains(space, &fact.id))
+            .collect())
+    }
+
+    /// Attach or replace the embeddings of existing facts in `space`.
+    ///
+    /// Use it to populate a new space for facts that already exist, or to
+    /// re-embed a space in place after switching embedding models; other
+    /// spaces are untouched. Every row is validated (the fact must exist and
+    /// all embeddings must share the space's dimension) before any is
+    /// written, and each row is persisted in the append log as its own
+    /// record. Returns the number of embeddings written.
+    ///
+    /// Rows are not written atomically: if an I/O error interrupts the batch,
+    /// the rows before it stay persisted and searchable, the rest are not
+    /// written, and the error is returned. Calling again with the same rows
+    /// is safe, since each row replaces the fact's embedding.
+    ///
+    /// Re-embedding a space with a model of a different dimension needs a
+    /// new space name: a space's dimension never changes.
+    #[cfg(feature = "vector")]
+    pub fn backfill_embeddings_in_space(
+        &self,
+        space: &str,
+        rows: impl IntoIterator<Item = (FactId, Vec<f32>)>,
+    ) -> Result<usize> {
+        vector::validate_space_name(space)?;
+        let rows: Vec<(FactId, Vec<f32>)> = rows.into_iter().collect();
+        let facts = rows
+            .iter()
+            .map(|(fact_id, _)| {
+                self.storage
+                    .fact_by_id(fact_id)?
+                    .map(|row| row.fact)
+                    .ok_or_else(|| KronroeError::not_found(format!("fact id {fact_id}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Index each row as soon as its record is appended, so an I/O error
+        // partway through leaves the index matching storage.
+        let mut spaces = self.vector_spaces()?;
+        self.storage.write_embeddings(space, &rows, |position| {
+            spaces.insert(space, &facts[position], rows[position].1.clone())
+        })?;
+        Ok(rows.len())
+    }
+
+    /// Score `space` with `metric` from now on, persisting the choice.
+    ///
+    /// Spaces default to [`Metric::Cosine`]. The metric may be set before
+    /// the space's first embedding or at any later point; an existing index
+    /// is rebuilt under the new metric.
+    #[cfg(feature = "vector")]
+    pub fn set_embedding_metric(&self, space: &str, metric: Metric) -> Result<()> {
+        vector::validate_space_name(space)?;
+        let mut spaces = self.vector_spaces()?;
+        if spaces.metric(space) == metric {
+            return Ok(());
+        }
+        self.storage
+            .write_embedding_space_registry_entry(space, &metric.encode())?;
+        spaces.set_metric(space, metric, self.storage.embedding_rows(space)?)
+    }
+
+    /// The [`Metric`] `space` is scored with.
+    #[cfg(feature = "vector")]
+    pub fn embedding_metric(&self, space: &str) -> Result<Metric> {
+        vector::validate_space_name(space)?;
+        Ok(self.vector_spaces()?.metric(space))
+    }
+
+    /// Names of the embedding spaces that hold at least one embedding, sorted.
+    #[cfg(feature = "vector")]
+    pub fn embedding_spaces(&self) -> Result<Vec<String>> {
+        self.storage.embedding_spaces()
+    }
+
+    /// Atomically write every embedding space's HNSW graph to `path`, for a
+    /// faster open with [`VectorConfig::with_checkpoint`]. Embeddings are not
+    /// duplicated into the checkpoint; they are always read from the database.
+    #[cfg(feature = "vector")]
+    pub fn save_vector_checkpoint(&self, path: &str) -> Result<()> {
+        let bytes = self.vector_spaces()?.to_checkpoint_bytes();
+        checkpoint::write_checkpoint_file(path, &bytes, "vector")
+    }
+
     /// Search for facts semantically similar to `query`, optionally filtered to
     /// those valid at a given point in time.
     ///
-    /// Results are sorted by cosine similarity in descending order (most similar
-    /// first). At most `k` results are returned.
+    /// Results are sorted by the space's [`Metric`] score in descending order
+    /// (most similar first; cosine similarity unless
+    /// [`set_embedding_metric`](TemporalGraph::set_embedding_metric) chose
+    /// another). At most `k` results are returned.
     ///
     /// Pass `at = None` to restrict results to currently-valid facts (both
     /// `valid_to` and `expired_at` are `None`). Pass `at = Some(t)` to use the
@@ -1105,9 +2302,12 @@ impl TemporalGraph {
     /// Only facts that were previously inserted with
     /// [`assert_fact_with_embedding`] can be returned — facts asserted via
     /// [`assert_fact`] have no embedding and are invisible to this method.
+    /// Searches [`DEFAULT_EMBEDDING_SPACE`]; see
+    /// [`search_by_vector_in_space`] for other spaces.
     ///
     /// [`assert_fact_with_embedding`]: TemporalGraph::assert_fact_with_embedding
     /// [`assert_fact`]: TemporalGraph::assert_fact
+    /// [`search_by_vector_in_space`]: TemporalGraph::search_by_vector_in_space
     #[cfg(feature = "vector")]
     pub fn search_by_vector(
         &self,
@@ -1115,59 +2315,116 @@ impl TemporalGraph {
         k: usize,
         at: Option<KronroeTimestamp>,
     ) -> Result<Vec<(Fact, f32)>> {
-        use std::collections::{HashMap, HashSet};
+        self.search_by_vector_in_space(DEFAULT_EMBEDDING_SPACE, query, k, at)
+    }
 
-        // Validate query dimension against the established index dimension.
-        // Return a clear error rather than silently producing zero-scored results
-        // (which `cosine_similarity` would return for mismatched lengths).
-        {
-            let idx = self
-                .vector_index
-                .lock()
-                .map_err(|_| KronroeError::internal("vector index lock poisoned"))?;
-            if let Some(d) = idx.dim() {
-                if query.len() != d {
-                    return Err(KronroeError::invalid_embedding(format!(
-                        "query dimension mismatch: index has dim {d}, query has {}",
-                        query.len()
-                    )));
-                }
-            }
-        }
-
-        // Collect all facts passing the temporal filter, then build an allow-set
-        // for the vector index and a lookup map for hydrating results.
-        let matching_facts = self.scan_prefix("", |f| match at {
-            Some(t) => f.was_valid_at(t),
-            None => f.is_currently_valid(),
-        })?;
-
-        let valid_ids: HashSet<FactId> = matching_facts.iter().map(|f| f.id.clone()).collect();
-        let facts_by_id: HashMap<FactId, Fact> = matching_facts
-            .into_iter()
-            .map(|f| (f.id.clone(), f))
-            .collect();
+    /// [`search_by_vector`] over the embeddings of `space`. A space with no
+    /// embeddings yet returns no results.
+    ///
+    /// [`search_by_vector`]: TemporalGraph::search_by_vector
+    #[cfg(feature = "vector")]
+    pub fn search_by_vector_in_space(
+        &self,
+        space: &str,
+        query: &[f32],
+        k: usize,
+        at: Option<KronroeTimestamp>,
+    ) -> Result<Vec<(Fact, f32)>> {
+        let filter = match at {
+            Some(t) => SearchFilter::new().with_valid_at(t),
+            None => SearchFilter::new().with_current_only(),
+        };
+        self.search_by_vector_in_space_filtered(space, query, k, &filter)
+    }
 
-        let hits = self
-            .vector_index
-            .lock()
-            .map_err(|_| KronroeError::internal("vector index lock poisoned"))?
-            .search(query, k, &valid_ids);
+    /// Vector search keeping only facts accepted by `filter`, which is
+    /// evaluated inside the vector index before the top `k` are chosen.
+    ///
+    /// Unlike [`search_by_vector`], the default filter accepts every fact
+    /// with an embedding, including invalidated ones; use
+    /// [`SearchFilter::with_current_only`] or [`SearchFilter::with_valid_at`]
+    /// for temporal validity. Searches [`DEFAULT_EMBEDDING_SPACE`].
+    ///
+    /// [`search_by_vector`]: TemporalGraph::search_by_vector
+    #[cfg(feature = "vector")]
+    pub fn search_by_vector_filtered(
+        &self,
+        query: &[f32],
+        k: usize,
+        filter: &SearchFilter,
+    ) -> Result<Vec<(Fact, f32)>> {
+        self.search_by_vector_in_space_filtered(DEFAULT_EMBEDDING_SPACE, query, k, filter)
+    }
 
-        let results = hits
-            .into_iter()
-            .filter_map(|(id, score)| facts_by_id.get(&id).map(|f| (f.clone(), score)))
-            .collect();
+    /// [`search_by_vector_filtered`] over the embeddings of `space`.
+    ///
+    /// [`search_by_vector_filtered`]: TemporalGraph::search_by_vector_filtered
+    #[cfg(feature = "vector")]
+    pub fn search_by_vector_in_space_filtered(
+        &self,
+        space: &str,
+        query: &[f32],
+        k: usize,
+        filter: &SearchFilter,
+    ) -> Result<Vec<(Fact, f32)>> {
+        vector::validate_space_name(space)?;
+        let (hits, quantized, metric) = {
+            let spaces = self.vector_spaces()?;
+            let Some(idx) = spaces.get(space) else {
+                return Ok(Vec::new());
+            };
+            // Validate query dimension against the established index dimension.
+            // Return a clear error rather than silently producing zero-scored results
+            // (which every metric returns for mismatched lengths).
+            if let Some(d) = idx.dim() {
+                if query.len() != d {
+                    return Err(KronroeError::invalid_embedding(format!(
+                        "query dimension mismatch: space '{space}' has dim {d}, query has {}",
+                        query.len()
+                    )));
+                }
+            }
+            let shortlist = idx.shortlist_len(k, spaces.rescore_multiplier());
+            let hits = idx.search_where(
+                query,
+                shortlist,
+                filter.current_only,
+                spaces.accepts(filter),
+            );
+            (hits, idx.is_quantized(), idx.metric())
+        };
+        // Quantized scores only pick the shortlist; rank it at full precision.
+        let hits = if quantized {
+            let ids: Vec<FactId> = hits.iter().map(|(id, _)| id.clone()).collect();
+            let full = self.storage.embeddings(space, &ids)?;
+            vector::rescore(query, hits, full, k, metric)
+        } else {
+            hits
+        };
 
+        // Only the hits are read from storage.
+        let mut results = Vec::with_capacity(hits.len());
+        for (id, score) in hits {
+            if let Some(row) = self.storage.fact_by_id(&id)? {
+                results.push((row.fact, score));
+            }
+        }
         Ok(results)
     }
 
-    #[cfg(all(feature = "hybrid-experimental", feature = "vector"))]
-    fn search_ranked(&self, query: &str, limit: usize) -> Result<Vec<(FactId, usize)>> {
+    /// Full-text channel for hybrid search: fact IDs with BM25 scores,
+    /// best first.
+    #[cfg(feature = "hybrid")]
+    fn text_channel(
+        &self,
+        query: &str,
+        limit: usize,
+        filter: &SearchFilter,
+    ) -> Result<Vec<(FactId, f64)>> {
         #[cfg(not(feature = "fulltext"))]
         {
-            let _ = (query, limit);
-            return Ok(Vec::new());
+            let _ = (query, limit, filter);
+            Ok(Vec::new())
         }
 
         #[cfg(feature = "fulltext")]
@@ -1176,38 +2433,44 @@ impl TemporalGraph {
                 return Ok(Vec::new());
             }
 
-            let facts = self.search(query, limit)?;
+            let hits = self.search_scored_filtered(query, limit, filter)?;
 
-            Ok(facts
+            Ok(hits
                 .into_iter()
-                .enumerate()
-                .map(|(rank, fact)| (fact.id, rank))
+                .map(|(fact, score)| (fact.id, score as f64))
                 .collect())
         }
     }
 
-    #[cfg(all(feature = "hybrid-experimental", feature = "vector"))]
-    fn search_by_vector_ranked(
+    /// Vector channel for hybrid search: fact IDs with similarity scores
+    /// under the space's metric, best first.
+    #[cfg(feature = "hybrid")]
+    fn vector_channel(
         &self,
+        space: &str,
         query: &[f32],
         limit: usize,
-        at: Option<KronroeTimestamp>,
-    ) -> Result<Vec<(FactId, usize)>> {
+        filter: &SearchFilter,
+    ) -> Result<Vec<(FactId, f64)>> {
         if limit == 0 {
             return Ok(Vec::new());
         }
-        let hits = self.search_by_vector(query, limit, at)?;
+        let hits = self.search_by_vector_in_space_filtered(space, query, limit, filter)?;
         Ok(hits
             .into_iter()
-            .enumerate()
-            .map(|(rank, (fact, _score))| (fact.id, rank))
+            .map(|(fact, score)| (fact.id, score as f64))
             .collect())
     }
 
-    /// Hybrid retrieval: RRF fusion + two-stage intent-gated reranking.
+    /// Hybrid retrieval: channel fusion + two-stage intent-gated reranking.
     ///
-    /// Combines full-text and vector search channels via Reciprocal Rank Fusion,
-    /// then applies a two-stage reranker (semantic pruning → temporal feasibility).
+    /// Combines full-text and vector search channels with
+    /// [`HybridSearchParams::fusion`]. The default,
+    /// [`FusionStrategy::TwoStageRerank`], fuses with Reciprocal Rank Fusion,
+    /// then applies [`IntentGatedReranker`] (semantic pruning → temporal
+    /// feasibility); the other strategies return the fused order directly.
+    /// A [`HybridSearchParams::reranker`] replaces the reranking step for
+    /// every strategy.
     ///
     /// Callers provide [`TemporalIntent`] and [`TemporalOperator`] to express what
     /// kind of time query they're making; the reranker adapts its scoring strategy
@@ -1216,15 +2479,56 @@ impl TemporalGraph {
     /// For timeless queries, an adaptive vector-dominance path adjusts weights
     /// based on the signal balance in the top candidates. For temporal queries,
     /// the reranker applies feasibility filtering and intent-weighted scoring.
-    #[cfg(all(feature = "hybrid-experimental", feature = "vector"))]
+    #[cfg(feature = "hybrid")]
     pub fn search_hybrid(
         &self,
         text_query: &str,
         vector_query: &[f32],
         params: HybridSearchParams,
         at: Option<KronroeTimestamp>,
+    ) -> Result<Vec<(Fact, HybridScoreBreakdown)>> {
+        self.search_hybrid_multi(&[(text_query, Some(vector_query))], params, at)
+    }
+
+    /// [`search_hybrid`](Self::search_hybrid) over several phrasings of one
+    /// need, in one pass.
+    ///
+    /// Each sub-query runs through the text channel, and through the vector
+    /// channel when it has an embedding; every ranked list is fused at once
+    /// under [`HybridSearchParams::fusion`] (Reciprocal Rank Fusion by
+    /// default), so a fact found by several sub-queries accumulates their
+    /// contributions. With [`HybridSearchParams::expand_query`], each
+    /// sub-query also contributes a lexical list for its
+    /// [`expand_query`](Self::expand_query) terms. Reranking then runs once
+    /// over the fused candidates.
+    ///
+    /// ```rust
+    /// # use kronroe::{HybridSearchParams, KronroeTimestamp, TemporalGraph};
+    /// let db = TemporalGraph::open_in_memory().unwrap();
+    /// let now = KronroeTimestamp::now_utc();
+    /// db.assert_fact_with_embedding("alice", "works_at", "Acme", now, vec![1.0, 0.0]).unwrap();
+    /// db.assert_fact_with_embedding("alice", "lives_in", "Lisbon", now, vec![0.0, 1.0]).unwrap();
+    ///
+    /// let queries: [(&str, Option<&[f32]>); 2] =
+    ///     [("employer", Some(&[1.0, 0.0])), ("Lisbon", None)];
+    /// let hits = db
+    ///     .search_hybrid_multi(&queries, HybridSearchParams::default(), None)
+    ///     .unwrap();
+    /// assert_eq!(hits.len(), 2);
+    /// ```
+    #[cfg(feature = "hybrid")]
+    pub fn search_hybrid_multi(
+        &self,
+        queries: &[(&str, Option<&[f32]>)],
+        params: HybridSearchParams,
+        at: Option<KronroeTimestamp>,
     ) -> Result<Vec<(Fact, HybridScoreBreakdown)>> {
         // ── Validation ──────────────────────────────────────────────────
+        if queries.is_empty() {
+            return Err(KronroeError::search(
+                "search_hybrid: at least one query is required".to_string(),
+            ));
+        }
         if params.k == 0 {
             return Err(KronroeError::search(
                 "search_hybrid: `k` must be >= 1".to_string(),
@@ -1251,49 +2555,42 @@ impl TemporalGraph {
                     .to_string(),
             ));
         }
-
-        // ── Stage 0: Reciprocal Rank Fusion ─────────────────────────────
-        let window = params.candidate_window;
-        let text_ranked = self.search_ranked(text_query, window)?;
-        let vec_ranked = self.search_by_vector_ranked(vector_query, window, at)?;
-
-        let rank_constant = params.rank_constant as f64;
-        let mut by_id: HashMap<FactId, HybridScoreBreakdown> = HashMap::new();
-
-        for (fact_id, rank) in text_ranked {
-            let contrib = params.text_weight as f64 / (rank_constant + (rank + 1) as f64);
-            let entry = by_id.entry(fact_id).or_insert(HybridScoreBreakdown {
-                final_score: 0.0,
-                text_rrf_contrib: 0.0,
-                vector_rrf_contrib: 0.0,
-                temporal_adjustment: 0.0,
-            });
-            entry.text_rrf_contrib += contrib;
-            entry.final_score += contrib;
+        if params
+            .valid_interval
+            .is_some_and(|(start, end)| start >= end)
+        {
+            return Err(KronroeError::search(
+                "search_hybrid: `valid_interval` start must be before its end".to_string(),
+            ));
         }
 
-        for (fact_id, rank) in vec_ranked {
-            let contrib = params.vector_weight as f64 / (rank_constant + (rank + 1) as f64);
-            let entry = by_id.entry(fact_id).or_insert(HybridScoreBreakdown {
-                final_score: 0.0,
-                text_rrf_contrib: 0.0,
-                vector_rrf_contrib: 0.0,
-                temporal_adjustment: 0.0,
-            });
-            entry.vector_rrf_contrib += contrib;
-            entry.final_score += contrib;
+        // ── Stage 0: Channel fusion ─────────────────────────────────────
+        let window = params.candidate_window;
+        let (text_filter, vector_filter) = hybrid::channel_filters(&params, at);
+        let mut text_lists = Vec::new();
+        let mut vector_lists = Vec::new();
+        for (text, vector) in queries {
+            text_lists.push(self.text_channel(text, window, &text_filter)?);
+            #[cfg(feature = "fulltext")]
+            if params.expand_query {
+                let terms = self.expand_query(text)?;
+                if !terms.is_empty() {
+                    let expanded = expansion::to_query(&terms);
+                    text_lists.push(self.text_channel(&expanded, window, &text_filter)?);
+                }
+            }
+            if let Some(vector) = vector {
+                vector_lists.push(self.vector_channel(
+                    &params.vector_space,
+                    vector,
+                    window,
+                    &vector_filter,
+                )?);
+            }
         }
-
-        let mut fused: Vec<(FactId, HybridScoreBreakdown)> = by_id.into_iter().collect();
-
-        // Sort by RRF score descending, FactId ascending for deterministic ties.
-        fused.sort_by(|(a_id, a), (b_id, b)| {
-            b.final_score
-                .partial_cmp(&a.final_score)
-                .unwrap_or(Ordering::Equal)
-                .then_with(|| a_id.cmp(b_id))
-        });
-        fused.truncate(window);
+        let text_lists: Vec<&[(FactId, f64)]> = text_lists.iter().map(Vec::as_slice).collect();
+        let vector_lists: Vec<&[(FactId, f64)]> = vector_lists.iter().map(Vec::as_slice).collect();
+        let fused = hybrid::fuse_lists(&text_lists, &vector_lists, &params);
 
         // Resolve FactIds to full Facts for the reranker.
         let mut resolved = Vec::with_capacity(fused.len());
@@ -1302,29 +2599,187 @@ impl TemporalGraph {
             resolved.push((fact, breakdown));
         }
 
-        // ── Stages 1+2: Two-stage reranker ──────────────────────────────
-        #[cfg(feature = "uncertainty")]
-        let reranked = {
-            let engine = self
-                .uncertainty_engine
-                .lock()
-                .map_err(|_| KronroeError::internal("uncertainty engine lock poisoned"))?;
-            hybrid::rerank_two_stage_with_uncertainty(
-                resolved,
-                params.k,
-                params.intent,
-                params.operator,
-                at,
-                &engine,
-            )
+        let reranker: std::sync::Arc<dyn Reranker> = match (&params.reranker, params.fusion) {
+            (Some(reranker), _) => reranker.clone(),
+            (None, FusionStrategy::TwoStageRerank) => std::sync::Arc::new(IntentGatedReranker),
+            (None, _) => {
+                resolved.truncate(params.k);
+                return Ok(resolved);
+            }
         };
-        #[cfg(not(feature = "uncertainty"))]
-        let reranked =
-            hybrid::rerank_two_stage(resolved, params.k, params.intent, params.operator, at);
 
+        // ── Stages 1+2: Rerank ──────────────────────────────────────────
+        // Snapshot the uncertainty model so no lock is held while a custom
+        // reranker runs.
+        #[cfg(feature = "uncertainty")]
+        let engine = self
+            .uncertainty_engine
+            .lock()
+            .map_err(|_| KronroeError::internal("uncertainty engine lock poisoned"))?
+            .clone();
+        let (intent, operator, interval) = (params.intent, params.operator, params.valid_interval);
+        let temporal_signal = |fact: &Fact| {
+            #[cfg(feature = "uncertainty")]
+            {
+                hybrid::intent_gated_temporal_signal_with_uncertainty(
+                    fact, intent, operator, at, interval, &engine,
+                )
+            }
+            #[cfg(not(feature = "uncertainty"))]
+            {
+                hybrid::intent_gated_temporal_signal(fact, intent, operator, at, interval)
+            }
+        };
+        let ctx = RerankContext::new(queries, &params, at, &temporal_signal);
+        let mut reranked = reranker.rerank(&ctx, resolved)?;
+        reranked.truncate(params.k);
         Ok(reranked)
     }
 
+    /// Detect the temporal constraint expressed in `query`
+    /// (see [`TemporalQuery::parse`]), resolving relative expressions
+    /// against `now` (`None` means the current time).
+    ///
+    /// Event expressions such as "before she moved" are anchored at the
+    /// `valid_from` of the best full-text match for the event clause. When
+    /// no fact matches, the query is treated as timeless but keeps
+    /// `expression` and `event` for auditing.
+    #[cfg(feature = "hybrid")]
+    pub fn detect_temporal(
+        &self,
+        query: &str,
+        now: Option<KronroeTimestamp>,
+    ) -> Result<TemporalQuery> {
+        let now = now.unwrap_or_else(KronroeTimestamp::now_utc);
+        let mut detected = TemporalQuery::parse(query, now);
+        if let Some(event) = detected.event.as_deref() {
+            let anchor = match self.text_channel(event, 1, &SearchFilter::new())?.first() {
+                Some((fact_id, _)) => Some(self.fact_by_id(fact_id)?.valid_from),
+                None => None,
+            };
+            match anchor {
+                Some(at) => detected.at = Some(at),
+                None => {
+                    detected.intent = TemporalIntent::Timeless;
+                    detected.operator = TemporalOperator::Current;
+                }
+            }
+        }
+        Ok(detected)
+    }
+
+    /// [`search_hybrid`](Self::search_hybrid) with the temporal intent,
+    /// operator and anchor time detected from `text_query` by
+    /// [`detect_temporal`](Self::detect_temporal).
+    ///
+    /// When a temporal expression is found it overrides
+    /// `params.intent`/`params.operator`, and a detected period ("in Q3
+    /// 2024") becomes `params.valid_interval` unless one is already set;
+    /// otherwise `params` is used as given. Returns the detection alongside the results so callers can
+    /// audit what was inferred.
+    #[cfg(feature = "hybrid")]
+    pub fn search_hybrid_auto(
+        &self,
+        text_query: &str,
+        vector_query: &[f32],
+        mut params: HybridSearchParams,
+        now: Option<KronroeTimestamp>,
+    ) -> Result<(TemporalQuery, Vec<(Fact, HybridScoreBreakdown)>)> {
+        let detected = self.detect_temporal(text_query, now)?;
+        let mut at = now;
+        if detected.is_temporal() {
+            params.intent = detected.intent;
+            params.operator = detected.operator;
+            at = detected.at.or(now);
+            if detected.intent == TemporalIntent::HistoricalInterval {
+                params.valid_interval = params.valid_interval.or(detected.interval);
+            }
+        }
+        let hits = self.search_hybrid(text_query, vector_query, params, at)?;
+        Ok((detected, hits))
+    }
+
+    // -----------------------------------------------------------------------
+    // RDF interchange
+    // -----------------------------------------------------------------------
+
+    /// Export every fact (including expired history) as RDF.
+    ///
+    /// Each fact becomes an RDF-star statement node annotated with its valid
+    /// time, transaction time, confidence, and source; currently valid facts
+    /// are additionally asserted as plain triples. See [`RdfOptions`] for IRI
+    /// minting.
+    #[cfg(feature = "rdf")]
+    pub fn export_rdf(&self, format: RdfFormat, options: &RdfOptions) -> Result<String> {
+        let facts = self.scan_prefix("", |_| true)?;
+        Ok(rdf::write_facts(&facts, format, options))
+    }
+
+    /// Import facts from RDF produced by [`export_rdf`] or another RDF-star tool.
+    ///
+    /// Annotated statements keep their fact IDs and timestamps; facts whose ID
+    /// already exists are skipped. Plain triples without annotations become
+    /// new facts valid from now, unless a currently valid fact with the same
+    /// subject, predicate, and object already exists. Re-importing the same
+    /// export is therefore a no-op.
+    ///
+    /// Imported facts are validated like [`assert_prepared_fact`]. With the
+    /// `contradiction` feature, currently valid facts also go through the
+    /// singleton check of [`assert_prepared_fact_checked`], so a `Reject`
+    /// policy stops the import at the first conflicting fact (facts before it
+    /// stay written) and automatic policies resolve conflicts as they would
+    /// for an assert. Returns the number of facts written.
+    ///
+    /// [`export_rdf`]: TemporalGraph::export_rdf
+    /// [`assert_prepared_fact`]: TemporalGraph::assert_prepared_fact
+    /// [`assert_prepared_fact_checked`]: TemporalGraph::assert_prepared_fact_checked
+    #[cfg(feature = "rdf")]
+    pub fn import_rdf(
+        &self,
+        input: &str,
+        format: RdfFormat,
+        options: &RdfOptions,
+    ) -> Result<usize> {
+        let parsed = rdf::parse_document(input, format, options, KronroeTimestamp::now_utc())?;
+        let mut imported = 0;
+        for mut fact in parsed.annotated {
+            Self::validate_fact(&mut fact)?;
+            if self.storage.fact_by_id(&fact.id)?.is_some() {
+                continue;
+            }
+            self.write_imported_fact(fact)?;
+            imported += 1;
+        }
+        for mut fact in parsed.plain {
+            Self::validate_fact(&mut fact)?;
+            let prefix = format!("{}:{}:", fact.subject, fact.predicate);
+            let duplicate = self.storage.scan_facts(&prefix)?.into_iter().any(|row| {
+                row.fact.is_currently_valid()
+                    && row.fact.subject == fact.subject
+                    && row.fact.predicate == fact.predicate
+                    && row.fact.object.same_value(&fact.object)
+            });
+            if duplicate {
+                continue;
+            }
+            self.write_imported_fact(fact)?;
+            imported += 1;
+        }
+        Ok(imported)
+    }
+
+    /// Write one validated imported fact, keeping its timestamps.
+    #[cfg(feature = "rdf")]
+    fn write_imported_fact(&self, fact: Fact) -> Result<()> {
+        #[cfg(feature = "contradiction")]
+        if fact.is_currently_valid() {
+            self.write_fact_checked(fact)?;
+            return Ok(());
+        }
+        self.storage.write_fact(&fact)?;
+        self.index_fact(&fact)
+    }
+
     // Internal: scan facts table, filter by prefix, apply predicate.
     fn scan_prefix(&self, prefix: &str, predicate: impl Fn(&Fact) -> bool) -> Result<Vec<Fact>> {
         let mut results = Vec::new();
@@ -1337,39 +2792,58 @@ impl TemporalGraph {
         Ok(results)
     }
 
+    /// Build the lexical document for a fact, one text run per field.
+    /// Subject aliases are added by the search index, which tracks them
+    /// separately.
+    ///
+    /// The predicate is indexed a second time with `_` replaced by spaces,
+    /// so `works_at` also matches the query `works at`.
+    ///
+    /// Object indexing rules by variant:
+    /// - `Text` / `Entity`: the full string.
+    /// - `Timestamp`: the calendar date (`YYYY-MM-DD`, so year, month, and day
+    ///   are each searchable tokens).
+    /// - `Integer`: the decimal digits (IDs, years, counts).
+    /// - `Json`: every object key and string leaf, recursively.
+    /// - `Number`, `Boolean`, `Bytes`: not indexed.
     #[cfg(feature = "fulltext")]
-    fn alias_map(&self, facts: &[Fact]) -> HashMap<String, Vec<String>> {
-        let mut aliases_by_subject: HashMap<String, Vec<String>> = HashMap::new();
-        for fact in facts {
-            let is_alias_predicate = fact.predicate == "alias"
-                || fact.predicate == "has_alias"
-                || fact.predicate == "aka";
-            if is_alias_predicate {
-                if let Value::Text(alias) | Value::Entity(alias) = &fact.object {
-                    aliases_by_subject
-                        .entry(fact.subject.clone())
-                        .or_default()
-                        .push(alias.clone());
-                }
+    fn search_document_fields(fact: &Fact) -> Vec<(SearchField, String)> {
+        let mut fields = vec![
+            (SearchField::Subject, fact.subject.clone()),
+            (SearchField::Predicate, fact.predicate.clone()),
+        ];
+        match &fact.object {
+            Value::Text(v) | Value::Entity(v) => fields.push((SearchField::Object, v.clone())),
+            Value::Timestamp(t) => fields.push((SearchField::Object, t.date_ymd())),
+            Value::Integer(n) => fields.push((SearchField::Object, n.to_string())),
+            Value::Json(json) => {
+                let mut terms = Vec::new();
+                Self::collect_json_search_terms(json, &mut terms);
+                fields.push((SearchField::Object, terms.join(" ")));
             }
+            Value::Number(_) | Value::Boolean(_) | Value::Bytes(_) => {}
         }
-        aliases_by_subject
+        fields.push((SearchField::Predicate, fact.predicate.replace('_', " ")));
+        fields
     }
 
     #[cfg(feature = "fulltext")]
-    fn search_document_content(fact: &Fact, aliases: Option<&Vec<String>>) -> String {
-        let mut content_parts = vec![fact.subject.as_str(), fact.predicate.as_str()];
-        if let Some(aliases) = aliases {
-            for alias in aliases {
-                content_parts.push(alias.as_str());
+    fn collect_json_search_terms<'a>(json: &'a JsonValue, out: &mut Vec<&'a str>) {
+        match json {
+            JsonValue::Str(s) => out.push(s.as_str()),
+            JsonValue::Array(items) => {
+                for item in items {
+                    Self::collect_json_search_terms(item, out);
+                }
             }
+            JsonValue::Object(map) => {
+                for (key, item) in map {
+                    out.push(key.as_str());
+                    Self::collect_json_search_terms(item, out);
+                }
+            }
+            JsonValue::Null | JsonValue::Bool(_) | JsonValue::Number(_) => {}
         }
-        if let Value::Text(v) | Value::Entity(v) = &fact.object {
-            content_parts.push(v.as_str());
-        }
-
-        let normalized_predicate = fact.predicate.replace('_', " ");
-        format!("{} {}", content_parts.join(" "), normalized_predicate)
     }
 
     // -----------------------------------------------------------------------
@@ -1677,6 +3151,104 @@ mod tests {
         assert!(subjects.iter().all(|&s| s == "alice"));
     }
 
+    #[test]
+    fn entity_queries_resolve_aliases_and_merged_subjects() {
+        let db = TemporalGraph::open_in_memory().unwrap();
+        let t1 = dt("2024-01-01T00:00:00Z");
+        let t2 = dt("2024-06-01T00:00:00Z");
+
+        db.assert_fact("alice", "works_at", "Acme", t1).unwrap();
+        db.assert_fact("alice", "aka", "Ally", t1).unwrap();
+        db.assert_fact("a.smith", "lives_in", "London", t1).unwrap();
+        db.assert_fact("a.smith", "alias", "Smithy", t1).unwrap();
+        db.assert_fact("bob", "alias", "B", t1).unwrap();
+        db.assert_fact("robert", "alias", "b", t1).unwrap();
+
+        assert_eq!(db.resolve_entity("ALLY").unwrap().as_deref(), Some("alice"));
+        assert_eq!(db.resolve_entity("nobody").unwrap(), None);
+        assert!(db.resolve_entity("b").is_err(), "alias of two entities");
+        assert_eq!(db.current_facts("ally", "works_at").unwrap().len(), 1);
+        assert!(db.current_facts("ally", "lives_in").unwrap().is_empty());
+
+        let merge = db.merge_entities("smithy", "ally", t2).unwrap();
+        assert_eq!(
+            db.resolve_entity("a.smith").unwrap().as_deref(),
+            Some("alice")
+        );
+        assert_eq!(
+            db.entity_subjects("Smithy").unwrap(),
+            vec!["alice", "a.smith"]
+        );
+        assert_eq!(db.entity_aliases("alice").unwrap(), vec!["Ally", "Smithy"]);
+        let lives_in = db.current_facts("ally", "lives_in").unwrap();
+        assert_eq!(lives_in.len(), 1);
+        assert_eq!(lives_in[0].subject, "a.smith", "facts keep their subject");
+        assert_eq!(db.facts_at("alice", "lives_in", t1).unwrap().len(), 1);
+        assert!(db.all_facts_about("a.smith").unwrap()[0].subject == "alice");
+        db.assert_fact("alice", "score", 10_i64, t1).unwrap();
+        db.assert_fact("a.smith", "score", 20_i64, t2).unwrap();
+        let spec = AggregateSpec::new(t1, dt("2024-12-01T00:00:00Z"));
+        let stats = db.aggregate("ally", "score", spec).unwrap().overall;
+        assert_eq!(stats.count, 2, "alias reaches the merged subject's facts");
+        assert_eq!(stats.max, Some(20.0));
+        assert!(db.merge_entities("alice", "a.smith", t2).is_err());
+
+        db.invalidate_fact(&merge, dt("2024-07-01T00:00:00Z"))
+            .unwrap();
+        assert_eq!(
+            db.resolve_entity("smithy").unwrap().as_deref(),
+            Some("a.smith")
+        );
+        assert!(db.current_facts("alice", "lives_in").unwrap().is_empty());
+        assert_eq!(
+            db.current_facts("a.smith", "same_as").unwrap().len(),
+            0,
+            "the merge stays in history only"
+        );
+        assert_eq!(db.facts_at("a.smith", "same_as", t2).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn entity_predicates_are_registrable_and_persist() {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap().to_string();
+        let now = KronroeTimestamp::now_utc();
+        {
+            let db = TemporalGraph::open(&path).unwrap();
+            db.assert_fact("alice", "nickname", "Ally", now).unwrap();
+            db.assert_fact("alice", "aka", "Al", now).unwrap();
+            db.assert_fact("a.smith", "same_person_as", "alice", now)
+                .unwrap();
+            assert_eq!(db.resolve_entity("ally").unwrap(), None);
+
+            db.register_alias_predicate("nickname").unwrap();
+            db.register_same_as_predicate("same_person_as").unwrap();
+            db.unregister_entity_predicate("aka").unwrap();
+            assert!(db.register_alias_predicate("").is_err());
+        }
+
+        let db = TemporalGraph::open(&path).unwrap();
+        assert_eq!(
+            db.alias_predicates().unwrap(),
+            vec!["alias", "has_alias", "nickname"]
+        );
+        assert_eq!(
+            db.same_as_predicates().unwrap(),
+            vec!["same_as", "same_person_as"]
+        );
+        assert_eq!(db.resolve_entity("ally").unwrap().as_deref(), Some("alice"));
+        assert_eq!(db.resolve_entity("al").unwrap(), None);
+        assert_eq!(
+            db.resolve_entity("a.smith").unwrap().as_deref(),
+            Some("alice")
+        );
+        #[cfg(feature = "fulltext")]
+        {
+            assert_eq!(db.search("alias:ally", 10).unwrap().len(), 2);
+            assert!(db.search("alias:al", 10).unwrap().is_empty());
+        }
+    }
+
     #[test]
     fn value_types() {
         let (db, _tmp) = open_temp_db();
@@ -1791,7 +3363,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(all(feature = "hybrid-experimental", feature = "vector"))]
+    #[cfg(feature = "hybrid")]
     fn hybrid_search_breakdown_sums_correctly() {
         let db = TemporalGraph::open_in_memory().unwrap();
         let t = KronroeTimestamp::now_utc();
@@ -1822,18 +3394,130 @@ mod tests {
         assert!(!hits.is_empty(), "hybrid search should return results");
 
         for (_fact, breakdown) in &hits {
-            let expected = breakdown.text_rrf_contrib
-                + breakdown.vector_rrf_contrib
-                + breakdown.temporal_adjustment;
+            let expected = breakdown.text_rrf_contrib + breakdown.vector_rrf_contrib;
             assert!(
                 (breakdown.final_score - expected).abs() < 1e-9,
                 "breakdown must sum to final_score"
             );
+            assert_eq!(breakdown.temporal_adjustment, 0.0, "timeless query");
+            assert!(matches!(breakdown.stage1, Stage1Decision::Kept { .. }));
+        }
+        assert!(
+            hits.windows(2)
+                .all(|w| w[0].1.rerank_score >= w[1].1.rerank_score),
+            "results are ordered by rerank_score"
+        );
+
+        // An empty embedding space leaves only the text channel.
+        let params = HybridSearchParams {
+            k: 5,
+            vector_space: "unused".to_string(),
+            ..HybridSearchParams::default()
+        };
+        let hits = db
+            .search_hybrid("Rust", &[1.0, 0.0, 0.0], params, None)
+            .unwrap();
+        assert!(!hits.is_empty());
+        assert!(hits.iter().all(|(_, b)| b.vector_rrf_contrib == 0.0));
+    }
+
+    #[test]
+    #[cfg(all(feature = "hybrid", feature = "fulltext"))]
+    fn hybrid_search_fusion_strategies_order_by_fused_score() {
+        let db = TemporalGraph::open_in_memory().unwrap();
+        let t = KronroeTimestamp::now_utc();
+        db.assert_fact_with_embedding("alice", "bio", "Rust compilers", t, vec![1.0, 0.0])
+            .unwrap();
+        db.assert_fact_with_embedding("bob", "bio", "Rust and Python", t, vec![0.6, 0.8])
+            .unwrap();
+        db.assert_fact_with_embedding("carol", "bio", "gardening", t, vec![0.0, 1.0])
+            .unwrap();
+
+        for fusion in FusionStrategy::ALL {
+            let params = HybridSearchParams {
+                k: 3,
+                fusion,
+                ..HybridSearchParams::default()
+            };
+            let hits = db.search_hybrid("Rust", &[1.0, 0.0], params, None).unwrap();
+            assert_eq!(hits[0].0.subject, "alice", "{fusion:?}");
+            if fusion != FusionStrategy::TwoStageRerank {
+                assert_eq!(hits.len(), 3, "{fusion:?}");
+                assert!(
+                    hits.windows(2)
+                        .all(|w| w[0].1.final_score >= w[1].1.final_score),
+                    "{fusion:?} must return the fused order"
+                );
+                assert!(hits.iter().all(|(_, b)| b.rerank_score == b.final_score
+                    && b.stage1 == Stage1Decision::NotApplied));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "hybrid")]
+    fn hybrid_search_uses_custom_reranker() {
+        struct SubjectDescending;
+        impl Reranker for SubjectDescending {
+            fn rerank(
+                &self,
+                ctx: &RerankContext<'_>,
+                mut candidates: Vec<(Fact, HybridScoreBreakdown)>,
+            ) -> Result<Vec<(Fact, HybridScoreBreakdown)>> {
+                assert_eq!(ctx.text_query, "Rust");
+                assert_eq!(ctx.k, 2);
+                assert!(candidates
+                    .iter()
+                    .all(|(f, _)| ctx.temporal_signal(f) == 0.0));
+                candidates.sort_by(|a, b| b.0.subject.cmp(&a.0.subject));
+                Ok(candidates)
+            }
+        }
+
+        let db = TemporalGraph::open_in_memory().unwrap();
+        let t = KronroeTimestamp::now_utc();
+        db.assert_fact_with_embedding("alice", "bio", "Rust compilers", t, vec![1.0, 0.0])
+            .unwrap();
+        db.assert_fact_with_embedding("bob", "bio", "Rust and Python", t, vec![0.6, 0.8])
+            .unwrap();
+        db.assert_fact_with_embedding("carol", "bio", "gardening", t, vec![0.0, 1.0])
+            .unwrap();
+
+        for fusion in FusionStrategy::ALL {
+            let params = HybridSearchParams {
+                k: 2,
+                fusion,
+                reranker: Some(std::sync::Arc::new(SubjectDescending)),
+                ..HybridSearchParams::default()
+            };
+            let hits = db.search_hybrid("Rust", &[1.0, 0.0], params, None).unwrap();
+            let subjects: Vec<&str> = hits.iter().map(|(f, _)| f.subject.as_str()).collect();
+            assert_eq!(subjects, ["carol", "bob"], "{fusion:?}");
         }
+
+        // The built-in reranker set explicitly matches the default.
+        let default = db
+            .search_hybrid("Rust", &[1.0, 0.0], HybridSearchParams::default(), None)
+            .unwrap();
+        let explicit = db
+            .search_hybrid(
+                "Rust",
+                &[1.0, 0.0],
+                HybridSearchParams {
+                    reranker: Some(std::sync::Arc::new(IntentGatedReranker)),
+                    ..HybridSearchParams::default()
+                },
+                None,
+            )
+            .unwrap();
+        let ids = |hits: &[(Fact, HybridScoreBreakdown)]| {
+            hits.iter().map(|(f, _)| f.id.clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&default), ids(&explicit));
     }
 
     #[test]
-    #[cfg(all(feature = "hybrid-experimental", feature = "vector"))]
+    #[cfg(feature = "hybrid")]
     fn hybrid_search_rejects_zero_rank_constant() {
         let db = TemporalGraph::open_in_memory().unwrap();
         db.assert_fact_with_embedding(
@@ -1857,7 +3541,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(all(feature = "hybrid-experimental", feature = "vector"))]
+    #[cfg(feature = "hybrid")]
     fn search_hybrid_returns_reranked_results() {
         let db = TemporalGraph::open_in_memory().unwrap();
         let t = KronroeTimestamp::now_utc();
@@ -1908,7 +3592,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(all(feature = "hybrid-experimental", feature = "vector"))]
+    #[cfg(feature = "hybrid")]
     fn search_hybrid_temporal_query_filters_infeasible() {
         let db = TemporalGraph::open_in_memory().unwrap();
         let jan2023 = dt("2023-01-01T00:00:00Z");
@@ -1949,47 +3633,260 @@ mod tests {
             matches!(first_object, Value::Text(s) if s == "BetaCorp"),
             "BetaCorp should rank first for jun-2023 AsOf query, got {first_object:?}"
         );
-    }
 
-    #[test]
-    #[cfg(all(feature = "hybrid-experimental", feature = "vector"))]
-    fn search_hybrid_default_params_match_eval_winner() {
-        let params = HybridSearchParams::default();
-        assert_eq!(params.rank_constant, 60);
-        assert!((params.text_weight - 0.8).abs() < f32::EPSILON);
-        assert!((params.vector_weight - 0.2).abs() < f32::EPSILON);
-        assert_eq!(params.candidate_window, 50);
-        assert_eq!(params.intent, TemporalIntent::Timeless);
-        assert_eq!(params.operator, TemporalOperator::Current);
+        // The breakdown explains the order: feasibility signal, its weighted
+        // adjustment, and the composite rerank score.
+        let breakdown = hits[0].1;
+        assert!(breakdown.temporal_signal > 0.0);
+        assert!((breakdown.temporal_adjustment - 0.22 * breakdown.temporal_signal).abs() < 1e-12);
+        assert!(breakdown.rerank_score > breakdown.temporal_adjustment);
+        assert_eq!(breakdown.stage1.window(), Some(14));
+        assert!(hits.iter().all(|(_, b)| b.temporal_signal > 0.0));
     }
 
     #[test]
-    #[cfg(all(
-        feature = "hybrid-experimental",
-        feature = "vector",
-        feature = "fulltext"
-    ))]
-    fn search_ranked_matches_search_scored_order() {
-        let db = TemporalGraph::open_in_memory().unwrap();
+    #[cfg(all(feature = "hybrid", feature = "fulltext"))]
+    fn search_hybrid_multi_fuses_sub_queries_and_expands() {
+        let (db, file) = open_temp_db();
         let now = KronroeTimestamp::now_utc();
+        let acme = db
+            .assert_fact_with_embedding("alice", "works_at", "Acme", now, vec![1.0, 0.0])
+            .unwrap();
+        let lisbon = db
+            .assert_fact_with_embedding("alice", "lives_in", "Lisbon", now, vec![0.0, 1.0])
+            .unwrap();
+        db.assert_fact("bob", "likes", "tea", now).unwrap();
+        let params = HybridSearchParams {
+            fusion: FusionStrategy::Rrf,
+            ..HybridSearchParams::default()
+        };
+        let ids = |queries: &[(&str, Option<&[f32]>)], params: &HybridSearchParams| {
+            db.search_hybrid_multi(queries, params.clone(), None)
+                .unwrap()
+                .into_iter()
+                .map(|(fact, _)| fact.id)
+                .collect::<Vec<_>>()
+        };
 
-        db.assert_fact("alice", "works_at", "Acme Corp", now)
+        // Lisbon ranks first in both channels of its sub-query; Acme only
+        // in the text channel of its own.
+        let queries: [(&str, Option<&[f32]>); 2] = [("Acme", None), ("Lisbon", Some(&[0.0, 1.0]))];
+        assert_eq!(ids(&queries, &params), [lisbon.clone(), acme.clone()]);
+        let hits = db
+            .search_hybrid_multi(&queries, params.clone(), None)
             .unwrap();
-        db.assert_fact("bob", "works_at", "Acme Industries", now)
+        assert!((hits[0].1.text_rrf_contrib - f64::from(0.8f32) / 61.0).abs() < 1e-12);
+        assert!((hits[1].1.vector_rrf_contrib - f64::from(0.2f32) / 62.0).abs() < 1e-12);
+
+        // One sub-query is exactly `search_hybrid`.
+        let single = db
+            .search_hybrid("Lisbon", &[0.0, 1.0], params.clone(), None)
             .unwrap();
-        db.assert_fact("carol", "works_at", "BetaCorp", now)
+        let multi = db
+            .search_hybrid_multi(&[("Lisbon", Some(&[0.0, 1.0]))], params.clone(), None)
             .unwrap();
+        assert_eq!(single.len(), multi.len());
+        for ((a, sa), (b, sb)) in single.iter().zip(&multi) {
+            assert_eq!(a.id, b.id);
+            assert_eq!(sa.final_score, sb.final_score);
+        }
 
-        let ranked = db.search_ranked("Acme", 10).unwrap();
-        let scored = db.search_scored("Acme", 10).unwrap();
+        // Expansion adds the predicate for a registered synonym.
+        let expanding = HybridSearchParams {
+            expand_query: true,
+            ..params.clone()
+        };
+        let employer: [(&str, Option<&[f32]>); 1] = [("employer", None)];
+        assert!(ids(&employer, &expanding).is_empty());
+        db.register_predicate_synonyms("works_at", ["employer"])
+            .unwrap();
+        assert_eq!(ids(&employer, &expanding), [acme]);
+        assert!(ids(&employer, &params).is_empty());
+        assert!(db.search_hybrid_multi(&[], params, None).is_err());
 
-        let ranked_ids: Vec<FactId> = ranked.into_iter().map(|(id, _)| id).collect();
-        let scored_ids: Vec<FactId> = scored.into_iter().map(|(fact, _)| fact.id).collect();
-        assert_eq!(
-            ranked_ids, scored_ids,
-            "hybrid lexical ranking input should match search_scored ordering"
-        );
-    }
+        drop(db);
+        let db = TemporalGraph::open(file.path().to_str().unwrap()).unwrap();
+        assert_eq!(db.predicate_synonyms("works_at").unwrap(), ["employer"]);
+        db.register_predicate_synonyms("works_at", Vec::<String>::new())
+            .unwrap();
+        assert!(db.predicate_synonyms("works_at").unwrap().is_empty());
+        assert!(db.expand_query("employer").unwrap().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "hybrid")]
+    fn search_hybrid_valid_interval_uses_overlap_and_bounds() {
+        let db = TemporalGraph::open_in_memory().unwrap();
+        let emb = vec![1.0, 0.0];
+        let gamma = db
+            .assert_fact_with_embedding(
+                "alice",
+                "works_at",
+                "Gamma",
+                dt("2022-01-01T00:00:00Z"),
+                emb.clone(),
+            )
+            .unwrap();
+        db.invalidate_fact(&gamma, dt("2023-01-01T00:00:00Z"))
+            .unwrap();
+        let beta = db
+            .assert_fact_with_embedding(
+                "alice",
+                "works_at",
+                "BetaCorp",
+                dt("2023-01-01T00:00:00Z"),
+                emb.clone(),
+            )
+            .unwrap();
+        db.invalidate_fact(&beta, dt("2024-08-01T00:00:00Z"))
+            .unwrap();
+        let acme = db
+            .assert_fact_with_embedding(
+                "alice",
+                "works_at",
+                "Acme",
+                dt("2024-08-01T00:00:00Z"),
+                emb.clone(),
+            )
+            .unwrap();
+
+        let search = |operator, interval, known_at| {
+            let params = HybridSearchParams {
+                k: 5,
+                intent: TemporalIntent::HistoricalInterval,
+                operator,
+                valid_interval: Some(interval),
+                known_at,
+                ..HybridSearchParams::default()
+            };
+            let mut ids: Vec<FactId> = db
+                .search_hybrid("works_at", &emb, params, None)
+                .unwrap()
+                .into_iter()
+                .map(|(fact, _)| fact.id)
+                .collect();
+            ids.sort();
+            ids
+        };
+        let sorted = |mut ids: Vec<FactId>| {
+            ids.sort();
+            ids
+        };
+        let q3_2024 = (dt("2024-07-01T00:00:00Z"), dt("2024-10-01T00:00:00Z"));
+        let year_2023 = (dt("2023-01-01T00:00:00Z"), dt("2024-01-01T00:00:00Z"));
+
+        // Both employers overlap Q3 2024, though neither spans all of it.
+        assert_eq!(
+            search(TemporalOperator::During, q3_2024, None),
+            sorted(vec![beta.clone(), acme.clone()])
+        );
+        assert_eq!(
+            search(TemporalOperator::Before, q3_2024, None),
+            sorted(vec![gamma.clone(), beta.clone()])
+        );
+        assert_eq!(search(TemporalOperator::After, year_2023, None), vec![acme]);
+        // Nothing was recorded in 2000.
+        assert!(search(
+            TemporalOperator::During,
+            q3_2024,
+            Some(dt("2000-01-01T00:00:00Z"))
+        )
+        .is_empty());
+
+        let params = HybridSearchParams {
+            valid_interval: Some((q3_2024.1, q3_2024.0)),
+            ..HybridSearchParams::default()
+        };
+        assert!(db.search_hybrid("works_at", &emb, params, None).is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "hybrid", feature = "fulltext"))]
+    fn search_hybrid_auto_detects_temporal_expressions() {
+        let db = TemporalGraph::open_in_memory().unwrap();
+        let jan2023 = dt("2023-01-01T00:00:00Z");
+        let jan2024 = dt("2024-01-01T00:00:00Z");
+        let now = dt("2026-10-18T00:00:00Z");
+        let beta = db
+            .assert_fact_with_embedding("alice", "works_at", "BetaCorp", jan2023, vec![1.0, 0.0])
+            .unwrap();
+        db.invalidate_fact(&beta, jan2024).unwrap();
+        db.assert_fact_with_embedding("alice", "works_at", "Acme", jan2024, vec![1.0, 0.0])
+            .unwrap();
+        db.assert_fact("alice", "relocated_to", "Lisbon", jan2024)
+            .unwrap();
+
+        let params = HybridSearchParams {
+            k: 5,
+            ..HybridSearchParams::default()
+        };
+        let (detected, hits) = db
+            .search_hybrid_auto(
+                "where did alice work in 2023",
+                &[1.0, 0.0],
+                params.clone(),
+                Some(now),
+            )
+            .unwrap();
+        assert_eq!(detected.intent, TemporalIntent::HistoricalInterval);
+        assert_eq!(detected.expression.as_deref(), Some("in 2023"));
+        assert_eq!(hits[0].0.id, beta);
+
+        let detected = db
+            .detect_temporal("where did alice work before she relocated", Some(now))
+            .unwrap();
+        assert_eq!(detected.operator, TemporalOperator::Before);
+        assert_eq!(detected.event.as_deref(), Some("relocated"));
+        assert_eq!(detected.at, Some(jan2024));
+
+        // An event nothing matches falls back to timeless.
+        let detected = db
+            .detect_temporal("alice's job before the zeppelin", Some(now))
+            .unwrap();
+        assert!(!detected.is_temporal());
+        assert_eq!(detected.event.as_deref(), Some("zeppelin"));
+
+        let (detected, _) = db
+            .search_hybrid_auto("alice employer", &[1.0, 0.0], params, Some(now))
+            .unwrap();
+        assert_eq!(detected, TemporalQuery::default());
+    }
+
+    #[test]
+    #[cfg(feature = "hybrid")]
+    fn search_hybrid_default_params_match_eval_winner() {
+        let params = HybridSearchParams::default();
+        assert_eq!(params.rank_constant, 60);
+        assert!((params.text_weight - 0.8).abs() < f32::EPSILON);
+        assert!((params.vector_weight - 0.2).abs() < f32::EPSILON);
+        assert_eq!(params.candidate_window, 50);
+        assert_eq!(params.intent, TemporalIntent::Timeless);
+        assert_eq!(params.operator, TemporalOperator::Current);
+    }
+
+    #[test]
+    #[cfg(all(feature = "hybrid", feature = "fulltext"))]
+    fn text_channel_matches_search_scored_order() {
+        let db = TemporalGraph::open_in_memory().unwrap();
+        let now = KronroeTimestamp::now_utc();
+
+        db.assert_fact("alice", "works_at", "Acme Corp", now)
+            .unwrap();
+        db.assert_fact("bob", "works_at", "Acme Industries", now)
+            .unwrap();
+        db.assert_fact("carol", "works_at", "BetaCorp", now)
+            .unwrap();
+
+        let ranked = db.text_channel("Acme", 10, &SearchFilter::new()).unwrap();
+        let scored = db.search_scored("Acme", 10).unwrap();
+
+        let ranked_ids: Vec<FactId> = ranked.into_iter().map(|(id, _)| id).collect();
+        let scored_ids: Vec<FactId> = scored.into_iter().map(|(fact, _)| fact.id).collect();
+        assert_eq!(
+            ranked_ids, scored_ids,
+            "hybrid lexical ranking input should match search_scored ordering"
+        );
+    }
 
     #[test]
     fn half_open_interval_boundary_at_valid_from() {
@@ -2101,6 +3998,307 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "fulltext")]
+    fn search_index_tracks_writes_and_survives_reopen() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("search.kronroe");
+        let path_str = path.to_str().unwrap();
+        let now = KronroeTimestamp::now_utc();
+
+        let (works_at, invalidated) = {
+            let db = TemporalGraph::open(path_str).unwrap();
+            assert!(db.search("zephyr", 10).unwrap().is_empty());
+
+            let works_at = db.assert_fact("alice", "works_at", "Zephyr", now).unwrap();
+            let invalidated = db.assert_fact("bob", "works_at", "Zephyr", now).unwrap();
+            db.invalidate_fact(&invalidated, now).unwrap();
+            db.assert_fact_idempotent("evt-1", "carol", "alias", "ace", now)
+                .unwrap();
+            db.assert_fact_idempotent("evt-1", "carol", "alias", "ignored", now)
+                .unwrap();
+
+            let hits = db.search("zephyr", 10).unwrap();
+            assert_eq!(hits.len(), 2);
+            let invalidated_hit = hits.iter().find(|f| f.id == invalidated).unwrap();
+            assert_eq!(invalidated_hit.expired_at, Some(now));
+            assert!(db.search("ignored", 10).unwrap().is_empty());
+            (works_at, invalidated)
+        };
+
+        let db = TemporalGraph::open(path_str).unwrap();
+        let ids: Vec<FactId> = db
+            .search("zephyr", 10)
+            .unwrap()
+            .into_iter()
+            .map(|f| f.id)
+            .collect();
+        assert!(ids.contains(&works_at) && ids.contains(&invalidated));
+        assert_eq!(db.search("ace", 10).unwrap().len(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "fulltext")]
+    fn alias_asserted_later_reindexes_existing_facts() {
+        let db = TemporalGraph::open_in_memory().unwrap();
+        let now = KronroeTimestamp::now_utc();
+
+        let works_at = db.assert_fact("alice", "works_at", "Acme", now).unwrap();
+        db.assert_fact("bob", "works_at", "Acme", now).unwrap();
+        assert!(db.search("ally", 10).unwrap().is_empty());
+
+        db.assert_fact("alice", "aka", "ally", now).unwrap();
+        let hits = db.search("ally", 10).unwrap();
+        assert_eq!(hits.len(), 2, "both alice facts gain the alias");
+        assert!(hits.iter().any(|f| f.id == works_at));
+        assert!(hits.iter().all(|f| f.subject == "alice"));
+    }
+
+    #[test]
+    #[cfg(feature = "fulltext")]
+    fn search_config_tokenizer_applies_to_facts_and_queries() {
+        let db = TemporalGraph::open_in_memory_with_search_config(
+            SearchConfig::new().with_tokenizer(StandardTokenizer::english()),
+        )
+        .unwrap();
+        let now = KronroeTimestamp::now_utc();
+
+        let works = db
+            .assert_fact("alice", "works_at", "Café Zürich", now)
+            .unwrap();
+        let tokyo = db.assert_fact("bob", "lives_in", "東京都", now).unwrap();
+
+        let ids = |query: &str| -> Vec<FactId> {
+            db.search(query, 10)
+                .unwrap()
+                .into_iter()
+                .map(|f| f.id)
+                .collect()
+        };
+        assert_eq!(ids("working at the cafe"), vec![works.clone()]);
+        assert_eq!(ids("zurich"), vec![works]);
+        assert_eq!(ids("東京"), vec![tokyo]);
+        assert!(
+            ids("the at").is_empty(),
+            "stopword-only queries match nothing"
+        );
+
+        let plain = TemporalGraph::open_in_memory().unwrap();
+        plain
+            .assert_fact("alice", "works_at", "Café Zürich", now)
+            .unwrap();
+        assert!(plain.search("working", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "fulltext")]
+    fn search_query_syntax_scopes_fields_and_phrases() {
+        let db = TemporalGraph::open_in_memory().unwrap();
+        let now = KronroeTimestamp::now_utc();
+
+        let alice_ny = db
+            .assert_fact("alice", "lives_in", "New York", now)
+            .unwrap();
+        let bob_york = db
+            .assert_fact("bob", "lives_in", "York, New Jersey border", now)
+            .unwrap();
+        let carol = db.assert_fact("carol", "knows", "alice", now).unwrap();
+        let alias = db.assert_fact("alice", "alias", "ally", now).unwrap();
+
+        let ids = |query: &str| -> Vec<FactId> {
+            let mut ids: Vec<FactId> = db
+                .search(query, 10)
+                .unwrap()
+                .into_iter()
+                .map(|f| f.id)
+                .collect();
+            ids.sort();
+            ids
+        };
+        let sorted = |mut ids: Vec<FactId>| {
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(ids("\"new york\""), vec![alice_ny.clone()]);
+        assert_eq!(ids("object:alice"), vec![carol.clone()]);
+        assert_eq!(
+            ids("subject:alice"),
+            sorted(vec![alice_ny.clone(), alias.clone()])
+        );
+        assert_eq!(ids("alias:ally AND lives_in"), vec![alice_ny.clone()]);
+        assert_eq!(ids("yor* -subject:alice"), vec![bob_york.clone()]);
+        assert_eq!(
+            ids("predicate:\"lives in\" OR predicate:know*"),
+            sorted(vec![alice_ny, bob_york, carol])
+        );
+    }
+
+    #[cfg(feature = "fulltext")]
+    fn scored_ids(db: &TemporalGraph, query: &str) -> Vec<(FactId, f32)> {
+        db.search_scored(query, 10)
+            .unwrap()
+            .into_iter()
+            .map(|(fact, score)| (fact.id, score))
+            .collect()
+    }
+
+    #[test]
+    #[cfg(feature = "fulltext")]
+    fn search_scored_explained_reports_fields_fuzziness_and_snippets() {
+        let db = TemporalGraph::open_in_memory().unwrap();
+        let now = KronroeTimestamp::now_utc();
+        let works = db
+            .assert_fact("alice", "works_at", "Acme Rocket Works", now)
+            .unwrap();
+        db.assert_fact("alice", "alias", "ally", now).unwrap();
+
+        let hits = db
+            .search_scored_explained("ally AND rockte", 10, &SearchFilter::new())
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        let (fact, score, explanation) = &hits[0];
+        assert_eq!(fact.id, works);
+        assert!(*score > 0.0);
+
+        let summary: Vec<(&str, &str, SearchField, bool)> = explanation
+            .matches
+            .iter()
+            .map(|m| {
+                (
+                    m.query_term.as_str(),
+                    m.term.as_str(),
+                    m.field,
+                    m.is_fuzzy(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("ally", "ally", SearchField::Alias, false),
+                ("rockte", "rocket", SearchField::Object, true),
+            ]
+        );
+        assert_eq!(explanation.snippets, vec!["Acme **Rocket** Works"]);
+
+        // Facts found some other way can be explained too.
+        let explained = db.explain_search_match("\"works at\" acm*", fact).unwrap();
+        let fields: Vec<(&str, SearchField)> = explained
+            .matches
+            .iter()
+            .map(|m| (m.term.as_str(), m.field))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("works", SearchField::Predicate),
+                ("at", SearchField::Predicate),
+                ("acme", SearchField::Object),
+            ]
+        );
+        assert_eq!(explained.snippets, vec!["**Acme** Rocket Works"]);
+        assert_eq!(
+            db.explain_search_match("zebra", fact).unwrap(),
+            SearchExplanation::default()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fulltext")]
+    fn search_filters_skip_stale_hits_before_the_limit_and_after_reopen() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("filtered.kronroe");
+        let path_str = path.to_str().unwrap();
+        let checkpoint = tmp.path().join("filtered.search");
+        let checkpoint_str = checkpoint.to_str().unwrap();
+        let config = || SearchConfig::new().with_checkpoint(checkpoint_str);
+        let now = KronroeTimestamp::now_utc();
+
+        let current = {
+            let db = TemporalGraph::open_with_search_config(path_str, config()).unwrap();
+            for employer in ["ACME (Acme Labs)", "ACME (Acme Corp)", "ACME (Acme Inc)"] {
+                let id = db.assert_fact("alice", "works_at", employer, now).unwrap();
+                db.invalidate_fact(&id, now).unwrap();
+            }
+            let current = db.assert_fact("alice", "works_at", "Acme", now).unwrap();
+            assert_ne!(db.search_scored("acme", 1).unwrap()[0].0.id, current);
+            db.save_search_checkpoint(checkpoint_str).unwrap();
+            current
+        };
+
+        // Reopening from the checkpoint still knows which facts were
+        // invalidated.
+        let db = TemporalGraph::open_with_search_config(path_str, config()).unwrap();
+        let filter = SearchFilter::new().with_current_only();
+        let hits = db.search_scored_filtered("acme", 1, &filter).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].0.id, current);
+        assert!(db
+            .search_scored_filtered("acme", 10, &filter.with_subject_prefix("bob"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "fulltext")]
+    fn search_checkpoint_is_used_only_when_it_matches_stored_facts() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("checkpointed.kronroe");
+        let path_str = path.to_str().unwrap();
+        let checkpoint = tmp.path().join("checkpointed.search");
+        let checkpoint_str = checkpoint.to_str().unwrap();
+        let now = KronroeTimestamp::now_utc();
+
+        // A missing checkpoint falls back to a rebuild.
+        let expected = {
+            let db = TemporalGraph::open_with_search_config(
+                path_str,
+                SearchConfig::new().with_checkpoint(checkpoint_str),
+            )
+            .unwrap();
+            db.assert_fact("alice", "works_at", "Acme", now).unwrap();
+            db.assert_fact("alice", "alias", "ally", now).unwrap();
+            db.save_search_checkpoint(checkpoint_str).unwrap();
+            scored_ids(&db, "ally acme")
+        };
+
+        {
+            let db = TemporalGraph::open_with_search_config(
+                path_str,
+                SearchConfig::new().with_checkpoint(checkpoint_str),
+            )
+            .unwrap();
+            assert_eq!(scored_ids(&db, "ally acme"), expected);
+            // Written after the checkpoint: the checkpoint is now stale.
+            db.assert_fact("bob", "works_at", "Acme", now).unwrap();
+        }
+
+        let db = TemporalGraph::open_with_search_config(
+            path_str,
+            SearchConfig::new().with_checkpoint(checkpoint_str),
+        )
+        .unwrap();
+        let subjects: Vec<String> = db
+            .search("acme", 10)
+            .unwrap()
+            .into_iter()
+            .map(|f| f.subject)
+            .collect();
+        assert!(subjects.contains(&"bob".to_string()));
+
+        std::fs::write(&checkpoint, b"not json").unwrap();
+        let db_after_corruption = {
+            drop(db);
+            TemporalGraph::open_with_search_config(
+                path_str,
+                SearchConfig::new().with_checkpoint(checkpoint_str),
+            )
+            .unwrap()
+        };
+        assert_eq!(db_after_corruption.search("acme", 10).unwrap().len(), 2);
+    }
+
     #[test]
     #[cfg(feature = "fulltext")]
     fn search_orders_exact_ties_by_fact_id() {
@@ -2195,73 +4393,500 @@ mod tests {
 
     #[test]
     #[cfg(feature = "vector")]
-    fn vector_dim_mismatch_returns_error() {
-        let db = TemporalGraph::open_in_memory().unwrap();
+    fn vector_dim_mismatch_returns_error() {
+        let db = TemporalGraph::open_in_memory().unwrap();
+        let now = KronroeTimestamp::now_utc();
+
+        // Establish dim = 3.
+        db.assert_fact_with_embedding("alice", "interest", "Rust", now, vec![1.0, 0.0, 0.0])
+            .unwrap();
+
+        // Subsequent insert with dim = 2 must return Err, not panic.
+        let result =
+            db.assert_fact_with_embedding("alice", "interest", "Python", now, vec![0.0, 1.0]);
+        assert!(
+            result.as_ref().is_err_and(|e| e.is_invalid_embedding()),
+            "dim mismatch must return InvalidEmbedding, not panic"
+        );
+
+        // The failed insert must not corrupt the index: the original fact is still
+        // retrievable.
+        let results = db.search_by_vector(&[1.0, 0.0, 0.0], 5, None).unwrap();
+        assert_eq!(results.len(), 1, "failed insert must leave index intact");
+    }
+
+    #[test]
+    #[cfg(feature = "vector")]
+    fn vector_search_wrong_query_dim_returns_error() {
+        let db = TemporalGraph::open_in_memory().unwrap();
+        let now = KronroeTimestamp::now_utc();
+
+        // Insert a dim-3 embedding to establish the index dimension.
+        db.assert_fact_with_embedding("alice", "interest", "Rust", now, vec![1.0, 0.0, 0.0])
+            .unwrap();
+
+        // Query with dim=2 must return Err, not silently score 0.0.
+        let result = db.search_by_vector(&[1.0, 0.0], 5, None);
+        assert!(
+            result.as_ref().is_err_and(|e| e.is_invalid_embedding()),
+            "wrong query dimension must return InvalidEmbedding"
+        );
+    }
+
+    /// Embeddings are persisted to the default backend; the vector index must survive a
+    /// close-and-reopen without any re-population by the caller.
+    #[test]
+    #[cfg(feature = "vector")]
+    fn vector_index_survives_reopen() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("durability.kronroe");
+        let path_str = path.to_str().unwrap();
+        let now = KronroeTimestamp::now_utc();
+
+        // Write two embeddings, then drop the database.
+        {
+            let db = TemporalGraph::open(path_str).unwrap();
+            db.assert_fact_with_embedding("alice", "interest", "Rust", now, vec![1.0, 0.0, 0.0])
+                .unwrap();
+            db.assert_fact_with_embedding("alice", "interest", "Python", now, vec![0.0, 1.0, 0.0])
+                .unwrap();
+        } // db dropped — file closed
+
+        // Reopen: the index must be rebuilt from storage automatically.
+        let db = TemporalGraph::open(path_str).unwrap();
+        let results = db.search_by_vector(&[1.0, 0.0, 0.0], 2, None).unwrap();
+        assert_eq!(results.len(), 2, "both embeddings must survive reopen");
+        assert!(
+            matches!(&results[0].0.object, Value::Text(s) if s == "Rust"),
+            "most similar fact after reopen should be Rust"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "vector")]
+    fn hnsw_vector_index_round_trips_through_a_checkpoint() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("hnsw.kronroe");
+        let path_str = path.to_str().unwrap();
+        let checkpoint = tmp.path().join("hnsw.vectors");
+        let config = VectorConfig::new()
+            .with_hnsw(HnswConfig::new().with_exact_threshold(0))
+            .with_checkpoint(checkpoint.to_str().unwrap());
+        let now = KronroeTimestamp::now_utc();
+
+        {
+            let db = TemporalGraph::open_with_vector_config(path_str, config.clone()).unwrap();
+            for (name, embedding) in [
+                ("Rust", vec![1.0, 0.0, 0.0]),
+                ("Python", vec![0.0, 1.0, 0.0]),
+                ("Go", vec![0.0, 0.0, 1.0]),
+            ] {
+                db.assert_fact_with_embedding("alice", "interest", name, now, embedding)
+                    .unwrap();
+            }
+            db.save_vector_checkpoint(checkpoint.to_str().unwrap())
+                .unwrap();
+        }
+        assert!(checkpoint.exists());
+
+        let db = TemporalGraph::open_with_vector_config(path_str, config).unwrap();
+        let results = db.search_by_vector(&[0.0, 0.9, 0.1], 1, None).unwrap();
+        assert!(matches!(&results[0].0.object, Value::Text(s) if s == "Python"));
+    }
+
+    #[test]
+    #[cfg(feature = "vector")]
+    fn named_embedding_spaces_are_independent_and_persist() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("spaces.kronroe");
+        let path_str = path.to_str().unwrap();
+        let checkpoint = tmp.path().join("spaces.vectors");
+        let now = KronroeTimestamp::now_utc();
+
+        let (rust, plain) = {
+            let db = TemporalGraph::open(path_str).unwrap();
+            let rust = db
+                .assert_fact_with_embedding("alice", "interest", "Rust", now, vec![1.0, 0.0, 0.0])
+                .unwrap();
+            db.assert_fact_with_embedding_in_space(
+                "alice",
+                "interest",
+                "Go",
+                now,
+                "minilm-2",
+                vec![0.0, 1.0],
+            )
+            .unwrap();
+            let plain = db.assert_fact("alice", "interest", "Zig", now).unwrap();
+
+            // Each space keeps its own dimension.
+            let err = db
+                .assert_fact_with_embedding_in_space("bob", "x", "y", now, "minilm-2", vec![1.0])
+                .unwrap_err();
+            assert!(err.is_invalid_embedding());
+            assert!(db
+                .search_by_vector_in_space("minilm-2", &[1.0, 0.0, 0.0], 5, None)
+                .is_err());
+            assert!(db
+                .search_by_vector_in_space("bad space", &[1.0], 5, None)
+                .is_err());
+            assert!(db
+                .search_by_vector_in_space("unused", &[1.0], 5, None)
+                .unwrap()
+                .is_empty());
+
+            // Backfill existing facts, re-embedding one in place.
+            let written = db
+                .backfill_embeddings_in_space(
+                    "minilm-2",
+                    [
+                        (rust.clone(), vec![1.0, 0.0]),
+                        (plain.clone(), vec![0.6, 0.8]),
+                    ],
+                )
+                .unwrap();
+            assert_eq!(written, 2);
+            let err = db
+                .backfill_embeddings_in_space("minilm-2", [(FactId::new(), vec![1.0, 0.0])])
+                .unwrap_err();
+            assert!(err.is_not_found());
+            let err = db
+                .backfill_embeddings_in_space("minilm-2", [(rust.clone(), vec![1.0])])
+                .unwrap_err();
+            assert!(err.is_invalid_embedding());
+            db.backfill_embeddings_in_space("minilm-2", [(plain.clone(), vec![0.8, 0.6])])
+                .unwrap();
+            db.save_vector_checkpoint(checkpoint.to_str().unwrap())
+                .unwrap();
+            (rust, plain)
+        };
+
+        let check = |db: &TemporalGraph| {
+            assert_eq!(db.embedding_spaces().unwrap(), vec!["default", "minilm-2"]);
+            let default = db.search_by_vector(&[1.0, 0.0, 0.0], 5, None).unwrap();
+            assert_eq!(default.len(), 1, "backfill leaves other spaces alone");
+            assert_eq!(default[0].0.id, rust);
+
+            let hits = db
+                .search_by_vector_in_space("minilm-2", &[1.0, 0.0], 5, None)
+                .unwrap();
+            let ids: Vec<&FactId> = hits.iter().map(|(fact, _)| &fact.id).collect();
+            assert_eq!(ids.len(), 3);
+            assert_eq!(ids[0], &rust);
+            assert_eq!(ids[1], &plain);
+            assert!((hits[1].1 - 0.8).abs() < 1e-6, "re-embedded vector is used");
+        };
+        let config = VectorConfig::new().with_checkpoint(checkpoint.to_str().unwrap());
+        let db = TemporalGraph::open_with_vector_config(path_str, config.clone()).unwrap();
+        check(&db);
+        db.storage.compact().unwrap();
+        drop(db);
+        check(&TemporalGraph::open_with_vector_config(path_str, config).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "vector")]
+    fn vector_search_filters_inside_the_index_without_scanning_facts() {
+        use crate::storage_observability::{StorageEvent, StorageObserver, StorageOperation};
+        use std::sync::{Arc, Mutex};
+
+        /// Largest number of fact rows read by a single storage call.
+        #[derive(Default)]
+        struct Scans(Mutex<usize>);
+        impl StorageObserver for Scans {
+            fn on_event(&self, event: StorageEvent) {
+                if event.operation == StorageOperation::ScanFacts {
+                    let mut max = self.0.lock().unwrap();
+                    *max = (*max).max(event.rows_scanned);
+                }
+            }
+        }
+
+        let scans = Arc::new(Scans::default());
+        let storage = KronroeStorage::open_in_memory_with_observer(scans.clone()).unwrap();
+        let db = TemporalGraph::init(storage, Default::default(), VectorConfig::new()).unwrap();
+        let now = KronroeTimestamp::now_utc();
+        let hour = crate::KronroeSpan::hours(1);
+
+        let acme = db
+            .assert_fact_with_source("alice", "works_at", "Acme", now - hour, 0.9, "hr")
+            .unwrap();
+        let blog = db
+            .assert_fact_with_source("alice", "wrote", "Blog", now, 0.4, "crawler")
+            .unwrap();
+        let beta = db
+            .assert_fact_with_source("bob", "works_at", "Beta", now, 0.8, "hr")
+            .unwrap();
+        db.backfill_embeddings_in_space(
+            DEFAULT_EMBEDDING_SPACE,
+            [
+                (acme.clone(), vec![1.0, 0.0]),
+                (blog.clone(), vec![0.9, 0.1]),
+                (beta.clone(), vec![0.8, 0.2]),
+            ],
+        )
+        .unwrap();
+        db.invalidate_fact(&acme, now).unwrap();
+        *scans.0.lock().unwrap() = 0;
+
+        let ids = |hits: Vec<(Fact, f32)>| -> Vec<FactId> {
+            hits.into_iter().map(|(fact, _)| fact.id).collect()
+        };
+        let query = [1.0, 0.0];
+        // Invalidation clears the fact's bit in the validity bitmap.
+        assert_eq!(
+            ids(db.search_by_vector(&query, 5, None).unwrap()),
+            [blog.clone(), beta.clone()]
+        );
+        assert_eq!(
+            ids(db
+                .search_by_vector(&query, 5, Some(now - crate::KronroeSpan::minutes(30)))
+                .unwrap()),
+            vec![acme.clone()]
+        );
+        let filtered =
+            |filter: SearchFilter| ids(db.search_by_vector_filtered(&query, 5, &filter).unwrap());
+        assert_eq!(
+            filtered(SearchFilter::new()),
+            [acme.clone(), blog.clone(), beta.clone()]
+        );
+        assert_eq!(
+            filtered(SearchFilter::new().with_source("hr")),
+            [acme.clone(), beta.clone()]
+        );
+        assert_eq!(
+            filtered(
+                SearchFilter::new()
+                    .with_current_only()
+                    .with_predicates(["works_at"])
+            ),
+            vec![beta.clone()]
+        );
+        assert_eq!(
+            filtered(
+                SearchFilter::new()
+                    .with_subject_prefix("ali")
+                    .with_min_confidence(0.5)
+            ),
+            vec![acme.clone()]
+        );
+        assert!(filtered(SearchFilter::new().with_subject_prefix("carol")).is_empty());
+        assert_eq!(
+            *scans.0.lock().unwrap(),
+            1,
+            "vector search reads only its hits, one fact at a time"
+        );
+        drop(db);
+    }
+
+    #[test]
+    #[cfg(feature = "vector")]
+    fn embeddings_can_be_set_replaced_and_removed_on_existing_facts() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("embeddings.kronroe");
+        let path_str = path.to_str().unwrap();
         let now = KronroeTimestamp::now_utc();
 
-        // Establish dim = 3.
-        db.assert_fact_with_embedding("alice", "interest", "Rust", now, vec![1.0, 0.0, 0.0])
-            .unwrap();
+        let (rust, go, zig) = {
+            let db = TemporalGraph::open(path_str).unwrap();
+            let rust = db.assert_fact("alice", "interest", "Rust", now).unwrap();
+            let go = db.assert_fact("alice", "interest", "Go", now).unwrap();
+            let zig = db.assert_fact("alice", "interest", "Zig", now).unwrap();
+            assert!(db
+                .search_by_vector(&[1.0, 0.0], 5, None)
+                .unwrap()
+                .is_empty());
+            assert_eq!(db.facts_missing_embeddings().unwrap().len(), 3);
 
-        // Subsequent insert with dim = 2 must return Err, not panic.
-        let result =
-            db.assert_fact_with_embedding("alice", "interest", "Python", now, vec![0.0, 1.0]);
-        assert!(
-            result.as_ref().is_err_and(|e| e.is_invalid_embedding()),
-            "dim mismatch must return InvalidEmbedding, not panic"
-        );
+            db.set_embedding(&rust, vec![1.0, 0.0]).unwrap();
+            let written = db
+                .backfill_embeddings([(go.clone(), vec![0.0, 1.0]), (zig.clone(), vec![0.6, 0.8])])
+                .unwrap();
+            assert_eq!(written, 2);
+            assert!(db.facts_missing_embeddings().unwrap().is_empty());
+
+            // Re-embed in place, then remove.
+            db.set_embedding(&go, vec![0.8, 0.6]).unwrap();
+            let hits = db.search_by_vector(&[1.0, 0.0], 5, None).unwrap();
+            assert_eq!(hits[1].0.id, go);
+            assert!((hits[1].1 - 0.8).abs() < 1e-6);
+            assert!(db.remove_embedding(&zig).unwrap());
+            assert!(!db.remove_embedding(&zig).unwrap());
+
+            assert!(db
+                .set_embedding(FactId::new(), vec![1.0, 0.0])
+                .unwrap_err()
+                .is_not_found());
+            assert!(db
+                .remove_embedding(FactId::new())
+                .unwrap_err()
+                .is_not_found());
+            assert!(db
+                .set_embedding(&zig, vec![1.0])
+                .unwrap_err()
+                .is_invalid_embedding());
+
+            // Emptying a space frees its dimension.
+            db.backfill_embeddings_in_space("draft", [(rust.clone(), vec![1.0])])
+                .unwrap();
+            assert!(db.remove_embedding_in_space("draft", &rust).unwrap());
+            assert_eq!(db.embedding_spaces().unwrap(), vec!["default"]);
+            db.backfill_embeddings_in_space("draft", [(rust.clone(), vec![1.0, 2.0, 3.0])])
+                .unwrap();
+            (rust, go, zig)
+        };
 
-        // The failed insert must not corrupt the index: the original fact is still
-        // retrievable.
-        let results = db.search_by_vector(&[1.0, 0.0, 0.0], 5, None).unwrap();
-        assert_eq!(results.len(), 1, "failed insert must leave index intact");
+        let check = |db: &TemporalGraph| {
+            let ids: Vec<FactId> = db
+                .search_by_vector(&[1.0, 0.0], 5, None)
+                .unwrap()
+                .into_iter()
+                .map(|(fact, _)| fact.id)
+                .collect();
+            assert_eq!(ids, [rust.clone(), go.clone()]);
+            let missing: Vec<FactId> = db
+                .facts_missing_embeddings()
+                .unwrap()
+                .into_iter()
+                .map(|fact| fact.id)
+                .collect();
+            assert_eq!(missing, vec![zig.clone()]);
+            assert_eq!(
+                db.facts_missing_embeddings_in_space("draft").unwrap().len(),
+                2
+            );
+        };
+        let db = TemporalGraph::open(path_str).unwrap();
+        check(&db);
+        db.storage.compact().unwrap();
+        drop(db);
+        check(&TemporalGraph::open(path_str).unwrap());
     }
 
     #[test]
     #[cfg(feature = "vector")]
-    fn vector_search_wrong_query_dim_returns_error() {
-        let db = TemporalGraph::open_in_memory().unwrap();
+    fn embedding_metrics_rank_per_space_and_persist() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("metrics.kronroe");
+        let path_str = path.to_str().unwrap();
         let now = KronroeTimestamp::now_utc();
+        let objects = |hits: Vec<(Fact, f32)>| -> Vec<String> {
+            hits.into_iter()
+                .map(|(fact, _)| match fact.object {
+                    Value::Text(s) => s,
+                    other => panic!("unexpected object {other:?}"),
+                })
+                .collect()
+        };
 
-        // Insert a dim-3 embedding to establish the index dimension.
-        db.assert_fact_with_embedding("alice", "interest", "Rust", now, vec![1.0, 0.0, 0.0])
+        {
+            let db = TemporalGraph::open(path_str).unwrap();
+            db.assert_fact_with_embedding("alice", "interest", "Rust", now, vec![1.0, 0.0])
+                .unwrap();
+            db.assert_fact_with_embedding("alice", "interest", "Go", now, vec![10.0, 1.0])
+                .unwrap();
+            let query = [9.0, 0.0];
+            assert_eq!(db.embedding_metric("default").unwrap(), Metric::Cosine);
+            assert_eq!(
+                objects(db.search_by_vector(&query, 2, None).unwrap()),
+                ["Rust", "Go"]
+            );
+
+            db.set_embedding_metric("default", Metric::L2).unwrap();
+            let hits = db.search_by_vector(&query, 2, None).unwrap();
+            assert!((hits[0].1 + 2.0f32.sqrt()).abs() < 1e-5);
+            assert_eq!(objects(hits), ["Go", "Rust"]);
+
+            // A metric may be chosen before the space holds anything.
+            db.set_embedding_metric("bits", Metric::Hamming).unwrap();
+            let facts = db.current_facts("alice", "interest").unwrap();
+            db.backfill_embeddings_in_space(
+                "bits",
+                facts.iter().map(|fact| {
+                    let bits = match &fact.object {
+                        Value::Text(s) if s == "Rust" => vec![1.0, 0.0, 1.0, 1.0],
+                        _ => vec![0.0, 1.0, 0.0, 1.0],
+                    };
+                    (fact.id.clone(), bits)
+                }),
+            )
             .unwrap();
+            let hits = db
+                .search_by_vector_in_space("bits", &[1.0, 0.0, 0.0, 1.0], 2, None)
+                .unwrap();
+            assert_eq!(hits[0].1, -1.0);
+            assert_eq!(hits[1].1, -2.0);
+            assert_eq!(objects(hits), ["Rust", "Go"]);
+            assert!(db.set_embedding_metric("bad space", Metric::Dot).is_err());
+        }
 
-        // Query with dim=2 must return Err, not silently score 0.0.
-        let result = db.search_by_vector(&[1.0, 0.0], 5, None);
-        assert!(
-            result.as_ref().is_err_and(|e| e.is_invalid_embedding()),
-            "wrong query dimension must return InvalidEmbedding"
-        );
+        let check = |db: &TemporalGraph| {
+            assert_eq!(db.embedding_metric("default").unwrap(), Metric::L2);
+            assert_eq!(db.embedding_metric("bits").unwrap(), Metric::Hamming);
+            let hits = db.search_by_vector(&[9.0, 0.0], 2, None).unwrap();
+            assert!(
+                (hits[0].1 + 2.0f32.sqrt()).abs() < 1e-5,
+                "exact after rescoring"
+            );
+            assert_eq!(objects(hits), ["Go", "Rust"]);
+        };
+        let db = TemporalGraph::open(path_str).unwrap();
+        check(&db);
+        db.storage.compact().unwrap();
+        drop(db);
+        check(&TemporalGraph::open(path_str).unwrap());
+        let config = VectorConfig::new().with_quantization(Quantization::Int8);
+        let db = TemporalGraph::open_with_vector_config(path_str, config).unwrap();
+        check(&db);
+        db.set_embedding_metric("default", Metric::Dot).unwrap();
+        let hits = db.search_by_vector(&[9.0, 0.0], 2, None).unwrap();
+        assert!((hits[0].1 - 90.0).abs() < 1e-4);
     }
 
-    /// Embeddings are persisted to the default backend; the vector index must survive a
-    /// close-and-reopen without any re-population by the caller.
     #[test]
     #[cfg(feature = "vector")]
-    fn vector_index_survives_reopen() {
+    fn quantized_search_rescores_and_binary_log_encoding_round_trips() {
         let tmp = tempfile::tempdir().unwrap();
-        let path = tmp.path().join("durability.kronroe");
+        let path = tmp.path().join("quantized.kronroe");
         let path_str = path.to_str().unwrap();
         let now = KronroeTimestamp::now_utc();
+        let embedding = vec![0.1f32, -0.73, 1.0 / 3.0, 2.5e-4];
 
-        // Write two embeddings, then drop the database.
         {
-            let db = TemporalGraph::open(path_str).unwrap();
-            db.assert_fact_with_embedding("alice", "interest", "Rust", now, vec![1.0, 0.0, 0.0])
+            let db = TemporalGraph::open_with_vector_config(
+                path_str,
+                VectorConfig::new().with_log_encoding(EmbeddingEncoding::Binary),
+            )
+            .unwrap();
+            db.assert_fact_with_embedding("alice", "note", "exact", now, embedding.clone())
                 .unwrap();
-            db.assert_fact_with_embedding("alice", "interest", "Python", now, vec![0.0, 1.0, 0.0])
+            db.assert_fact_with_embedding("alice", "note", "other", now, vec![1.0, 0.0, 0.0, 0.0])
                 .unwrap();
-        } // db dropped — file closed
-
-        // Reopen: the index must be rebuilt from storage automatically.
+        }
+        let log = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(log.matches("\"embedding_f32le\"").count(), 2);
+        assert!(!log.contains("\"embedding\":["));
+
+        // Reopened with the default JSON encoding and a quantized index: the binary
+        // records still load, and rescoring restores exact similarities.
+        for quantization in [Quantization::Int8, Quantization::Binary] {
+            let db = TemporalGraph::open_with_vector_config(
+                path_str,
+                VectorConfig::new().with_quantization(quantization),
+            )
+            .unwrap();
+            let results = db.search_by_vector(&embedding, 2, None).unwrap();
+            assert!(matches!(&results[0].0.object, Value::Text(s) if s == "exact"));
+            assert!((results[0].1 - 1.0).abs() < 1e-6, "{quantization:?}");
+            let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+            assert!((results[1].1 - 0.1 / norm).abs() < 1e-6, "{quantization:?}");
+        }
         let db = TemporalGraph::open(path_str).unwrap();
-        let results = db.search_by_vector(&[1.0, 0.0, 0.0], 2, None).unwrap();
-        assert_eq!(results.len(), 2, "both embeddings must survive reopen");
-        assert!(
-            matches!(&results[0].0.object, Value::Text(s) if s == "Rust"),
-            "most similar fact after reopen should be Rust"
-        );
+        db.assert_fact_with_embedding("bob", "note", "json", now, vec![0.0, 1.0, 0.0, 0.0])
+            .unwrap();
+        drop(db);
+        let log = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(log.matches("\"embedding\":[").count(), 1);
     }
 
     #[test]
@@ -2390,6 +5015,164 @@ mod tests {
         assert!(matches!(&facts[0].object, Value::Text(s) if s == "London"));
     }
 
+    #[cfg(feature = "contradiction")]
+    #[test]
+    fn assert_fact_checked_supersede_older_resolves_and_audits() {
+        let (db, file) = open_temp_db();
+        db.register_singleton_predicate("lives_in", ConflictPolicy::SupersedeOlder)
+            .unwrap();
+        let london = db
+            .assert_fact("alice", "lives_in", "London", dt("2024-01-01T00:00:00Z"))
+            .unwrap();
+
+        // A later move closes the older fact at the new start.
+        let (paris, contradictions) = db
+            .assert_fact_checked("alice", "lives_in", "Paris", dt("2024-06-01T00:00:00Z"))
+            .unwrap();
+        assert_eq!(
+            contradictions[0].applied_resolution,
+            Some(AppliedResolution::ClosedFact {
+                fact_id: london.to_string(),
+                valid_to: dt("2024-06-01T00:00:00Z"),
+            })
+        );
+        let closed = db.fact_by_id(&london).unwrap();
+        assert_eq!(closed.valid_to, Some(dt("2024-06-01T00:00:00Z")));
+        assert!(closed.expired_at.is_none());
+
+        // A backdated fact is the older one: it closes at the earliest
+        // start it conflicts with.
+        let (rome, contradictions) = db
+            .assert_fact_checked("alice", "lives_in", "Rome", dt("2023-01-01T00:00:00Z"))
+            .unwrap();
+        assert_eq!(contradictions.len(), 2);
+        assert_eq!(
+            db.fact_by_id(&rome).unwrap().valid_to,
+            Some(dt("2024-01-01T00:00:00Z"))
+        );
+
+        // The same start retracts the existing fact.
+        let (berlin, contradictions) = db
+            .assert_fact_checked("alice", "lives_in", "Berlin", dt("2024-06-01T00:00:00Z"))
+            .unwrap();
+        assert_eq!(
+            contradictions[0].applied_resolution,
+            Some(AppliedResolution::RetractedFact {
+                fact_id: paris.to_string()
+            })
+        );
+        assert!(db.fact_by_id(&paris).unwrap().expired_at.is_some());
+        assert!(db
+            .detect_contradictions("alice", "lives_in")
+            .unwrap()
+            .is_empty());
+        let current = db.current_facts("alice", "lives_in").unwrap();
+        assert_eq!(current.len(), 1);
+        assert_eq!(current[0].id, berlin);
+
+        let resolutions = db.contradiction_resolutions("alice", "lives_in").unwrap();
+        assert_eq!(resolutions.len(), 3);
+        assert_eq!(resolutions[0].fact_id, paris.to_string());
+        assert_eq!(resolutions[0].policy, ConflictPolicy::SupersedeOlder);
+        assert_eq!(resolutions[1].applied.len(), 2);
+
+        drop(db);
+        let db = TemporalGraph::open(file.path().to_str().unwrap()).unwrap();
+        assert_eq!(
+            db.contradiction_resolutions("alice", "lives_in").unwrap(),
+            resolutions
+        );
+        assert!(db
+            .contradiction_resolutions("alice", "works_at")
+            .unwrap()
+            .is_empty());
+        assert!(db.fact_by_id(&paris).unwrap().expired_at.is_some());
+    }
+
+    #[cfg(feature = "contradiction")]
+    #[test]
+    fn assert_prepared_fact_checked_prefers_higher_confidence() {
+        let db = TemporalGraph::open_in_memory().unwrap();
+        db.register_singleton_predicate("works_at", ConflictPolicy::PreferHigherConfidence)
+            .unwrap();
+        let t = dt("2024-01-01T00:00:00Z");
+        let fact = |object: &str, confidence| {
+            Fact::new("alice", "works_at", object, t).with_confidence(confidence)
+        };
+        let acme = db.assert_prepared_fact(fact("Acme", 0.6)).unwrap();
+
+        // Higher confidence wins and retracts the existing fact.
+        let (beta, contradictions) = db.assert_prepared_fact_checked(fact("Beta", 0.9)).unwrap();
+        assert_eq!(
+            contradictions[0].applied_resolution,
+            Some(AppliedResolution::RetractedFact {
+                fact_id: acme.to_string()
+            })
+        );
+        assert!(db.fact_by_id(&acme).unwrap().expired_at.is_some());
+
+        // Lower confidence and ties lose: the new fact is stored retracted.
+        for confidence in [0.5, 0.9] {
+            let (loser, contradictions) = db
+                .assert_prepared_fact_checked(fact("Gamma", confidence))
+                .unwrap();
+            assert_eq!(
+                contradictions[0].applied_resolution,
+                Some(AppliedResolution::RetractedFact {
+                    fact_id: loser.to_string()
+                })
+            );
+            let stored = db.fact_by_id(&loser).unwrap();
+            assert_eq!(stored.expired_at, Some(stored.recorded_at));
+        }
+        let current = db.current_facts("alice", "works_at").unwrap();
+        assert_eq!(current.len(), 1);
+        assert_eq!(current[0].id, beta);
+        assert_eq!(
+            db.contradiction_resolutions("alice", "works_at")
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[cfg(all(feature = "contradiction", feature = "uncertainty"))]
+    #[test]
+    fn assert_prepared_fact_checked_prefers_trusted_source() {
+        use crate::SourceWeight;
+
+        let db = TemporalGraph::open_in_memory().unwrap();
+        db.register_singleton_predicate("email", ConflictPolicy::PreferTrustedSource)
+            .unwrap();
+        db.register_source_weight("user:owner", SourceWeight::new(1.5))
+            .unwrap();
+        let t = dt("2024-01-01T00:00:00Z");
+        let fact = |object: &str, source: &str, confidence| {
+            Fact::new("alice", "email", object, t)
+                .with_source(source)
+                .with_confidence(confidence)
+        };
+        let owner = db
+            .assert_prepared_fact(fact("a@example.com", "user:owner", 0.5))
+            .unwrap();
+
+        // A more confident but less trusted source loses.
+        let (guess, _) = db
+            .assert_prepared_fact_checked(fact("b@example.com", "api:guess", 1.0))
+            .unwrap();
+        assert!(db.fact_by_id(&guess).unwrap().expired_at.is_some());
+        assert!(db.fact_by_id(&owner).unwrap().expired_at.is_none());
+
+        // Equal weights fall back to confidence.
+        let (update, _) = db
+            .assert_prepared_fact_checked(fact("c@example.com", "user:owner", 0.7))
+            .unwrap();
+        assert!(db.fact_by_id(&owner).unwrap().expired_at.is_some());
+        let current = db.current_facts("alice", "email").unwrap();
+        assert_eq!(current.len(), 1);
+        assert_eq!(current[0].id, update);
+    }
+
     #[cfg(feature = "contradiction")]
     #[test]
     fn detect_all_contradictions_across_subjects() {
@@ -3162,4 +5945,262 @@ mod tests {
         assert_eq!(db1.current_facts("alice", "works_at").unwrap().len(), 1);
         assert_eq!(db2.current_facts("alice", "works_at").unwrap().len(), 0);
     }
+
+    #[cfg(feature = "rdf")]
+    #[test]
+    fn rdf_export_import_round_trips_between_databases() {
+        let (src, _tmp) = open_temp_db();
+        let jan = dt("2024-01-01T00:00:00Z");
+        let id = src
+            .assert_fact_with_source(
+                "alice",
+                "works_at",
+                Value::Entity("acme".into()),
+                jan,
+                0.8,
+                "hr",
+            )
+            .unwrap();
+        src.invalidate_fact(&id, dt("2024-06-01T00:00:00Z"))
+            .unwrap();
+        src.assert_fact("alice", "age", 34.0, jan).unwrap();
+
+        for format in [RdfFormat::NQuads, RdfFormat::TurtleStar] {
+            let exported = src.export_rdf(format, &RdfOptions::default()).unwrap();
+            let dst = TemporalGraph::open_in_memory().unwrap();
+            assert_eq!(
+                dst.import_rdf(&exported, format, &RdfOptions::default())
+                    .unwrap(),
+                2
+            );
+            assert_eq!(
+                dst.import_rdf(&exported, format, &RdfOptions::default())
+                    .unwrap(),
+                0,
+                "re-import must skip existing fact ids"
+            );
+
+            let restored = dst.fact_by_id(&id).unwrap();
+            assert_eq!(restored.valid_to, Some(dt("2024-06-01T00:00:00Z")));
+            assert_eq!(restored.confidence, 0.8);
+            assert_eq!(restored.source.as_deref(), Some("hr"));
+            assert!(dst.current_facts("alice", "works_at").unwrap().is_empty());
+            assert_eq!(dst.current_facts("alice", "age").unwrap().len(), 1);
+            assert_eq!(
+                dst.facts_at("alice", "works_at", dt("2024-03-01T00:00:00Z"))
+                    .unwrap()
+                    .len(),
+                1
+            );
+        }
+    }
+
+    #[cfg(feature = "rdf")]
+    #[test]
+    fn rdf_plain_triple_reimport_skips_current_duplicates() {
+        let db = TemporalGraph::open_in_memory().unwrap();
+        let input = "<https://kronroe.dev/id/entity/alice> \
+                     <https://kronroe.dev/id/predicate/works_at> \"Acme\" .\n";
+        let options = RdfOptions::default();
+        assert_eq!(
+            db.import_rdf(input, RdfFormat::NQuads, &options).unwrap(),
+            1
+        );
+        assert_eq!(
+            db.import_rdf(input, RdfFormat::NQuads, &options).unwrap(),
+            0
+        );
+        assert_eq!(db.current_facts("alice", "works_at").unwrap().len(), 1);
+        #[cfg(feature = "fulltext")]
+        assert_eq!(db.search("acme", 10).unwrap().len(), 1);
+    }
+
+    #[cfg(all(feature = "rdf", feature = "contradiction"))]
+    #[test]
+    fn rdf_import_applies_singleton_policy() {
+        let db = TemporalGraph::open_in_memory().unwrap();
+        db.register_singleton_predicate("works_at", ConflictPolicy::Reject)
+            .unwrap();
+        db.assert_fact("alice", "works_at", "Acme", dt("2024-01-01T00:00:00Z"))
+            .unwrap();
+        let input = "<https://kronroe.dev/id/entity/alice> \
+                     <https://kronroe.dev/id/predicate/works_at> \"Beta\" .\n";
+        let err = db
+            .import_rdf(input, RdfFormat::NQuads, &RdfOptions::default())
+            .unwrap_err();
+        assert!(err.is_contradiction_rejected());
+        assert_eq!(db.current_facts("alice", "works_at").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn rich_value_variants_round_trip_through_reopen() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("rich-values.kronroe");
+        let path_str = path.to_str().unwrap();
+        let jan = dt("2024-01-01T00:00:00Z");
+        let json = JsonValue::parse_str(r#"{"tier":"gold","tags":["a","b"],"n":1.5}"#).unwrap();
+
+        {
+            let db = TemporalGraph::open(path_str).unwrap();
+            db.assert_fact("alice", "born", dt("1990-05-17T08:30:00.123456Z"), jan)
+                .unwrap();
+            db.assert_fact("alice", "account_id", 9_007_199_254_740_993_i64, jan)
+                .unwrap();
+            db.assert_fact("alice", "avatar", vec![0_u8, 159, 255], jan)
+                .unwrap();
+            db.assert_fact("alice", "profile", json.clone(), jan)
+                .unwrap();
+        }
+
+        let db = TemporalGraph::open(path_str).unwrap();
+        let born = db.current_facts("alice", "born").unwrap();
+        assert!(
+            matches!(born[0].object, Value::Timestamp(t) if t == dt("1990-05-17T08:30:00.123456Z"))
+        );
+        let id = db.current_facts("alice", "account_id").unwrap();
+        assert!(matches!(
+            id[0].object,
+            Value::Integer(9_007_199_254_740_993)
+        ));
+        let avatar = db.current_facts("alice", "avatar").unwrap();
+        assert!(matches!(&avatar[0].object, Value::Bytes(b) if b == &[0, 159, 255]));
+        let profile = db.current_facts("alice", "profile").unwrap();
+        assert!(matches!(&profile[0].object, Value::Json(j) if *j == json));
+    }
+
+    #[test]
+    fn value_tagged_json_round_trip() {
+        for value in [
+            Value::Integer(-42),
+            Value::Timestamp(dt("2024-02-29T12:00:00Z")),
+            Value::Bytes(b"hello".to_vec()),
+            Value::Json(JsonValue::parse_str(r#"[1,{"k":null}]"#).unwrap()),
+        ] {
+            let encoded = value.to_json_string();
+            let decoded = Value::from_json_str(&encoded).unwrap();
+            assert_eq!(decoded.to_json_string(), encoded);
+        }
+        assert_eq!(
+            Value::Integer(i64::MIN).to_json_string(),
+            r#"{"type":"Integer","value":"-9223372036854775808"}"#
+        );
+        assert!(matches!(
+            Value::from_json_str(r#"{"type":"Integer","value":7}"#).unwrap(),
+            Value::Integer(7)
+        ));
+        assert!(Value::from_json_str(r#"{"type":"Integer","value":1.5}"#).is_err());
+    }
+
+    #[cfg(feature = "fulltext")]
+    #[test]
+    fn search_indexes_rich_values_by_variant_rules() {
+        let (db, _tmp) = open_temp_db();
+        let jan = dt("2024-01-01T00:00:00Z");
+        db.assert_fact("alice", "hired", dt("2019-07-04T00:00:00Z"), jan)
+            .unwrap();
+        db.assert_fact("bob", "badge", 424242_i64, jan).unwrap();
+        db.assert_fact(
+            "carol",
+            "profile",
+            JsonValue::parse_str(r#"{"team":"platypus","level":3}"#).unwrap(),
+            jan,
+        )
+        .unwrap();
+        db.assert_fact("dave", "blob", b"zebra".to_vec(), jan)
+            .unwrap();
+
+        let hits = db.search("2019", 5).unwrap();
+        assert_eq!(hits[0].subject, "alice");
+        let hits = db.search("424242", 5).unwrap();
+        assert_eq!(hits[0].subject, "bob");
+        let hits = db.search("platypus", 5).unwrap();
+        assert_eq!(hits[0].subject, "carol");
+        let hits = db.search("team", 5).unwrap();
+        assert_eq!(hits[0].subject, "carol");
+        assert!(
+            db.search("zebra", 5).unwrap().is_empty(),
+            "bytes payloads must not be indexed"
+        );
+    }
+
+    #[test]
+    fn fact_metadata_persists_and_filters() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("metadata.kronroe");
+        let path_str = path.to_str().unwrap();
+        let jan = dt("2024-01-01T00:00:00Z");
+
+        let tagged_id = {
+            let db = TemporalGraph::open(path_str).unwrap();
+            let plain = db.assert_fact("alice", "works_at", "Acme", jan).unwrap();
+            assert!(!db
+                .fact_by_id(&plain)
+                .unwrap()
+                .to_json_string()
+                .contains("metadata"));
+
+            let fact = Fact::new("alice", "lives_in", "Paris", jan)
+                .with_source("episode:conv-42")
+                .with_metadata("model", "extractor-v2")
+                .with_metadata("turn", 7_i64);
+            let id = db.assert_prepared_fact(fact).unwrap();
+            db.assert_prepared_fact(
+                Fact::new("bob", "lives_in", "Rome", jan).with_metadata("model", "other"),
+            )
+            .unwrap();
+            id
+        };
+
+        let db = TemporalGraph::open(path_str).unwrap();
+        let stored = db.fact_by_id(&tagged_id).unwrap();
+        assert_eq!(stored.source.as_deref(), Some("episode:conv-42"));
+        assert_eq!(stored.metadata.get("turn"), Some(&Value::Integer(7)));
+
+        let hits = db
+            .facts_with_metadata("model", &Value::Text("extractor-v2".into()))
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, tagged_id);
+        let hits = db.facts_with_metadata("turn", &Value::Number(7.0)).unwrap();
+        assert_eq!(hits.len(), 1, "Integer and Number compare numerically");
+        assert!(db
+            .facts_with_metadata("turn", &Value::Number(7.5))
+            .unwrap()
+            .is_empty());
+
+        let err = db
+            .assert_prepared_fact(Fact::new("alice", "x", "y", jan).with_metadata("", 1_i64))
+            .unwrap_err();
+        assert!(err.is_invalid_input());
+    }
+
+    #[test]
+    fn aggregate_follows_corrections_on_the_valid_time_axis() {
+        let (db, _tmp) = open_temp_db();
+        db.assert_fact("acct", "balance", 100.0, dt("2024-01-01T00:00:00Z"))
+            .unwrap();
+        let wrong = db
+            .assert_fact("acct", "balance", 1_000_i64, dt("2024-01-11T00:00:00Z"))
+            .unwrap();
+        db.correct_fact(&wrong, 200_i64, dt("2024-01-21T00:00:00Z"))
+            .unwrap();
+        db.assert_fact("acct", "owner", "alice", dt("2024-01-01T00:00:00Z"))
+            .unwrap();
+
+        let result = db
+            .aggregate(
+                "acct",
+                "balance",
+                AggregateSpec::new(dt("2024-01-01T00:00:00Z"), dt("2024-01-31T00:00:00Z"))
+                    .with_bucket(TimeBucket::Day),
+            )
+            .unwrap();
+        assert_eq!(result.buckets.len(), 30);
+        assert_eq!(result.overall.count, 3);
+        assert_eq!(result.overall.max, Some(1000.0));
+        assert_eq!(result.overall.last, Some(200.0));
+        assert_eq!(result.buckets[15].value_at_start, Some(1000.0));
+        assert_eq!(result.buckets[25].value_at_start, Some(200.0));
+        assert_eq!(result.buckets[25].stats.avg, Some(200.0));
+    }
 }
@@ -0,0 +1,637 @@
+//! Rule-based temporal expression detection for hybrid queries.
+//!
+//! [`TemporalQuery::parse`] reads a natural-language query and infers the
+//! [`TemporalIntent`], [`TemporalOperator`] and anchor time that
+//! [`HybridSearchParams`](crate::HybridSearchParams) otherwise expects the
+//! caller to supply:
+//!
+//! | Query | Intent | Operator | Anchor |
+//! |---|---|---|---|
+//! | "where does Alice work currently" | `CurrentState` | `Current` | now |
+//! | "where did Alice work in 2023" | `HistoricalInterval` | `During` | 2023 |
+//! | "Alice's team as of Q3 2024" | `HistoricalPoint` | `AsOf` | 2024-07-01 |
+//! | "what did Alice do last March" | `HistoricalInterval` | `During` | that March |
+//! | "Alice's city 3 years ago" | `HistoricalPoint` | `AsOf` | now − 3 × 365 days |
+//! | "where did she live before she moved" | `HistoricalPoint` | `Before` | the event |
+//!
+//! Event expressions ("before she moved") carry no date; their anchor is
+//! resolved against the graph by
+//! [`TemporalGraph::detect_temporal`](crate::TemporalGraph::detect_temporal).
+//! Queries without a recognized expression stay `Timeless`.
+
+use crate::hybrid::{TemporalIntent, TemporalOperator};
+use crate::{KronroeSpan, KronroeTimestamp};
+
+/// Temporal constraints detected in a query.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[non_exhaustive]
+pub struct TemporalQuery {
+    /// Detected intent; `Timeless` when nothing was recognized.
+    pub intent: TemporalIntent,
+    /// Detected operator.
+    pub operator: TemporalOperator,
+    /// Anchor time passed to hybrid search. `None` means now.
+    pub at: Option<KronroeTimestamp>,
+    /// Calendar period named by the expression, as `[start, end)`.
+    pub interval: Option<(KronroeTimestamp, KronroeTimestamp)>,
+    /// The matched expression, as written in the query (e.g. `"in 2023"`).
+    pub expression: Option<String>,
+    /// Event clause of a relative expression (e.g. `"moved"` for
+    /// "before she moved"), anchored at the best matching fact.
+    pub event: Option<String>,
+}
+
+impl TemporalQuery {
+    /// Detect the first temporal expression in `query`, resolving relative
+    /// expressions ("last March", "3 years ago") against `now`.
+    ///
+    /// Event anchors are left unresolved (`at` is `None`); use
+    /// [`TemporalGraph::detect_temporal`](crate::TemporalGraph::detect_temporal)
+    /// to resolve them.
+    pub fn parse(query: &str, now: KronroeTimestamp) -> Self {
+        let tokens = tokenize(query);
+        (0..tokens.len())
+            .find_map(|i| match_at(&tokens, i, now))
+            .map(|m| {
+                let start = tokens[m.first].start;
+                let end = tokens[m.last].end;
+                TemporalQuery {
+                    expression: Some(query[start..end].to_string()),
+                    ..m.query
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    /// Whether a temporal constraint was detected.
+    pub fn is_temporal(&self) -> bool {
+        self.intent != TemporalIntent::Timeless
+    }
+}
+
+struct Token {
+    word: String,
+    start: usize,
+    end: usize,
+    /// Followed by clause punctuation (`,`, `.`, `;`, `?`, `!`).
+    clause_end: bool,
+}
+
+fn tokenize(query: &str) -> Vec<Token> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut start = None;
+    for (i, c) in query.char_indices().chain([(query.len(), ' ')]) {
+        let is_word = c.is_alphanumeric() || (c == '-' && start.is_some());
+        match (is_word, start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                let raw = query[s..i].trim_end_matches('-');
+                tokens.push(Token {
+                    word: raw.to_lowercase(),
+                    start: s,
+                    end: s + raw.len(),
+                    clause_end: false,
+                });
+                start = None;
+            }
+            _ => {}
+        }
+        if matches!(c, ',' | '.' | ';' | '?' | '!') {
+            if let Some(last) = tokens.last_mut() {
+                last.clause_end = true;
+            }
+        }
+    }
+    tokens
+}
+
+/// A detection and the token range it was read from.
+struct Match {
+    query: TemporalQuery,
+    first: usize,
+    last: usize,
+}
+
+/// A calendar period `[start, end)`.
+struct Period {
+    start: KronroeTimestamp,
+    end: KronroeTimestamp,
+    single_day: bool,
+}
+
+impl Period {
+    fn midpoint(&self) -> KronroeTimestamp {
+        let half = (self.end.to_unix_micros() - self.start.to_unix_micros()) / 2;
+        self.start + KronroeSpan::microseconds(half)
+    }
+}
+
+const CURRENT_WORDS: &[&str] = &[
+    "currently",
+    "nowadays",
+    "presently",
+    "now",
+    "today",
+    "still",
+];
+const CURRENT_PHRASES: &[&[&str]] = &[
+    &["at", "the", "moment"],
+    &["at", "present"],
+    &["these", "days"],
+    &["right", "now"],
+];
+const EVENT_FILLER: &[&str] = &[
+    "a", "an", "the", "he", "she", "they", "it", "i", "we", "you", "his", "her", "their", "its",
+    "my", "our", "your", "had", "has", "have", "was", "were",
+];
+const MAX_EVENT_WORDS: usize = 6;
+
+fn match_at(tokens: &[Token], i: usize, now: KronroeTimestamp) -> Option<Match> {
+    let word = tokens[i].word.as_str();
+    let next = tokens.get(i + 1).map(|t| t.word.as_str());
+
+    for phrase in CURRENT_PHRASES {
+        if words_match(tokens, i, phrase) {
+            return Some(current(i, i + phrase.len() - 1));
+        }
+    }
+    if CURRENT_WORDS.contains(&word) {
+        return Some(current(i, i));
+    }
+
+    // Prepositions, longest first.
+    let (operator, body) = match (word, next) {
+        ("as", Some("of")) => (Some(TemporalOperator::AsOf), i + 2),
+        ("prior", Some("to")) | ("up", Some("to")) => (Some(TemporalOperator::Before), i + 2),
+        ("in" | "on" | "at" | "during" | "throughout" | "within" | "around", _) => {
+            (Some(TemporalOperator::During), i + 1)
+        }
+        ("before", _) => (Some(TemporalOperator::Before), i + 1),
+        ("by" | "until" | "till", _) => (Some(TemporalOperator::By), i + 1),
+        ("after" | "since" | "following", _) => (Some(TemporalOperator::After), i + 1),
+        _ => (None, i),
+    };
+    let preposition = operator.is_some();
+
+    if let Some((period, last)) = period_at(tokens, body, now, preposition) {
+        let (intent, operator) = match operator {
+            Some(TemporalOperator::During) | None if !period.single_day => {
+                (TemporalIntent::HistoricalInterval, TemporalOperator::During)
+            }
+            Some(TemporalOperator::During) | None => {
+                (TemporalIntent::HistoricalPoint, TemporalOperator::AsOf)
+            }
+            Some(op) => (TemporalIntent::HistoricalPoint, op),
+        };
+        // "after 2022" starts once the period is over; "since 2022" within it.
+        let at = match intent {
+            TemporalIntent::HistoricalInterval => period.midpoint(),
+            _ if matches!(word, "after" | "following") => period.end,
+            _ => period.start,
+        };
+        return Some(Match {
+            query: TemporalQuery {
+                intent,
+                operator,
+                at: Some(at),
+                interval: Some((period.start, period.end)),
+                ..TemporalQuery::default()
+            },
+            first: i,
+            last,
+        });
+    }
+
+    if let Some((span, last)) = ago_at(tokens, i) {
+        return Some(Match {
+            query: TemporalQuery {
+                intent: TemporalIntent::HistoricalPoint,
+                operator: TemporalOperator::AsOf,
+                at: Some(now - span),
+                ..TemporalQuery::default()
+            },
+            first: i,
+            last,
+        });
+    }
+
+    // "before she moved", "since the merger": anchored at an event. "by" and
+    // "up to" only take periods: "papers written by alice", "up to 5 items".
+    match operator {
+        _ if matches!(word, "by" | "up") => None,
+        Some(op @ (TemporalOperator::Before | TemporalOperator::By | TemporalOperator::After)) => {
+            let (event, last) = event_at(tokens, body)?;
+            Some(Match {
+                query: TemporalQuery {
+                    intent: TemporalIntent::HistoricalPoint,
+                    operator: op,
+                    event: Some(event),
+                    ..TemporalQuery::default()
+                },
+                first: i,
+                last,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn current(first: usize, last: usize) -> Match {
+    Match {
+        query: TemporalQuery {
+            intent: TemporalIntent::CurrentState,
+            operator: TemporalOperator::Current,
+            ..TemporalQuery::default()
+        },
+        first,
+        last,
+    }
+}
+
+fn words_match(tokens: &[Token], i: usize, words: &[&str]) -> bool {
+    words
+        .iter()
+        .enumerate()
+        .all(|(k, w)| tokens.get(i + k).is_some_and(|t| t.word == *w))
+}
+
+/// Calendar period starting at token `i`, with the index of its last token.
+///
+/// A lone month name ("in March"), year, or quarter only counts after a
+/// preposition, since words like "may" and "march" are common outside dates.
+fn period_at(
+    tokens: &[Token],
+    i: usize,
+    now: KronroeTimestamp,
+    after_preposition: bool,
+) -> Option<(Period, usize)> {
+    let word = tokens.get(i)?.word.as_str();
+    let next = tokens.get(i + 1).map(|t| t.word.as_str());
+    let (year, month, day) = now.utc_ymd();
+
+    match word {
+        "last" | "previous" | "past" | "this" => {
+            let last = word != "this";
+            let unit = next?;
+            let period = match unit {
+                "year" => year_period(if last { year - 1 } else { year }),
+                "quarter" => {
+                    let q = (month - 1) / 3 + 1;
+                    let (y, q) = match (last, q) {
+                        (true, 1) => (year - 1, 4),
+                        (true, q) => (year, q - 1),
+                        (false, q) => (year, q),
+                    };
+                    quarter_period(y, q)
+                }
+                "month" => {
+                    let (y, m) = match (last, month) {
+                        (true, 1) => (year - 1, 12),
+                        (true, m) => (year, m - 1),
+                        (false, m) => (year, m),
+                    };
+                    month_period(y, m)
+                }
+                "week" => {
+                    let today = day_start(year, month, day)?;
+                    let weekday =
+                        (today.to_unix_micros().div_euclid(86_400_000_000) + 3).rem_euclid(7);
+                    let monday = today - KronroeSpan::days(weekday);
+                    let start = if last {
+                        monday - KronroeSpan::days(7)
+                    } else {
+                        monday
+                    };
+                    Some(Period {
+                        start,
+                        end: start + KronroeSpan::days(7),
+                        single_day: false,
+                    })
+                }
+                other => {
+                    let m = month_number(other)?;
+                    let y = if !last || m < month { year } else { year - 1 };
+                    month_period(y, m)
+                }
+            }?;
+            Some((period, i + 1))
+        }
+        "yesterday" => {
+            let start = day_start(year, month, day)? - KronroeSpan::days(1);
+            Some((
+                Period {
+                    start,
+                    end: start + KronroeSpan::days(1),
+                    single_day: true,
+                },
+                i,
+            ))
+        }
+        _ => {
+            if let Some(period) = iso_period(word) {
+                return Some((period, i));
+            }
+            // A bare year needs a preposition, like a bare month: "ticket 2048"
+            // and "port 2000" are identifiers, "in 2048" is a period.
+            if let Some(y) = year_number(word) {
+                if !after_preposition {
+                    return None;
+                }
+                return Some((year_period(y)?, i));
+            }
+            if let Some(q) = quarter_number(word) {
+                if let Some(y) = next.and_then(year_number) {
+                    return Some((quarter_period(y, q)?, i + 1));
+                }
+                // Likewise "q1 revenue report" names a quarter, "in q1" is one.
+                if !after_preposition {
+                    return None;
+                }
+                let current_q = (month - 1) / 3 + 1;
+                let y = if q <= current_q { year } else { year - 1 };
+                return Some((quarter_period(y, q)?, i));
+            }
+            // "15 March 2024" or "March 15, 2024" / "March 2024" / "March".
+            let (m, d, mut last) = match (day_number(word), next.and_then(month_number)) {
+                (Some(d), Some(m)) => (m, Some(d), i + 1),
+                _ => {
+                    let m = month_number(word)?;
+                    match next.and_then(day_number) {
+                        Some(d) => (m, Some(d), i + 1),
+                        None => (m, None, i),
+                    }
+                }
+            };
+            let explicit_year = tokens.get(last + 1).and_then(|t| year_number(&t.word));
+            if explicit_year.is_none() && !after_preposition {
+                return None;
+            }
+            let y = match explicit_year {
+                Some(y) => {
+                    last += 1;
+                    y
+                }
+                None if (m, d.unwrap_or(1)) <= (month, day) => year,
+                None => year - 1,
+            };
+            let period = match d {
+                Some(d) => {
+                    let start = day_start(y, m, d)?;
+                    Period {
+                        start,
+                        end: start + KronroeSpan::days(1),
+                        single_day: true,
+                    }
+                }
+                None => month_period(y, m)?,
+            };
+            Some((period, last))
+        }
+    }
+}
+
+/// "3 years ago", "a month ago": the span back from now.
+fn ago_at(tokens: &[Token], i: usize) -> Option<(KronroeSpan, usize)> {
+    let count = count_number(&tokens.get(i)?.word)?;
+    let unit = tokens.get(i + 1)?.word.as_str();
+    if tokens.get(i + 2)?.word != "ago" {
+        return None;
+    }
+    let days = match unit.trim_end_matches('s') {
+        "day" => 1,
+        "week" => 7,
+        "month" => 30,
+        "year" => 365,
+        _ => return None,
+    };
+    Some((KronroeSpan::days(days * count), i + 2))
+}
+
+/// Event clause starting at token `i`, up to clause punctuation.
+fn event_at(tokens: &[Token], i: usize) -> Option<(String, usize)> {
+    let mut words = Vec::new();
+    let mut last = None;
+    for (k, token) in tokens.iter().enumerate().skip(i).take(MAX_EVENT_WORDS) {
+        if !EVENT_FILLER.contains(&token.word.as_str()) {
+            words.push(token.word.as_str());
+        }
+        last = Some(k);
+        if token.clause_end {
+            break;
+        }
+    }
+    if words.is_empty() {
+        return None;
+    }
+    Some((words.join(" "), last?))
+}
+
+fn day_start(year: i32, month: u32, day: u32) -> Option<KronroeTimestamp> {
+    KronroeTimestamp::from_utc_components(year, month, day, 0, 0, 0, 0).ok()
+}
+
+fn year_period(year: i32) -> Option<Period> {
+    Some(Period {
+        start: day_start(year, 1, 1)?,
+        end: day_start(year + 1, 1, 1)?,
+        single_day: false,
+    })
+}
+
+fn quarter_period(year: i32, quarter: u32) -> Option<Period> {
+    let first = (quarter - 1) * 3 + 1;
+    let end = if quarter == 4 {
+        day_start(year + 1, 1, 1)?
+    } else {
+        day_start(year, first + 3, 1)?
+    };
+    Some(Period {
+        start: day_start(year, first, 1)?,
+        end,
+        single_day: false,
+    })
+}
+
+fn month_period(year: i32, month: u32) -> Option<Period> {
+    let end = if month == 12 {
+        day_start(year + 1, 1, 1)?
+    } else {
+        day_start(year, month + 1, 1)?
+    };
+    Some(Period {
+        start: day_start(year, month, 1)?,
+        end,
+        single_day: false,
+    })
+}
+
+/// `YYYY-MM-DD` or `YYYY-MM`.
+fn iso_period(word: &str) -> Option<Period> {
+    let mut parts = word.split('-');
+    let year = year_number(parts.next()?)?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    match parts.next() {
+        None => month_period(year, month),
+        Some(day) => {
+            let start = day_start(year, month, day.parse().ok()?)?;
+            if parts.next().is_some() {
+                return None;
+            }
+            Some(Period {
+                start,
+                end: start + KronroeSpan::days(1),
+                single_day: true,
+            })
+        }
+    }
+}
+
+fn year_number(word: &str) -> Option<i32> {
+    if word.len() != 4 {
+        return None;
+    }
+    word.parse().ok().filter(|y| (1900..=2199).contains(y))
+}
+
+fn quarter_number(word: &str) -> Option<u32> {
+    word.strip_prefix('q')?
+        .parse()
+        .ok()
+        .filter(|q| (1..=4).contains(q))
+}
+
+fn day_number(word: &str) -> Option<u32> {
+    let digits = word
+        .strip_suffix("st")
+        .or_else(|| word.strip_suffix("nd"))
+        .or_else(|| word.strip_suffix("rd"))
+        .or_else(|| word.strip_suffix("th"))
+        .unwrap_or(word);
+    if digits.len() > 2 {
+        return None;
+    }
+    digits.parse().ok().filter(|d| (1..=31).contains(d))
+}
+
+fn count_number(word: &str) -> Option<i64> {
+    const WORDS: [&str; 12] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven",
+        "twelve",
+    ];
+    match word {
+        "a" | "an" => Some(1),
+        _ => WORDS
+            .iter()
+            .position(|w| *w == word)
+            .map(|n| n as i64 + 1)
+            .or_else(|| word.parse().ok().filter(|n| (1..=1_000).contains(n))),
+    }
+}
+
+fn month_number(word: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+    MONTHS
+        .iter()
+        .position(|m| *m == word || (word.len() >= 3 && m.starts_with(word)))
+        .map(|m| m as u32 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(year: i32, month: u32, day: u32) -> KronroeTimestamp {
+        KronroeTimestamp::from_utc_components(year, month, day, 0, 0, 0, 0).unwrap()
+    }
+
+    fn now() -> KronroeTimestamp {
+        dt(2026, 10, 18)
+    }
+
+    #[test]
+    fn detects_periods_and_anchors() {
+        let q = TemporalQuery::parse("Where did Alice work in 2023?", now());
+        assert_eq!(q.intent, TemporalIntent::HistoricalInterval);
+        assert_eq!(q.operator, TemporalOperator::During);
+        assert_eq!(q.interval, Some((dt(2023, 1, 1), dt(2024, 1, 1))));
+        assert_eq!(q.expression.as_deref(), Some("in 2023"));
+
+        let q = TemporalQuery::parse("Alice's team as of Q3 2024", now());
+        assert_eq!(q.intent, TemporalIntent::HistoricalPoint);
+        assert_eq!(q.operator, TemporalOperator::AsOf);
+        assert_eq!(q.at, Some(dt(2024, 7, 1)));
+        assert_eq!(q.interval, Some((dt(2024, 7, 1), dt(2024, 10, 1))));
+        assert_eq!(q.expression.as_deref(), Some("as of Q3 2024"));
+
+        let q = TemporalQuery::parse("what did alice do last March", now());
+        assert_eq!(q.interval, Some((dt(2026, 3, 1), dt(2026, 4, 1))));
+        let q = TemporalQuery::parse("what did alice do last November", now());
+        assert_eq!(q.interval, Some((dt(2025, 11, 1), dt(2025, 12, 1))));
+
+        let q = TemporalQuery::parse("alice's projects after 2022", now());
+        assert_eq!(q.operator, TemporalOperator::After);
+        assert_eq!(q.at, Some(dt(2023, 1, 1)));
+
+        let q = TemporalQuery::parse("alice's city on March 15, 2024", now());
+        assert_eq!(q.intent, TemporalIntent::HistoricalPoint);
+        assert_eq!(q.at, Some(dt(2024, 3, 15)));
+        assert_eq!(q.expression.as_deref(), Some("on March 15, 2024"));
+
+        let q = TemporalQuery::parse("alice's city 3 years ago", now());
+        assert_eq!(q.at, Some(now() - KronroeSpan::days(3 * 365)));
+
+        let q = TemporalQuery::parse("who did alice report to before 2024-02", now());
+        assert_eq!(q.operator, TemporalOperator::Before);
+        assert_eq!(q.at, Some(dt(2024, 2, 1)));
+    }
+
+    #[test]
+    fn detects_current_and_events_and_ignores_plain_queries() {
+        let q = TemporalQuery::parse("where does alice currently work", now());
+        assert_eq!(q.intent, TemporalIntent::CurrentState);
+        assert_eq!(q.at, None);
+
+        let q = TemporalQuery::parse("where did she live before she moved, exactly", now());
+        assert_eq!(q.intent, TemporalIntent::HistoricalPoint);
+        assert_eq!(q.operator, TemporalOperator::Before);
+        assert_eq!(q.event.as_deref(), Some("moved"));
+        assert_eq!(q.at, None);
+
+        let q = TemporalQuery::parse("who led the team until she left", now());
+        assert_eq!(q.operator, TemporalOperator::By);
+        assert_eq!(q.event.as_deref(), Some("left"));
+        let q = TemporalQuery::parse("papers finished by 2024", now());
+        assert_eq!(q.operator, TemporalOperator::By);
+        assert_eq!(q.at, Some(dt(2024, 1, 1)));
+        let q = TemporalQuery::parse("revenue in q1", now());
+        assert_eq!(q.intent, TemporalIntent::HistoricalInterval);
+
+        for plain in [
+            "alice may join acme",
+            "march of the penguins",
+            "who works in Lisbon",
+            "status of ticket 2048",
+            "error code 1999 in the parser",
+            "port 2000 config",
+            "papers written by alice",
+            "projects led by bob",
+            "q1 revenue report",
+            "",
+        ] {
+            assert!(!TemporalQuery::parse(plain, now()).is_temporal(), "{plain}");
+        }
+    }
+}
@@ -0,0 +1,585 @@
+//! Pluggable text analysis for the full-text index.
+//!
+//! A [`Tokenizer`] turns fact text and queries into index terms. The same
+//! tokenizer is applied to both, so anything it normalizes away (case,
+//! diacritics, inflection) is matched transparently.
+//!
+//! [`StandardTokenizer::new`] reproduces Kronroe's original behaviour
+//! (lowercase, split on non-alphanumerics). Each analysis stage is opt-in:
+//!
+//! ```rust
+//! use kronroe::{StandardTokenizer, Stemmer, Tokenizer};
+//!
+//! let tokenizer = StandardTokenizer::new()
+//!     .with_diacritic_folding(true)
+//!     .with_english_stopwords()
+//!     .with_stemmer(Stemmer::English)
+//!     .with_cjk_bigrams(true);
+//! assert_eq!(tokenizer.tokenize("She is working at Café Zürich"), ["work", "cafe", "zurich"]);
+//! assert_eq!(tokenizer.tokenize("東京都"), ["東京", "京都"]);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+/// Converts text into index terms.
+///
+/// Implementations must be deterministic: the index is built incrementally
+/// and may be checkpointed, so the same text must always produce the same
+/// terms.
+pub trait Tokenizer: Send + Sync {
+    /// Stable identifier for this tokenizer's configuration.
+    ///
+    /// Search checkpoints record it and are discarded when it changes, so
+    /// any change to the produced terms must change the id.
+    fn id(&self) -> String;
+
+    /// Split `text` into normalized terms, in order.
+    fn tokenize(&self, text: &str) -> Vec<String>;
+}
+
+/// Stemming algorithm applied by [`StandardTokenizer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Stemmer {
+    /// The Porter stemming algorithm for English ("working", "works",
+    /// "worked" → "work").
+    English,
+}
+
+/// Configurable built-in tokenizer.
+///
+/// Stages run in order: Unicode folding, splitting on non-alphanumerics
+/// (with CJK bigram segmentation), lowercasing, stopword removal, then
+/// stemming. Stopwords are matched before stemming, against the folded,
+/// lowercased word.
+#[derive(Debug, Clone, Default)]
+pub struct StandardTokenizer {
+    fold_diacritics: bool,
+    cjk_bigrams: bool,
+    stemmer: Option<Stemmer>,
+    stopwords: BTreeSet<String>,
+    /// `stopwords` after diacritic folding, matched when folding is on.
+    folded_stopwords: BTreeSet<String>,
+}
+
+impl StandardTokenizer {
+    /// Lowercase and split on non-alphanumeric characters; no other
+    /// normalization. This is the default for every database.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every stage enabled with English settings: diacritic folding, English
+    /// stopwords, Porter stemming, and CJK bigrams.
+    pub fn english() -> Self {
+        Self::new()
+            .with_diacritic_folding(true)
+            .with_english_stopwords()
+            .with_stemmer(Stemmer::English)
+            .with_cjk_bigrams(true)
+    }
+
+    /// Fold compatibility forms and strip diacritics before splitting
+    /// (`"Café"` → `"cafe"`, `"Straße"` → `"strasse"`, full-width
+    /// `"ＡＢＣ"` → `"abc"`). Combining marks are removed rather than
+    /// treated as separators.
+    pub fn with_diacritic_folding(mut self, enabled: bool) -> Self {
+        self.fold_diacritics = enabled;
+        self
+    }
+
+    /// Segment runs of Han, Kana, and Hangul characters into overlapping
+    /// bigrams instead of keeping each run as one token. A run of a single
+    /// character becomes a unigram.
+    pub fn with_cjk_bigrams(mut self, enabled: bool) -> Self {
+        self.cjk_bigrams = enabled;
+        self
+    }
+
+    /// Stem every term with `stemmer`.
+    pub fn with_stemmer(mut self, stemmer: Stemmer) -> Self {
+        self.stemmer = Some(stemmer);
+        self
+    }
+
+    /// Add words to the stopword list. Words are lowercased, and folded when
+    /// diacritic folding is enabled, before comparison, in whichever order
+    /// the builder methods are called.
+    pub fn with_stopwords<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for word in words {
+            let word = word.as_ref().to_lowercase();
+            self.folded_stopwords
+                .insert(fold_text(&word).to_lowercase());
+            self.stopwords.insert(word);
+        }
+        self
+    }
+
+    /// Add [`ENGLISH_STOPWORDS`] to the stopword list.
+    pub fn with_english_stopwords(self) -> Self {
+        self.with_stopwords(ENGLISH_STOPWORDS.iter().copied())
+    }
+
+    fn push_word(&self, word: &str, out: &mut Vec<String>) {
+        let stopwords = if self.fold_diacritics {
+            &self.folded_stopwords
+        } else {
+            &self.stopwords
+        };
+        if stopwords.contains(word) {
+            return;
+        }
+        match self.stemmer {
+            Some(Stemmer::English) => out.push(porter_stem(word)),
+            None => out.push(word.to_string()),
+        }
+    }
+
+    fn push_cjk_run(run: &[char], out: &mut Vec<String>) {
+        match run {
+            [] => {}
+            [single] => out.push(single.to_string()),
+            _ => out.extend(run.windows(2).map(|pair| pair.iter().collect())),
+        }
+    }
+}
+
+impl Tokenizer for StandardTokenizer {
+    fn id(&self) -> String {
+        let mut id = String::from("standard/1");
+        if self.fold_diacritics {
+            id.push_str("+fold");
+        }
+        if self.cjk_bigrams {
+            id.push_str("+cjk");
+        }
+        if let Some(Stemmer::English) = self.stemmer {
+            id.push_str("+porter");
+        }
+        if !self.stopwords.is_empty() {
+            let mut hash = 0xcbf29ce484222325u64;
+            for word in &self.stopwords {
+                for byte in word.as_bytes().iter().chain([&0u8]) {
+                    hash ^= u64::from(*byte);
+                    hash = hash.wrapping_mul(0x100000001b3);
+                }
+            }
+            id.push_str(&format!("+stop:{hash:016x}"));
+        }
+        id
+    }
+
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        let text: Cow<'_, str> = if self.fold_diacritics {
+            Cow::Owned(fold_text(text))
+        } else {
+            Cow::Borrowed(text)
+        };
+
+        let mut tokens = Vec::new();
+        let mut word = String::new();
+        let mut cjk_run: Vec<char> = Vec::new();
+
+        for ch in text.chars() {
+            if self.cjk_bigrams && is_cjk(ch) {
+                if !word.is_empty() {
+                    self.push_word(&std::mem::take(&mut word), &mut tokens);
+                }
+                cjk_run.push(ch);
+                continue;
+            }
+            if !cjk_run.is_empty() {
+                Self::push_cjk_run(&cjk_run, &mut tokens);
+                cjk_run.clear();
+            }
+            if ch.is_alphanumeric() {
+                word.extend(ch.to_lowercase());
+            } else if !word.is_empty() {
+                self.push_word(&std::mem::take(&mut word), &mut tokens);
+            }
+        }
+
+        Self::push_cjk_run(&cjk_run, &mut tokens);
+        if !word.is_empty() {
+            self.push_word(&word, &mut tokens);
+        }
+        tokens
+    }
+}
+
+/// Common English function words, used by
+/// [`StandardTokenizer::with_english_stopwords`].
+#[rustfmt::skip]
+pub const ENGLISH_STOPWORDS: &[&str] = &[
+    "a", "about", "above", "after", "again", "against", "all", "am", "an", "and", "any", "are",
+    "as", "at", "be", "because", "been", "before", "being", "below", "between", "both", "but", "by",
+    "can", "did", "do", "does", "doing", "down", "during", "each", "few", "for", "from", "further",
+    "had", "has", "have", "having", "he", "her", "here", "hers", "herself", "him", "himself", "his",
+    "how", "i", "if", "in", "into", "is", "it", "its", "itself", "just", "me", "more", "most", "my",
+    "myself", "no", "nor", "not", "now", "of", "off", "on", "once", "only", "or", "other", "our",
+    "ours", "ourselves", "out", "over", "own", "same", "she", "should", "so", "some", "such",
+    "than", "that", "the", "their", "theirs", "them", "themselves", "then", "there", "these",
+    "they", "this", "those", "through", "to", "too", "under", "until", "up", "very", "was", "we",
+    "were", "what", "when", "where", "which", "while", "who", "whom", "why", "will", "with", "you",
+    "your", "yours", "yourself", "yourselves",
+];
+
+fn is_cjk(ch: char) -> bool {
+    matches!(
+        ch as u32,
+        0x3040..=0x30FF      // Hiragana, Katakana
+            | 0x31F0..=0x31FF  // Katakana phonetic extensions
+            | 0x3400..=0x4DBF  // CJK extension A
+            | 0x4E00..=0x9FFF  // CJK unified ideographs
+            | 0xAC00..=0xD7AF  // Hangul syllables
+            | 0xF900..=0xFAFF  // CJK compatibility ideographs
+            | 0xFF66..=0xFF9F  // Half-width Katakana
+            | 0x20000..=0x2FA1F // CJK extensions B+ and supplement
+    )
+}
+
+/// Strip diacritics and fold compatibility forms (full-width ASCII,
+/// ideographic space) so that visually equivalent text tokenizes the same.
+fn fold_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch as u32 {
+            // Combining diacritical marks (decomposed input).
+            0x0300..=0x036F => {}
+            // Full-width ASCII variants.
+            0xFF01..=0xFF5E => out.push(char::from_u32(ch as u32 - 0xFEE0).unwrap_or(ch)),
+            0x3000 => out.push(' '),
+            _ => match fold_latin(ch) {
+                Some(folded) => out.push_str(folded),
+                None => out.push(ch),
+            },
+        }
+    }
+    out
+}
+
+fn fold_latin(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'Ā' | 'ā' | 'Ă'
+        | 'ă' | 'Ą' | 'ą' => "a",
+        'Æ' | 'æ' => "ae",
+        'Ç' | 'ç' | 'Ć' | 'ć' | 'Ĉ' | 'ĉ' | 'Ċ' | 'ċ' | 'Č' | 'č' => "c",
+        'Ð' | 'ð' | 'Ď' | 'ď' | 'Đ' | 'đ' => "d",
+        'È' | 'É' | 'Ê' | 'Ë' | 'è' | 'é' | 'ê' | 'ë' | 'Ē' | 'ē' | 'Ĕ' | 'ĕ' | 'Ė' | 'ė' | 'Ę'
+        | 'ę' | 'Ě' | 'ě' => "e",
+        'Ĝ' | 'ĝ' | 'Ğ' | 'ğ' | 'Ġ' | 'ġ' | 'Ģ' | 'ģ' => "g",
+        'Ĥ' | 'ĥ' | 'Ħ' | 'ħ' => "h",
+        'Ì' | 'Í' | 'Î' | 'Ï' | 'ì' | 'í' | 'î' | 'ï' | 'Ĩ' | 'ĩ' | 'Ī' | 'ī' | 'Ĭ' | 'ĭ' | 'Į'
+        | 'į' | 'İ' | 'ı' => "i",
+        'Ĳ' | 'ĳ' => "ij",
+        'Ĵ' | 'ĵ' => "j",
+        'Ķ' | 'ķ' => "k",
+        'Ĺ' | 'ĺ' | 'Ļ' | 'ļ' | 'Ľ' | 'ľ' | 'Ŀ' | 'ŀ' | 'Ł' | 'ł' => "l",
+        'Ñ' | 'ñ' | 'Ń' | 'ń' | 'Ņ' | 'ņ' | 'Ň' | 'ň' => "n",
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'Ō' | 'ō' | 'Ŏ'
+        | 'ŏ' | 'Ő' | 'ő' => "o",
+        'Œ' | 'œ' => "oe",
+        'Ŕ' | 'ŕ' | 'Ŗ' | 'ŗ' | 'Ř' | 'ř' => "r",
+        'Ś' | 'ś' | 'Ŝ' | 'ŝ' | 'Ş' | 'ş' | 'Š' | 'š' => "s",
+        'ß' => "ss",
+        'Ţ' | 'ţ' | 'Ť' | 'ť' | 'Ŧ' | 'ŧ' => "t",
+        'Þ' | 'þ' => "th",
+        'Ù' | 'Ú' | 'Û' | 'Ü' | 'ù' | 'ú' | 'û' | 'ü' | 'Ũ' | 'ũ' | 'Ū' | 'ū' | 'Ŭ' | 'ŭ' | 'Ů'
+        | 'ů' | 'Ű' | 'ű' | 'Ų' | 'ų' => "u",
+        'Ŵ' | 'ŵ' => "w",
+        'Ý' | 'ý' | 'ÿ' | 'Ŷ' | 'ŷ' | 'Ÿ' => "y",
+        'Ź' | 'ź' | 'Ż' | 'ż' | 'Ž' | 'ž' => "z",
+        _ => return None,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Porter stemmer
+// ---------------------------------------------------------------------------
+
+/// Stem an English word with the Porter algorithm.
+///
+/// Words shorter than three letters or containing anything other than ASCII
+/// lowercase letters are returned unchanged.
+fn porter_stem(word: &str) -> String {
+    if word.len() < 3 || !word.bytes().all(|b| b.is_ascii_lowercase()) {
+        return word.to_string();
+    }
+    let mut w = word.as_bytes().to_vec();
+    step1a(&mut w);
+    step1b(&mut w);
+    step1c(&mut w);
+    step2(&mut w);
+    step3(&mut w);
+    step4(&mut w);
+    step5(&mut w);
+    String::from_utf8(w).expect("stemmer only removes or adds ASCII")
+}
+
+fn is_consonant(w: &[u8], i: usize) -> bool {
+    match w[i] {
+        b'a' | b'e' | b'i' | b'o' | b'u' => false,
+        b'y' => i == 0 || !is_consonant(w, i - 1),
+        _ => true,
+    }
+}
+
+/// Number of vowel-consonant sequences in `w` (Porter's *m*).
+fn measure(w: &[u8]) -> usize {
+    let n = w.len();
+    let mut i = 0;
+    while i < n && is_consonant(w, i) {
+        i += 1;
+    }
+    let mut m = 0;
+    loop {
+        while i < n && !is_consonant(w, i) {
+            i += 1;
+        }
+        if i >= n {
+            return m;
+        }
+        while i < n && is_consonant(w, i) {
+            i += 1;
+        }
+        m += 1;
+    }
+}
+
+fn has_vowel(w: &[u8]) -> bool {
+    (0..w.len()).any(|i| !is_consonant(w, i))
+}
+
+fn ends_double_consonant(w: &[u8]) -> bool {
+    let n = w.len();
+    n >= 2 && w[n - 1] == w[n - 2] && is_consonant(w, n - 1)
+}
+
+/// Consonant-vowel-consonant ending where the last consonant is not w, x, y.
+fn ends_cvc(w: &[u8]) -> bool {
+    let n = w.len();
+    n >= 3
+        && is_consonant(w, n - 3)
+        && !is_consonant(w, n - 2)
+        && is_consonant(w, n - 1)
+        && !matches!(w[n - 1], b'w' | b'x' | b'y')
+}
+
+/// Replace `suffix` with `replacement` if the remaining stem has measure
+/// greater than `min_measure`. Returns whether `suffix` matched at all.
+fn replace_suffix(w: &mut Vec<u8>, suffix: &str, replacement: &str, min_measure: usize) -> bool {
+    if !w.ends_with(suffix.as_bytes()) {
+        return false;
+    }
+    let stem_len = w.len() - suffix.len();
+    if measure(&w[..stem_len]) > min_measure {
+        w.truncate(stem_len);
+        w.extend_from_slice(replacement.as_bytes());
+    }
+    true
+}
+
+fn step1a(w: &mut Vec<u8>) {
+    if w.ends_with(b"sses") || w.ends_with(b"ies") {
+        w.truncate(w.len() - 2);
+    } else if w.ends_with(b"s") && !w.ends_with(b"ss") {
+        w.pop();
+    }
+}
+
+fn step1b(w: &mut Vec<u8>) {
+    if w.ends_with(b"eed") {
+        if measure(&w[..w.len() - 3]) > 0 {
+            w.pop();
+        }
+        return;
+    }
+    let suffix_len = if w.ends_with(b"ed") && has_vowel(&w[..w.len() - 2]) {
+        2
+    } else if w.ends_with(b"ing") && has_vowel(&w[..w.len() - 3]) {
+        3
+    } else {
+        return;
+    };
+    w.truncate(w.len() - suffix_len);
+
+    if w.ends_with(b"at") || w.ends_with(b"bl") || w.ends_with(b"iz") {
+        w.push(b'e');
+    } else if ends_double_consonant(w) && !matches!(w[w.len() - 1], b'l' | b's' | b'z') {
+        w.pop();
+    } else if measure(w) == 1 && ends_cvc(w) {
+        w.push(b'e');
+    }
+}
+
+fn step1c(w: &mut [u8]) {
+    let n = w.len();
+    if w.ends_with(b"y") && has_vowel(&w[..n - 1]) {
+        w[n - 1] = b'i';
+    }
+}
+
+fn step2(w: &mut Vec<u8>) {
+    const RULES: &[(&str, &str)] = &[
+        ("ational", "ate"),
+        ("tional", "tion"),
+        ("enci", "ence"),
+        ("anci", "ance"),
+        ("izer", "ize"),
+        ("bli", "ble"),
+        ("alli", "al"),
+        ("entli", "ent"),
+        ("eli", "e"),
+        ("ousli", "ous"),
+        ("ization", "ize"),
+        ("ation", "ate"),
+        ("ator", "ate"),
+        ("alism", "al"),
+        ("iveness", "ive"),
+        ("fulness", "ful"),
+        ("ousness", "ous"),
+        ("aliti", "al"),
+        ("iviti", "ive"),
+        ("biliti", "ble"),
+        ("logi", "log"),
+    ];
+    for (suffix, replacement) in RULES {
+        if replace_suffix(w, suffix, replacement, 0) {
+            return;
+        }
+    }
+}
+
+fn step3(w: &mut Vec<u8>) {
+    const RULES: &[(&str, &str)] = &[
+        ("icate", "ic"),
+        ("ative", ""),
+        ("alize", "al"),
+        ("iciti", "ic"),
+        ("ical", "ic"),
+        ("ful", ""),
+        ("ness", ""),
+    ];
+    for (suffix, replacement) in RULES {
+        if replace_suffix(w, suffix, replacement, 0) {
+            return;
+        }
+    }
+}
+
+fn step4(w: &mut Vec<u8>) {
+    const SUFFIXES: &[&str] = &[
+        "al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement", "ment", "ent", "ion",
+        "ou", "ism", "ate", "iti", "ous", "ive", "ize",
+    ];
+    for suffix in SUFFIXES {
+        if !w.ends_with(suffix.as_bytes()) {
+            continue;
+        }
+        let stem_len = w.len() - suffix.len();
+        let stem_ok = *suffix != "ion" || matches!(w[..stem_len].last(), Some(b's' | b't'));
+        if stem_ok && measure(&w[..stem_len]) > 1 {
+            w.truncate(stem_len);
+        }
+        return;
+    }
+}
+
+fn step5(w: &mut Vec<u8>) {
+    if w.ends_with(b"e") {
+        let stem = &w[..w.len() - 1];
+        let m = measure(stem);
+        if m > 1 || (m == 1 && !ends_cvc(stem)) {
+            w.pop();
+        }
+    }
+    if w.ends_with(b"ll") && measure(w) > 1 {
+        w.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_tokenizer_matches_original_behaviour() {
+        let tokens = StandardTokenizer::new().tokenize("Alice works_at Acme-Corp! 東京都");
+        assert_eq!(
+            tokens,
+            vec!["alice", "works", "at", "acme", "corp", "東京都"]
+        );
+    }
+
+    #[test]
+    fn porter_stemmer_matches_reference_vocabulary() {
+        let cases = [
+            ("caresses", "caress"),
+            ("ponies", "poni"),
+            ("cats", "cat"),
+            ("agreed", "agre"),
+            ("plastered", "plaster"),
+            ("motoring", "motor"),
+            ("sing", "sing"),
+            ("conflated", "conflat"),
+            ("hopping", "hop"),
+            ("filing", "file"),
+            ("happy", "happi"),
+            ("relational", "relat"),
+            ("generalization", "gener"),
+            ("controlling", "control"),
+            ("works", "work"),
+            ("working", "work"),
+            ("worked", "work"),
+        ];
+        for (word, stem) in cases {
+            assert_eq!(porter_stem(word), stem, "stem of {word}");
+        }
+    }
+
+    #[test]
+    fn folding_stopwords_and_cjk_bigrams_compose() {
+        let tokenizer = StandardTokenizer::english();
+        assert_eq!(
+            tokenizer.tokenize("Ｔｈｅ naïve CAFE\u{301} in Straße"),
+            vec!["naiv", "cafe", "strass"]
+        );
+        assert_eq!(
+            tokenizer.tokenize("私は東京に住む"),
+            vec!["私は", "は東", "東京", "京に", "に住", "住む"]
+        );
+        assert_eq!(tokenizer.tokenize("alice 京"), vec!["alic", "京"]);
+    }
+
+    #[test]
+    fn stopwords_are_folded_when_folding_is_enabled() {
+        let folded = StandardTokenizer::new()
+            .with_diacritic_folding(true)
+            .with_stopwords(["café"]);
+        assert_eq!(folded.tokenize("Café au lait"), vec!["au", "lait"]);
+        let folded_later = StandardTokenizer::new()
+            .with_stopwords(["Café"])
+            .with_diacritic_folding(true);
+        assert_eq!(folded_later.tokenize("cafe au lait"), vec!["au", "lait"]);
+
+        let unfolded = StandardTokenizer::new().with_stopwords(["café"]);
+        assert_eq!(unfolded.tokenize("café cafe"), vec!["cafe"]);
+    }
+
+    #[test]
+    fn tokenizer_id_changes_with_configuration() {
+        let base = StandardTokenizer::new();
+        let custom = StandardTokenizer::new().with_stopwords(["acme"]);
+        let other = StandardTokenizer::new().with_stopwords(["corp"]);
+        assert_eq!(base.id(), StandardTokenizer::default().id());
+        assert_ne!(base.id(), custom.id());
+        assert_ne!(custom.id(), other.id());
+        assert_ne!(base.id(), StandardTokenizer::english().id());
+        assert_eq!(custom.tokenize("Acme corp"), vec!["corp"]);
+    }
+}
@@ -214,6 +214,7 @@ pub fn compute_effective_confidence(
 /// Loaded from storage on init, kept in sync by the registration methods on
 /// [`TemporalGraph`](crate::TemporalGraph). Follows the same pattern as
 /// `ContradictionDetector`.
+#[derive(Clone)]
 pub(crate) struct UncertaintyEngine {
     volatility: HashMap<String, PredicateVolatility>,
     source_weights: HashMap<String, SourceWeight>,
@@ -279,6 +280,7 @@ mod tests {
             expired_at: None,
             confidence,
             source: None,
+            metadata: Default::default(),
         }
     }
 
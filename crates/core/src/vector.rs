@@ -1,57 +1,477 @@
 //! In-memory vector index for semantic similarity search.
 //!
-//! Phase 0 implementation: flat (brute-force) cosine similarity over pre-computed
-//! embeddings. No external dependencies. Works on every target — native, WASM, iOS,
-//! Android.
+//! [`Metric`] scores over pre-computed embeddings. Small indexes are searched
+//! exhaustively; once an index reaches [`HnswConfig::exact_threshold`]
+//! entries it also maintains an [HNSW](crate::hnsw) graph and answers queries
+//! approximately. No external dependencies. Works on every target — native,
+//! WASM, iOS, Android.
 //!
 //! Callers supply embeddings; Kronroe never generates them. Embedding generation is
 //! the responsibility of `kronroe-agent-memory` or the calling application.
 //!
+//! With [`Quantization`] enabled the index holds compact codes instead of the
+//! `f32` values; [`VectorIndex::search_where`] then ranks by approximate scores and
+//! the caller re-scores an enlarged shortlist with [`rescore`].
+//!
+//! Searches are filtered inside the index: each [`VectorIndex`] keeps a
+//! bitmap of the entries whose facts are currently valid, and
+//! [`VectorSpaces`] keeps the [`SearchFilter`] fields of every embedded fact,
+//! both refreshed on every fact write. A filtered search never loads facts
+//! from storage: through the HNSW graph it checks the filter only on the
+//! slots the walk returns, and enumerates passing entries only when the
+//! filter turns out to be selective.
+//!
 //! # Complexity
-//! - `insert`: O(1) amortised
-//! - `remove`: O(n) swap-remove — acceptable at Phase 0 scale (hundreds to low
-//!   thousands of facts)
-//! - `search`: O(n·d) where d is embedding dimension
+//! - `insert` / `remove`: O(1) below the threshold; see [`crate::hnsw`] above it
+//! - `search`: O(n·d) exhaustive, or O(log n · ef · M · d) through the graph
 //!
-//! When corpora grow to tens of thousands of entries a proper HNSW index should
-//! replace this module. See CLAUDE.md §0.8 for the evaluation notes.
+//! The graph can be saved to a checkpoint file next to the database so it is
+//! not rebuilt on every open (see [`VectorConfig::with_checkpoint`]).
 
-use crate::{FactId, KronroeError, Result};
+use crate::checkpoint;
+use crate::filter::{DocumentMeta, SearchFilter};
+use crate::hnsw::{read_u32, take, HnswConfig, HnswGraph, Points, Scored};
+use crate::metric::{l2_norm, Metric};
+use crate::quantize::{Codes, EmbeddingEncoding, Quantization, Query};
+use crate::{Fact, FactId, KronroeError, Result};
+#[cfg(test)]
 use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap};
+
+const CHECKPOINT_MAGIC: &[u8] = b"kronroe-vector-index-v1\n";
+const SPACES_CHECKPOINT_MAGIC: &[u8] = b"kronroe-vector-spaces-v1\n";
+
+/// Embedding space used by the vector APIs that do not take a space name.
+pub const DEFAULT_EMBEDDING_SPACE: &str = "default";
+
+/// Persisted `(fact, embedding)` pairs of one space.
+pub(crate) type EmbeddingRows = Vec<(FactId, Vec<f32>)>;
+
+/// Below this share of indexed entries passing the caller's filter
+/// (1 in `SELECTIVE_FILTER_RATIO`), the filtered entries are scored
+/// exhaustively instead of walking the graph.
+const SELECTIVE_FILTER_RATIO: usize = 8;
+
+/// Vector search settings for a database, passed to
+/// [`TemporalGraph::open_with_vector_config`].
+///
+/// ```rust,no_run
+/// # use kronroe::{HnswConfig, Quantization, TemporalGraph, VectorConfig};
+/// let config = VectorConfig::new()
+///     .with_hnsw(HnswConfig::new().with_ef_search(128))
+///     .with_quantization(Quantization::Int8)
+///     .with_checkpoint("memory.kronroe.vectors");
+/// let db = TemporalGraph::open_with_vector_config("memory.kronroe", config).unwrap();
+/// ```
+///
+/// [`TemporalGraph::open_with_vector_config`]: crate::TemporalGraph::open_with_vector_config
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VectorConfig {
+    pub(crate) hnsw: HnswConfig,
+    pub(crate) checkpoint: Option<String>,
+    pub(crate) quantization: Quantization,
+    pub(crate) rescore_multiplier: Option<usize>,
+    pub(crate) log_encoding: EmbeddingEncoding,
+}
+
+impl Default for VectorConfig {
+    fn default() -> Self {
+        Self {
+            hnsw: HnswConfig::default(),
+            checkpoint: None,
+            quantization: Quantization::None,
+            rescore_multiplier: None,
+            log_encoding: EmbeddingEncoding::Json,
+        }
+    }
+}
+
+impl VectorConfig {
+    /// Default [`HnswConfig`], full-precision index, JSON log encoding, and
+    /// no checkpoint.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Approximate nearest-neighbour index parameters.
+    pub fn with_hnsw(mut self, hnsw: HnswConfig) -> Self {
+        self.hnsw = hnsw;
+        self
+    }
+
+    /// Seed the graph from a checkpoint written by
+    /// [`TemporalGraph::save_vector_checkpoint`].
+    ///
+    /// The checkpoint is only used if it was built with the same `m`,
+    /// `ef_construction`, and quantization and covers exactly the embeddings
+    /// currently stored; a missing, stale, or unreadable checkpoint is
+    /// ignored and the graph is rebuilt.
+    ///
+    /// [`TemporalGraph::save_vector_checkpoint`]: crate::TemporalGraph::save_vector_checkpoint
+    pub fn with_checkpoint(mut self, path: impl Into<String>) -> Self {
+        self.checkpoint = Some(path.into());
+        self
+    }
+
+    /// How the in-memory index holds embeddings (default
+    /// [`Quantization::None`]).
+    pub fn with_quantization(mut self, quantization: Quantization) -> Self {
+        self.quantization = quantization;
+        self
+    }
+
+    /// With quantization enabled, `k * multiplier` candidates are
+    /// shortlisted from the codes and re-scored at full precision (minimum
+    /// `1`). Defaults to `4` for [`Quantization::Int8`] and `32` for the much
+    /// coarser [`Quantization::Binary`]; ignored for [`Quantization::None`].
+    pub fn with_rescore_multiplier(mut self, multiplier: usize) -> Self {
+        self.rescore_multiplier = Some(multiplier.max(1));
+        self
+    }
+
+    /// How new embeddings are written to the append log (default
+    /// [`EmbeddingEncoding::Json`]). Existing records keep their encoding.
+    pub fn with_log_encoding(mut self, encoding: EmbeddingEncoding) -> Self {
+        self.log_encoding = encoding;
+        self
+    }
+
+    pub fn hnsw(&self) -> HnswConfig {
+        self.hnsw
+    }
 
-/// An entry in the index: a fact identifier paired with its embedding vector.
+    pub fn quantization(&self) -> Quantization {
+        self.quantization
+    }
+
+    pub fn rescore_multiplier(&self) -> usize {
+        self.rescore_multiplier.unwrap_or(match self.quantization {
+            Quantization::None => 1,
+            Quantization::Int8 => 4,
+            Quantization::Binary => 32,
+        })
+    }
+
+    pub fn log_encoding(&self) -> EmbeddingEncoding {
+        self.log_encoding
+    }
+}
+
+/// Check that `space` is usable as an embedding space name: 1–64 ASCII
+/// letters, digits, `-`, `_`, `.`, or `:`.
+pub(crate) fn validate_space_name(space: &str) -> Result<()> {
+    let valid_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':');
+    if space.is_empty() || space.len() > 64 || !space.chars().all(valid_char) {
+        return Err(KronroeError::invalid_input(format!(
+            "invalid embedding space name '{space}': use 1-64 ASCII letters, digits, '-', '_', '.', or ':'"
+        )));
+    }
+    Ok(())
+}
+
+/// One [`VectorIndex`] per named embedding space, all built with the
+/// database's [`VectorConfig`] and each with its own [`Metric`]. A space
+/// exists once it holds an embedding; its dimension is fixed by the first
+/// one.
+#[derive(Debug, Default)]
+pub(crate) struct VectorSpaces {
+    config: VectorConfig,
+    spaces: BTreeMap<String, VectorIndex>,
+    /// Registered metrics; spaces without an entry use [`Metric::Cosine`].
+    metrics: BTreeMap<String, Metric>,
+    /// Subject and filterable fields of every fact with an embedding in any
+    /// space. Not checkpointed; refreshed from storage on open.
+    meta: HashMap<FactId, (String, DocumentMeta)>,
+}
+
+impl VectorSpaces {
+    /// Build every space from persisted embeddings, adopting graphs from a
+    /// [`to_checkpoint_bytes`](Self::to_checkpoint_bytes) checkpoint where
+    /// they still match.
+    pub(crate) fn from_rows(
+        rows_by_space: Vec<(String, EmbeddingRows)>,
+        metrics: BTreeMap<String, Metric>,
+        config: VectorConfig,
+        checkpoint: Option<&[u8]>,
+    ) -> Result<Self> {
+        let checkpoints = checkpoint
+            .and_then(read_spaces_checkpoint)
+            .unwrap_or_default();
+        let mut spaces = BTreeMap::new();
+        for (space, rows) in rows_by_space {
+            let bytes = checkpoints.get(space.as_str()).copied();
+            let metric = metrics.get(&space).copied().unwrap_or_default();
+            let index = VectorIndex::from_rows(rows, &config, metric, bytes)?;
+            spaces.insert(space, index);
+        }
+        Ok(Self {
+            config,
+            spaces,
+            metrics,
+            meta: HashMap::new(),
+        })
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.spaces.is_empty()
+    }
+
+    pub(crate) fn get(&self, space: &str) -> Option<&VectorIndex> {
+        self.spaces.get(space)
+    }
+
+    /// Insert or replace `fact`'s embedding in `space`, creating the space
+    /// if it is new.
+    pub(crate) fn insert(&mut self, space: &str, fact: &Fact, embedding: Vec<f32>) -> Result<()> {
+        self.get_or_insert(space)
+            .insert(fact.id.clone(), embedding)?;
+        self.observe(fact);
+        Ok(())
+    }
+
+    /// Remove `id`'s embedding from `space`. A space left empty is dropped,
+    /// so its next embedding may have any dimension.
+    pub(crate) fn remove(&mut self, space: &str, id: &FactId) {
+        let Some(index) = self.spaces.get_mut(space) else {
+            return;
+        };
+        index.remove(id);
+        if index.is_empty() {
+            self.spaces.remove(space);
+        }
+        if !self.spaces.values().any(|index| index.contains(id)) {
+            self.meta.remove(id);
+        }
+    }
+
+    /// Does `id` have an embedding in `space`?
+    pub(crate) fn contains(&self, space: &str, id: &FactId) -> bool {
+        self.spaces
+            .get(space)
+            .is_some_and(|index| index.contains(id))
+    }
+
+    /// Refresh the filterable fields and validity bits of `fact` after a
+    /// write. Facts without an embedding are ignored.
+    pub(crate) fn observe(&mut self, fact: &Fact) {
+        let current = fact.is_currently_valid();
+        let mut embedded = false;
+        for index in self.spaces.values_mut() {
+            embedded |= index.set_current(&fact.id, current);
+        }
+        if embedded {
+            self.meta.insert(
+                fact.id.clone(),
+                (fact.subject.clone(), DocumentMeta::of(fact)),
+            );
+        }
+    }
+
+    /// Entry filter for [`VectorIndex::search_where`], or `None` when
+    /// `filter` restricts nothing beyond `current_only`, which the caller
+    /// passes separately so the validity bitmap is used.
+    pub(crate) fn accepts<'a>(
+        &'a self,
+        filter: &'a SearchFilter,
+    ) -> Option<impl Fn(&FactId) -> bool + 'a> {
+        let beyond_current = SearchFilter {
+            current_only: false,
+            ..filter.clone()
+        };
+        (!beyond_current.is_unrestricted()).then_some(move |id: &FactId| {
+            self.meta
+                .get(id)
+                .is_some_and(|(subject, meta)| filter.accepts(subject, meta))
+        })
+    }
+
+    /// The index for `space`, created empty if the space is new.
+    fn get_or_insert(&mut self, space: &str) -> &mut VectorIndex {
+        let config = &self.config;
+        let metric = self.metric(space);
+        self.spaces.entry(space.to_string()).or_insert_with(|| {
+            VectorIndex::with_config(config.hnsw)
+                .with_quantization(config.quantization)
+                .with_metric(metric)
+        })
+    }
+
+    pub(crate) fn metric(&self, space: &str) -> Metric {
+        self.metrics.get(space).copied().unwrap_or_default()
+    }
+
+    /// Switch `space` to `metric`, rebuilding its index from `rows` (the
+    /// space's persisted embeddings) since every score changes.
+    pub(crate) fn set_metric(
+        &mut self,
+        space: &str,
+        metric: Metric,
+        rows: EmbeddingRows,
+    ) -> Result<()> {
+        self.metrics.insert(space.to_string(), metric);
+        if rows.is_empty() {
+            self.spaces.remove(space);
+        } else {
+            let mut index = VectorIndex::from_rows(rows, &self.config, metric, None)?;
+            for (id, (_, meta)) in &self.meta {
+                index.set_current(id, meta.is_current());
+            }
+            self.spaces.insert(space.to_string(), index);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn rescore_multiplier(&self) -> usize {
+        self.config.rescore_multiplier()
+    }
+
+    /// Every space's [`VectorIndex::to_checkpoint_bytes`], keyed by name.
+    pub(crate) fn to_checkpoint_bytes(&self) -> Vec<u8> {
+        let mut out = SPACES_CHECKPOINT_MAGIC.to_vec();
+        out.extend_from_slice(&(self.spaces.len() as u32).to_le_bytes());
+        for (space, index) in &self.spaces {
+            let bytes = index.to_checkpoint_bytes();
+            out.extend_from_slice(&(space.len() as u32).to_le_bytes());
+            out.extend_from_slice(space.as_bytes());
+            out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+            out.extend_from_slice(&bytes);
+        }
+        out
+    }
+}
+
+/// Split a spaces checkpoint into per-space index checkpoints.
+fn read_spaces_checkpoint(bytes: &[u8]) -> Option<BTreeMap<&str, &[u8]>> {
+    let mut input = bytes.strip_prefix(SPACES_CHECKPOINT_MAGIC)?;
+    let count = read_u32(&mut input)?;
+    let mut spaces = BTreeMap::new();
+    for _ in 0..count {
+        let len = read_u32(&mut input)? as usize;
+        let space = std::str::from_utf8(take(&mut input, len)?).ok()?;
+        let len = read_u32(&mut input)? as usize;
+        spaces.insert(space, take(&mut input, len)?);
+    }
+    input.is_empty().then_some(spaces)
+}
+
+/// An entry in the index: a fact identifier paired with its (possibly
+/// quantized) embedding.
 #[derive(Debug, Clone)]
 struct Entry {
     id: FactId,
-    embedding: Vec<f32>,
+    codes: Codes,
+    norm: f32,
 }
 
-/// Flat vector index keyed by [`FactId`].
+/// Vector index keyed by [`FactId`].
 ///
 /// Held entirely in memory as a read-optimised cache. The backing store is the
 /// persisted embedding set, which is written atomically alongside the fact row in
 /// [`TemporalGraph::assert_fact_with_embedding`] and read back by
-/// [`TemporalGraph`]'s `rebuild_vector_index_from_db` on every `open` / `open_in_memory`
-/// call. The storage backend is the source of truth; this struct is a derived view.
+/// [`TemporalGraph`]'s `rebuild_vector_index_from_storage` on every `open` /
+/// `open_in_memory` call. The storage backend is the source of truth; this struct
+/// is a derived view.
 #[derive(Debug, Default, Clone)]
 pub struct VectorIndex {
-    entries: Vec<Entry>,
+    /// Entries by slot; the HNSW graph refers to entries by slot. Removed
+    /// entries leave a vacant slot, which is reused only when the same id is
+    /// inserted again.
+    slots: Vec<Option<Entry>>,
+    slot_by_id: HashMap<FactId, u32>,
     /// Expected embedding dimension. Set on first insert; subsequent inserts are
     /// validated against it.
     dim: Option<usize>,
+    config: HnswConfig,
+    quantization: Quantization,
+    metric: Metric,
+    /// Bit `slot` is set while the entry's fact is currently valid. Kept up
+    /// to date by [`VectorSpaces::observe`]; not checkpointed.
+    current: Vec<u64>,
+    /// Number of bits set in `current`.
+    current_count: usize,
+    /// Built once the index reaches `config.exact_threshold` entries.
+    graph: Option<HnswGraph>,
+}
+
+/// Distances (negated [`Metric`] scores) between slots.
+struct SlotPoints<'a>(&'a [Option<Entry>], Metric);
+
+impl Points for SlotPoints<'_> {
+    fn distance(&self, a: u32, b: u32) -> f32 {
+        match (&self.0[a as usize], &self.0[b as usize]) {
+            (Some(a), Some(b)) => -a.codes.similarity(a.norm, &b.codes, b.norm, self.1),
+            _ => f32::INFINITY,
+        }
+    }
 }
 
 impl VectorIndex {
     /// Create an empty index.
+    #[allow(dead_code)]
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Create an empty index with the given approximate-search parameters.
+    pub fn with_config(config: HnswConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Hold embeddings as `quantization` codes. Must be set while empty.
+    pub(crate) fn with_quantization(mut self, quantization: Quantization) -> Self {
+        debug_assert!(self.is_empty());
+        self.quantization = quantization;
+        self
+    }
+
+    /// Score entries with `metric`. Must be set while empty.
+    pub(crate) fn with_metric(mut self, metric: Metric) -> Self {
+        debug_assert!(self.is_empty());
+        self.metric = metric;
+        self
+    }
+
+    /// Build an index from persisted embeddings, adopting the graph from
+    /// `checkpoint` when it matches them and building it otherwise.
+    pub(crate) fn from_rows(
+        rows: EmbeddingRows,
+        config: &VectorConfig,
+        metric: Metric,
+        checkpoint: Option<&[u8]>,
+    ) -> Result<Self> {
+        let mut index = Self::with_config(config.hnsw)
+            .with_quantization(config.quantization)
+            .with_metric(metric);
+        for (id, embedding) in rows {
+            index.insert_entry(id, embedding)?;
+        }
+        let adopted = checkpoint.is_some_and(|bytes| index.adopt_checkpoint(bytes));
+        if !adopted && index.len() >= config.hnsw.exact_threshold() {
+            index.build_graph();
+        }
+        Ok(index)
+    }
+
     /// Insert or replace an embedding for `id`.
     ///
     /// Returns an error if the embedding is empty or if its dimension differs
     /// from the first embedding ever inserted into this index.
     pub fn insert(&mut self, id: FactId, embedding: Vec<f32>) -> Result<()> {
+        let slot = self.insert_entry(id, embedding)?;
+        if let Some(graph) = &mut self.graph {
+            graph.insert(slot, &SlotPoints(&self.slots, self.metric));
+        } else if self.len() >= self.config.exact_threshold() {
+            self.build_graph();
+        }
+        Ok(())
+    }
+
+    /// Validate and store an embedding, unlinking a replaced one from the
+    /// graph. Returns the entry's slot, not yet linked into the graph.
+    fn insert_entry(&mut self, id: FactId, embedding: Vec<f32>) -> Result<u32> {
         if embedding.is_empty() {
             return Err(KronroeError::invalid_embedding(
                 "embedding must not be empty",
@@ -69,73 +489,275 @@ impl VectorIndex {
             Some(_) => {}
         }
 
+        let codes = self.quantization.encode(embedding);
+        let entry = Entry {
+            id: id.clone(),
+            norm: codes.norm(),
+            codes,
+        };
         // Replace an existing entry for the same id (e.g. after `correct_fact`).
-        if let Some(entry) = self.entries.iter_mut().find(|e| e.id == id) {
-            entry.embedding = embedding;
-        } else {
-            self.entries.push(Entry { id, embedding });
+        if let Some(&slot) = self.slot_by_id.get(&id) {
+            if let Some(graph) = &mut self.graph {
+                graph.remove(slot, &SlotPoints(&self.slots, self.metric));
+            }
+            self.slots[slot as usize] = Some(entry);
+            return Ok(slot);
         }
-        Ok(())
+        let slot = u32::try_from(self.slots.len())
+            .map_err(|_| KronroeError::invalid_embedding("vector index is full"))?;
+        self.slots.push(Some(entry));
+        self.slot_by_id.insert(id, slot);
+        Ok(slot)
+    }
+
+    fn build_graph(&mut self) {
+        let mut graph = HnswGraph::new(&self.config);
+        let points = SlotPoints(&self.slots, self.metric);
+        for (slot, entry) in self.slots.iter().enumerate() {
+            if entry.is_some() {
+                graph.insert(slot as u32, &points);
+            }
+        }
+        self.graph = Some(graph);
     }
 
     /// Remove the entry for `id`. No-op if `id` is not present.
     ///
-    /// Uses swap-remove for O(1) memory ops at the cost of non-stable ordering —
-    /// acceptable because search results are always re-ranked by score.
-    ///
     /// Not called from `invalidate_fact` by design: invalidated facts are
-    /// excluded via the `valid_ids` allow-list in `search_by_vector`, so their
+    /// excluded through the validity bitmap in `search_by_vector`, so their
     /// embeddings must remain in the index to support historical point-in-time
-    /// searches. This method exists for future compaction / explicit eviction
-    /// scenarios (e.g. permanent deletion in Phase 1).
-    #[allow(dead_code)]
+    /// searches. Used when an embedding is explicitly removed with
+    /// [`TemporalGraph::remove_embedding`](crate::TemporalGraph::remove_embedding).
     pub fn remove(&mut self, id: &FactId) {
-        if let Some(pos) = self.entries.iter().position(|e| &e.id == id) {
-            self.entries.swap_remove(pos);
+        let Some(slot) = self.slot_by_id.remove(id) else {
+            return;
+        };
+        if let Some(graph) = &mut self.graph {
+            graph.remove(slot, &SlotPoints(&self.slots, self.metric));
+        }
+        self.slots[slot as usize] = None;
+        self.set_slot_current(slot, false);
+    }
+
+    pub(crate) fn contains(&self, id: &FactId) -> bool {
+        self.slot_by_id.contains_key(id)
+    }
+
+    /// Mark whether `id`'s fact is currently valid. Returns false (and does
+    /// nothing) if `id` has no entry.
+    pub(crate) fn set_current(&mut self, id: &FactId, current: bool) -> bool {
+        match self.slot_by_id.get(id) {
+            Some(&slot) => {
+                self.set_slot_current(slot, current);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn set_slot_current(&mut self, slot: u32, current: bool) {
+        let (word, bit) = (slot as usize / 64, slot % 64);
+        if word >= self.current.len() {
+            if !current {
+                return;
+            }
+            self.current.resize(word + 1, 0);
+        }
+        let was_current = self.current[word] & (1 << bit) != 0;
+        if current {
+            self.current[word] |= 1 << bit;
+        } else {
+            self.current[word] &= !(1 << bit);
+        }
+        match (was_current, current) {
+            (false, true) => self.current_count += 1,
+            (true, false) => self.current_count -= 1,
+            _ => {}
         }
     }
 
-    /// Return the top-`k` entries by cosine similarity to `query`, restricted to
-    /// the `valid_ids` allow-list.
+    fn is_slot_current(&self, slot: u32) -> bool {
+        self.current
+            .get(slot as usize / 64)
+            .is_some_and(|bits| bits & (1 << (slot % 64)) != 0)
+    }
+
+    /// Slots whose bit is set in the validity bitmap, ascending.
+    fn current_slots(&self) -> impl Iterator<Item = u32> + '_ {
+        self.current.iter().enumerate().flat_map(|(word, &bits)| {
+            let mut bits = bits;
+            std::iter::from_fn(move || {
+                (bits != 0).then(|| {
+                    let bit = bits.trailing_zeros();
+                    bits &= bits - 1;
+                    (word * 64) as u32 + bit
+                })
+            })
+        })
+    }
+
+    /// Return the top-`k` entries by [`Metric`] score against `query`, restricted to
+    /// the `valid_ids` allow-list. See [`search_where`](Self::search_where).
+    #[cfg(test)]
+    pub fn search(
+        &self,
+        query: &[f32],
+        k: usize,
+        valid_ids: &HashSet<FactId>,
+    ) -> Vec<(FactId, f32)> {
+        if valid_ids.is_empty() {
+            return Vec::new();
+        }
+        self.search_where(query, k, false, Some(|id: &FactId| valid_ids.contains(id)))
+    }
+
+    /// Return the top-`k` entries by [`Metric`] score against `query` among
+    /// those accepted by `accept` (all entries when `None`) and, with
+    /// `current_only`, set in the validity bitmap. With quantization the
+    /// scores are approximate; see [`rescore`].
     ///
-    /// `valid_ids` is computed by the caller from the bi-temporal index (e.g. all
-    /// facts valid at time T), enabling temporal filtering without coupling this
-    /// module to a specific storage backend or time library.
+    /// Through the HNSW graph, the graph is walked first and only the slots
+    /// it returns are checked against the bitmap and `accept`, so an
+    /// unrestricted or broad filter costs the same as an unfiltered search.
+    /// Passing entries are enumerated only when the walk comes up short or
+    /// the bitmap shows the filter is selective; `accept` is expected to be a
+    /// cheap in-memory check such as [`VectorSpaces::accepts`].
     ///
-    /// Results are returned in descending similarity order. If fewer than `k`
-    /// entries pass the filter, all passing entries are returned.
+    /// Results are returned in descending score order. If fewer than `k`
+    /// entries pass the filter, all passing entries are returned. Through the
+    /// HNSW graph the result is approximate; when the graph yields fewer than
+    /// `k` passing entries, the filtered entries are scored exhaustively.
     ///
-    /// Returns an empty `Vec` if `valid_ids` is empty or `k` is zero.
-    pub fn search(
+    /// Returns an empty `Vec` if no entry passes or `k` is zero.
+    pub(crate) fn search_where(
         &self,
         query: &[f32],
         k: usize,
-        valid_ids: &HashSet<FactId>,
+        current_only: bool,
+        accept: Option<impl Fn(&FactId) -> bool>,
     ) -> Vec<(FactId, f32)> {
-        if k == 0 || valid_ids.is_empty() || self.entries.is_empty() {
+        if k == 0 || self.is_empty() {
             return Vec::new();
         }
 
-        let query_norm = l2_norm(query);
-        if query_norm == 0.0 {
+        let query = Query::new(query, self.quantization);
+        // A zero query has no direction, so nothing is cosine-similar to it;
+        // the other metrics still rank entries against it.
+        if self.metric == Metric::Cosine && query.norm() == 0.0 {
             return Vec::new();
         }
 
-        let mut scored: Vec<(FactId, f32)> = self
-            .entries
-            .iter()
-            .filter(|e| valid_ids.contains(&e.id))
-            .map(|e| {
-                let score = cosine_similarity(query, &e.embedding, query_norm);
-                (e.id.clone(), score)
-            })
-            .collect();
+        let passes = |slot: u32| {
+            (!current_only || self.is_slot_current(slot))
+                && self.slots[slot as usize]
+                    .as_ref()
+                    .is_some_and(|entry| accept.as_ref().is_none_or(|accept| accept(&entry.id)))
+        };
+        let score = |slot: u32| match &self.slots[slot as usize] {
+            Some(entry) => entry.codes.similarity_to(entry.norm, &query, self.metric),
+            None => f32::NEG_INFINITY,
+        };
+        let valid_slots = || -> Vec<u32> {
+            if current_only {
+                self.current_slots().filter(|slot| passes(*slot)).collect()
+            } else {
+                (0..self.slots.len() as u32)
+                    .filter(|slot| passes(*slot))
+                    .collect()
+            }
+        };
+
+        let Some(graph) = &self.graph else {
+            return self.rank_exhaustive(valid_slots(), k, score);
+        };
+        // Walk the graph with a beam widened by the share of entries the
+        // filter is expected to reject, keeping the passing hits.
+        let graph_hits = |candidates: usize| -> Vec<(FactId, f32)> {
+            let ef =
+                (self.config.ef_search().max(k) * self.len() / candidates.max(1)).min(self.len());
+            graph
+                .search(&|slot| -score(slot), ef)
+                .into_iter()
+                .filter(|scored| passes(scored.slot))
+                .filter_map(|Scored { distance, slot }| {
+                    let entry = self.slots[slot as usize].as_ref()?;
+                    Some((entry.id.clone(), -distance))
+                })
+                .take(k)
+                .collect()
+        };
+
+        // Without `accept`, the number of candidates is known up front.
+        let known = match (&accept, current_only) {
+            (None, false) => Some(self.len()),
+            (None, true) => Some(self.current_count),
+            (Some(_), _) => None,
+        };
+        if known == Some(0) {
+            return Vec::new();
+        }
+        if known.is_none_or(|n| n * SELECTIVE_FILTER_RATIO >= self.len()) {
+            let hits = graph_hits(known.unwrap_or(self.len()));
+            if hits.len() >= k.min(known.unwrap_or(k)) {
+                return hits;
+            }
+        }
 
-        // Partial sort: bring the top-k to the front. For small n (Phase 0) a full
-        // sort is fine and avoids the unstable behaviour of select_nth_unstable.
-        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        // The filter is selective, or the walk came up short.
+        let valid = valid_slots();
+        if valid.is_empty() {
+            return Vec::new();
+        }
+        if known.is_none() && valid.len() * SELECTIVE_FILTER_RATIO >= self.len() {
+            let hits = graph_hits(valid.len());
+            if hits.len() >= k.min(valid.len()) {
+                return hits;
+            }
+        }
+        self.rank_exhaustive(valid, k, score)
+    }
+
+    /// Score `slots` exhaustively and return the top `k`.
+    fn rank_exhaustive(
+        &self,
+        slots: Vec<u32>,
+        k: usize,
+        score: impl Fn(u32) -> f32,
+    ) -> Vec<(FactId, f32)> {
+        let mut scored: Vec<(u32, f32)> =
+            slots.into_iter().map(|slot| (slot, score(slot))).collect();
+        // Ties keep insertion order, so results do not depend on hash order.
+        scored.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.0.cmp(&b.0))
+        });
         scored.truncate(k);
         scored
+            .into_iter()
+            .filter_map(|(slot, score)| {
+                let entry = self.slots[slot as usize].as_ref()?;
+                Some((entry.id.clone(), score))
+            })
+            .collect()
+    }
+
+    /// Shortlist size to request from [`search_where`](Self::search_where) for a final
+    /// top-`k`: `k * multiplier` when quantized, `k` otherwise.
+    pub(crate) fn shortlist_len(&self, k: usize, multiplier: usize) -> usize {
+        match self.quantization {
+            Quantization::None => k,
+            _ => k.saturating_mul(multiplier.max(1)),
+        }
+    }
+
+    pub(crate) fn metric(&self) -> Metric {
+        self.metric
+    }
+
+    /// True if scores from [`search_where`](Self::search_where) must be re-scored.
+    pub(crate) fn is_quantized(&self) -> bool {
+        self.quantization != Quantization::None
     }
 
     /// Expected embedding dimension (set on first insert, `None` if empty).
@@ -149,23 +771,134 @@ impl VectorIndex {
     /// Number of entries currently in the index.
     #[allow(dead_code)]
     pub fn len(&self) -> usize {
-        self.entries.len()
+        self.slot_by_id.len()
     }
 
     /// True if the index contains no entries.
     #[allow(dead_code)]
     pub fn is_empty(&self) -> bool {
-        self.entries.is_empty()
+        self.slot_by_id.is_empty()
     }
-}
 
-// ---------------------------------------------------------------------------
-// Math helpers
-// ---------------------------------------------------------------------------
+    /// Serialize the slot layout and graph. Embeddings are not included;
+    /// they are read back from storage.
+    pub(crate) fn to_checkpoint_bytes(&self) -> Vec<u8> {
+        let mut out = CHECKPOINT_MAGIC.to_vec();
+        out.extend_from_slice(&self.fingerprint().to_le_bytes());
+        out.extend_from_slice(&(self.slots.len() as u32).to_le_bytes());
+        for slot in &self.slots {
+            let id = slot.as_ref().map_or("", |entry| entry.id.as_str());
+            out.extend_from_slice(&(id.len() as u32).to_le_bytes());
+            out.extend_from_slice(id.as_bytes());
+        }
+        match &self.graph {
+            Some(graph) => {
+                out.push(1);
+                graph.write_to(&mut out);
+            }
+            None => out.push(0),
+        }
+        out
+    }
+
+    /// Replace the slot layout and graph with a checkpoint's if it covers
+    /// exactly the current entries and was built with the same parameters.
+    fn adopt_checkpoint(&mut self, bytes: &[u8]) -> bool {
+        let Some((slots, graph)) = self.read_checkpoint(bytes) else {
+            return false;
+        };
+        self.slot_by_id = slots
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, entry)| Some((entry.as_ref()?.id.clone(), slot as u32)))
+            .collect();
+        self.slots = slots;
+        self.graph = graph;
+        true
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn read_checkpoint(&self, bytes: &[u8]) -> Option<(Vec<Option<Entry>>, Option<HnswGraph>)> {
+        let mut input = bytes.strip_prefix(CHECKPOINT_MAGIC)?;
+        let fingerprint = u64::from_le_bytes(take(&mut input, 8)?.try_into().ok()?);
+        if fingerprint != self.fingerprint() {
+            return None;
+        }
+        let slot_count = read_u32(&mut input)? as usize;
+        let mut slots = Vec::with_capacity(slot_count.min(input.len()));
+        let mut seen = 0usize;
+        for _ in 0..slot_count {
+            let len = read_u32(&mut input)? as usize;
+            let id = std::str::from_utf8(take(&mut input, len)?).ok()?;
+            if id.is_empty() {
+                slots.push(None);
+                continue;
+            }
+            let current = self.slot_by_id.get(&FactId::parse(id).ok()?)?;
+            slots.push(self.slots[*current as usize].clone());
+            seen += 1;
+        }
+        if seen != self.len() {
+            return None;
+        }
+        let graph = match *take(&mut input, 1)?.first()? {
+            0 => None,
+            1 => Some(HnswGraph::read_from(&mut input)?),
+            _ => return None,
+        };
+        let graph_fits = graph.as_ref().is_none_or(|graph| {
+            graph.matches_config(&self.config) && graph.slot_count() == slot_count
+        });
+        if !input.is_empty() || !graph_fits {
+            return None;
+        }
+        Some((slots, graph))
+    }
+
+    /// Order-independent hash of every `(id, codes)` pair, seeded with the
+    /// metric the graph was built under.
+    fn fingerprint(&self) -> u64 {
+        self.slots
+            .iter()
+            .flatten()
+            .map(|entry| {
+                let hash = checkpoint::fnv1a(entry.id.as_str().as_bytes());
+                entry.codes.fingerprint(hash)
+            })
+            .fold(
+                self.len() as u64 ^ checkpoint::fnv1a(self.metric.name().as_bytes()),
+                u64::wrapping_add,
+            )
+    }
+}
 
-/// Euclidean (L2) norm of `v`.
-fn l2_norm(v: &[f32]) -> f32 {
-    v.iter().map(|x| x * x).sum::<f32>().sqrt()
+/// Re-score a quantized shortlist against full-precision embeddings and keep
+/// the best `k`.
+///
+/// `full[i]` is the stored embedding of `shortlist[i]`; a missing one keeps
+/// its approximate score. Ties keep shortlist order.
+pub(crate) fn rescore(
+    query: &[f32],
+    shortlist: Vec<(FactId, f32)>,
+    full: Vec<Option<Vec<f32>>>,
+    k: usize,
+    metric: Metric,
+) -> Vec<(FactId, f32)> {
+    let query_norm = l2_norm(query);
+    let mut scored: Vec<(FactId, f32)> = shortlist
+        .into_iter()
+        .zip(full)
+        .map(|((id, approximate), embedding)| match embedding {
+            Some(embedding) => {
+                let norm = l2_norm(&embedding);
+                (id, metric.score(query, query_norm, &embedding, norm))
+            }
+            None => (id, approximate),
+        })
+        .collect();
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    scored.truncate(k);
+    scored
 }
 
 /// Cosine similarity between `a` and `b`.
@@ -174,21 +907,9 @@ fn l2_norm(v: &[f32]) -> f32 {
 /// query is scored against many entries.
 ///
 /// Returns a value in `[-1.0, 1.0]`. Returns `0.0` if `b` is the zero vector.
+#[cfg(test)]
 fn cosine_similarity(a: &[f32], b: &[f32], a_norm: f32) -> f32 {
-    // Runtime guard (not debug-only): `insert` enforces uniform dims, but if a
-    // and b somehow differ `zip` would silently truncate and return a wrong
-    // score.  Returning 0.0 on mismatch is the safest neutral value.
-    if a.len() != b.len() {
-        return 0.0;
-    }
-
-    let b_norm = l2_norm(b);
-    if b_norm == 0.0 {
-        return 0.0;
-    }
-
-    let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
-    dot / (a_norm * b_norm)
+    crate::metric::cosine_with_norms(a, a_norm, b, l2_norm(b))
 }
 
 // ---------------------------------------------------------------------------
@@ -420,4 +1141,264 @@ mod tests {
         let results = idx.search(&[0.0, 0.0], 5, &valid);
         assert!(results.is_empty());
     }
+
+    // ------------------------------------------------------------------
+    // HNSW — recall against exhaustive search
+    // ------------------------------------------------------------------
+
+    /// Deterministic embeddings scattered around `clusters` random centres.
+    fn clustered_embeddings(n: usize, dim: usize, clusters: usize, seed: u64) -> Vec<Vec<f32>> {
+        let mut state = seed;
+        let mut next = move || {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            ((z ^ (z >> 31)) >> 40) as f32 / (1u64 << 24) as f32 * 2.0 - 1.0
+        };
+        let centres: Vec<Vec<f32>> = (0..clusters)
+            .map(|_| (0..dim).map(|_| next()).collect())
+            .collect();
+        (0..n)
+            .map(|i| {
+                centres[i % clusters]
+                    .iter()
+                    .map(|c| c + 0.35 * next())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Share of the exhaustive top-`k` that the approximate index returns.
+    fn recall_at_k(
+        approx: &VectorIndex,
+        exact: &VectorIndex,
+        queries: &[Vec<f32>],
+        k: usize,
+        valid: &HashSet<FactId>,
+    ) -> f64 {
+        let mut found = 0;
+        let mut expected = 0;
+        for query in queries {
+            let truth: HashSet<FactId> = exact
+                .search(query, k, valid)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
+            expected += truth.len();
+            found += approx
+                .search(query, k, valid)
+                .into_iter()
+                .filter(|(id, _)| truth.contains(id))
+                .count();
+        }
+        found as f64 / expected as f64
+    }
+
+    #[test]
+    fn hnsw_recall_matches_flat_search_through_filters_and_updates() {
+        let config = HnswConfig::new()
+            .with_m(12)
+            .with_ef_construction(100)
+            .with_ef_search(48)
+            .with_exact_threshold(0);
+        let mut approx = VectorIndex::with_config(config);
+        let mut exact = VectorIndex::with_config(config.with_exact_threshold(usize::MAX));
+        let ids = make_ids(2000);
+        for (id, embedding) in ids.iter().zip(clustered_embeddings(2000, 16, 40, 1)) {
+            approx.insert(id.clone(), embedding.clone()).unwrap();
+            exact.insert(id.clone(), embedding).unwrap();
+        }
+        assert!(approx.graph.is_some() && exact.graph.is_none());
+        let queries = clustered_embeddings(60, 16, 40, 2);
+
+        let all = all_ids(&ids);
+        let recall = recall_at_k(&approx, &exact, &queries, 10, &all);
+        assert!(recall >= 0.95, "recall@10 {recall}");
+
+        let half: HashSet<FactId> = ids.iter().step_by(2).cloned().collect();
+        let recall = recall_at_k(&approx, &exact, &queries, 10, &half);
+        assert!(recall >= 0.95, "filtered recall@10 {recall}");
+
+        for id in ids.iter().step_by(3) {
+            approx.remove(id);
+            exact.remove(id);
+        }
+        let replacements = clustered_embeddings(200, 16, 40, 3);
+        for (id, embedding) in ids.iter().skip(1).step_by(5).zip(replacements) {
+            approx.insert(id.clone(), embedding.clone()).unwrap();
+            exact.insert(id.clone(), embedding).unwrap();
+        }
+        assert_eq!(approx.len(), exact.len());
+        let recall = recall_at_k(&approx, &exact, &queries, 10, &all);
+        assert!(recall >= 0.9, "recall@10 after updates {recall}");
+    }
+
+    #[test]
+    fn current_only_graph_search_uses_validity_bitmap() {
+        let config = HnswConfig::new().with_exact_threshold(0);
+        let mut idx = VectorIndex::with_config(config);
+        let ids = make_ids(400);
+        for (id, embedding) in ids.iter().zip(clustered_embeddings(400, 8, 10, 7)) {
+            idx.insert(id.clone(), embedding).unwrap();
+        }
+        for id in &ids {
+            idx.set_current(id, true);
+        }
+        for id in ids.iter().step_by(2) {
+            idx.set_current(id, false);
+        }
+        idx.remove(&ids[1]);
+        assert_eq!(idx.current_count, 199);
+
+        let query = clustered_embeddings(1, 8, 10, 8).remove(0);
+        let unfiltered = None::<fn(&FactId) -> bool>;
+        let hits = idx.search_where(&query, 20, true, unfiltered);
+        assert_eq!(hits.len(), 20);
+        let odd: HashSet<&FactId> = ids.iter().skip(3).step_by(2).collect();
+        assert!(hits.iter().all(|(id, _)| odd.contains(id)));
+
+        // A selective bitmap is enumerated instead of walking the graph.
+        for id in ids.iter().skip(7) {
+            idx.set_current(id, false);
+        }
+        let hits = idx.search_where(&query, 20, true, unfiltered);
+        assert_eq!(hits.len(), 2, "only slots 3 and 5 remain current");
+    }
+
+    #[test]
+    fn quantized_shortlists_rescored_at_full_precision_match_exact_search() {
+        let ids = make_ids(1500);
+        let embeddings = clustered_embeddings(1500, 64, 30, 5);
+        let full: HashMap<FactId, Vec<f32>> = ids
+            .iter()
+            .cloned()
+            .zip(embeddings.iter().cloned())
+            .collect();
+        let valid = all_ids(&ids);
+        let queries = clustered_embeddings(40, 64, 30, 6);
+        let mut exact = VectorIndex::with_config(HnswConfig::new());
+        for (id, embedding) in ids.iter().zip(&embeddings) {
+            exact.insert(id.clone(), embedding.clone()).unwrap();
+        }
+
+        for (quantization, multiplier, min_recall) in [
+            (Quantization::Int8, 4, 0.97),
+            (Quantization::Binary, 40, 0.95),
+        ] {
+            for threshold in [usize::MAX, 0] {
+                let mut idx = VectorIndex::with_config(
+                    HnswConfig::new()
+                        .with_m(12)
+                        .with_ef_construction(100)
+                        .with_exact_threshold(threshold),
+                )
+                .with_quantization(quantization);
+                for (id, embedding) in ids.iter().zip(&embeddings) {
+                    idx.insert(id.clone(), embedding.clone()).unwrap();
+                }
+                assert!(idx.is_quantized());
+
+                let (mut found, mut expected) = (0, 0);
+                for query in &queries {
+                    let truth = exact.search(query, 10, &valid);
+                    let shortlist = idx.search(query, idx.shortlist_len(10, multiplier), &valid);
+                    let rows = shortlist
+                        .iter()
+                        .map(|(id, _)| full.get(id).cloned())
+                        .collect();
+                    let hits = rescore(query, shortlist, rows, 10, Metric::Cosine);
+                    assert_eq!(hits.len(), 10);
+                    // Rescored similarities are exact, so shared hits agree.
+                    for (id, score) in &hits {
+                        if let Some((_, exact_score)) = truth.iter().find(|(t, _)| t == id) {
+                            assert!((score - exact_score).abs() < 1e-5);
+                        }
+                    }
+                    expected += truth.len();
+                    found += hits
+                        .iter()
+                        .filter(|(id, _)| truth.iter().any(|(t, _)| t == id))
+                        .count();
+                }
+                let recall = found as f64 / expected as f64;
+                assert!(
+                    recall >= min_recall,
+                    "{quantization:?} (threshold {threshold}) recall@10 {recall}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn graph_is_built_at_the_threshold_and_restored_from_checkpoints() {
+        let config = HnswConfig::new().with_m(6).with_exact_threshold(50);
+        let vector_config = VectorConfig::new().with_hnsw(config);
+        let ids = make_ids(80);
+        let rows: Vec<(FactId, Vec<f32>)> = ids
+            .iter()
+            .cloned()
+            .zip(clustered_embeddings(80, 8, 5, 4))
+            .collect();
+
+        let mut idx = VectorIndex::with_config(config);
+        for (id, embedding) in rows.iter().take(49).cloned() {
+            idx.insert(id, embedding).unwrap();
+        }
+        assert!(idx.graph.is_none());
+        for (id, embedding) in rows.iter().skip(49).cloned() {
+            idx.insert(id, embedding).unwrap();
+        }
+        assert!(idx.graph.is_some());
+        idx.remove(&ids[7]);
+
+        let bytes = idx.to_checkpoint_bytes();
+        let mut reordered: Vec<(FactId, Vec<f32>)> = rows
+            .iter()
+            .filter(|(id, _)| *id != ids[7])
+            .cloned()
+            .collect();
+        reordered.reverse();
+        let restored = VectorIndex::from_rows(
+            reordered.clone(),
+            &vector_config,
+            Metric::Cosine,
+            Some(&bytes),
+        )
+        .unwrap();
+        assert_eq!(restored.graph, idx.graph, "checkpoint graph adopted");
+        let query = rows[3].1.clone();
+        let valid = all_ids(&ids);
+        assert_eq!(
+            restored.search(&query, 5, &valid),
+            idx.search(&query, 5, &valid)
+        );
+
+        let rebuilt = VectorIndex::from_rows(
+            reordered.clone(),
+            &vector_config.clone().with_hnsw(config.with_m(8)),
+            Metric::Cosine,
+            Some(&bytes),
+        )
+        .unwrap();
+        assert!(rebuilt
+            .graph
+            .as_ref()
+            .unwrap()
+            .matches_config(&config.with_m(8)));
+        let other_metric =
+            VectorIndex::from_rows(reordered.clone(), &vector_config, Metric::L2, Some(&bytes))
+                .unwrap();
+        assert_ne!(
+            other_metric.graph, idx.graph,
+            "graph of another metric ignored"
+        );
+        reordered.pop();
+        let stale = VectorIndex::from_rows(reordered, &vector_config, Metric::Cosine, Some(&bytes))
+            .unwrap();
+        assert_ne!(stale.graph, idx.graph, "stale checkpoint ignored");
+        assert!(
+            VectorIndex::from_rows(rows, &vector_config, Metric::Cosine, Some(b"garbage")).is_ok()
+        );
+    }
 }
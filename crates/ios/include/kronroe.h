@@ -65,6 +65,18 @@ char *kronroe_graph_assert_fact(KronroeGraphHandle *handle,
                                 const char *source,
                                 const char *valid_from_iso);
 
+/**
+ * Assert a fact whose object is a tagged value JSON string, e.g.
+ * {"type":"Integer","value":"42"} or {"type":"Timestamp","value":"2024-01-01T00:00:00Z"}.
+ * valid_from_iso: RFC 3339 timestamp, or NULL for current time.
+ * Returns fact ID string, or NULL on error. Free with `kronroe_string_free`.
+ */
+char *kronroe_graph_assert_value_json(KronroeGraphHandle *handle,
+                                      const char *subject,
+                                      const char *predicate,
+                                      const char *value_json,
+                                      const char *valid_from_iso);
+
 /**
  * Return currently valid facts for (entity, predicate) as JSON array.
  * Free with `kronroe_string_free`.
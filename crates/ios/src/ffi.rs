@@ -1,5 +1,6 @@
 use kronroe::KronroeTimestamp;
 use kronroe::TemporalGraph;
+use kronroe::Value;
 use std::cell::RefCell;
 use std::ffi::{c_char, CStr, CString};
 use std::ptr;
@@ -340,6 +341,95 @@ pub unsafe extern "C" fn kronroe_graph_assert_fact(
     }
 }
 
+#[no_mangle]
+/// Assert a fact whose object is a tagged Kronroe value, e.g.
+/// `{"type":"Integer","value":"42"}` or `{"type":"Json","value":{...}}`.
+///
+/// Accepts every `Value` variant in the same encoding used by the JSON
+/// returned from the query functions. `valid_from_iso` may be NULL for now.
+///
+/// Returns the fact ID as a newly allocated C string, or NULL on error.
+///
+/// # Safety
+/// All string pointers must be valid NUL-terminated UTF-8 or NULL where noted.
+/// The returned pointer must be freed with `kronroe_string_free`.
+pub unsafe extern "C" fn kronroe_graph_assert_value_json(
+    handle: *mut KronroeGraphHandle,
+    subject: *const c_char,
+    predicate: *const c_char,
+    value_json: *const c_char,
+    valid_from_iso: *const c_char,
+) -> *mut c_char {
+    clear_last_error();
+    if handle.is_null() {
+        set_last_error("graph handle is null".to_string());
+        return ptr::null_mut();
+    }
+    let subject = match cstr_to_string(subject, "subject") {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(e);
+            return ptr::null_mut();
+        }
+    };
+    let predicate = match cstr_to_string(predicate, "predicate") {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(e);
+            return ptr::null_mut();
+        }
+    };
+    let value = match cstr_to_string(value_json, "value_json") {
+        Ok(v) => match Value::from_json_str(&v) {
+            Ok(value) => value,
+            Err(err) => {
+                set_last_error(err.to_string());
+                return ptr::null_mut();
+            }
+        },
+        Err(e) => {
+            set_last_error(e);
+            return ptr::null_mut();
+        }
+    };
+
+    let valid_from = if valid_from_iso.is_null() {
+        KronroeTimestamp::now_utc()
+    } else {
+        match cstr_to_string(valid_from_iso, "valid_from_iso") {
+            Ok(iso) => match KronroeTimestamp::parse_rfc3339(&iso) {
+                Ok(ts) => ts,
+                Err(e) => {
+                    set_last_error(format!("invalid valid_from: {e}"));
+                    return ptr::null_mut();
+                }
+            },
+            Err(e) => {
+                set_last_error(e);
+                return ptr::null_mut();
+            }
+        }
+    };
+
+    let graph = unsafe { &*handle };
+    match graph
+        .graph
+        .assert_fact(&subject, &predicate, value, valid_from)
+    {
+        Ok(fact_id) => match CString::new(fact_id.as_str()) {
+            Ok(cs) => cs.into_raw(),
+            Err(_) => {
+                set_last_error("fact ID encoding failed".to_string());
+                ptr::null_mut()
+            }
+        },
+        Err(err) => {
+            set_last_error(err.to_string());
+            ptr::null_mut()
+        }
+    }
+}
+
 #[no_mangle]
 /// Return currently valid facts for (entity, predicate) as JSON.
 ///
@@ -664,6 +754,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ffi_assert_value_json_accepts_rich_types() {
+        let subject = c("alice");
+        let entity = c("alice");
+        let handle = kronroe_graph_open_in_memory();
+        assert!(!handle.is_null());
+
+        for (predicate, value) in [
+            (
+                "employee_id",
+                r#"{"type":"Integer","value":"9007199254740993"}"#,
+            ),
+            (
+                "born",
+                r#"{"type":"Timestamp","value":"1990-05-17T00:00:00Z"}"#,
+            ),
+            ("profile", r#"{"type":"Json","value":{"team":"core"}}"#),
+        ] {
+            let predicate = c(predicate);
+            let value = c(value);
+            let id_ptr = unsafe {
+                kronroe_graph_assert_value_json(
+                    handle,
+                    subject.as_ptr(),
+                    predicate.as_ptr(),
+                    value.as_ptr(),
+                    std::ptr::null(),
+                )
+            };
+            assert!(!id_ptr.is_null(), "typed assert should succeed");
+            unsafe { kronroe_string_free(id_ptr) };
+        }
+
+        let bad = c(r#"{"type":"Integer","value":"nope"}"#);
+        let predicate = c("employee_id");
+        let id_ptr = unsafe {
+            kronroe_graph_assert_value_json(
+                handle,
+                subject.as_ptr(),
+                predicate.as_ptr(),
+                bad.as_ptr(),
+                std::ptr::null(),
+            )
+        };
+        assert!(id_ptr.is_null(), "malformed value should be rejected");
+
+        let json_ptr = unsafe { kronroe_graph_facts_about_json(handle, entity.as_ptr()) };
+        let json = unsafe { CStr::from_ptr(json_ptr) }
+            .to_str()
+            .expect("valid utf8");
+        let facts: serde_json::Value = serde_json::from_str(json).expect("valid json");
+        let arr = facts.as_array().expect("json array");
+        assert_eq!(arr.len(), 3);
+        let id = arr
+            .iter()
+            .find(|f| f["predicate"] == "employee_id")
+            .expect("integer fact");
+        assert_eq!(id["object"]["type"], "Integer");
+        assert_eq!(id["object"]["value"], "9007199254740993");
+
+        unsafe {
+            kronroe_string_free(json_ptr);
+            kronroe_graph_close(handle);
+        }
+    }
+
     #[test]
     fn ffi_failure_path_null_handle_assert_sets_error() {
         let subject = c("alice");
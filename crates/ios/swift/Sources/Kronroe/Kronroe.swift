@@ -145,6 +145,42 @@ public final class KronroeGraph {
         return s
     }
 
+    /// Assert a fact whose object is a tagged value, e.g.
+    /// `{"type":"Integer","value":"42"}` or `{"type":"Json","value":{"k":1}}`.
+    /// - Returns: The new fact's `kf_...` ID.
+    @discardableResult
+    public func assertValueJSON(
+        subject: String,
+        predicate: String,
+        valueJSON: String,
+        validFrom: String? = nil
+    ) throws -> String {
+        guard let handle else {
+            throw KronroeError.assertFailed("graph handle is nil")
+        }
+        let result = subject.withCString { cSubject in
+            predicate.withCString { cPredicate in
+                valueJSON.withCString { cValue in
+                    if let validFrom {
+                        return validFrom.withCString { cValidFrom in
+                            kronroe_graph_assert_value_json(handle, cSubject, cPredicate, cValue, cValidFrom)
+                        }
+                    } else {
+                        return kronroe_graph_assert_value_json(handle, cSubject, cPredicate, cValue, nil)
+                    }
+                }
+            }
+        }
+        guard let raw = result else {
+            throw KronroeError.assertFailed(Self.lastErrorMessage())
+        }
+        defer { kronroe_string_free(raw) }
+        guard let s = String(validatingUTF8: raw) else {
+            throw KronroeError.invalidUTF8
+        }
+        return s
+    }
+
     /// Query currently valid facts for a specific entity and predicate.
     public func currentFactsJSON(entity: String, predicate: String) throws -> String {
         guard let handle else {
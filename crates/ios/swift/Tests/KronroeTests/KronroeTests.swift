@@ -25,6 +25,22 @@ final class KronroeTests: XCTestCase {
         XCTAssertTrue(json.contains("\"subject\":\"alice\""))
     }
 
+    func testAssertValueJSONStoresTypedValues() throws {
+        let graph = try KronroeGraph.openInMemory()
+        try graph.assertValueJSON(
+            subject: "alice",
+            predicate: "employee_id",
+            valueJSON: #"{"type":"Integer","value":"9007199254740993"}"#
+        )
+        let json = try graph.factsAboutJSON(entity: "alice")
+        XCTAssertTrue(json.contains(#""type":"Integer","value":"9007199254740993""#))
+        XCTAssertThrowsError(try graph.assertValueJSON(
+            subject: "alice",
+            predicate: "born",
+            valueJSON: #"{"type":"Timestamp","value":"yesterday"}"#
+        ))
+    }
+
     func testFailurePathOpenInvalidPath() {
         let invalidURL = URL(fileURLWithPath: "/")
         XCTAssertThrowsError(try KronroeGraph.open(url: invalidURL))
@@ -1,15 +1,17 @@
 #[cfg(test)]
 use kronroe::FactId;
 use kronroe::{
-    ErrorContext, Fact, KronroeError, KronroeSpan, KronroeTimestamp, OptionContext, Value,
+    ErrorContext, Fact, KronroeError, KronroeSpan, KronroeTimestamp, OptionContext,
+    SearchExplanation, Value,
 };
 #[cfg(feature = "hybrid")]
-use kronroe::{TemporalIntent, TemporalOperator};
+use kronroe::{FusionStrategy, TemporalIntent, TemporalOperator, TemporalQuery};
 use kronroe_agent_memory::{
     is_high_impact_predicate, AgentMemory, ConfidenceShift, FactCorrection, MemoryHealthReport,
     RecallForTaskReport, RecallOptions, RecallScore, WhatChangedReport,
 };
 use serde_json::{json, Map, Value as JsonValue};
+use std::collections::BTreeMap;
 use std::env;
 use std::io::{self, BufRead, BufReader, Write};
 
@@ -52,6 +54,7 @@ fn recall_input_schema(include_scores: bool) -> JsonValue {
     if include_scores {
         properties.insert("include_scores".to_string(), json!({ "type": "boolean" }));
     }
+    properties.insert("explain".to_string(), json!({ "type": "boolean" }));
     properties.insert(
         "max_scored_rows".to_string(),
         json!({ "type": "integer", "minimum": 1 }),
@@ -77,6 +80,26 @@ fn recall_input_schema(include_scores: bool) -> JsonValue {
                 "enum": ["current", "as_of", "during", "before", "by", "after", "unknown"]
             }),
         );
+        properties.insert(
+            "fusion".to_string(),
+            json!({
+                "type": "string",
+                "enum": FusionStrategy::ALL.map(FusionStrategy::name)
+            }),
+        );
+        properties.insert("auto_temporal".to_string(), json!({ "type": "boolean" }));
+        properties.insert(
+            "valid_interval".to_string(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "start": {"type": "string"},
+                    "end": {"type": "string"}
+                },
+                "required": ["start", "end"]
+            }),
+        );
+        properties.insert("known_at".to_string(), json!({ "type": "string" }));
     }
     json!({
         "type": "object",
@@ -317,7 +340,7 @@ fn tools_schema() -> Vec<JsonValue> {
         }),
         json!({
             "name": "recall_scored",
-            "description": "Recall facts with per-channel scoring metadata.",
+            "description": "Recall facts with per-channel scoring metadata. Set explain to include matched terms, fields, fuzzy flags, and highlighted snippets.",
             "inputSchema": recall_input_schema(false)
         }),
         json!({
@@ -338,7 +361,13 @@ fn tools_schema() -> Vec<JsonValue> {
             "description": "Return all facts about an entity.",
             "inputSchema": {
                 "type": "object",
-                "properties": { "entity": {"type": "string"} },
+                "properties": {
+                    "entity": {"type": "string"},
+                    "metadata": {
+                        "type": "object",
+                        "description": "Optional filter: only return facts whose metadata has every given key set to the given value."
+                    }
+                },
                 "required": ["entity"]
             }
         }),
@@ -351,10 +380,19 @@ fn tools_schema() -> Vec<JsonValue> {
                     "subject": {"type": "string"},
                     "predicate": {"type": "string"},
                     "object": {},
+                    "object_type": {
+                        "type": "string",
+                        "enum": ["text", "entity", "number", "integer", "boolean", "timestamp", "bytes", "json"],
+                        "description": "Optional explicit type for object. Timestamps are RFC3339 strings, bytes are base64 strings, json accepts any JSON value. When omitted, scalars map to text, number, or boolean."
+                    },
                     "valid_from": {"type": "string"},
                     "confidence": {"type": "number"},
                     "source": {"type": "string"},
-                    "idempotency_key": {"type": "string"}
+                    "idempotency_key": {"type": "string"},
+                    "metadata": {
+                        "type": "object",
+                        "description": "Optional qualifiers stored on the fact (e.g. model, turn, locale, evidence). Scalars map like object; arrays and objects are stored as JSON."
+                    }
                 },
                 "required": ["subject", "predicate", "object"]
             }
@@ -437,8 +475,19 @@ fn call_tool(state: &mut AppState, params: Option<&JsonValue>) -> Result<JsonVal
                 .get("entity")
                 .and_then(JsonValue::as_str)
                 .context("entity is required")?;
+            let filter = parse_metadata(args.get("metadata"))?;
             let facts = state.memory.facts_about(entity)?;
-            let out: Vec<JsonValue> = facts.into_iter().map(|fact| fact_to_json(&fact)).collect();
+            let out: Vec<JsonValue> = facts
+                .into_iter()
+                .filter(|fact| {
+                    filter.iter().all(|(key, value)| {
+                        fact.metadata
+                            .get(key)
+                            .is_some_and(|stored| stored.same_value(value))
+                    })
+                })
+                .map(|fact| fact_to_json(&fact))
+                .collect();
             Ok(json!({
                 "content": [{ "type": "text", "text": format!("{} fact(s) about {entity}", out.len()) }],
                 "structuredContent": { "facts": out }
@@ -453,11 +502,17 @@ fn call_tool(state: &mut AppState, params: Option<&JsonValue>) -> Result<JsonVal
                 .get("predicate")
                 .and_then(JsonValue::as_str)
                 .context("predicate is required")?;
-            let object = json_to_value(args.get("object").context("object is required")?)?;
+            let object = args.get("object").context("object is required")?;
+            let object = match args.get("object_type").and_then(JsonValue::as_str) {
+                Some(object_type) => json_to_typed_value(object, object_type)?,
+                None => json_to_value(object)?,
+            };
             let valid_from = parse_valid_from(args.get("valid_from"))?;
             let confidence = parse_confidence(args.get("confidence"))?;
             let source = args.get("source").and_then(JsonValue::as_str);
             let idempotency_key = args.get("idempotency_key").and_then(JsonValue::as_str);
+            let mut params = kronroe_agent_memory::AssertParams::new(valid_from);
+            params.metadata = parse_metadata(args.get("metadata"))?;
 
             if idempotency_key.is_some() && (confidence.is_some() || source.is_some()) {
                 return Err(KronroeError::invalid_input(
@@ -471,14 +526,14 @@ fn call_tool(state: &mut AppState, params: Option<&JsonValue>) -> Result<JsonVal
                     subject,
                     predicate,
                     object,
-                    kronroe_agent_memory::AssertParams { valid_from },
+                    params.clone(),
                 )?
             } else if let Some(source) = source {
                 state.memory.assert_with_source_with_params(
                     subject,
                     predicate,
                     object,
-                    kronroe_agent_memory::AssertParams { valid_from },
+                    params.clone(),
                     confidence.unwrap_or(1.0),
                     source,
                 )?
@@ -487,16 +542,13 @@ fn call_tool(state: &mut AppState, params: Option<&JsonValue>) -> Result<JsonVal
                     subject,
                     predicate,
                     object,
-                    kronroe_agent_memory::AssertParams { valid_from },
+                    params.clone(),
                     confidence,
                 )?
             } else {
-                state.memory.assert_with_params(
-                    subject,
-                    predicate,
-                    object,
-                    kronroe_agent_memory::AssertParams { valid_from },
-                )?
+                state
+                    .memory
+                    .assert_with_params(subject, predicate, object, params.clone())?
             };
 
             Ok(json!({
@@ -676,10 +728,24 @@ fn call_tool_recall(
         };
         (limit, include_scores)
     };
+    let explain = args
+        .get("explain")
+        .and_then(JsonValue::as_bool)
+        .unwrap_or(false);
+    if explain && !include_scores {
+        return Err(KronroeError::invalid_input(
+            "explain requires include_scores",
+        ));
+    }
 
     let mut opts = RecallOptions::new(query).with_limit(limit);
     let query_embedding = parse_embedding(args.get("query_embedding"))?;
     let use_hybrid = args.get("use_hybrid").and_then(JsonValue::as_bool);
+    let explicit_temporal =
+        args.get("temporal_intent").is_some() || args.get("temporal_operator").is_some();
+    let time_bounds = args.get("valid_interval").is_some() || args.get("known_at").is_some();
+    #[cfg(feature = "hybrid")]
+    let mut detected: Option<TemporalQuery> = None;
     if let Some(embedding) = query_embedding.as_deref() {
         opts = opts.with_embedding(embedding);
         #[cfg(feature = "hybrid")]
@@ -687,12 +753,38 @@ fn call_tool_recall(
             if use_hybrid != Some(false) {
                 opts = opts.with_hybrid(true);
             }
+            // Clients rarely classify time themselves: detect it from the
+            // query unless they did or opted out.
+            let auto_temporal = args.get("auto_temporal").and_then(JsonValue::as_bool);
+            if auto_temporal == Some(true) && explicit_temporal {
+                return Err(KronroeError::invalid_input(
+                    "auto_temporal cannot be combined with temporal_intent or temporal_operator",
+                ));
+            }
+            if use_hybrid != Some(false) && auto_temporal.unwrap_or(!explicit_temporal) {
+                let found = state.memory.detect_temporal(query)?;
+                opts = opts.with_detected_temporal(&found);
+                detected = Some(found);
+            }
             if let Some(intent) = parse_temporal_intent(args.get("temporal_intent"))? {
                 opts = opts.with_temporal_intent(intent);
             }
             if let Some(operator) = parse_temporal_operator(args.get("temporal_operator"))? {
                 opts = opts.with_temporal_operator(operator);
             }
+            if let Some((start, end)) = parse_valid_interval(args.get("valid_interval"))? {
+                opts = opts.with_valid_interval(start, end);
+            }
+            if let Some(known_at) = args.get("known_at") {
+                let known_at = known_at
+                    .as_str()
+                    .and_then(|s| s.parse::<KronroeTimestamp>().ok())
+                    .ok_or_else(|| KronroeError::invalid_input("known_at must be RFC3339"))?;
+                opts = opts.with_known_at(known_at);
+            }
+            if let Some(fusion) = parse_fusion(args.get("fusion"))? {
+                opts = opts.with_fusion(fusion);
+            }
         }
         #[cfg(not(feature = "hybrid"))]
         {
@@ -701,7 +793,7 @@ fn call_tool_recall(
                     "hybrid is unavailable in this build",
                 ));
             }
-            if args.get("temporal_intent").is_some() || args.get("temporal_operator").is_some() {
+            if explicit_temporal || time_bounds || args.get("auto_temporal").is_some() {
                 return Err(KronroeError::invalid_input(
                     "temporal controls are unavailable without hybrid feature",
                 ));
@@ -718,11 +810,29 @@ fn call_tool_recall(
         ));
     } else {
         #[cfg(feature = "hybrid")]
-        if args.get("temporal_intent").is_some() || args.get("temporal_operator").is_some() {
+        if explicit_temporal {
             return Err(KronroeError::invalid_input(
                 "temporal_intent and temporal_operator require query_embedding",
             ));
         }
+        #[cfg(feature = "hybrid")]
+        if args.get("auto_temporal").is_some() {
+            return Err(KronroeError::invalid_input(
+                "auto_temporal requires query_embedding",
+            ));
+        }
+        #[cfg(feature = "hybrid")]
+        if time_bounds {
+            return Err(KronroeError::invalid_input(
+                "valid_interval and known_at require query_embedding",
+            ));
+        }
+        #[cfg(feature = "hybrid")]
+        if args.get("fusion").is_some() {
+            return Err(KronroeError::invalid_input(
+                "fusion requires query_embedding",
+            ));
+        }
         // Text-only path preserves historical behavior.
     }
 
@@ -776,17 +886,31 @@ fn call_tool_recall(
     }
 
     let recall = state.memory.recall_scored_with_options(&opts)?;
+    #[cfg(feature = "hybrid")]
+    let temporal = detected.as_ref().map(temporal_query_to_json);
+    #[cfg(not(feature = "hybrid"))]
+    let temporal: Option<JsonValue> = None;
     if scored_only || include_scores {
         let mut results = Vec::with_capacity(recall.len());
         for (fact, score) in recall {
-            results.push(json!({
+            let mut result = json!({
                 "fact": fact_to_json(&fact),
                 "score": recall_score_to_json(&score),
-            }));
+            });
+            if explain {
+                let explanation = state.memory.explain_recall(query, &fact)?;
+                result["explanation"] = search_explanation_to_json(&explanation);
+            }
+            results.push(result);
+        }
+        let text = format!("found {} scored fact(s)", results.len());
+        let mut structured = json!({ "results": results });
+        if let Some(temporal) = temporal {
+            structured["temporal"] = temporal;
         }
         return Ok(json!({
-            "content": [{ "type": "text", "text": format!("found {} scored fact(s)", results.len()) }],
-            "structuredContent": { "results": results }
+            "content": [{ "type": "text", "text": text }],
+            "structuredContent": structured
         }));
     }
 
@@ -794,9 +918,14 @@ fn call_tool_recall(
         .into_iter()
         .map(|(fact, _)| fact_to_json(&fact))
         .collect();
+    let text = format!("found {} fact(s)", facts.len());
+    let mut structured = json!({ "facts": facts });
+    if let Some(temporal) = temporal {
+        structured["temporal"] = temporal;
+    }
     Ok(json!({
-        "content": [{ "type": "text", "text": format!("found {} fact(s)", facts.len()) }],
-        "structuredContent": { "facts": facts }
+        "content": [{ "type": "text", "text": text }],
+        "structuredContent": structured
     }))
 }
 
@@ -1104,6 +1233,67 @@ fn parse_temporal_operator(v: Option<&JsonValue>) -> Result<Option<TemporalOpera
     Ok(Some(parsed))
 }
 
+#[cfg(feature = "hybrid")]
+fn parse_fusion(v: Option<&JsonValue>) -> Result<Option<FusionStrategy>> {
+    v.and_then(JsonValue::as_str).map(str::parse).transpose()
+}
+
+/// Parse a `valid_interval` argument: `{"start": RFC3339, "end": RFC3339}`
+/// with `start` before `end`.
+#[cfg(feature = "hybrid")]
+fn parse_valid_interval(
+    v: Option<&JsonValue>,
+) -> Result<Option<(KronroeTimestamp, KronroeTimestamp)>> {
+    let Some(v) = v else {
+        return Ok(None);
+    };
+    let bound = |key: &str| {
+        v.get(key)
+            .and_then(JsonValue::as_str)
+            .and_then(|s| s.parse::<KronroeTimestamp>().ok())
+            .ok_or_else(|| {
+                KronroeError::invalid_input(format!("valid_interval.{key} must be RFC3339"))
+            })
+    };
+    let (start, end) = (bound("start")?, bound("end")?);
+    if start >= end {
+        return Err(KronroeError::invalid_input(
+            "valid_interval.start must be before valid_interval.end",
+        ));
+    }
+    Ok(Some((start, end)))
+}
+
+#[cfg(feature = "hybrid")]
+fn temporal_query_to_json(detected: &TemporalQuery) -> JsonValue {
+    let intent = match detected.intent {
+        TemporalIntent::Timeless => "timeless",
+        TemporalIntent::CurrentState => "current_state",
+        TemporalIntent::HistoricalPoint => "historical_point",
+        TemporalIntent::HistoricalInterval => "historical_interval",
+    };
+    let operator = match detected.operator {
+        TemporalOperator::Current => "current",
+        TemporalOperator::AsOf => "as_of",
+        TemporalOperator::During => "during",
+        TemporalOperator::Before => "before",
+        TemporalOperator::By => "by",
+        TemporalOperator::After => "after",
+        TemporalOperator::Unknown => "unknown",
+    };
+    json!({
+        "intent": intent,
+        "operator": operator,
+        "at": detected.at.map(|at| at.to_rfc3339()),
+        "interval": detected.interval.map(|(start, end)| json!({
+            "start": start.to_rfc3339(),
+            "end": end.to_rfc3339(),
+        })),
+        "expression": detected.expression,
+        "event": detected.event,
+    })
+}
+
 #[cfg(not(feature = "hybrid"))]
 #[allow(clippy::unnecessary_wraps, dead_code)]
 fn parse_temporal_intent(_: Option<&JsonValue>) -> Result<Option<()>> {
@@ -1151,21 +1341,82 @@ fn json_to_value(v: &JsonValue) -> Result<Value> {
     }
 }
 
+/// Parse a `metadata` argument: scalars map like `object`, arrays and
+/// objects become [`Value::Json`].
+fn parse_metadata(v: Option<&JsonValue>) -> Result<BTreeMap<String, Value>> {
+    let Some(v) = v.filter(|v| !v.is_null()) else {
+        return Ok(BTreeMap::new());
+    };
+    let entries = v
+        .as_object()
+        .ok_or_else(|| KronroeError::invalid_input("metadata must be an object"))?;
+    let mut metadata = BTreeMap::new();
+    for (key, value) in entries {
+        let value = match value {
+            JsonValue::Array(_) | JsonValue::Object(_) => Value::Json(
+                kronroe::JsonValue::parse_str(&value.to_string())
+                    .map_err(|e| KronroeError::invalid_input(format!("metadata.{key}: {e}")))?,
+            ),
+            scalar => json_to_value(scalar)
+                .map_err(|e| KronroeError::invalid_input(format!("metadata.{key}: {e}")))?,
+        };
+        metadata.insert(key.clone(), value);
+    }
+    Ok(metadata)
+}
+
+fn json_to_typed_value(v: &JsonValue, object_type: &str) -> Result<Value> {
+    let tag = match object_type {
+        "text" => "Text",
+        "entity" => "Entity",
+        "number" => "Number",
+        "integer" => "Integer",
+        "boolean" => "Boolean",
+        "timestamp" => "Timestamp",
+        "bytes" => "Bytes",
+        "json" => "Json",
+        other => {
+            return Err(KronroeError::invalid_input(format!(
+                "unknown object_type '{other}'"
+            )))
+        }
+    };
+    // Integers travel as JSON numbers here; the core codec expects the
+    // lossless decimal-string form.
+    let value = match (tag, v) {
+        ("Integer", JsonValue::Number(n)) => json!(n.to_string()),
+        _ => v.clone(),
+    };
+    Value::from_json_str(&json!({ "type": tag, "value": value }).to_string())
+}
+
+fn value_to_json(value: &Value) -> JsonValue {
+    match value {
+        Value::Text(v) | Value::Entity(v) => json!(v),
+        Value::Number(v) => json!(v),
+        Value::Boolean(v) => json!(v),
+        Value::Timestamp(v) => json!(v.to_rfc3339()),
+        Value::Integer(v) => json!(v),
+        Value::Bytes(_) => json!(value.to_string()),
+        Value::Json(v) => serde_json::from_str(&v.to_json_string()).unwrap_or(JsonValue::Null),
+    }
+}
+
 fn fact_to_json(fact: &Fact) -> JsonValue {
     json!({
         "id": fact.id.as_str(),
         "subject": fact.subject,
         "predicate": fact.predicate,
-        "object": match &fact.object {
-            Value::Text(v) | Value::Entity(v) => json!(v),
-            Value::Number(v) => json!(v),
-            Value::Boolean(v) => json!(v),
-        },
+        "object": value_to_json(&fact.object),
         "object_type": match fact.object {
             Value::Text(_) => "text",
             Value::Entity(_) => "entity",
             Value::Number(_) => "number",
             Value::Boolean(_) => "boolean",
+            Value::Timestamp(_) => "timestamp",
+            Value::Integer(_) => "integer",
+            Value::Bytes(_) => "bytes",
+            Value::Json(_) => "json",
         },
         "valid_from": fact.valid_from.to_rfc3339(),
         "valid_to": fact.valid_to.map(|v| v.to_rfc3339()),
@@ -1173,6 +1424,11 @@ fn fact_to_json(fact: &Fact) -> JsonValue {
         "expired_at": fact.expired_at.map(|v| v.to_rfc3339()),
         "confidence": fact.confidence,
         "source": fact.source,
+        "metadata": fact
+            .metadata
+            .iter()
+            .map(|(key, value)| (key.clone(), value_to_json(value)))
+            .collect::<Map<_, _>>(),
     })
 }
 
@@ -1197,18 +1453,32 @@ fn recall_score_to_json(score: &RecallScore) -> JsonValue {
             rrf_score,
             text_contrib,
             vector_contrib,
+            temporal_adjustment,
+            temporal_signal,
+            rerank_score,
             confidence,
             effective_confidence,
             ..
         } => {
-            json!({
+            #[cfg_attr(not(feature = "hybrid"), allow(unused_mut))]
+            let mut out = json!({
                 "type": "hybrid",
                 "rrf_score": rrf_score,
                 "text_contrib": text_contrib,
                 "vector_contrib": vector_contrib,
+                "temporal_adjustment": temporal_adjustment,
+                "temporal_signal": temporal_signal,
+                "rerank_score": rerank_score,
                 "confidence": confidence,
                 "effective_confidence": effective_confidence,
-            })
+            });
+            #[cfg(feature = "hybrid")]
+            if let RecallScore::Hybrid { stage1, .. } = score {
+                out["stage1"] = json!(stage1.name());
+                out["stage1_rank"] = json!(stage1.rank());
+                out["stage1_window"] = json!(stage1.window());
+            }
+            out
         }
         _ => json!({
             "type": "unsupported",
@@ -1216,6 +1486,26 @@ fn recall_score_to_json(score: &RecallScore) -> JsonValue {
     }
 }
 
+fn search_explanation_to_json(explanation: &SearchExplanation) -> JsonValue {
+    let matches: Vec<JsonValue> = explanation
+        .matches
+        .iter()
+        .map(|m| {
+            json!({
+                "query_term": m.query_term,
+                "term": m.term,
+                "field": m.field.as_str(),
+                "fuzzy": m.is_fuzzy(),
+                "edit_distance": m.edit_distance,
+            })
+        })
+        .collect();
+    json!({
+        "matches": matches,
+        "snippets": explanation.snippets,
+    })
+}
+
 fn fact_correction_to_json(correction: &FactCorrection) -> JsonValue {
     json!({
         "old_fact": fact_to_json(&correction.old_fact),
@@ -2049,6 +2339,51 @@ mod tests {
         assert!(results[0].get("fact").is_some());
     }
 
+    #[test]
+    fn recall_scored_explain_reports_matches_and_snippets() {
+        let mut state = temp_state();
+        for (predicate, object) in [("works_at", "Acme Rocket Works"), ("alias", "ally")] {
+            call_tool(
+                &mut state,
+                Some(&json!({
+                    "name": "assert_fact",
+                    "arguments": { "subject": "alice", "predicate": predicate, "object": object }
+                })),
+            )
+            .unwrap();
+        }
+
+        let out = call_tool(
+            &mut state,
+            Some(&json!({
+                "name": "recall_scored",
+                "arguments": { "query": "ally AND rockte", "explain": true }
+            })),
+        )
+        .unwrap();
+        let results = out["structuredContent"]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        let explanation = &results[0]["explanation"];
+        assert_eq!(
+            explanation["matches"],
+            json!([
+                {"query_term": "ally", "term": "ally", "field": "alias", "fuzzy": false, "edit_distance": 0},
+                {"query_term": "rockte", "term": "rocket", "field": "object", "fuzzy": true, "edit_distance": 1}
+            ])
+        );
+        assert_eq!(explanation["snippets"], json!(["Acme **Rocket** Works"]));
+
+        let err = call_tool(
+            &mut state,
+            Some(&json!({
+                "name": "recall",
+                "arguments": { "query": "ally", "explain": true }
+            })),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("explain requires include_scores"));
+    }
+
     #[test]
     fn recall_scored_rejects_confidence_mode_without_threshold() {
         let mut state = temp_state();
@@ -2331,6 +2666,181 @@ mod tests {
         assert_eq!(on_type, "text");
     }
 
+    #[cfg(feature = "hybrid")]
+    #[test]
+    fn recall_scored_accepts_fusion_strategy() {
+        let mut state = temp_state();
+        let _ = call_tool(
+            &mut state,
+            Some(&json!({
+                "name": "remember",
+                "arguments": {
+                    "text": "Alice loves rust",
+                    "query_embedding": [1.0, 0.0, 0.0]
+                }
+            })),
+        )
+        .unwrap();
+
+        for fusion in ["two_stage", "rrf", "min_max", "z_score"] {
+            let out = call_tool(
+                &mut state,
+                Some(&json!({
+                    "name": "recall_scored",
+                    "arguments": {
+                        "query": "rust",
+                        "query_embedding": [1.0, 0.0, 0.0],
+                        "fusion": fusion,
+                        "limit": 1
+                    }
+                })),
+            )
+            .unwrap();
+            let rows = out
+                .get("structuredContent")
+                .and_then(|v| v.get("results"))
+                .and_then(JsonValue::as_array)
+                .map_or(0, Vec::len);
+            assert_eq!(rows, 1, "fusion {fusion}");
+        }
+
+        let err = call_tool(
+            &mut state,
+            Some(&json!({
+                "name": "recall_scored",
+                "arguments": {
+                    "query": "rust",
+                    "query_embedding": [1.0, 0.0, 0.0],
+                    "fusion": "borda"
+                }
+            })),
+        )
+        .expect_err("unknown fusion should be rejected")
+        .to_string();
+        assert!(err.contains("unknown fusion strategy"));
+    }
+
+    #[cfg(feature = "hybrid")]
+    #[test]
+    fn recall_scored_reports_detected_temporal_intent() {
+        let mut state = temp_state();
+        let _ = call_tool(
+            &mut state,
+            Some(&json!({
+                "name": "remember",
+                "arguments": {
+                    "text": "Alice loves rust",
+                    "query_embedding": [1.0, 0.0, 0.0]
+                }
+            })),
+        )
+        .unwrap();
+
+        let recall = |state: &mut AppState, arguments: JsonValue| {
+            call_tool(
+                state,
+                Some(&json!({ "name": "recall_scored", "arguments": arguments })),
+            )
+        };
+        let out = recall(
+            &mut state,
+            json!({ "query": "rust currently", "query_embedding": [1.0, 0.0, 0.0] }),
+        )
+        .unwrap();
+        let temporal = &out["structuredContent"]["temporal"];
+        assert_eq!(temporal["intent"], "current_state");
+        assert_eq!(temporal["operator"], "current");
+        assert_eq!(temporal["expression"], "currently");
+
+        let out = recall(
+            &mut state,
+            json!({ "query": "rust as of Q3 2024", "query_embedding": [1.0, 0.0, 0.0] }),
+        )
+        .unwrap();
+        let temporal = &out["structuredContent"]["temporal"];
+        assert_eq!(temporal["intent"], "historical_point");
+        assert_eq!(temporal["operator"], "as_of");
+        assert_eq!(temporal["at"], "2024-07-01T00:00:00Z");
+        assert_eq!(temporal["interval"]["end"], "2024-10-01T00:00:00Z");
+        let score = &out["structuredContent"]["results"][0]["score"];
+        assert!(score["temporal_signal"].as_f64().is_some());
+        assert!(score["temporal_adjustment"].as_f64().is_some());
+        assert!(score["rerank_score"].as_f64().is_some());
+        assert_eq!(score["stage1_window"], 14);
+        assert!(score["stage1"] == "kept" || score["stage1"] == "kept_infeasible");
+
+        // Explicit controls and opting out skip detection.
+        for arguments in [
+            json!({
+                "query": "rust in 2023",
+                "query_embedding": [1.0, 0.0, 0.0],
+                "temporal_intent": "timeless"
+            }),
+            json!({
+                "query": "rust in 2023",
+                "query_embedding": [1.0, 0.0, 0.0],
+                "auto_temporal": false
+            }),
+        ] {
+            let out = recall(&mut state, arguments).unwrap();
+            assert!(out["structuredContent"].get("temporal").is_none());
+        }
+
+        let err = recall(
+            &mut state,
+            json!({ "query": "rust", "auto_temporal": true }),
+        )
+        .expect_err("auto_temporal needs an embedding")
+        .to_string();
+        assert!(err.contains("auto_temporal requires query_embedding"));
+
+        // Explicit time bounds: the fact was recorded now, so it overlaps a
+        // valid-time interval reaching into the future but was unknown in 2000.
+        let bounded = |known_at: &str| {
+            json!({
+                "query": "rust",
+                "query_embedding": [1.0, 0.0, 0.0],
+                "temporal_intent": "historical_interval",
+                "valid_interval": {
+                    "start": "2000-01-01T00:00:00Z",
+                    "end": "2999-01-01T00:00:00Z"
+                },
+                "known_at": known_at
+            })
+        };
+        let rows = |out: &JsonValue| {
+            out["structuredContent"]["results"]
+                .as_array()
+                .map_or(0, Vec::len)
+        };
+        let out = recall(&mut state, bounded("2999-01-01T00:00:00Z")).unwrap();
+        assert_eq!(rows(&out), 1);
+        let out = recall(&mut state, bounded("2000-01-01T00:00:00Z")).unwrap();
+        assert_eq!(rows(&out), 0);
+
+        let err = recall(
+            &mut state,
+            json!({
+                "query": "rust",
+                "query_embedding": [1.0, 0.0, 0.0],
+                "valid_interval": {
+                    "start": "2024-10-01T00:00:00Z",
+                    "end": "2024-07-01T00:00:00Z"
+                }
+            }),
+        )
+        .expect_err("reversed interval should be rejected")
+        .to_string();
+        assert!(err.contains("valid_interval.start must be before valid_interval.end"));
+        let err = recall(
+            &mut state,
+            json!({ "query": "rust", "known_at": "2024-07-01T00:00:00Z" }),
+        )
+        .expect_err("time bounds need an embedding")
+        .to_string();
+        assert!(err.contains("valid_interval and known_at require query_embedding"));
+    }
+
     #[test]
     fn correct_fact_returns_new_fact_id() {
         let mut state = temp_state();
@@ -2933,6 +3443,22 @@ mod tests {
             !recall_props.contains_key("temporal_operator"),
             "default build should not advertise temporal_operator"
         );
+        assert!(
+            !recall_props.contains_key("fusion"),
+            "default build should not advertise fusion"
+        );
+        assert!(
+            !recall_props.contains_key("auto_temporal"),
+            "default build should not advertise auto_temporal"
+        );
+        assert!(
+            !recall_props.contains_key("valid_interval"),
+            "default build should not advertise valid_interval"
+        );
+        assert!(
+            !recall_props.contains_key("known_at"),
+            "default build should not advertise known_at"
+        );
     }
 
     #[cfg(not(feature = "uncertainty"))]
@@ -3012,6 +3538,139 @@ mod tests {
         assert!(err.contains("object must be a scalar"));
     }
 
+    #[test]
+    fn assert_fact_accepts_explicit_object_type() {
+        let mut state = temp_state();
+        for (predicate, object, object_type) in [
+            ("employee_id", json!(9007199254740993_i64), "integer"),
+            ("born", json!("1990-05-17T00:00:00Z"), "timestamp"),
+            ("avatar", json!("AJ//"), "bytes"),
+            (
+                "profile",
+                json!({ "company": "Acme", "tags": ["a"] }),
+                "json",
+            ),
+        ] {
+            call_tool(
+                &mut state,
+                Some(&json!({
+                    "name": "assert_fact",
+                    "arguments": {
+                        "subject": "alice",
+                        "predicate": predicate,
+                        "object": object,
+                        "object_type": object_type
+                    }
+                })),
+            )
+            .expect("typed assert should succeed");
+        }
+
+        let out = call_tool(
+            &mut state,
+            Some(&json!({
+                "name": "facts_about",
+                "arguments": { "entity": "alice" }
+            })),
+        )
+        .expect("facts_about should succeed");
+        let facts = out["structuredContent"]["facts"].as_array().unwrap();
+        let by_predicate = |p: &str| {
+            facts
+                .iter()
+                .find(|f| f["predicate"] == p)
+                .unwrap_or_else(|| panic!("missing {p}"))
+        };
+        assert_eq!(by_predicate("employee_id")["object_type"], "integer");
+        assert_eq!(
+            by_predicate("employee_id")["object"],
+            json!(9007199254740993_i64)
+        );
+        assert_eq!(by_predicate("born")["object_type"], "timestamp");
+        assert_eq!(by_predicate("avatar")["object"], "AJ//");
+        assert_eq!(
+            by_predicate("profile")["object"],
+            json!({ "company": "Acme", "tags": ["a"] })
+        );
+
+        let err = call_tool(
+            &mut state,
+            Some(&json!({
+                "name": "assert_fact",
+                "arguments": {
+                    "subject": "alice",
+                    "predicate": "born",
+                    "object": "yesterday",
+                    "object_type": "timestamp"
+                }
+            })),
+        )
+        .expect_err("invalid timestamp should fail")
+        .to_string();
+        assert!(err.contains("Timestamp"), "{err}");
+    }
+
+    #[test]
+    fn assert_fact_metadata_round_trips_and_filters_facts_about() {
+        let mut state = temp_state();
+        for (object, model) in [("Acme", "extractor-v2"), ("Initech", "other")] {
+            call_tool(
+                &mut state,
+                Some(&json!({
+                    "name": "assert_fact",
+                    "arguments": {
+                        "subject": "alice",
+                        "predicate": "works_at",
+                        "object": object,
+                        "idempotency_key": format!("k-{object}"),
+                        "metadata": {
+                            "model": model,
+                            "turn": 3,
+                            "evidence": { "quote": "I work at Acme" }
+                        }
+                    }
+                })),
+            )
+            .expect("assert with metadata should succeed");
+        }
+
+        let out = call_tool(
+            &mut state,
+            Some(&json!({
+                "name": "facts_about",
+                "arguments": {
+                    "entity": "alice",
+                    "metadata": { "model": "extractor-v2" }
+                }
+            })),
+        )
+        .expect("filtered facts_about should succeed");
+        let facts = out["structuredContent"]["facts"].as_array().unwrap();
+        assert_eq!(facts.len(), 1);
+        assert_eq!(facts[0]["object"], "Acme");
+        assert_eq!(facts[0]["metadata"]["turn"], json!(3.0));
+        assert_eq!(
+            facts[0]["metadata"]["evidence"],
+            json!({ "quote": "I work at Acme" })
+        );
+
+        let err = call_tool(
+            &mut state,
+            Some(&json!({
+                "name": "assert_fact",
+                "arguments": {
+                    "subject": "alice",
+                    "predicate": "works_at",
+                    "object": "Acme",
+                    "metadata": ["not", "an", "object"]
+                }
+            })),
+        )
+        .expect_err("non-object metadata should fail")
+        .to_string();
+        assert!(err.contains("metadata must be an object"));
+    }
+
     #[test]
     fn assemble_context_rejects_zero_max_tokens() {
         let mut state = temp_state();
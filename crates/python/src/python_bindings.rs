@@ -13,22 +13,48 @@ use kronroe_core::{Fact, KronroeTimestamp, TemporalGraph, Value};
 use kronroe_core::{TemporalIntent, TemporalOperator};
 use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyBool, PyByteArray, PyBytes, PyDict, PyInt, PyList, PyType};
+use pyo3::IntoPyObjectExt;
 
 fn to_py_err<E: std::fmt::Display>(err: E) -> PyErr {
     PyRuntimeError::new_err(err.to_string())
 }
 
+/// Map a Python object onto a Kronroe `Value`.
+///
+/// `int` becomes `Integer` (exact up to 64 bits), `float` becomes `Number`,
+/// `bytes`/`bytearray` become `Bytes`, timezone-aware `datetime` becomes
+/// `Timestamp`, and `dict`/`list` become `Json`.
 fn py_to_value(obj: &Bound<'_, PyAny>) -> PyResult<Value> {
-    if let Ok(b) = obj.extract::<bool>() {
-        Ok(Value::Boolean(b))
+    let py = obj.py();
+    if obj.is_instance_of::<PyBool>() {
+        Ok(Value::Boolean(obj.extract::<bool>()?))
+    } else if obj.is_instance_of::<PyInt>() {
+        obj.extract::<i64>()
+            .map(Value::Integer)
+            .map_err(|_| PyValueError::new_err("int object must fit in a signed 64-bit integer"))
     } else if let Ok(n) = obj.extract::<f64>() {
         Ok(Value::Number(n))
     } else if let Ok(s) = obj.extract::<String>() {
         Ok(Value::Text(s))
+    } else if obj.is_instance_of::<PyBytes>() || obj.is_instance_of::<PyByteArray>() {
+        Ok(Value::Bytes(obj.extract::<Vec<u8>>()?))
+    } else if obj.is_instance(&py.import("datetime")?.getattr("datetime")?)? {
+        let iso: String = obj.call_method0("isoformat")?.extract()?;
+        KronroeTimestamp::parse_rfc3339(&iso)
+            .map(Value::Timestamp)
+            .map_err(|_| PyValueError::new_err("datetime object must be timezone-aware"))
+    } else if obj.is_instance_of::<PyDict>() || obj.is_instance_of::<PyList>() {
+        let text: String = py
+            .import("json")?
+            .call_method1("dumps", (obj,))?
+            .extract()?;
+        kronroe_core::JsonValue::parse_str(&text)
+            .map(Value::Json)
+            .map_err(|e| PyValueError::new_err(format!("object is not valid JSON: {e}")))
     } else {
         Err(PyTypeError::new_err(
-            "object must be str, int, float, or bool",
+            "object must be str, int, float, bool, bytes, datetime, dict, or list",
         ))
     }
 }
@@ -143,11 +169,7 @@ fn fact_to_dict<'py>(py: Python<'py>, fact: &Fact) -> PyResult<Bound<'py, PyDict
     d.set_item("id", fact.id.as_str())?;
     d.set_item("subject", fact.subject.clone())?;
     d.set_item("predicate", fact.predicate.clone())?;
-    match &fact.object {
-        Value::Text(v) | Value::Entity(v) => d.set_item("object", v)?,
-        Value::Number(v) => d.set_item("object", v)?,
-        Value::Boolean(v) => d.set_item("object", v)?,
-    };
+    d.set_item("object", value_to_py(py, &fact.object)?)?;
     d.set_item(
         "object_type",
         match fact.object {
@@ -155,6 +177,10 @@ fn fact_to_dict<'py>(py: Python<'py>, fact: &Fact) -> PyResult<Bound<'py, PyDict
             Value::Number(_) => "number",
             Value::Boolean(_) => "boolean",
             Value::Entity(_) => "entity",
+            Value::Timestamp(_) => "timestamp",
+            Value::Integer(_) => "integer",
+            Value::Bytes(_) => "bytes",
+            Value::Json(_) => "json",
         },
     )?;
     d.set_item("valid_from", fact.valid_from.to_rfc3339())?;
@@ -163,9 +189,28 @@ fn fact_to_dict<'py>(py: Python<'py>, fact: &Fact) -> PyResult<Bound<'py, PyDict
     d.set_item("expired_at", fact.expired_at.map(|v| v.to_rfc3339()))?;
     d.set_item("confidence", fact.confidence)?;
     d.set_item("source", fact.source.clone())?;
+    let metadata = PyDict::new(py);
+    for (key, value) in &fact.metadata {
+        metadata.set_item(key, value_to_py(py, value)?)?;
+    }
+    d.set_item("metadata", metadata)?;
     Ok(d)
 }
 
+fn value_to_py<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    match value {
+        Value::Text(v) | Value::Entity(v) => v.as_str().into_bound_py_any(py),
+        Value::Number(v) => (*v).into_bound_py_any(py),
+        Value::Boolean(v) => (*v).into_bound_py_any(py),
+        Value::Timestamp(v) => v.to_rfc3339().into_bound_py_any(py),
+        Value::Integer(v) => (*v).into_bound_py_any(py),
+        Value::Bytes(v) => Ok(PyBytes::new(py, v).into_any()),
+        Value::Json(v) => py
+            .import("json")?
+            .call_method1("loads", (v.to_json_string(),)),
+    }
+}
+
 fn facts_to_pylist(py: Python<'_>, facts: Vec<Fact>) -> PyResult<Vec<Py<PyDict>>> {
     let mut out = Vec::with_capacity(facts.len());
     for fact in &facts {
@@ -194,6 +239,9 @@ fn recall_score_to_dict(py: Python<'_>, score: &RecallScore) -> PyResult<Py<PyDi
             rrf_score,
             text_contrib,
             vector_contrib,
+            temporal_adjustment,
+            temporal_signal,
+            rerank_score,
             confidence,
             effective_confidence,
             ..
@@ -202,6 +250,15 @@ fn recall_score_to_dict(py: Python<'_>, score: &RecallScore) -> PyResult<Py<PyDi
             d.set_item("rrf_score", *rrf_score)?;
             d.set_item("text_contrib", *text_contrib)?;
             d.set_item("vector_contrib", *vector_contrib)?;
+            d.set_item("temporal_adjustment", *temporal_adjustment)?;
+            d.set_item("temporal_signal", *temporal_signal)?;
+            d.set_item("rerank_score", *rerank_score)?;
+            #[cfg(feature = "hybrid")]
+            if let RecallScore::Hybrid { stage1, .. } = score {
+                d.set_item("stage1", stage1.name())?;
+                d.set_item("stage1_rank", stage1.rank())?;
+                d.set_item("stage1_window", stage1.window())?;
+            }
             d.set_item("confidence", *confidence)?;
             d.set_item("effective_confidence", *effective_confidence)?;
         }
@@ -1356,7 +1413,7 @@ mod tests {
                         "alice",
                         "nickname",
                         "Bex",
-                        AssertParams { valid_from: old },
+                        AssertParams::new(old),
                         0.4,
                     )
                     .expect("assert nickname");
@@ -1366,7 +1423,7 @@ mod tests {
                         "alice",
                         "email",
                         "alice@example.com",
-                        AssertParams { valid_from: old },
+                        AssertParams::new(old),
                         0.9,
                     )
                     .expect("assert email");
@@ -35,6 +35,16 @@ def run_agent_memory_checks(db_path: Path) -> None:
     base_id = mem.assert_fact("bob", "works_at", "OldCo")
     require(isinstance(base_id, str) and base_id, "assert_fact should return an id")
 
+    # Rich value types round-trip through the typed dict shape.
+    mem.assert_fact("carol", "employee_id", 2**53 + 1)
+    mem.assert_fact("carol", "profile", {"team": "core", "tags": ["a"]})
+    mem.assert_fact("carol", "avatar", b"\x00\x9f")
+    by_predicate = {f["predicate"]: f for f in mem.facts_about("carol")}
+    require(by_predicate["employee_id"]["object"] == 2**53 + 1, "int should stay exact")
+    require(by_predicate["employee_id"]["object_type"] == "integer", "int maps to integer")
+    require(by_predicate["profile"]["object"] == {"team": "core", "tags": ["a"]}, "dict maps to json")
+    require(by_predicate["avatar"]["object"] == b"\x00\x9f", "bytes round-trip")
+
     # Confidence/source path
     conf_id = mem.assert_with_confidence(
         "bob", "works_at", "Acme", 0.92, source="user:smoke"
@@ -114,17 +114,33 @@ fn recall_score_payload(score: &RecallScore) -> JsonValue {
             rrf_score,
             text_contrib,
             vector_contrib,
+            temporal_adjustment,
+            temporal_signal,
+            rerank_score,
             confidence,
             effective_confidence,
             ..
-        } => json!({
-            "type": "hybrid",
-            "rrf_score": rrf_score,
-            "text_contrib": text_contrib,
-            "vector_contrib": vector_contrib,
-            "confidence": confidence,
-            "effective_confidence": effective_confidence,
-        }),
+        } => {
+            #[cfg_attr(not(feature = "hybrid"), allow(unused_mut))]
+            let mut out = json!({
+                "type": "hybrid",
+                "rrf_score": rrf_score,
+                "text_contrib": text_contrib,
+                "vector_contrib": vector_contrib,
+                "temporal_adjustment": temporal_adjustment,
+                "temporal_signal": temporal_signal,
+                "rerank_score": rerank_score,
+                "confidence": confidence,
+                "effective_confidence": effective_confidence,
+            });
+            #[cfg(feature = "hybrid")]
+            if let RecallScore::Hybrid { stage1, .. } = score {
+                out["stage1"] = json!(stage1.name());
+                out["stage1_rank"] = json!(stage1.rank());
+                out["stage1_window"] = json!(stage1.window());
+            }
+            out
+        }
         RecallScore::TextOnly {
             rank,
             bm25_score,
@@ -207,7 +223,7 @@ impl WasmGraph {
         let valid_from = parse_valid_from(valid_from_iso)?;
         let id = self
             .inner
-            .assert_with_params(subject, predicate, object, AssertParams { valid_from })
+            .assert_with_params(subject, predicate, object, AssertParams::new(valid_from))
             .map_err(to_js_err)?;
         Ok(id.to_string())
     }
@@ -257,6 +273,69 @@ impl WasmGraph {
         Ok(id.to_string())
     }
 
+    /// Assert an exact 64-bit integer fact (JS `BigInt`).
+    #[wasm_bindgen]
+    pub fn assert_integer_fact(
+        &self,
+        subject: &str,
+        predicate: &str,
+        value: i64,
+    ) -> Result<String, JsValue> {
+        let id = self
+            .inner
+            .assert(subject, predicate, Value::Integer(value))
+            .map_err(to_js_err)?;
+        Ok(id.to_string())
+    }
+
+    /// Assert a timestamp fact (ISO 8601 / RFC 3339 string).
+    #[wasm_bindgen]
+    pub fn assert_timestamp_fact(
+        &self,
+        subject: &str,
+        predicate: &str,
+        value_iso: &str,
+    ) -> Result<String, JsValue> {
+        let value = parse_valid_from(value_iso)?;
+        let id = self
+            .inner
+            .assert(subject, predicate, Value::Timestamp(value))
+            .map_err(to_js_err)?;
+        Ok(id.to_string())
+    }
+
+    /// Assert a binary fact (`Uint8Array`). Stored facts report it as base64.
+    #[wasm_bindgen]
+    pub fn assert_bytes_fact(
+        &self,
+        subject: &str,
+        predicate: &str,
+        value: Vec<u8>,
+    ) -> Result<String, JsValue> {
+        let id = self
+            .inner
+            .assert(subject, predicate, Value::Bytes(value))
+            .map_err(to_js_err)?;
+        Ok(id.to_string())
+    }
+
+    /// Assert a structured JSON fact from a JSON string (e.g. `JSON.stringify(obj)`).
+    #[wasm_bindgen]
+    pub fn assert_json_fact(
+        &self,
+        subject: &str,
+        predicate: &str,
+        json: &str,
+    ) -> Result<String, JsValue> {
+        let value = kronroe::JsonValue::parse_str(json)
+            .map_err(|e| JsValue::from_str(&format!("invalid JSON: {e}")))?;
+        let id = self
+            .inner
+            .assert(subject, predicate, Value::Json(value))
+            .map_err(to_js_err)?;
+        Ok(id.to_string())
+    }
+
     /// Assert a numeric fact with a specific valid_from timestamp (ISO 8601).
     #[wasm_bindgen]
     pub fn assert_number_fact_at(
@@ -273,7 +352,7 @@ impl WasmGraph {
                 subject,
                 predicate,
                 Value::Number(value),
-                AssertParams { valid_from },
+                AssertParams::new(valid_from),
             )
             .map_err(to_js_err)?;
         Ok(id.to_string())
@@ -295,7 +374,7 @@ impl WasmGraph {
                 subject,
                 predicate,
                 Value::Boolean(value),
-                AssertParams { valid_from },
+                AssertParams::new(valid_from),
             )
             .map_err(to_js_err)?;
         Ok(id.to_string())
@@ -317,7 +396,7 @@ impl WasmGraph {
                 subject,
                 predicate,
                 Value::Entity(entity.to_string()),
-                AssertParams { valid_from },
+                AssertParams::new(valid_from),
             )
             .map_err(to_js_err)?;
         Ok(id.to_string())
@@ -663,6 +742,30 @@ mod tests {
         assert!(all.contains("acme_corp"));
     }
 
+    #[test]
+    fn wasm_graph_rich_typed_values() {
+        let graph = WasmGraph::open().unwrap();
+
+        graph
+            .assert_integer_fact("alice", "employee_id", i64::MAX)
+            .unwrap();
+        graph
+            .assert_timestamp_fact("alice", "born", "1990-05-17T00:00:00Z")
+            .unwrap();
+        graph
+            .assert_bytes_fact("alice", "avatar", vec![0, 159, 255])
+            .unwrap();
+        graph
+            .assert_json_fact("alice", "profile", r#"{"team":"core"}"#)
+            .unwrap();
+
+        let all = graph.all_facts_about("alice").unwrap();
+        assert!(all.contains(r#""type":"Integer","value":"9223372036854775807""#));
+        assert!(all.contains(r#""type":"Timestamp","value":"1990-05-17T00:00:00Z""#));
+        assert!(all.contains(r#""type":"Bytes","value":"AJ//""#));
+        assert!(all.contains(r#""type":"Json","value":{"team":"core"}"#));
+    }
+
     #[test]
     fn wasm_graph_temporal_query() {
         let graph = WasmGraph::open().unwrap();
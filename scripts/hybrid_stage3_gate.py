@@ -22,6 +22,34 @@ def _write_json(path: Path, payload: dict) -> None:
     path.write_text(json.dumps(payload, indent=2, sort_keys=True) + "\n", encoding="utf-8")
 
 
+def _compare_fusion(summaries: list[dict]) -> dict[str, dict]:
+    """Aggregate per-strategy fusion results across wrapper summaries."""
+    comparison: dict[str, dict] = {}
+    for item in summaries:
+        fusion = item.get("fusion")
+        if not isinstance(fusion, dict):
+            continue
+        for strategy, result in fusion.items():
+            if not isinstance(result, dict):
+                continue
+            entry = comparison.setdefault(
+                strategy, {"runs": 0, "passed": 0, "hits_at_1": 0, "latencies_ms": []}
+            )
+            entry["runs"] += 1
+            if result.get("status") == "pass":
+                entry["passed"] += 1
+            if result.get("hit_at_1") is True:
+                entry["hits_at_1"] += 1
+            if isinstance(result.get("latency_ms"), int):
+                entry["latencies_ms"].append(result["latency_ms"])
+
+    for entry in comparison.values():
+        latencies = entry.pop("latencies_ms")
+        entry["hit_at_1_rate"] = entry["hits_at_1"] / entry["runs"]
+        entry["mean_latency_ms"] = sum(latencies) / len(latencies) if latencies else None
+    return comparison
+
+
 def main() -> int:
     parser = argparse.ArgumentParser(
         description="Compute a single PASS/FAIL gate decision from wrapper smoke summaries."
@@ -43,6 +71,15 @@ def main() -> int:
         default=60000,
         help="Maximum allowed duration per wrapper run (default: 60000).",
     )
+    parser.add_argument(
+        "--require-fusion",
+        action="append",
+        default=[],
+        help=(
+            "Fusion strategy (two_stage, rrf, min_max, z_score) that must pass with "
+            "hit@1 in every summary that reports fusion results. Repeatable."
+        ),
+    )
     args = parser.parse_args()
 
     input_paths = [Path(raw).resolve() for raw in args.input]
@@ -88,6 +125,17 @@ def main() -> int:
     if missing:
         failures.append(f"missing required wrapper summaries: {', '.join(missing)}")
 
+    fusion_comparison = _compare_fusion(summaries)
+    for strategy in args.require_fusion:
+        entry = fusion_comparison.get(strategy)
+        if entry is None:
+            failures.append(f"fusion {strategy}: no results reported")
+        elif entry["passed"] != entry["runs"] or entry["hits_at_1"] != entry["runs"]:
+            failures.append(
+                f"fusion {strategy}: passed {entry['passed']}/{entry['runs']}, "
+                f"hit@1 {entry['hits_at_1']}/{entry['runs']}"
+            )
+
     decision = "PASS" if not failures else "FAIL"
     payload = {
         "schema_version": "1.0",
@@ -97,12 +145,21 @@ def main() -> int:
         "required_wrappers": sorted(required_wrappers),
         "inputs": [str(path) for path in input_paths],
         "per_wrapper": per_wrapper,
+        "required_fusion": sorted(args.require_fusion),
+        "fusion_comparison": fusion_comparison,
         "failures": failures,
     }
 
     if args.output:
         _write_json(Path(args.output).resolve(), payload)
 
+    for strategy, entry in sorted(fusion_comparison.items()):
+        latency = entry["mean_latency_ms"]
+        latency_text = f"{latency:.1f}ms" if latency is not None else "n/a"
+        print(
+            f"fusion {strategy}: hit@1 {entry['hit_at_1_rate']:.2f} "
+            f"({entry['hits_at_1']}/{entry['runs']}), mean latency {latency_text}"
+        )
     print(f"HYBRID_STAGE3_WRAPPER_GATE={decision}")
     if failures:
         print("Gate failures:", file=sys.stderr)
@@ -14,6 +14,17 @@ from typing import BinaryIO
 
 REPO_ROOT = Path(__file__).resolve().parent.parent
 
+FUSION_STRATEGIES = ("two_stage", "rrf", "min_max", "z_score")
+
+# Labeled fixture for comparing fusion strategies: (text, embedding).
+FUSION_FIXTURE = [
+    ("bob maintains the Rust compiler", [0.9, 0.1, 0.0]),
+    ("carol gardens on weekends", [0.0, 0.0, 1.0]),
+    ("dave writes Python and some Rust", [0.5, 0.5, 0.0]),
+]
+FUSION_QUERY = {"query": "rust compiler", "query_embedding": [1.0, 0.0, 0.0]}
+FUSION_EXPECTED_TOP = "bob maintains the Rust compiler"
+
 
 def _write_message(stdin: BinaryIO, payload: dict) -> None:
     body = json.dumps(payload, separators=(",", ":")).encode("utf-8")
@@ -146,7 +157,52 @@ def _prepare_wrapper_install(wrapper: str, tmp: Path) -> None:
     _prepare_python_wrapper(tmp)
 
 
-def _run_smoke(wrapper: str, binary: Path) -> None:
+def _call_tool(stdin: BinaryIO, stdout: BinaryIO, request_id: int, name: str, arguments: dict) -> dict:
+    _write_message(
+        stdin,
+        {
+            "jsonrpc": "2.0",
+            "id": request_id,
+            "method": "tools/call",
+            "params": {"name": name, "arguments": arguments},
+        },
+    )
+    return _read_message(stdout)
+
+
+def _compare_fusion(stdin: BinaryIO, stdout: BinaryIO, first_id: int) -> dict:
+    """Run the labeled fixture query once per fusion strategy."""
+    request_id = first_id
+    for text, embedding in FUSION_FIXTURE:
+        _call_tool(stdin, stdout, request_id, "remember", {"text": text, "query_embedding": embedding})
+        request_id += 1
+
+    results: dict[str, dict] = {}
+    for strategy in FUSION_STRATEGIES:
+        start = time.perf_counter()
+        response = _call_tool(
+            stdin,
+            stdout,
+            request_id,
+            "recall_scored",
+            {**FUSION_QUERY, "use_hybrid": True, "fusion": strategy, "limit": 3},
+        )
+        request_id += 1
+        latency_ms = int((time.perf_counter() - start) * 1000)
+        rows = response.get("result", {}).get("structuredContent", {}).get("results", [])
+        if "error" in response or not rows:
+            results[strategy] = {"status": "fail", "latency_ms": latency_ms, "error": str(response)}
+            continue
+        top_text = rows[0].get("fact", {}).get("object")
+        results[strategy] = {
+            "status": "pass",
+            "latency_ms": latency_ms,
+            "hit_at_1": top_text == FUSION_EXPECTED_TOP,
+        }
+    return results
+
+
+def _run_smoke(wrapper: str, binary: Path) -> dict:
     with tempfile.TemporaryDirectory(prefix=f"kronroe-{wrapper}-wrapper-smoke-") as tmpdir:
         tmp = Path(tmpdir)
         _prepare_wrapper_install(wrapper, tmp)
@@ -232,6 +288,8 @@ def _run_smoke(wrapper: str, binary: Path) -> None:
                 raise RuntimeError(
                     f"expected hybrid score type via {wrapper}, got {score_type!r}: {scored}"
                 )
+
+            return _compare_fusion(proc.stdin, proc.stdout, first_id=4)
         finally:
             if proc.stdin:
                 proc.stdin.close()
@@ -281,7 +339,7 @@ def main() -> int:
     start = time.perf_counter()
     summary_path = Path(args.summary_out).resolve() if args.summary_out else None
     try:
-        _run_smoke(args.wrapper, binary)
+        fusion = _run_smoke(args.wrapper, binary)
         duration_ms = int((time.perf_counter() - start) * 1000)
         if summary_path is not None:
             _write_summary(
@@ -292,6 +350,7 @@ def main() -> int:
                     "status": "pass",
                     "duration_ms": duration_ms,
                     "timestamp_unix": int(time.time()),
+                    "fusion": fusion,
                 },
             )
         print(f"{args.wrapper} wrapper hybrid smoke: PASS")